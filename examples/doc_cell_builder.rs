@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a cell with a value, format,
+//! note and hyperlink in a single call.
+
+use rust_xlsxwriter::{CellBuilder, Format, Note, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new().set_bold().set_font_color("#0000FF");
+
+    // Create a cell with a value and several properties.
+    let cell = CellBuilder::new("Rust")
+        .set_format(&format)
+        .set_note(&Note::new("The Rust home page"))
+        .set_url("https://www.rust-lang.org")?;
+
+    // Write the cell to the worksheet.
+    worksheet.write_cell(1, 1, &cell)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating setting multi-level categories for a chart
+//! series.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart. The quarters are the outer category level
+    // and the months are the inner category level.
+    worksheet.write_column(0, 0, ["Q1", "", "", "Q2", "", ""])?;
+    worksheet.write_column(0, 1, ["Jan", "Feb", "Mar", "Apr", "May", "Jun"])?;
+    worksheet.write_column(0, 2, [50, 30, 40, 60, 35, 45])?;
+
+    // Create a new chart with a 2 column category range.
+    let mut chart = Chart::new(ChartType::Column);
+    chart
+        .add_series()
+        .set_categories("Sheet1!$A$1:$B$6")
+        .set_values("Sheet1!$C$1:$C$6");
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 4, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
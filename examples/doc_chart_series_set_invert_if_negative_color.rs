@@ -3,8 +3,8 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 //! A chart example demonstrating setting the "Invert if negative" property and
-//! associated color for a chart series. This also requires that you set a solid
-//! fill color for the series.
+//! associated color for a chart series, along with a solid fill color for the
+//! positive values.
 
 use rust_xlsxwriter::{Chart, ChartSolidFill, ChartType, Workbook, XlsxError};
 
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating setting the chart series categories and
+//! values from literal data.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+
+    // Add a data series using literal data instead of a worksheet range.
+    chart
+        .add_series()
+        .set_categories_from_strings(&["Jan", "Feb", "Mar"])
+        .set_values_from_values(&[50.0, 30.0, 40.0]);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 0, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating displaying empty cells as connected lines and
+//! including data from hidden rows.
+
+use rust_xlsxwriter::{Chart, ChartEmptyCells, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart, with a gap and a hidden row.
+    worksheet.write(0, 0, 5)?;
+    worksheet.write(1, 0, 30)?;
+    worksheet.write(3, 0, 30)?;
+    worksheet.write(4, 0, 5)?;
+    worksheet.write(5, 0, 20)?;
+    worksheet.set_row_hidden(4)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Line);
+
+    // Add a data series using Excel formula syntax to describe the range.
+    chart.add_series().set_values("Sheet1!$A$1:$A$6");
+
+    // Connect the points on either side of the empty cell and include the
+    // hidden row in the chart.
+    chart.show_empty_cells_as(ChartEmptyCells::Connected);
+    chart.show_hidden_data();
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
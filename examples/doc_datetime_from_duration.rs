@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a time duration, that is
+//! greater than 24 hours, with an elapsed time format.
+
+use std::time::Duration;
+
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create an elapsed time format for durations.
+    let format = Format::new().set_num_format("[h]:mm");
+
+    // Create a datetime object from a 37.5 hour duration.
+    let duration = ExcelDateTime::from_duration(Duration::from_secs(37 * 3600 + 1800))?;
+
+    // Write the duration. It will be displayed as "37:30".
+    worksheet.write_with_format(0, 0, &duration, &format)?;
+
+    workbook.save("datetime.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates using the format preset constructors.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some headers.
+    worksheet.write_row_with_format(0, 0, ["Item", "Cost"], &Format::header())?;
+
+    // Write some data.
+    worksheet.write_with_format(1, 0, "Rent", &Format::italic())?;
+    worksheet.write_with_format(1, 1, 1000, &Format::money())?;
+
+    // The presets can be extended with other properties.
+    let format = Format::money().set_bold();
+
+    worksheet.write_with_format(2, 0, "Total", &Format::bold())?;
+    worksheet.write_with_format(2, 1, 1000, &format)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates resetting the alignment of a copy of a
+//! format so that numbers revert to the default right alignment.
+
+use rust_xlsxwriter::{Format, FormatAlign, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format1 = Format::new().set_bold().set_align(FormatAlign::Center);
+
+    // Keep the bold property but use the default alignment.
+    let format2 = format1.clone().reset_alignment();
+
+    worksheet.write_number_with_format(0, 0, 123, &format1)?;
+    worksheet.write_number_with_format(1, 0, 123, &format2)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting locale aware currency number
+//! formats.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Set column width for clarity.
+    worksheet.set_column_width(0, 20)?;
+
+    let format1 = Format::new().set_num_format_locale("EUR", 0x0407);
+    let format2 = Format::new().set_num_format_locale("GBP", 0x0809);
+    let format3 = Format::new().set_num_format_locale("JPY", 0x0411);
+
+    worksheet.write_number_with_format(0, 0, 1234.56, &format1)?;
+    worksheet.write_number_with_format(1, 0, 1234.56, &format2)?;
+    worksheet.write_number_with_format(2, 0, 1234.56, &format3)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a note with a long line of text
+//! and with multiple lines to a worksheet and sizing them to fit the text.
+
+use rust_xlsxwriter::{Note, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Create some notes that would overflow the default note box.
+    let note1 = Note::new("This is a longer note that would be truncated in the default note box.")
+        .set_autosize(true)
+        .set_visible(true);
+
+    let note2 =
+        Note::new("Monday: 8 hours\nTuesday: 7.5 hours\nWednesday: 8 hours\nThursday: 9 hours")
+            .set_autosize(true)
+            .set_visible(true);
+
+    // Add the notes to worksheet cells.
+    worksheet.insert_note(2, 0, &note1)?;
+    worksheet.insert_note(8, 0, &note2)?;
+
+    // Save the file to disk.
+    workbook.save("notes.xlsx")?;
+
+    Ok(())
+}
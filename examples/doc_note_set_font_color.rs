@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a note to a worksheet cell with a
+//! dark background color and a white font.
+
+use rust_xlsxwriter::{Color, Note, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new note.
+    let note = Note::new("Some text for the note")
+        .set_background_color("#1F4E78")
+        .set_font_color(Color::White)
+        .set_font_name("Arial")
+        .set_font_size(10);
+
+    // Add the note to a worksheet cell.
+    worksheet.insert_note(2, 0, &note)?;
+
+    // Save the file to disk.
+    workbook.save("notes.xlsx")?;
+
+    Ok(())
+}
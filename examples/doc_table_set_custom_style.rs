@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of setting a custom style for a worksheet table.
+
+use rust_xlsxwriter::{Color, Format, FormatBorder, Table, TableCustomStyle, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample data for the table.
+    let items = ["Apples", "Pears", "Bananas", "Oranges"];
+    let data = [
+        [10000, 5000, 8000, 6000],
+        [2000, 3000, 4000, 5000],
+        [6000, 6000, 6500, 6000],
+        [500, 300, 200, 700],
+    ];
+
+    // Write the table data.
+    worksheet.write_column(3, 1, items)?;
+    worksheet.write_row_matrix(3, 2, data)?;
+
+    // Set the column widths for clarity.
+    worksheet.set_column_range_width(1, 6, 12)?;
+
+    // Create a custom table style with brand colors.
+    let style = TableCustomStyle::new("BrandStyle")
+        .set_whole_table_format(
+            Format::new()
+                .set_border(FormatBorder::Thin)
+                .set_border_color("#1F3864"),
+        )
+        .set_header_row_format(
+            Format::new()
+                .set_bold()
+                .set_font_color(Color::White)
+                .set_background_color("#1F3864"),
+        )
+        .set_first_row_stripe_format(Format::new().set_background_color("#D9E2F3"));
+
+    // Create a new table and set the custom style.
+    let table = Table::new().set_custom_style(&style);
+
+    // Add the table to the worksheet.
+    worksheet.add_table(2, 1, 6, 5, &table)?;
+
+    // Save the file to disk.
+    workbook.save("tables.xlsx")?;
+
+    Ok(())
+}
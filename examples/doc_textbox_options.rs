@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates inserting a text box into a worksheet.
+
+use rust_xlsxwriter::{TextBoxOptions, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some text box options.
+    let options = TextBoxOptions::new()
+        .set_width(256)
+        .set_height(100)
+        .set_fill_color("#FFFFC0")
+        .set_border_color("#FF0000")
+        .set_font_size(14)
+        .set_font_bold();
+
+    // Insert a text box.
+    worksheet.insert_textbox(1, 1, "This is some text\nin a text box", &options)?;
+
+    // Save the file to disk.
+    workbook.save("textbox.xlsx")?;
+
+    Ok(())
+}
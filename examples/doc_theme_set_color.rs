@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a custom workbook theme with
+//! brand colors and fonts.
+
+use rust_xlsxwriter::{Color, Format, Theme, ThemeColor, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Create a theme with custom accent colors and fonts.
+    let theme = Theme::new()
+        .set_name("Brand")
+        .set_color(ThemeColor::Accent1, "1B5E20")
+        .set_color(ThemeColor::Accent2, Color::RGB(0xF9A825))
+        .set_major_font("Georgia")
+        .set_minor_font("Verdana");
+
+    workbook.set_theme(&theme);
+
+    // Formats that use theme colors will pick up the custom palette.
+    let format1 = Format::new().set_background_color(Color::Theme(4, 0));
+    let format2 = Format::new().set_background_color(Color::Theme(5, 0));
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string_with_format(0, 0, "Accent 1", &format1)?;
+    worksheet.write_string_with_format(1, 0, "Accent 2", &format2)?;
+
+    workbook.save("theme.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a threaded comment, with a
+//! reply, to a worksheet cell.
+
+use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add the comment authors to the workbook persons registry.
+    let jane = workbook.add_person("Jane Smith", "jane@example.com");
+    let john = workbook.add_person("John Smith", "john@example.com");
+
+    // Create a threaded comment with a reply.
+    let comment = ThreadedComment::new("Should this be higher?")
+        .set_author(jane)
+        .add_reply(john, "No, it is correct.");
+
+    // Add the threaded comment to a worksheet cell.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_number(0, 0, 1234)?;
+    worksheet.insert_threaded_comment(0, 0, &comment)?;
+
+    // Save the file to disk.
+    workbook.save("threaded_comments.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding people to the workbook persons
+//! registry and using them as the authors of a threaded comment and a reply.
+
+use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add the comment authors to the persons registry.
+    let jane = workbook.add_person("Jane Smith", "jane@example.com");
+    let john = workbook.add_person("John Smith", "john@example.com");
+
+    let comment = ThreadedComment::new("Is this correct?")
+        .set_author(jane)
+        .add_reply(john, "Yes.");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.insert_threaded_comment(0, 0, &comment)?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
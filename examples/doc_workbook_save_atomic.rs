@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates saving a workbook via a temporary file.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, "Hello")?;
+
+    // The file is written to "workbook.xlsx.tmp" and then renamed.
+    workbook.save_atomic("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates saving a workbook and reporting the
+//! progress of the worksheets.
+
+use rust_xlsxwriter::{SaveProgress, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    for _ in 0..3 {
+        let worksheet = workbook.add_worksheet();
+        worksheet.write(0, 0, "Hello")?;
+    }
+
+    workbook.save_with_progress("workbook.xlsx", |progress: SaveProgress| {
+        if progress.part_name.starts_with("xl/worksheets/sheet") {
+            println!(
+                "Written worksheet {} of {}",
+                progress.worksheets_written, progress.worksheet_count
+            );
+        }
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the workbook calculation id to
+//! 0 to force a full recalculation of the formulas when the file is opened.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_calc_id(0);
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_column(0, 0, [10, 20, 30])?;
+    worksheet.write_formula(3, 0, "=SUM(A1:A3)")?;
+
+    workbook.save("formulas.xlsx")?;
+
+    Ok(())
+}
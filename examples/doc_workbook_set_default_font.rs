@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the default font of a workbook.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Use Arial 10 as the default font.
+    workbook.set_default_font("Arial", 10);
+
+    let bold = Format::new().set_bold();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_with_format(0, 0, "Arial 10 bold", &bold)?;
+    worksheet.write(1, 0, "Arial 10")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
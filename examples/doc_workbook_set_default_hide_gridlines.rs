@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates hiding the screen gridlines for all new
+//! worksheets in a workbook.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Hide the gridlines for all the worksheets added after this point.
+    workbook.set_default_hide_gridlines(true);
+
+    for _ in 0..5 {
+        workbook.add_worksheet();
+    }
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
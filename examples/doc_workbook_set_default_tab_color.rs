@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a default tab color for a group
+//! of new worksheets and then changing it for the next group.
+
+use rust_xlsxwriter::{Color, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_default_tab_color(Color::Green);
+    workbook.add_worksheet();
+    workbook.add_worksheet();
+
+    workbook.set_default_tab_color(Color::Red);
+    workbook.add_worksheet();
+    workbook.add_worksheet();
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
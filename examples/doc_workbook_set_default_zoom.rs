@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a default zoom level for all new
+//! worksheets in a workbook.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Set the zoom for all the worksheets added after this point.
+    workbook.set_default_zoom(150);
+
+    for _ in 0..5 {
+        workbook.add_worksheet();
+    }
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing formulas with German function
+//! names.
+
+use rust_xlsxwriter::{FormulaLocale, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_formula_locale(FormulaLocale::German);
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_column(0, 0, [10, 20, 30])?;
+
+    // Write formulas using German function names.
+    worksheet.write_formula(0, 1, "=SUMME(A1:A3)")?;
+    worksheet.write_formula(1, 1, "=WENN(A1>15,\"Groß\",\"Klein\")")?;
+    worksheet.write_formula(2, 1, "=MITTELWERT(A1:A3)")?;
+
+    workbook.save("formulas.xlsx")?;
+
+    Ok(())
+}
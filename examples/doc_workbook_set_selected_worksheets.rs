@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates selecting a group of worksheets.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.add_worksheet().set_name("January")?;
+    workbook.add_worksheet().set_name("February")?;
+    workbook.add_worksheet().set_name("March")?;
+    workbook.add_worksheet().set_name("Summary")?;
+
+    // Select the monthly worksheets as a group.
+    workbook.set_selected_worksheets(&[0, 1, 2])?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
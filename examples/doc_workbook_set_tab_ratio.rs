@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the ratio of the worksheet tab
+//! bar to the horizontal scrollbar.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    for _ in 0..20 {
+        workbook.add_worksheet();
+    }
+
+    workbook.set_tab_ratio(80);
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the workbook theme to one of
+//! the preset themes.
+
+use rust_xlsxwriter::{Color, Format, Theme, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Use the Excel 2013-2022 color palette.
+    workbook.set_theme(&Theme::office_2013());
+
+    let format = Format::new().set_background_color(Color::Theme(4, 0));
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string_with_format(0, 0, "Accent 1", &format)?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
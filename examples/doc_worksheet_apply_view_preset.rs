@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a presentation view for a
+//! worksheet.
+
+use rust_xlsxwriter::{ViewPreset, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_row(0, 0, ["Region", "Sales"])?;
+    worksheet.write_column(1, 0, ["North", "South", "East", "West"])?;
+    worksheet.write_column(1, 1, [1200, 800, 1500, 950])?;
+
+    // Hide the gridlines and headers, zoom in and freeze the header row.
+    worksheet.apply_view_preset(ViewPreset::Presentation);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates autofitting the columns of serialized
+//! data without autofitting the rest of the worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Customer {
+        name: &'static str,
+        email: &'static str,
+        city: &'static str,
+    }
+
+    // Create some data instances.
+    let customers = [
+        Customer {
+            name: "Alice Anderson",
+            email: "alice.anderson@example.com",
+            city: "Amsterdam",
+        },
+        Customer {
+            name: "Bob Brown",
+            email: "bob@example.com",
+            city: "Buenos Aires",
+        },
+    ];
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.deserialize_headers::<Customer>(0, 0)?;
+
+    // Serialize the data.
+    worksheet.serialize(&customers)?;
+
+    // Autofit the columns of the serialized data.
+    worksheet.autofit_serialized_columns();
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
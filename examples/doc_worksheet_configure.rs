@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates configuring several worksheets with the
+//! same options.
+
+use rust_xlsxwriter::{Color, Workbook, WorksheetOptions, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let options = WorksheetOptions::new()
+        .set_tab_color(Color::Green)
+        .set_zoom(150)
+        .set_screen_gridlines(false)
+        .set_protection_password("abc123");
+
+    for region in ["North", "South", "East", "West"] {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(region)?;
+        worksheet.configure(&options)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
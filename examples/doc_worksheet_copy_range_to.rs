@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates copying a range of cells from one
+//! worksheet into a new workbook.
+
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut worksheet = Worksheet::new();
+    let bold = Format::new().set_bold();
+
+    // Add some data to the worksheet.
+    worksheet.merge_range(0, 0, 0, 1, "Sales", &bold)?;
+    worksheet.write_row(1, 0, ["North", "South"])?;
+    worksheet.write_row(2, 0, [100, 200])?;
+    worksheet.write_formula(3, 0, "=SUM(A3:B3)")?;
+
+    // Copy part of the data to a worksheet in a new workbook. The
+    // formula is adjusted to "=SUM(B4:C4)".
+    let mut summary = Worksheet::new();
+    worksheet.copy_range_to(&mut summary, 0, 0, 3, 1, 1, 1)?;
+
+    let mut workbook = Workbook::new();
+    workbook.push_worksheet(summary);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates ignoring Excel warnings in worksheet
+//! cells.
+
+use rust_xlsxwriter::{IgnoreError, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write some numbers as strings.
+    worksheet.write_string(0, 0, "123")?;
+    worksheet.write_string(1, 0, "456")?;
+    worksheet.write_string(2, 0, "789")?;
+
+    // Turn off the "Number Stored as Text" warning in the first cell.
+    worksheet.ignore_error(0, 0, IgnoreError::NumberStoredAsText)?;
+
+    // Turn off the warning in the remaining cells.
+    worksheet.ignore_error_range(1, 0, 2, 0, IgnoreError::NumberStoredAsText)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
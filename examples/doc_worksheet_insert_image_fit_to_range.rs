@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates inserting an image into a worksheet and
+//! scaling it to fit a range of cells.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object.
+    let image = Image::new("examples/rust_logo.png")?;
+
+    // Insert the image and scale it to fit the range B2:D8.
+    worksheet.insert_image_fit_to_range(1, 1, 7, 3, &image, false)?;
+
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! This example shows how to add an image with a hyperlink to a worksheet.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object.
+    let image = Image::new("examples/rust_logo.png")?;
+
+    // Insert the image with a link to the Rust home page.
+    worksheet.insert_image_with_url(1, 2, &image, "https://www.rust-lang.org")?;
+
+    // Save the file to disk.
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding several notes to a worksheet at
+//! once.
+
+use std::collections::HashMap;
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_column(0, 0, [10, 25, 40])?;
+
+    // Annotate some of the cells.
+    let notes = HashMap::from([
+        ((0, 0), "Below target"),
+        ((2, 0), "Above target"),
+        ((4, 0), "Awaiting data"),
+    ]);
+
+    worksheet.insert_notes(&notes)?;
+
+    workbook.save("notes.xlsx")?;
+
+    Ok(())
+}
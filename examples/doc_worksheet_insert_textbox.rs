@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates inserting a text box into a worksheet.
+
+use rust_xlsxwriter::{TextBoxOptions, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Insert a text box with the default options.
+    let options = TextBoxOptions::new();
+    worksheet.insert_textbox(1, 1, "Hello", &options)?;
+
+    // Insert a text box with a fill color and a larger font.
+    let options = TextBoxOptions::new()
+        .set_fill_color("#DDEBF7")
+        .set_font_size(14);
+    worksheet.insert_textbox(1, 5, "World", &options)?;
+
+    // Save the file.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
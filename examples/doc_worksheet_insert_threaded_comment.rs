@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding threaded comments to a
+//! worksheet. Several comments and replies share the same authors.
+
+use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add the comment authors to the workbook persons registry.
+    let jane = workbook.add_person("Jane Smith", "jane@example.com");
+    let john = workbook.add_person("John Smith", "john@example.com");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_column(0, 0, [1234, 5678])?;
+
+    // Add a threaded comment with replies.
+    let comment = ThreadedComment::new("Should this be higher?")
+        .set_author(jane)
+        .add_reply(john, "No, it is correct.")
+        .add_reply(jane, "Thanks.")
+        .set_resolved(true);
+
+    worksheet.insert_threaded_comment(0, 0, &comment)?;
+
+    // Add another threaded comment by the same author.
+    let comment = ThreadedComment::new("Please check this value.").set_author(john);
+
+    worksheet.insert_threaded_comment(1, 0, &comment)?;
+
+    // Save the file to disk.
+    workbook.save("threaded_comments.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates merging a range of cells with a rich
+//! string that contains a bold title and a normal subtitle.
+
+use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let default = Format::default();
+    let bold = Format::new().set_bold().set_font_size(14);
+
+    let segments = [
+        (&bold, "Quarterly Report"),
+        (&default, " - Sales by region"),
+    ];
+
+    // Format for the merged range.
+    let format = Format::new()
+        .set_align(FormatAlign::Center)
+        .set_align(FormatAlign::VerticalCenter)
+        .set_border(FormatBorder::Thin);
+
+    worksheet.merge_range_rich(1, 1, 2, 6, &segments, &format)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates merging several ranges with the same
+//! format in one call.
+
+use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new()
+        .set_align(FormatAlign::Center)
+        .set_border(FormatBorder::Thin);
+
+    // Merge a 2x2 block of cells in each quadrant of a grid.
+    let ranges = [(1, 1, 2, 2), (1, 4, 2, 5), (4, 1, 5, 2), (4, 4, 5, 5)];
+
+    worksheet.merge_ranges(&ranges, "", &format)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
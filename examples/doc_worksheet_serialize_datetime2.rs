@@ -56,7 +56,7 @@ fn main() -> Result<(), XlsxError> {
     ];
 
     // Set up the start location and headers of the data to be serialized. Note,
-    // we need to add a cell format for the datetime data.
+    // we add a custom cell format for the datetime data.
     let custom_headers = [
         CustomSerializeField::new("name").rename("Student"),
         CustomSerializeField::new("dob")
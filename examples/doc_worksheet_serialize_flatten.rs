@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing a struct with a
+//! `#[serde(flatten)]` sub-struct field. The fields of the sub-struct are
+//! written to their own columns.
+
+use rust_xlsxwriter::{SerializeFieldOptions, Workbook, XlsxError};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some serializable structs with a flattened sub-struct.
+    #[derive(Serialize)]
+    struct Address {
+        city: &'static str,
+        country: &'static str,
+    }
+
+    #[derive(Serialize)]
+    struct Customer {
+        name: &'static str,
+        #[serde(flatten)]
+        address: Address,
+    }
+
+    let customers = [
+        Customer {
+            name: "Aoife",
+            address: Address {
+                city: "Dublin",
+                country: "Ireland",
+            },
+        },
+        Customer {
+            name: "Caoimhe",
+            address: Address {
+                city: "Galway",
+                country: "Ireland",
+            },
+        },
+    ];
+
+    // Structs with flattened fields are serialized as maps so we use the map
+    // keys as the headers.
+    let header_options = SerializeFieldOptions::new().use_map_keys_as_headers(true);
+
+    // Set the serialization location and headers.
+    worksheet.serialize_headers_with_options(0, 0, &customers[0], &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&customers)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
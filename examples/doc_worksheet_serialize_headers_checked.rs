@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates checking the custom header field names
+//! of a Serde derived data structure to catch a misspelled field name.
+
+use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Serialize)]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+    }
+
+    // Create a data instance.
+    let item = Produce {
+        fruit: "Peach",
+        cost: 1.05,
+    };
+
+    // Set up custom headers with a misspelled field name.
+    let custom_headers = [
+        CustomSerializeField::new("fruit").rename("Fruit"),
+        CustomSerializeField::new("cots").rename("Price"),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    // Check the custom headers against the fields of the struct.
+    let result = worksheet.serialize_headers_checked(0, 0, &item, &header_options);
+
+    // Print the error. This shows:
+    //
+    //   Serialization error: 'Custom field name 'cots' not found in
+    //   struct 'Produce'. Valid field names are: 'fruit', 'cost''.
+    //
+    if let Err(error) = result {
+        println!("{error}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates pre-formatting a fixed number of cells
+//! for a serialized field.
+
+use rust_xlsxwriter::{CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add a currency format for the serialized values.
+    let currency_format = Format::new().set_num_format("$0.00");
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+    }
+
+    // Pre-format 5 cells in the "cost" column, even though only 2 rows are
+    // serialized initially.
+    let custom_headers = [
+        CustomSerializeField::new("fruit"),
+        CustomSerializeField::new("cost")
+            .set_value_format(currency_format)
+            .set_cell_format_range_len(5),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    // Set the serialization location and custom headers.
+    worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&Produce {
+        fruit: "Peach",
+        cost: 1.05,
+    })?;
+    worksheet.serialize(&Produce {
+        fruit: "Plum",
+        cost: 0.15,
+    })?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing a map of keyed structs with
+//! the key written to the first column.
+
+use std::collections::BTreeMap;
+
+use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+    }
+
+    // Create some data instances.
+    let item1 = Produce {
+        fruit: "Peach",
+        cost: 1.05,
+    };
+    let item2 = Produce {
+        fruit: "Plum",
+        cost: 0.15,
+    };
+    let item3 = Produce {
+        fruit: "Pear",
+        cost: 0.75,
+    };
+
+    // Create a map of the data keyed by id.
+    let items = BTreeMap::from([(101, item1), (102, item2), (103, item3)]);
+
+    // Add a key column with the header "Id".
+    let key_field = CustomSerializeField::new("id").rename("Id");
+    let header_options = SerializeFieldOptions::new().set_key_field(&key_field);
+
+    // Set the serialization location and headers.
+    worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+
+    // Serialize the keys and data.
+    worksheet.serialize_keyed(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
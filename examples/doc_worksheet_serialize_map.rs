@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing a map to key/value columns
+//! in a worksheet.
+
+use std::collections::BTreeMap;
+
+use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a map of metric names and values.
+    let metrics = BTreeMap::from([
+        ("Requests", 12_500.0),
+        ("Errors", 42.0),
+        ("Latency (ms)", 18.5),
+    ]);
+
+    // Rename the "key" and "value" headers.
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&[
+        CustomSerializeField::new("key").rename("Metric"),
+        CustomSerializeField::new("value").rename("Value"),
+    ]);
+
+    // Set the serialization location and headers.
+    worksheet.serialize_headers_with_options(0, 0, &metrics, &header_options)?;
+
+    // Serialize the map entries.
+    worksheet.serialize(&metrics)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing nested structs to grouped
+//! header columns.
+
+use rust_xlsxwriter::{
+    CustomSerializeField, Format, FormatAlign, SerializeFieldOptions, Workbook, XlsxError,
+};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some serializable nested structs.
+    #[derive(Serialize)]
+    struct Quarter {
+        revenue: f64,
+        cost: f64,
+    }
+
+    #[derive(Serialize)]
+    struct Sale {
+        region: &'static str,
+        q1: Quarter,
+        q2: Quarter,
+    }
+
+    let sales = [
+        Sale {
+            region: "North",
+            q1: Quarter {
+                revenue: 1000.0,
+                cost: 400.0,
+            },
+            q2: Quarter {
+                revenue: 1200.0,
+                cost: 450.0,
+            },
+        },
+        Sale {
+            region: "South",
+            q1: Quarter {
+                revenue: 800.0,
+                cost: 300.0,
+            },
+            q2: Quarter {
+                revenue: 900.0,
+                cost: 350.0,
+            },
+        },
+    ];
+
+    // Rename the group labels and use nested headers.
+    let custom_headers = [
+        CustomSerializeField::new("q1").rename("Q1"),
+        CustomSerializeField::new("q2").rename("Q2"),
+    ];
+    let header_options = SerializeFieldOptions::new()
+        .set_header_format(Format::new().set_bold().set_align(FormatAlign::Center))
+        .set_custom_headers(&custom_headers)
+        .use_nested_headers(true);
+
+    // Set the serialization location and headers.
+    worksheet.serialize_headers_with_options(0, 0, &sales[0], &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&sales)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates resetting the serialization position to
+//! overwrite previously serialized data.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+    }
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.deserialize_headers::<Produce>(0, 0)?;
+
+    // Serialize some initial data.
+    worksheet.serialize(&[
+        Produce {
+            fruit: "Peach",
+            cost: 1.05,
+        },
+        Produce {
+            fruit: "Plum",
+            cost: 0.15,
+        },
+    ])?;
+
+    // Reset the serialization position and overwrite the data with new values.
+    worksheet.serialize_reset("Produce");
+
+    worksheet.serialize(&[
+        Produce {
+            fruit: "Peach",
+            cost: 1.10,
+        },
+        Produce {
+            fruit: "Plum",
+            cost: 0.20,
+        },
+    ])?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
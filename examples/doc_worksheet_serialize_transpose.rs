@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing instances of a Serde derived
+//! data structure in a transposed layout with the field headers in the first
+//! column and each instance in a new column.
+
+use rust_xlsxwriter::{Format, SerializeFieldOptions, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Snapshot {
+        time: &'static str,
+        cpu: f64,
+        memory: f64,
+        disk: f64,
+        network: f64,
+    }
+
+    let snapshots = [
+        Snapshot {
+            time: "09:00",
+            cpu: 0.25,
+            memory: 0.60,
+            disk: 0.41,
+            network: 0.12,
+        },
+        Snapshot {
+            time: "10:00",
+            cpu: 0.75,
+            memory: 0.65,
+            disk: 0.42,
+            network: 0.37,
+        },
+        Snapshot {
+            time: "11:00",
+            cpu: 0.50,
+            memory: 0.70,
+            disk: 0.42,
+            network: 0.21,
+        },
+    ];
+
+    // Write the headers in a column and each snapshot in a new column.
+    let header_options = SerializeFieldOptions::new()
+        .set_header_format(Format::new().set_bold())
+        .set_transpose(true);
+
+    // Set the serialization location and headers.
+    worksheet.deserialize_headers_with_options::<Snapshot>(0, 0, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&snapshots)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
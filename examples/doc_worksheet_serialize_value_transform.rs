@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates transforming values during
+//! serialization to mask names and to clamp numbers to a minimum value.
+
+use rust_xlsxwriter::{
+    CustomSerializeField, SerializeFieldOptions, SerializedValue, Workbook, XlsxError,
+};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    struct Account {
+        name: &'static str,
+        balance: f64,
+    }
+
+    // Create some data instances.
+    let item1 = Account {
+        name: "Alice",
+        balance: 12.5,
+    };
+
+    let item2 = Account {
+        name: "Bob",
+        balance: -3.0,
+    };
+
+    // Mask all but the first character of a string.
+    fn mask(value: SerializedValue) -> SerializedValue {
+        match value {
+            SerializedValue::String(name) => {
+                let masked = name
+                    .chars()
+                    .take(1)
+                    .chain(name.chars().skip(1).map(|_| '*'));
+                SerializedValue::String(masked.collect())
+            }
+            _ => value,
+        }
+    }
+
+    // Clamp numbers to a minimum value captured by a closure.
+    let min_balance = 0.0;
+    let clamp = move |value: SerializedValue| match value {
+        SerializedValue::Number(number) => SerializedValue::Number(number.max(min_balance)),
+        _ => value,
+    };
+
+    // Set the custom headers.
+    let custom_headers = [
+        CustomSerializeField::new("name").set_value_transform(mask),
+        CustomSerializeField::new("balance").set_value_transform(clamp),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    // Set the serialization location and custom headers.
+    worksheet.deserialize_headers_with_options::<Account>(0, 0, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&item1)?;
+    worksheet.serialize(&item2)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
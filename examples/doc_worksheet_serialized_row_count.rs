@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting the number of serialized rows in
+//! order to write a totals row below the data.
+
+use rust_xlsxwriter::{Format, Formula, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+    }
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.deserialize_headers::<Produce>(0, 0)?;
+
+    // Serialize the data in chunks.
+    for chunk in [["Peach", "Plum"], ["Pear", "Apple"]] {
+        for fruit in chunk {
+            worksheet.serialize(&Produce { fruit, cost: 1.25 })?;
+        }
+    }
+
+    // Get the number of serialized rows.
+    let row_count = worksheet
+        .serialized_row_count("Produce")
+        .unwrap_or_default();
+
+    // Write a totals row below the data, which starts after the header row.
+    let bold = Format::new().set_bold();
+    let total_row = row_count + 1;
+
+    worksheet.write_with_format(total_row, 0, "Total", &bold)?;
+    worksheet.write_formula_with_format(
+        total_row,
+        1,
+        Formula::new(format!("=SUM(B2:B{total_row})")),
+        &bold,
+    )?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
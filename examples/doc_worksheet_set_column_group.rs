@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates grouping worksheet columns into an
+//! outline with a collapsed inner group.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_row(0, 0, ["Jan", "Feb", "Mar", "Q1", "Apr", "May", "Jun", "Q2"])?;
+
+    // Group the month columns of each quarter and collapse the second
+    // quarter. The quarter columns are the summary columns.
+    worksheet.set_column_group(0, 2, 1, false)?;
+    worksheet.set_column_group(4, 6, 1, true)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
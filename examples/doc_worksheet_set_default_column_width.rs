@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the default column width for
+//! all columns in a worksheet while overriding the width of one column.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Set the default column width in Excel character units.
+    worksheet.set_default_column_width(15);
+
+    // Override the width for the first column.
+    worksheet.set_column_width(0, 30)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
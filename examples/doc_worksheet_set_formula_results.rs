@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the results of several formulas
+//! at once.
+
+use std::collections::HashMap;
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_formula(0, 0, "=1+1")?;
+    worksheet.write_formula(1, 0, "=2+2")?;
+    worksheet.write_formula(2, 0, "=2+2")?;
+
+    // Set the formula results calculated by the application.
+    let results = HashMap::from([((0, 0), 2), ((1, 0), 4), ((2, 0), 4)]);
+
+    worksheet.set_formula_results(&results);
+
+    workbook.save("formulas.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting typed formula results.
+
+use rust_xlsxwriter::{FormulaResult, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_formula(0, 0, "=1+1")?;
+    worksheet.write_formula(1, 0, "=1>2")?;
+    worksheet.write_formula(2, 0, "=1/0")?;
+
+    // Set the formula results with the same types as Excel.
+    worksheet.set_formula_typed_result(0, 0, 2);
+    worksheet.set_formula_typed_result(1, 0, false);
+    worksheet.set_formula_typed_result(2, 0, FormulaResult::Error("#DIV/0!".into()));
+
+    workbook.save("formulas.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a worksheet to be recalculated
+//! when the file is opened.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_formula(0, 0, "=SUM(1, 2, 3)")?;
+
+    worksheet.set_full_calc_on_load(true);
+
+    workbook.save("formulas.xlsx")?;
+
+    Ok(())
+}
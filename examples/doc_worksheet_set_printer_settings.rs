@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding printer settings from a template
+//! file to a worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Printer settings extracted from an existing xlsx file.
+    let data = std::fs::read("examples/printerSettings1.bin")?;
+
+    worksheet.set_printer_settings(&data);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
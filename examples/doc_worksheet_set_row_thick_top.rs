@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the thick top and bottom flags
+//! for worksheet rows that are separated by a medium border.
+
+use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(0, 0, "Section 1")?;
+    worksheet.write_string(4, 0, "Section 2")?;
+
+    // Draw a border between the sections with a row format.
+    let border = Format::new().set_border_bottom(FormatBorder::Medium);
+    worksheet.set_row_format(3, &border)?;
+
+    // Flag the rows on either side of the border so that Excel allows space
+    // for it.
+    worksheet.set_row_thick_bottom(3)?;
+    worksheet.set_row_thick_top(4)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
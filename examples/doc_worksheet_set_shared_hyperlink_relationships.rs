@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a column of repeated links that
+//! share a single worksheet relationship.
+
+use rust_xlsxwriter::{Url, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_shared_hyperlink_relationships(true);
+
+    // All of these links will refer to the same relationship.
+    for row in 0..1000 {
+        worksheet.write(row, 0, Url::new("https://www.rust-lang.org"))?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a fixed sheet id for a
+//! worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet().set_name("Data")?;
+    worksheet.set_sheet_id(100)?;
+
+    let worksheet = workbook.add_worksheet().set_name("Summary")?;
+    worksheet.set_sheet_id(200)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
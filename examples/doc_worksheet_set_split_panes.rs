@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting split worksheet panes with the
+//! bottom right pane pre-scrolled to cell `E21`.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(0, 0, "Scroll down or across")?;
+
+    // Split the worksheet below the top row and right of the first column.
+    worksheet.set_split_panes(1, 1)?;
+
+    // Pre-scroll the bottom right pane to cell E21.
+    worksheet.set_freeze_panes_top_cell(20, 4)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
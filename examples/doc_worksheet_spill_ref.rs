@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates referring to the spilled range of a
+//! dynamic array formula from another formula.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write a dynamic formula that spills into the range A1:A10.
+    worksheet.write_dynamic_formula(0, 0, "=SEQUENCE(10)")?;
+
+    // Sum the spilled range using the "Sheet1!$A$1#" reference.
+    let spill_ref = worksheet.spill_ref(0, 0);
+    worksheet.write_formula(0, 2, format!("=SUM({spill_ref})").as_str())?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates exporting worksheet data to a CSV
+//! buffer.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some data to the worksheet.
+    worksheet.write_row(0, 0, ["Name", "Price"])?;
+    worksheet.write_row(1, 0, ["Apple, red", "1.5"])?;
+    worksheet.write(2, 1, 2.25)?;
+
+    // Export the data as CSV.
+    let mut buffer = vec![];
+    worksheet.to_csv(&mut buffer)?;
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "Name,Price\r\n\"Apple, red\",1.5\r\n,2.25\r\n"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting several worksheet view
+//! properties using the view builder.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet
+        .view()
+        .right_to_left()
+        .zoom(150)
+        .hide_gridlines()
+        .select(3, 2)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
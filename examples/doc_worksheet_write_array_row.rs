@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing fixed size arrays of data as a
+//! row and as a column.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some fixed size array data to write.
+    let data: [f64; 4] = [1.5, 2.5, 3.5, 4.5];
+
+    // Write the array data as a row and as a column.
+    worksheet.write_array_row(0, 0, data)?;
+    worksheet.write_array_column(2, 0, data)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
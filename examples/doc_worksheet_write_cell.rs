@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing cells with values and
+//! properties using a `CellBuilder`.
+
+use rust_xlsxwriter::{CellBuilder, Format, Note, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new().set_num_format("0.00%");
+
+    // Write a number with a format and a note.
+    let cell = CellBuilder::new(0.25)
+        .set_format(&format)
+        .set_note(&Note::new("Target growth rate"));
+
+    worksheet.write_cell(0, 0, &cell)?;
+
+    // Write a string with a hyperlink to another worksheet.
+    let cell = CellBuilder::new("Summary").set_url("internal:Sheet1!A1")?;
+
+    worksheet.write_cell(1, 0, &cell)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
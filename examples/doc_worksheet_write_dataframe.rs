@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a Polars dataframe to a
+//! worksheet.
+
+use polars::prelude::*;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let df = df!(
+        "Region" => &["East", "West", "North"],
+        "Sales" => &[Some(3000), None, Some(4000)],
+        "Target" => &[true, false, true],
+    )?;
+
+    worksheet.write_dataframe(0, 0, &df)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
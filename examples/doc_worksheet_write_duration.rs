@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing time durations, some greater
+//! than 24 hours, to a worksheet.
+
+use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write some durations in seconds. They are displayed as "7:30",
+    // "26:15" and "40:00".
+    worksheet.write_duration(0, 0, 7.5 * 3600.0)?;
+    worksheet.write_duration(1, 0, 26.25 * 3600.0)?;
+    worksheet.write_duration(2, 0, 40 * 3600)?;
+
+    // Add a total of the durations.
+    worksheet.write(3, 0, Formula::new("=SUM(A1:A3)"))?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing JSON data to a worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+use serde_json::json;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let data = json!({
+        "name": "Quarterly sales",
+        "meta": {"year": 2024, "final": true},
+        "sales": [
+            {"region": "East", "total": 3000},
+            {"region": "West", "total": 8000},
+        ],
+    });
+
+    worksheet.write_json(0, 0, &data)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
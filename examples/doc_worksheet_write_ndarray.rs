@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a 2D `ndarray` array to a
+//! worksheet.
+
+use ndarray::array;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let data = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    worksheet.write_ndarray(0, 0, &data)?;
+
+    // Write the transpose of the array, which is a view.
+    worksheet.write_ndarray(3, 0, &data.t())?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
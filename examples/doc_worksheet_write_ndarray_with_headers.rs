@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a 2D `ndarray` array to a
+//! worksheet with column headers.
+
+use ndarray::array;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let data = array![[0.1, 10.5, 1.0], [0.2, 11.2, 1.5], [0.3, 11.9, 2.0]];
+
+    worksheet.write_ndarray_with_headers(0, 0, &["Time", "Temp", "Pressure"], &data)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing product codes with leading zeros
+//! to a worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write some product codes with leading zeros.
+    worksheet.write_number_as_text(0, 0, "00123")?;
+    worksheet.write_number_as_text(1, 0, "00456")?;
+    worksheet.write_number_as_text(2, 0, "01789")?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing numbers stored as strings to a
+//! worksheet as numeric cells.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Some data read from a CSV file.
+    let fields = ["1234", "-12.5", "1.5e3", " 42 "];
+
+    // Write the fields as numbers.
+    for (row, field) in fields.iter().enumerate() {
+        worksheet.write_number_from_str(row as u32, 0, field)?;
+    }
+
+    // Strings that aren't numbers are an error.
+    let result = worksheet.write_number_from_str(4, 0, "N/A");
+    assert!(result.is_err());
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
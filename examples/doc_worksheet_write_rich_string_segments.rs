@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a "rich" string where the
+//! unformatted segments use the font size of the cell format.
+
+use rust_xlsxwriter::{Color, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_column_width(0, 40)?;
+
+    // Add a format for one of the segments and a format for the cell.
+    let red = Format::new().set_font_color(Color::Red).set_font_size(16);
+    let large = Format::new().set_font_size(16);
+
+    // Segments without a format use the font of the cell format.
+    let segments = [(None, "This is "), (Some(&red), "red"), (None, " text")];
+    worksheet.write_rich_string_segments_with_format(0, 0, &segments, &large)?;
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a row of data from a lazy
+//! iterator and then adding a total to the right of the data.
+
+use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write the squares of some numbers without collecting them.
+    let squares = (1..=5).map(|n| n * n);
+
+    if let Some(last_col) = worksheet.write_row_iter(0, 0, squares)? {
+        // Add a total after the last column.
+        worksheet.write(0, last_col + 1, Formula::new("=SUM(A1:E1)"))?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing an array of row arrays to a
+//! worksheet with formats for each column.
+
+use rust_xlsxwriter::{Format, MatrixFormats, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some array data to write.
+    let data = [
+        [10.0, 0.11, 1200.0],
+        [20.0, 0.21, 2400.0],
+        [30.0, 0.31, 3600.0],
+    ];
+
+    // Some formats for each column.
+    let formats = [
+        Format::new().set_bold(),
+        Format::new().set_num_format("0%"),
+        Format::new().set_num_format("$#,##0.00"),
+    ];
+
+    // Write the array data as a series of formatted rows.
+    worksheet.write_row_matrix_with_formats(0, 0, data, MatrixFormats::PerColumn(&formats))?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a column of urls with
+//! alternative text and a user defined format.
+
+use rust_xlsxwriter::{Color, Format, FormatUnderline, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let link_format = Format::new()
+        .set_font_color(Color::Red)
+        .set_underline(FormatUnderline::Single);
+
+    let links = [
+        ("https://www.rust-lang.org", "Rust"),
+        ("https://crates.io", "Crates"),
+        ("https://docs.rs", ""),
+    ];
+
+    // Write the links. The last one displays the url since it has no text.
+    for (row, (url, text)) in (0..).zip(links) {
+        worksheet.write_url_with_text_and_format(row, 0, url, text, &link_format)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing cells with value metadata.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add an entry to the workbook value metadata registry.
+    let stock = workbook.add_value_metadata("XLSTOCK");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_with_metadata(0, 0, "MSFT", stock)?;
+    worksheet.write_with_metadata(1, 0, "AAPL", stock)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
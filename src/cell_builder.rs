@@ -0,0 +1,132 @@
+// cell_builder - A module to represent a cell value and its properties.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use crate::{Format, IntoExcelData, Note, Url, XlsxError};
+
+#[derive(Clone)]
+/// The `CellBuilder` struct represents a worksheet cell value and its
+/// properties.
+///
+/// The `CellBuilder` struct bundles a cell value with an optional format,
+/// note and hyperlink so that they can be written to a worksheet cell
+/// together using [`Worksheet::write_cell()`](crate::Worksheet::write_cell).
+///
+/// This avoids ordering issues that can occur when the properties of a cell
+/// are set in separate calls. For example a
+/// [`Worksheet::write()`](crate::Worksheet::write) after a
+/// [`Worksheet::set_cell_format()`](crate::Worksheet::set_cell_format) will
+/// replace the cell format with the default format.
+///
+/// The cell value can be any type that implements [`IntoExcelData`], such as
+/// numbers, strings, booleans, dates and [`Formula`](crate::Formula).
+///
+/// # Examples
+///
+/// The following example demonstrates writing a cell with a value, format,
+/// note and hyperlink in a single call.
+///
+/// ```
+/// # // This code is available in examples/doc_cell_builder.rs
+/// #
+/// # use rust_xlsxwriter::{CellBuilder, Format, Note, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     let format = Format::new().set_bold().set_font_color("#0000FF");
+///
+///     // Create a cell with a value and several properties.
+///     let cell = CellBuilder::new("Rust")
+///         .set_format(&format)
+///         .set_note(&Note::new("The Rust home page"))
+///         .set_url("https://www.rust-lang.org")?;
+///
+///     // Write the cell to the worksheet.
+///     worksheet.write_cell(1, 1, &cell)?;
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+pub struct CellBuilder<T: IntoExcelData + Clone> {
+    pub(crate) value: T,
+    pub(crate) format: Option<Format>,
+    pub(crate) note: Option<Note>,
+    pub(crate) url: Option<Url>,
+}
+
+impl<T: IntoExcelData + Clone> CellBuilder<T> {
+    /// Create a new `CellBuilder` object to represent a cell value.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The cell value. Any type that implements [`IntoExcelData`].
+    ///
+    pub fn new(value: T) -> CellBuilder<T> {
+        CellBuilder {
+            value,
+            format: None,
+            note: None,
+            url: None,
+        }
+    }
+
+    /// Set the format for the cell.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    pub fn set_format(mut self, format: &Format) -> CellBuilder<T> {
+        self.format = Some(format.clone());
+        self
+    }
+
+    /// Set a note for the cell.
+    ///
+    /// # Parameters
+    ///
+    /// - `note`: The [`Note`] to add to the cell.
+    ///
+    pub fn set_note(mut self, note: &Note) -> CellBuilder<T> {
+        self.note = Some(note.clone());
+        self
+    }
+
+    /// Set a Url/Hyperlink for the cell.
+    ///
+    /// The cell value is used as the text of the link. If a format isn't set
+    /// then the default hyperlink format is used.
+    ///
+    /// See [`Url`] for an explanation of the URIs supported by Excel.
+    ///
+    /// # Parameters
+    ///
+    /// - `link`: The url/hyperlink associated with the cell as a string or
+    ///   [`Url`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::MaxUrlLengthExceeded`] - URL string or anchor exceeds
+    ///   Excel's limit of 2080 characters.
+    /// - [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
+    ///   [`Worksheet::write_url()`](crate::Worksheet::write_url).
+    /// - [`XlsxError::ParameterError`] - URL mouseover tool tip exceeds Excel's
+    ///   limit of 255 characters.
+    ///
+    pub fn set_url(mut self, link: impl Into<Url>) -> Result<CellBuilder<T>, XlsxError> {
+        let mut url = link.into();
+        url.initialize()?;
+
+        self.url = Some(url);
+        Ok(self)
+    }
+}
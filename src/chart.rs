@@ -833,6 +833,7 @@ pub struct Chart {
     axis_ids: (u32, u32),
     axis2_ids: (u32, u32),
     category_has_num_format: bool,
+    category_has_multi_level: bool,
     chart_type: ChartType,
     chart_group_type: ChartType,
     pub(crate) title: ChartTitle,
@@ -950,6 +951,7 @@ impl Chart {
             axis2_ids: (0, 0),
             series: vec![],
             category_has_num_format: false,
+            category_has_multi_level: false,
             chart_type,
             chart_group_type: chart_type,
             title: ChartTitle::new(),
@@ -1895,8 +1897,9 @@ impl Chart {
     ///
     /// # Parameters
     ///
-    /// - `hole_size`: The hole size for a Doughnut chart. The range is 0 <=
-    ///   `hole_size` <= 90 and the default is 50.
+    /// - `hole_size`: The hole size for a Doughnut chart. The range is 10 <=
+    ///   `hole_size` <= 90 and the default is 50. Values outside the range are
+    ///   ignored.
     ///
     ///
     /// # Examples
@@ -1942,7 +1945,7 @@ impl Chart {
     /// src="https://rustxlsxwriter.github.io/images/chart_set_hole_size.png">
     ///
     pub fn set_hole_size(&mut self, hole_size: u8) -> &mut Chart {
-        if (0..=90).contains(&hole_size) {
+        if (10..=90).contains(&hole_size) {
             self.hole_size = hole_size;
         }
         self
@@ -2720,6 +2723,30 @@ impl Chart {
                 series.category_range.validate()?;
             }
 
+            // Check that a multi-level category range has the same number of
+            // points as the values range. The other dimension of the range is
+            // the number of category levels.
+            if self.chart_group_type != ChartType::Scatter
+                && series.category_range.has_data()
+                && !series.category_range.is_1d()
+            {
+                let (num_rows, num_cols) = series.category_range.number_of_range_points();
+                let num_categories = if series.has_category_levels_in_columns() {
+                    num_rows
+                } else {
+                    num_cols
+                };
+                let num_values = series.value_range.number_of_points();
+
+                if num_categories != num_values {
+                    return Err(XlsxError::ChartError(format!(
+                        "Multi-level category range '{}' has {num_categories} categories \
+                         but the values range has {num_values} points",
+                        series.category_range.error_range()
+                    )));
+                }
+            }
+
             // Validate Polynomial trendline range.
             if let ChartTrendlineType::Polynomial(order) = series.trendline.trend_type {
                 if !(2..6).contains(&order) {
@@ -2741,6 +2768,11 @@ impl Chart {
             }
         }
 
+        // Pad literal category and value data to the same number of points.
+        for series in &mut self.series {
+            series.pad_literal_data();
+        }
+
         Ok(self)
     }
 
@@ -2754,10 +2786,56 @@ impl Chart {
     /// - [`ChartEmptyCells::Connected`]: Show empty cells in the chart
     ///   connected by a line to the previous point.
     ///
+    /// This corresponds to the "Show empty cells as" option in the Excel
+    /// "Hidden and Empty Cell Settings" dialog.
+    ///
     /// # Parameters
     ///
     /// `option` - A [`ChartEmptyCells`] enum value.
     ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating displaying empty cells as connected
+    /// lines and including data from hidden rows.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_show_empty_cells_as.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartEmptyCells, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart, with a gap and a hidden row.
+    /// #     worksheet.write(0, 0, 5)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(3, 0, 30)?;
+    /// #     worksheet.write(4, 0, 5)?;
+    /// #     worksheet.write(5, 0, 20)?;
+    /// #     worksheet.set_row_hidden(4)?;
+    /// #
+    ///     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Line);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$6");
+    ///
+    ///     // Connect the points on either side of the empty cell and include
+    ///     // the hidden row in the chart.
+    ///     chart.show_empty_cells_as(ChartEmptyCells::Connected);
+    ///     chart.show_hidden_data();
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
     pub fn show_empty_cells_as(&mut self, option: ChartEmptyCells) -> &mut Chart {
         self.show_empty_cells_as = Some(option);
 
@@ -2774,6 +2852,11 @@ impl Chart {
 
     /// Display data on charts from hidden rows or columns.
     ///
+    /// By default Excel only plots data from visible cells. This corresponds
+    /// to turning on the "Show data in hidden rows and columns" option in the
+    /// Excel "Hidden and Empty Cell Settings" dialog. See
+    /// [`Chart::show_empty_cells_as()`] for an example.
+    ///
     pub fn show_hidden_data(&mut self) -> &mut Chart {
         self.show_hidden_data = true;
 
@@ -3785,8 +3868,21 @@ impl Chart {
 
             self.write_series_title(&series.title);
 
-            // Write the c:spPr element.
-            self.write_sp_pr(&series.format);
+            // Write the c:spPr element. Excel ignores an inverted fill color
+            // unless the series also has an explicit fill so, if one hasn't
+            // been set, we add a solid fill with the default series color.
+            if !series.inverted_color.is_auto_or_default() && !series.format.has_fill() {
+                let accent = 4 + (self.series_index % 6) as u8;
+                let mut solid_fill = ChartSolidFill::new();
+                solid_fill.set_color(Color::Theme(accent, 0));
+
+                let mut format = series.format.clone();
+                format.solid_fill = Some(solid_fill);
+
+                self.write_sp_pr(&format);
+            } else {
+                self.write_sp_pr(&series.format);
+            }
 
             if let Some(marker) = &series.marker {
                 if !marker.automatic {
@@ -3832,10 +3928,20 @@ impl Chart {
 
             // Write the c:cat element.
             if series.category_range.has_data() {
-                // We only set a default num format for non-string categories.
-                self.category_has_num_format =
-                    series.category_range.cache.cache_type != ChartRangeCacheDataType::String;
-                self.write_cat(&series.category_range);
+                if series.category_range.is_1d() {
+                    // We only set a default num format for non-string categories.
+                    self.category_has_num_format =
+                        series.category_range.cache.cache_type != ChartRangeCacheDataType::String;
+                    self.write_cat(&series.category_range);
+                } else {
+                    // Multi-level categories are always written as strings.
+                    self.category_has_num_format = false;
+                    self.category_has_multi_level = true;
+                    self.write_multi_level_cat(
+                        &series.category_range,
+                        series.has_category_levels_in_columns(),
+                    );
+                }
             }
 
             // Write the c:val element.
@@ -4032,6 +4138,62 @@ impl Chart {
         self.writer.xml_end_tag("c:cat");
     }
 
+    // Write the <c:cat> element for a multi-level category range.
+    fn write_multi_level_cat(&mut self, range: &ChartRange, levels_in_columns: bool) {
+        self.writer.xml_start_tag_only("c:cat");
+        self.writer.xml_start_tag_only("c:multiLvlStrRef");
+
+        // Write the c:f element.
+        self.write_range_formula(&range.formula_abs());
+
+        // Write the c:multiLvlStrCache element.
+        if range.cache.has_data() {
+            self.write_multi_level_str_cache(range, levels_in_columns);
+        }
+
+        self.writer.xml_end_tag("c:multiLvlStrRef");
+        self.writer.xml_end_tag("c:cat");
+    }
+
+    // Write the <c:multiLvlStrCache> element.
+    fn write_multi_level_str_cache(&mut self, range: &ChartRange, levels_in_columns: bool) {
+        let (num_rows, num_cols) = range.number_of_range_points();
+        let (num_points, num_levels) = if levels_in_columns {
+            (num_rows, num_cols)
+        } else {
+            (num_cols, num_rows)
+        };
+
+        self.writer.xml_start_tag_only("c:multiLvlStrCache");
+
+        // Write the c:ptCount element.
+        self.write_pt_count(num_points);
+
+        // Write the c:lvl elements. Excel stores the innermost level, i.e., the
+        // last column or row, first. The cache data is stored in row order.
+        for level in (0..num_levels).rev() {
+            self.writer.xml_start_tag_only("c:lvl");
+
+            for point in 0..num_points {
+                let index = if levels_in_columns {
+                    point * num_cols + level
+                } else {
+                    level * num_cols + point
+                };
+
+                if let Some(value) = range.cache.data.get(index) {
+                    if !value.is_empty() {
+                        self.write_pt(point, value);
+                    }
+                }
+            }
+
+            self.writer.xml_end_tag("c:lvl");
+        }
+
+        self.writer.xml_end_tag("c:multiLvlStrCache");
+    }
+
     // Write the <c:val> element.
     fn write_val(&mut self, range: &ChartRange) {
         self.writer.xml_start_tag_only("c:val");
@@ -4062,13 +4224,25 @@ impl Chart {
     // Write the <c:numRef> or <c:strRef> elements. Value range must be written
     // as a numRef where strings are treated as zero.
     fn write_cache_ref(&mut self, range: &ChartRange, is_num_only: bool) {
-        if range.cache.cache_type == ChartRangeCacheDataType::String && !is_num_only {
+        if range.is_literal {
+            self.write_cache_lit(range, is_num_only);
+        } else if range.cache.cache_type == ChartRangeCacheDataType::String && !is_num_only {
             self.write_str_ref(range);
         } else {
             self.write_num_ref(range);
         }
     }
 
+    // Write the <c:numLit> or <c:strLit> elements for literal data that isn't
+    // stored in a worksheet.
+    fn write_cache_lit(&mut self, range: &ChartRange, is_num_only: bool) {
+        if range.cache.cache_type == ChartRangeCacheDataType::String && !is_num_only {
+            self.write_str_cache("c:strLit", &range.cache);
+        } else {
+            self.write_num_cache("c:numLit", &range.cache);
+        }
+    }
+
     // Write the <c:numRef> element.
     fn write_num_ref(&mut self, range: &ChartRange) {
         self.writer.xml_start_tag_only("c:numRef");
@@ -4078,7 +4252,7 @@ impl Chart {
 
         // Write the c:numCache element.
         if range.cache.has_data() {
-            self.write_num_cache(&range.cache);
+            self.write_num_cache("c:numCache", &range.cache);
         }
 
         self.writer.xml_end_tag("c:numRef");
@@ -4093,15 +4267,15 @@ impl Chart {
 
         // Write the c:strCache element.
         if range.cache.has_data() {
-            self.write_str_cache(&range.cache);
+            self.write_str_cache("c:strCache", &range.cache);
         }
 
         self.writer.xml_end_tag("c:strRef");
     }
 
-    // Write the <c:numCache> element.
-    fn write_num_cache(&mut self, cache: &ChartRangeCacheData) {
-        self.writer.xml_start_tag_only("c:numCache");
+    // Write the <c:numCache> or <c:numLit> element.
+    fn write_num_cache(&mut self, tag: &str, cache: &ChartRangeCacheData) {
+        self.writer.xml_start_tag_only(tag);
 
         // Write the c:formatCode element.
         if cache.cache_type == ChartRangeCacheDataType::Date {
@@ -4126,12 +4300,12 @@ impl Chart {
             }
         }
 
-        self.writer.xml_end_tag("c:numCache");
+        self.writer.xml_end_tag(tag);
     }
 
-    // Write the <c:strCache> element.
-    fn write_str_cache(&mut self, cache: &ChartRangeCacheData) {
-        self.writer.xml_start_tag_only("c:strCache");
+    // Write the <c:strCache> or <c:strLit> element.
+    fn write_str_cache(&mut self, tag: &str, cache: &ChartRangeCacheData) {
+        self.writer.xml_start_tag_only(tag);
 
         // Write the c:ptCount element.
         self.write_pt_count(cache.data.len());
@@ -4141,7 +4315,7 @@ impl Chart {
             self.write_pt(index, value);
         }
 
-        self.writer.xml_end_tag("c:strCache");
+        self.writer.xml_end_tag(tag);
     }
 
     // Write the <c:f> element.
@@ -4285,6 +4459,11 @@ impl Chart {
             self.write_tick_mark_skip(x_axis.tick_interval);
         }
 
+        // Write the c:noMultiLvlLbl element.
+        if self.category_has_multi_level {
+            self.write_no_multi_lvl_lbl();
+        }
+
         self.writer.xml_end_tag("c:catAx");
     }
 
@@ -4780,6 +4959,13 @@ impl Chart {
         self.writer.xml_empty_tag("c:tickMarkSkip", &attributes);
     }
 
+    // Write the <c:noMultiLvlLbl> element.
+    fn write_no_multi_lvl_lbl(&mut self) {
+        let attributes = [("val", "0")];
+
+        self.writer.xml_empty_tag("c:noMultiLvlLbl", &attributes);
+    }
+
     // Write the <c:majorUnit> element.
     fn write_major_unit(&mut self, value: &String) {
         let attributes = [("val", value.to_string())];
@@ -6885,6 +7071,14 @@ impl ChartSeries {
     /// The data range can be set using a formula as shown in the first part of
     /// the example below or using a list of values as shown in the second part.
     ///
+    /// If the category range spans more than one column, or more than one row
+    /// for series values in a row, then it is treated as a multi-level
+    /// category range. The first column is the outermost level of the
+    /// category labels, such as a year or quarter, and the last column is the
+    /// innermost level, such as a month. See the second example below. The
+    /// number of rows in a multi-level category range must match the number of
+    /// points in the values range.
+    ///
     /// # Parameters
     ///
     /// - `range`: The range property which can be one of two generic types:
@@ -6895,6 +7089,12 @@ impl ChartSeries {
     ///      `("Sheet1", 0, 0, 2, 0)` (this gives the same range as the previous
     ///      string value).
     ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ChartError`] - A multi-level category range doesn't have
+    ///   the same number of points as the values range. This error is raised
+    ///   when the chart is validated or inserted into a worksheet.
+    ///
     /// # Examples
     ///
     /// A chart example demonstrating setting the chart series categories and
@@ -6952,6 +7152,41 @@ impl ChartSeries {
     /// <img
     /// src="https://rustxlsxwriter.github.io/images/chart_series_set_categories.png">
     ///
+    /// A chart example demonstrating setting multi-level categories for a
+    /// chart series.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_set_categories_multi_level.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add some data for the chart. The quarters are the outer category level
+    ///     // and the months are the inner category level.
+    ///     worksheet.write_column(0, 0, ["Q1", "", "", "Q2", "", ""])?;
+    ///     worksheet.write_column(0, 1, ["Jan", "Feb", "Mar", "Apr", "May", "Jun"])?;
+    ///     worksheet.write_column(0, 2, [50, 30, 40, 60, 35, 45])?;
+    ///
+    ///     // Create a new chart with a 2 column category range.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///     chart
+    ///         .add_series()
+    ///         .set_categories("Sheet1!$A$1:$B$6")
+    ///         .set_values("Sheet1!$C$1:$C$6");
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 4, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
     pub fn set_categories<T>(&mut self, range: T) -> &mut ChartSeries
     where
         T: IntoChartRange,
@@ -6960,6 +7195,103 @@ impl ChartSeries {
         self
     }
 
+    /// Add literal values to a chart series.
+    ///
+    /// This method sets the values of a chart series from a list of numbers
+    /// instead of a worksheet range. The data is stored in the chart itself so
+    /// it isn't displayed in, or linked to, any worksheet. This can be useful
+    /// for small standalone charts.
+    ///
+    /// If the series also has literal categories, see
+    /// [`ChartSeries::set_categories_from_strings()`], and the number of
+    /// categories and values differ then the shorter list is padded with blank
+    /// points.
+    ///
+    /// # Parameters
+    ///
+    /// - `values`: A slice of numbers to use as the series values.
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating setting the chart series categories and
+    /// values from literal data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_set_values_from_values.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using literal data instead of a worksheet range.
+    ///     chart
+    ///         .add_series()
+    ///         .set_categories_from_strings(&["Jan", "Feb", "Mar"])
+    ///         .set_values_from_values(&[50.0, 30.0, 40.0]);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 0, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_values_from_values(&mut self, values: &[f64]) -> &mut ChartSeries {
+        let data = values.iter().map(f64::to_string).collect();
+
+        self.value_range = ChartRange::new_from_literal(data, ChartRangeCacheDataType::Number);
+        self
+    }
+
+    /// Add literal category labels to a chart series.
+    ///
+    /// This method sets the categories of a chart series from a list of
+    /// strings instead of a worksheet range. The data is stored in the chart
+    /// itself so it isn't displayed in, or linked to, any worksheet.
+    ///
+    /// If the series also has literal values, see
+    /// [`ChartSeries::set_values_from_values()`], and the number of categories
+    /// and values differ then the shorter list is padded with blank points.
+    ///
+    /// # Parameters
+    ///
+    /// - `categories`: A slice of strings to use as the category labels.
+    ///
+    pub fn set_categories_from_strings(&mut self, categories: &[&str]) -> &mut ChartSeries {
+        let data = categories.iter().map(|s| (*s).to_string()).collect();
+
+        self.category_range = ChartRange::new_from_literal(data, ChartRangeCacheDataType::String);
+        self
+    }
+
+    // Check if the levels of a multi-level category range are in columns, which
+    // is the case when the series values are in a column, or in rows.
+    pub(crate) fn has_category_levels_in_columns(&self) -> bool {
+        let (num_rows, num_cols) = self.value_range.number_of_range_points();
+
+        self.value_range.is_literal || num_rows >= num_cols
+    }
+
+    // Pad literal category and value data to the same number of points.
+    pub(crate) fn pad_literal_data(&mut self) {
+        if self.value_range.is_literal && self.category_range.is_literal {
+            let values = &mut self.value_range.cache.data;
+            let categories = &mut self.category_range.cache.data;
+            let num_points = values.len().max(categories.len());
+
+            values.resize(num_points, String::new());
+            categories.resize(num_points, String::new());
+        }
+    }
+
     /// Plot the chart series on the secondary axis.
     ///
     /// It is possible to add a secondary axis of the same type to a chart by
@@ -8111,8 +8443,9 @@ impl ChartSeries {
     ///
     /// The negative values are usually shown as a white solid fill with a black
     /// border but the `set_invert_if_negative_color()` method can be use to set
-    /// a user defined color. This also requires that you set a
-    /// [`ChartSolidFill`] for the series.
+    /// a user defined color. Excel only applies the inverted color if the
+    /// series also has a fill so, if you don't set a [`ChartSolidFill`] for the
+    /// series, a solid fill with the default series color is added.
     ///
     /// # Parameters
     ///
@@ -8121,8 +8454,8 @@ impl ChartSeries {
     /// # Examples
     ///
     /// A chart example demonstrating setting the "Invert if negative" property
-    /// and associated color for a chart series. This also requires that you set
-    /// a solid fill color for the series.
+    /// and associated color for a chart series, along with a solid fill color
+    /// for the positive values.
     ///
     /// ```
     /// # // This code is available in examples/doc_chart_series_set_invert_if_negative_color.rs
@@ -8283,6 +8616,7 @@ pub struct ChartRange {
     last_col: ColNum,
     range_string: String,
     pub(crate) cache: ChartRangeCacheData,
+    pub(crate) is_literal: bool,
 }
 
 impl Default for ChartRange {
@@ -8325,6 +8659,19 @@ impl ChartRange {
             last_col,
             range_string: String::new(),
             cache: ChartRangeCacheData::new(),
+            is_literal: false,
+        }
+    }
+
+    // Create a chart range from literal data that isn't stored in a worksheet.
+    pub(crate) fn new_from_literal(
+        data: Vec<String>,
+        cache_type: ChartRangeCacheDataType,
+    ) -> ChartRange {
+        ChartRange {
+            cache: ChartRangeCacheData { cache_type, data },
+            is_literal: true,
+            ..ChartRange::default()
         }
     }
 
@@ -8356,7 +8703,8 @@ impl ChartRange {
         let mut last_col = 0;
 
         // Parse the chart range string into the worksheet name and range parts.
-        if let Some(position) = range_string.find('!') {
+        // The last "!" is used since it is a valid character in sheet names.
+        if let Some(position) = range_string.rfind('!') {
             let range = &range_string[position + 1..].replace('$', "");
 
             if utility::is_valid_range(range) {
@@ -8402,19 +8750,18 @@ impl ChartRange {
             sheet_name = &sheet_name[1..];
         }
 
-        // Strip the quotes from quoted sheet names.
-        if sheet_name.starts_with('\'') && sheet_name.ends_with('\'') {
-            sheet_name = &sheet_name[1..sheet_name.len() - 1];
-        }
+        // Strip the quotes, and any doubled quotes, from quoted sheet names.
+        let sheet_name = utility::unquote_sheetname(sheet_name);
 
         ChartRange {
-            sheet_name: sheet_name.to_string(),
+            sheet_name,
             first_row,
             first_col,
             last_row,
             last_col,
             range_string: range_string.to_string(),
             cache: ChartRangeCacheData::new(),
+            is_literal: false,
         }
     }
 
@@ -8464,11 +8811,19 @@ impl ChartRange {
 
     // Check that the range has data.
     pub(crate) fn has_data(&self) -> bool {
-        !self.sheet_name.is_empty()
+        if self.is_literal {
+            self.cache.has_data()
+        } else {
+            !self.sheet_name.is_empty()
+        }
     }
 
     // Get the number of X or Y data points in the range.
     pub(crate) fn number_of_points(&self) -> usize {
+        if self.is_literal {
+            return self.cache.data.len();
+        }
+
         let row_range = (self.last_row - self.first_row + 1) as usize;
         let col_range = (self.last_col - self.first_col + 1) as usize;
 
@@ -8507,6 +8862,11 @@ impl ChartRange {
 
     // Check that the row/column values in the range are valid.
     pub(crate) fn validate(&self) -> Result<(), XlsxError> {
+        // Literal data doesn't refer to a worksheet range.
+        if self.is_literal {
+            return Ok(());
+        }
+
         let range = self.error_range();
 
         let error_message = format!("Sheet name error for range: '{range}'");
@@ -13796,6 +14156,14 @@ impl ChartFormat {
             || self.no_fill
             || self.no_line
     }
+
+    // Check if a fill type has been set for the struct.
+    fn has_fill(&self) -> bool {
+        self.solid_fill.is_some()
+            || self.pattern_fill.is_some()
+            || self.gradient_fill.is_some()
+            || self.no_fill
+    }
 }
 
 /// The `ChartLine` struct represents a chart line/border.
@@ -17176,8 +17544,6 @@ impl ChartDataTable {
     ///
     /// `font`: A [`ChartFont`] struct reference to represent the font
     /// properties.
-    ///
-
     ///
     pub fn set_font(mut self, font: &ChartFont) -> ChartDataTable {
         self.font = Some(font.clone());
@@ -17615,8 +17981,8 @@ impl ChartPlotArea {
     /// # Parameters
     ///
     /// - `format`: A [`ChartFormat`] struct reference or a sub struct that will
-    ///    convert into a `ChartFormat` instance. See the docs for
-    ///    [`IntoChartFormat`] for details.
+    ///   convert into a `ChartFormat` instance. See the docs for
+    ///   [`IntoChartFormat`] for details.
     ///
     /// # Examples
     ///
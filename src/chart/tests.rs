@@ -7,7 +7,7 @@
 #[cfg(test)]
 mod chart_tests {
 
-    use crate::chart::{Chart, ChartRange, ChartSeries, ChartType, XlsxError};
+    use crate::chart::{Chart, ChartFont, ChartRange, ChartSeries, ChartType, XlsxError};
     use crate::test_functions::xml_to_vec;
     use crate::ChartRangeCacheDataType;
     use pretty_assertions::assert_eq;
@@ -294,4 +294,265 @@ mod chart_tests {
         assert_eq!("'Sheet 1'!$A$1:$A$5", range.formula_abs());
         assert_eq!("Sheet 1", range.sheet_name);
     }
+
+    #[test]
+    fn test_range_with_quoted_sheet_names() {
+        let range_string = "='My Sheet'!$A$1:$A$5";
+        let range = ChartRange::new_from_string(range_string);
+        assert_eq!("'My Sheet'!$A$1:$A$5", range.formula_abs());
+        assert_eq!("My Sheet", range.sheet_name);
+
+        let range_string = "='Sales Q1 (EU)'!$B$2";
+        let range = ChartRange::new_from_string(range_string);
+        assert_eq!("'Sales Q1 (EU)'!$B$2", range.formula_abs());
+        assert_eq!("Sales Q1 (EU)", range.sheet_name);
+
+        let range_string = "='Bob''s Data'!$A$1:$A$5";
+        let range = ChartRange::new_from_string(range_string);
+        assert_eq!("'Bob''s Data'!$A$1:$A$5", range.formula_abs());
+        assert_eq!("Bob's Data", range.sheet_name);
+
+        let range_string = "='Hello!'!$A$1:$A$5";
+        let range = ChartRange::new_from_string(range_string);
+        assert_eq!("'Hello!'!$A$1:$A$5", range.formula_abs());
+        assert_eq!("Hello!", range.sheet_name);
+
+        let range = ChartRange::new_from_range("My Sheet", 0, 1, 4, 1);
+        assert_eq!("'My Sheet'!$B$1:$B$5", range.formula_abs());
+        assert_eq!("'My Sheet'!B1:B5", range.formula());
+
+        let range = ChartRange::new_from_range("Bob's Data", 0, 1, 4, 1);
+        assert_eq!("'Bob''s Data'!$B$1:$B$5", range.formula_abs());
+
+        let range = ChartRange::new_from_range("Sheet1", 0, 1, 4, 1);
+        assert_eq!("Sheet1!$B$1:$B$5", range.formula_abs());
+    }
+
+    #[test]
+    fn test_invert_if_negative_default_fill() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart
+            .add_series()
+            .set_values("Sheet1!$B$1:$B$5")
+            .set_invert_if_negative_color("#FF0000");
+
+        let series = chart.get_series(true);
+        chart.write_series(&series);
+
+        // The second series gets a solid fill with the default series color so
+        // that Excel applies the inverted fill color.
+        let got = chart.writer.read_to_str();
+        let expected = concat!(
+            r#"<c:order val="1"/>"#,
+            r#"<c:spPr><a:solidFill><a:schemeClr val="accent2"/></a:solidFill></c:spPr>"#,
+            r#"<c:invertIfNegative val="1"/>"#,
+        );
+        assert!(got.contains(expected));
+        assert!(got.contains(r#"<c14:spPr xmlns:c14="http://schemas.microsoft.com/office/drawing/2007/8/2/chart"><a:solidFill><a:srgbClr val="FF0000"/></a:solidFill></c14:spPr>"#));
+
+        // The first series is unchanged.
+        assert!(got.contains(r#"<c:order val="0"/><c:val>"#));
+    }
+
+    #[test]
+    fn test_literal_series_data() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_categories_from_strings(&["Jan", "Feb"])
+            .set_values_from_values(&[50.0, 30.5, 40.0]);
+
+        // Validation should pad the shorter categories list.
+        chart.validate().unwrap();
+
+        let series = chart.get_series(true);
+        chart.write_series(&series);
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <c:ser>
+              <c:idx val="0"/>
+              <c:order val="0"/>
+              <c:cat>
+                <c:strLit>
+                  <c:ptCount val="3"/>
+                  <c:pt idx="0"><c:v>Jan</c:v></c:pt>
+                  <c:pt idx="1"><c:v>Feb</c:v></c:pt>
+                  <c:pt idx="2"><c:v></c:v></c:pt>
+                </c:strLit>
+              </c:cat>
+              <c:val>
+                <c:numLit>
+                  <c:formatCode>General</c:formatCode>
+                  <c:ptCount val="3"/>
+                  <c:pt idx="0"><c:v>50</c:v></c:pt>
+                  <c:pt idx="1"><c:v>30.5</c:v></c:pt>
+                  <c:pt idx="2"><c:v>40</c:v></c:pt>
+                </c:numLit>
+              </c:val>
+            </c:ser>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Empty literal values aren't a valid values range.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values_from_values(&[]);
+
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_multi_level_categories() {
+        let mut categories = ChartRange::new_from_string("Sheet1!$A$1:$B$4");
+        categories.set_cache(
+            &["Q1", "Jan", "", "Feb", "Q2", "Mar", "", "Apr"],
+            ChartRangeCacheDataType::String,
+        );
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_categories(&categories)
+            .set_values("Sheet1!$C$1:$C$4");
+
+        chart.validate().unwrap();
+
+        let series = chart.get_series(true);
+        chart.write_series(&series);
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <c:ser>
+              <c:idx val="0"/>
+              <c:order val="0"/>
+              <c:cat>
+                <c:multiLvlStrRef>
+                  <c:f>Sheet1!$A$1:$B$4</c:f>
+                  <c:multiLvlStrCache>
+                    <c:ptCount val="4"/>
+                    <c:lvl>
+                      <c:pt idx="0"><c:v>Jan</c:v></c:pt>
+                      <c:pt idx="1"><c:v>Feb</c:v></c:pt>
+                      <c:pt idx="2"><c:v>Mar</c:v></c:pt>
+                      <c:pt idx="3"><c:v>Apr</c:v></c:pt>
+                    </c:lvl>
+                    <c:lvl>
+                      <c:pt idx="0"><c:v>Q1</c:v></c:pt>
+                      <c:pt idx="2"><c:v>Q2</c:v></c:pt>
+                    </c:lvl>
+                  </c:multiLvlStrCache>
+                </c:multiLvlStrRef>
+              </c:cat>
+              <c:val>
+                <c:numRef>
+                  <c:f>Sheet1!$C$1:$C$4</c:f>
+                </c:numRef>
+              </c:val>
+            </c:ser>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // The number of categories must match the number of values.
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$B$3")
+            .set_values("Sheet1!$C$1:$C$4");
+
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_chart_font_reuse() {
+        let mut font = ChartFont::new();
+        font.set_name("Arial")
+            .set_size(12)
+            .set_bold()
+            .set_italic()
+            .set_underline()
+            .set_color("#FF0000");
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_axis_ids(64052224, 64055552);
+
+        chart.title().set_name("Title").set_font(&font);
+        chart
+            .x_axis()
+            .set_name("X")
+            .set_name_font(&font)
+            .set_font(&font);
+        chart.legend().set_font(&font);
+
+        chart.assemble_xml_file();
+
+        // The same font properties are written for the title, the axis name,
+        // the axis numbers and the legend.
+        let got = chart.writer.read_to_str();
+        let font_properties = r#"sz="1200" b="1" i="1" u="sng""#;
+
+        assert_eq!(
+            4,
+            got.matches(&format!("<a:defRPr {font_properties}")).count()
+        );
+        assert_eq!(
+            2,
+            got.matches(&format!("<a:rPr lang=\"en-US\" {font_properties}"))
+                .count()
+        );
+        assert_eq!(6, got.matches(r#"<a:srgbClr val="FF0000"/>"#).count());
+        assert_eq!(6, got.matches(r#"<a:latin typeface="Arial"/>"#).count());
+    }
+
+    #[test]
+    fn test_chart_style_range() {
+        let mut chart = Chart::new(ChartType::Column);
+        assert_eq!(2, chart.style);
+
+        chart.set_style(48);
+        assert_eq!(48, chart.style);
+
+        // Values outside the Excel range 1-48 are ignored.
+        chart.set_style(0);
+        assert_eq!(48, chart.style);
+
+        chart.set_style(49);
+        assert_eq!(48, chart.style);
+
+        chart.set_style(1);
+        chart.write_style();
+
+        let got = chart.writer.read_to_str();
+        assert_eq!(r#"<c:style val="1"/>"#, got);
+    }
+
+    #[test]
+    fn test_pie_rotation_and_hole_size_range() {
+        let mut chart = Chart::new(ChartType::Doughnut);
+
+        chart.set_hole_size(10).set_rotation(360);
+        assert_eq!(10, chart.hole_size);
+        assert_eq!(360, chart.rotation);
+
+        // Values outside the Excel ranges are ignored.
+        chart.set_hole_size(9).set_rotation(361);
+        assert_eq!(10, chart.hole_size);
+        assert_eq!(360, chart.rotation);
+
+        chart.set_hole_size(91);
+        assert_eq!(10, chart.hole_size);
+    }
 }
@@ -8,7 +8,7 @@
 
 use std::collections::BTreeMap;
 
-use crate::{utility, xmlwriter::XMLWriter, ColNum, Note, RowNum};
+use crate::{utility, xmlwriter::XMLWriter, ColNum, Color, Note, RowNum};
 
 /// A struct to represent a Comment.
 ///
@@ -59,14 +59,36 @@ impl Comment {
 
     // Write the <comments> element.
     fn write_comments(&mut self) {
-        let attributes = [(
+        let mut attributes = vec![(
             "xmlns",
             "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
         )];
 
+        // The revision namespace is required for the ids that link legacy
+        // notes to threaded comments.
+        if self.has_threaded_comments() {
+            attributes.push((
+                "xmlns:mc",
+                "http://schemas.openxmlformats.org/markup-compatibility/2006",
+            ));
+            attributes.push(("mc:Ignorable", "xr"));
+            attributes.push((
+                "xmlns:xr",
+                "http://schemas.microsoft.com/office/spreadsheetml/2014/revision",
+            ));
+        }
+
         self.writer.xml_start_tag("comments", &attributes);
     }
 
+    // Check if any of the notes are the legacy version of a threaded comment.
+    fn has_threaded_comments(&self) -> bool {
+        self.notes
+            .values()
+            .flat_map(|columns| columns.values())
+            .any(|note| note.threaded_comment_id.is_some())
+    }
+
     // Write the <authors> element.
     fn write_authors(&mut self) {
         if self.note_authors.is_empty() {
@@ -106,7 +128,13 @@ impl Comment {
     // Write the <comment> element.
     fn write_comment(&mut self, row: RowNum, col: ColNum, note: &Note) {
         let cell = utility::row_col_to_cell(row, col);
-        let attributes = vec![("ref", cell), ("authorId", note.author_id.to_string())];
+        let mut attributes = vec![("ref", cell), ("authorId", note.author_id.to_string())];
+
+        // Link the legacy note to its threaded comment.
+        if let Some(id) = &note.threaded_comment_id {
+            attributes.push(("shapeId", "0".to_string()));
+            attributes.push(("xr:uid", id.clone()));
+        }
 
         self.writer.xml_start_tag("comment", &attributes);
 
@@ -164,7 +192,7 @@ impl Comment {
         self.write_font_size(note);
 
         // Write the color element.
-        self.write_font_color();
+        self.write_font_color(note);
 
         // Write the rFont element.
         self.write_font_name(note);
@@ -183,8 +211,11 @@ impl Comment {
     }
 
     // Write the <color> element.
-    fn write_font_color(&mut self) {
-        let attributes = [("indexed", "81".to_string())];
+    fn write_font_color(&mut self, note: &Note) {
+        let attributes = match note.format.font.color {
+            Color::Default | Color::Automatic => vec![("indexed", "81".to_string())],
+            color => color.attributes(),
+        };
 
         self.writer.xml_empty_tag("color", &attributes);
     }
@@ -950,6 +950,9 @@ pub trait ConditionalFormat {
     /// Check if the conditional format uses Excel 2010+ extensions only.
     fn has_x14_only(&self) -> bool;
 
+    /// Get the user defined priority of the conditional format, if present.
+    fn priority(&self) -> Option<u32>;
+
     /// Clone a reference into a concrete Box type.
     fn box_clone(&self) -> Box<dyn ConditionalFormat + Send>;
 }
@@ -991,6 +994,10 @@ macro_rules! generate_conditional_format_impls {
                 self.has_x14_only()
             }
 
+            fn priority(&self) -> Option<u32> {
+                self.priority()
+            }
+
 
             fn box_clone(&self) -> Box<dyn ConditionalFormat + Send> {
                 Box::new(self.clone())
@@ -1185,6 +1192,7 @@ pub struct ConditionalFormatCell {
     rule: Option<ConditionalFormatCellRule<ConditionalFormatValue>>,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1198,6 +1206,7 @@ impl ConditionalFormatCell {
             rule: None,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -1536,6 +1545,7 @@ pub struct ConditionalFormatBlank {
     is_inverted: bool,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1549,6 +1559,7 @@ impl ConditionalFormatBlank {
             is_inverted: false,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -1737,6 +1748,7 @@ pub struct ConditionalFormatError {
     is_inverted: bool,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1750,6 +1762,7 @@ impl ConditionalFormatError {
             is_inverted: false,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -1939,6 +1952,7 @@ pub struct ConditionalFormatDuplicate {
     is_inverted: bool,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1952,6 +1966,7 @@ impl ConditionalFormatDuplicate {
             is_inverted: false,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2134,6 +2149,7 @@ pub struct ConditionalFormatFormula {
     formula: Formula,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2147,6 +2163,7 @@ impl ConditionalFormatFormula {
             formula: Formula::new(""),
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2413,6 +2430,7 @@ pub struct ConditionalFormatAverage {
     criteria: ConditionalFormatAverageRule,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2426,6 +2444,7 @@ impl ConditionalFormatAverage {
             criteria: ConditionalFormatAverageRule::AboveAverage,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2655,6 +2674,7 @@ pub struct ConditionalFormatTop {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2669,6 +2689,7 @@ impl ConditionalFormatTop {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2908,6 +2929,7 @@ pub struct ConditionalFormatText {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2922,6 +2944,7 @@ impl ConditionalFormatText {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -3175,6 +3198,7 @@ pub struct ConditionalFormatDate {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -3189,6 +3213,7 @@ impl ConditionalFormatDate {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -3426,6 +3451,7 @@ pub struct ConditionalFormat2ColorScale {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -3445,6 +3471,7 @@ impl ConditionalFormat2ColorScale {
             max_color: Color::RGB(0x63BE7B),
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -3889,6 +3916,7 @@ pub struct ConditionalFormat3ColorScale {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -3911,6 +3939,7 @@ impl ConditionalFormat3ColorScale {
             max_color: Color::RGB(0x63BE7B),
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -4421,6 +4450,7 @@ pub struct ConditionalFormatDataBar {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -4449,6 +4479,7 @@ impl ConditionalFormatDataBar {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: true,
             has_x14_only: false,
             format: None,
@@ -5697,6 +5728,7 @@ pub struct ConditionalFormatIconSet {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -5715,6 +5747,7 @@ impl ConditionalFormatIconSet {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -7399,6 +7432,28 @@ macro_rules! generate_conditional_common_methods {
             self
         }
 
+        /// Set the priority of the conditional format rule.
+        ///
+        /// When more than one conditional format rule applies to a cell the
+        /// rules are evaluated in order of priority, with 1 being the highest
+        /// priority. By default the rules in a worksheet have a priority based
+        /// on the order in which they are added to the worksheet. The
+        /// `set_priority()` method can be used to change this order.
+        ///
+        /// The priorities are written to the file in ascending order across
+        /// all the rules in the worksheet. Rules with the same priority, or
+        /// without a priority, are ordered by when they were added. See also
+        /// [`set_stop_if_true()`](Self::set_stop_if_true).
+        ///
+        /// # Parameters
+        ///
+        /// - `priority`: The rule priority. Lower values are evaluated first.
+        ///
+        pub fn set_priority(mut self, priority: u32) -> $t {
+            self.priority = Some(priority);
+            self
+        }
+
         // Get the index of the format object in the conditional format.
         pub(crate) fn format_index(&self) -> Option<u32> {
             self.format.as_ref().map(|format| format.dxf_index)
@@ -7423,6 +7478,11 @@ macro_rules! generate_conditional_common_methods {
         pub(crate) fn has_x14_only(&self) -> bool {
             self.has_x14_only
         }
+
+        // Get the user defined priority of the conditional format.
+        pub(crate) fn priority(&self) -> Option<u32> {
+            self.priority
+        }
     }
     )*)
 }
@@ -7486,6 +7546,28 @@ impl ConditionalFormatCell {
         self
     }
 
+    /// Set the priority of the conditional format rule.
+    ///
+    /// When more than one conditional format rule applies to a cell the
+    /// rules are evaluated in order of priority, with 1 being the highest
+    /// priority. By default the rules in a worksheet have a priority based
+    /// on the order in which they are added to the worksheet. The
+    /// `set_priority()` method can be used to change this order.
+    ///
+    /// The priorities are written to the file in ascending order across
+    /// all the rules in the worksheet. Rules with the same priority, or
+    /// without a priority, are ordered by when they were added. See also
+    /// [`set_stop_if_true()`](Self::set_stop_if_true).
+    ///
+    /// # Parameters
+    ///
+    /// - `priority`: The rule priority. Lower values are evaluated first.
+    ///
+    pub fn set_priority(mut self, priority: u32) -> ConditionalFormatCell {
+        self.priority = Some(priority);
+        self
+    }
+
     // Get the index of the format object in the conditional format.
     pub(crate) fn format_index(&self) -> Option<u32> {
         self.format.as_ref().map(|format| format.dxf_index)
@@ -7510,6 +7592,11 @@ impl ConditionalFormatCell {
     pub(crate) fn has_x14_only(&self) -> bool {
         self.has_x14_only
     }
+
+    // Get the user defined priority of the conditional format.
+    pub(crate) fn priority(&self) -> Option<u32> {
+        self.priority
+    }
 }
 
 // -----------------------------------------------------------------------
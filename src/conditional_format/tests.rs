@@ -5227,4 +5227,64 @@ mod conditional_format_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn conditional_format_priority() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // Overlapping rules. The priority should follow the order the rules
+        // are added, not the order of the ranges.
+        let conditional_format =
+            ConditionalFormatCell::new().set_rule(ConditionalFormatCellRule::GreaterThan(5));
+        worksheet.add_conditional_format(0, 1, 3, 1, &conditional_format)?;
+
+        let conditional_format =
+            ConditionalFormatCell::new().set_rule(ConditionalFormatCellRule::LessThan(10));
+        worksheet.add_conditional_format(0, 0, 3, 1, &conditional_format)?;
+
+        // A user defined priority moves the rule to the top.
+        let conditional_format = ConditionalFormatCell::new()
+            .set_rule(ConditionalFormatCellRule::EqualTo(7))
+            .set_priority(1)
+            .set_stop_if_true(true);
+        worksheet.add_conditional_format(0, 1, 3, 1, &conditional_format)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <conditionalFormatting sqref="A1:B4">
+                <cfRule type="cellIs" priority="3" operator="lessThan">
+                  <formula>10</formula>
+                </cfRule>
+              </conditionalFormatting>
+              <conditionalFormatting sqref="B1:B4">
+                <cfRule type="cellIs" priority="2" operator="greaterThan">
+                  <formula>5</formula>
+                </cfRule>
+                <cfRule type="cellIs" priority="1" stopIfTrue="1" operator="equal">
+                  <formula>7</formula>
+                </cfRule>
+              </conditionalFormatting>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
 }
@@ -110,6 +110,33 @@ impl ContentTypes {
         self.add_override(&part_name, content_type);
     }
 
+    // Add the name of a threaded comment file to the ContentTypes overrides.
+    pub(crate) fn add_threaded_comments_name(&mut self, index: u16) {
+        let content_type = "application/vnd.ms-excel.threadedcomments+xml";
+        let part_name = format!("/xl/threadedComments/threadedComment{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
+    // Add the name of a printer settings file to the ContentTypes overrides.
+    // An override is used instead of a default extension since the "bin"
+    // extension is also used for xlsm vbaProject files.
+    pub(crate) fn add_printer_settings_name(&mut self, index: u16) {
+        let content_type =
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.printerSettings";
+        let part_name = format!("/xl/printerSettings/printerSettings{index}.bin");
+
+        self.add_override(&part_name, content_type);
+    }
+
+    // Add the persons file to the ContentTypes overrides.
+    pub(crate) fn add_persons(&mut self) {
+        self.add_override(
+            "/xl/persons/person.xml",
+            "application/vnd.ms-excel.person+xml",
+        );
+    }
+
     // Add the sharedStrings link to the ContentTypes overrides.
     pub(crate) fn add_share_strings(&mut self) {
         self.add_override(
@@ -176,7 +176,7 @@ use std::fmt;
 /// reference you should take care to ensure that the ranges or formulas are
 /// valid in Excel.
 ///
-#[derive(Clone)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 pub struct DataValidation {
     pub(crate) validation_type: DataValidationType,
     pub(crate) rule: DataValidationRuleInternal,
@@ -1326,6 +1326,28 @@ impl DataValidation {
             && self.error_title.is_empty()
             && self.error_message.is_empty()
     }
+
+    // Check if the rule values contain relative cell references. Excel applies
+    // these relative to the top left cell of the range so data validations
+    // that contain them can't be combined with other ranges.
+    pub(crate) fn has_relative_references(&self) -> bool {
+        let values = match &self.rule {
+            DataValidationRuleInternal::EqualTo(value)
+            | DataValidationRuleInternal::NotEqualTo(value)
+            | DataValidationRuleInternal::LessThan(value)
+            | DataValidationRuleInternal::LessThanOrEqualTo(value)
+            | DataValidationRuleInternal::GreaterThan(value)
+            | DataValidationRuleInternal::GreaterThanOrEqualTo(value)
+            | DataValidationRuleInternal::ListSource(value)
+            | DataValidationRuleInternal::CustomFormula(value) => vec![value],
+            DataValidationRuleInternal::Between(min, max)
+            | DataValidationRuleInternal::NotBetween(min, max) => vec![min, max],
+        };
+
+        values
+            .iter()
+            .any(|value| Formula::shift_references(value, 1, 1).as_ref() != Some(*value))
+    }
 }
 
 /// Trait to map rust types into data validation types
@@ -1428,7 +1450,7 @@ impl IntoDataValidationValue for &NaiveTime {
 // -----------------------------------------------------------------------
 
 /// The `DataValidationType` enum defines the type of data validation.
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 pub(crate) enum DataValidationType {
     Whole,
 
@@ -1536,7 +1558,7 @@ impl<T: IntoDataValidationValue> DataValidationRule<T> {
 
 // This is a variation on `DataValidationRule` that is used for internal storage
 // of the validation rule. It only uses the String type.
-#[derive(Clone)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 pub(crate) enum DataValidationRuleInternal {
     EqualTo(String),
 
@@ -1583,7 +1605,7 @@ impl fmt::Display for DataValidationRuleInternal {
 /// The `DataValidationErrorStyle` enum defines the type of error dialog that is
 /// shown when there is and error in a data validation.
 ///
-#[derive(Clone)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 pub enum DataValidationErrorStyle {
     /// Show a "Stop" dialog. This is the default.
     Stop,
@@ -1671,4 +1671,74 @@ mod data_validation_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn data_validation_28() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        // Identical validations should be combined into a single element.
+        let data_validation =
+            DataValidation::new().allow_whole_number(DataValidationRule::GreaterThan(10));
+
+        worksheet.add_data_validation(0, 0, 0, 0, &data_validation)?;
+        worksheet.add_data_validation(1, 0, 1, 0, &data_validation)?;
+        worksheet.add_data_validation(2, 0, 2, 0, &data_validation)?;
+        worksheet.add_data_validation(0, 2, 4, 2, &data_validation)?;
+
+        // Validations with relative references shouldn't be combined.
+        let data_validation = DataValidation::new().allow_custom(Formula::new("=AND(B1>0,$D$1>0)"));
+
+        worksheet.add_data_validation(0, 1, 0, 1, &data_validation)?;
+        worksheet.add_data_validation(1, 1, 1, 1, &data_validation)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <dataValidations count="3">
+                <dataValidation type="whole" operator="greaterThan" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="A1 A2 A3 C1:C5">
+                  <formula1>10</formula1>
+                </dataValidation>
+                <dataValidation type="custom" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B1">
+                  <formula1>AND(B1&gt;0,$D$1&gt;0)</formula1>
+                </dataValidation>
+                <dataValidation type="custom" allowBlank="1" showInputMessage="1" showErrorMessage="1" sqref="B2">
+                  <formula1>AND(B1&gt;0,$D$1&gt;0)</formula1>
+                </dataValidation>
+              </dataValidations>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Validations without relative references are combined.
+        let data_validation = DataValidation::new().allow_list_formula(Formula::new("=$D$1:$D$3"));
+        assert!(!data_validation.has_relative_references());
+
+        let data_validation =
+            DataValidation::new().allow_decimal_number(DataValidationRule::Between(-1.5, 1e10));
+        assert!(!data_validation.has_relative_references());
+
+        let data_validation = DataValidation::new().allow_list_strings(&["A1", "B2"])?;
+        assert!(!data_validation.has_relative_references());
+
+        let data_validation = DataValidation::new().allow_list_formula(Formula::new("=D1:D3"));
+        assert!(data_validation.has_relative_references());
+
+        Ok(())
+    }
 }
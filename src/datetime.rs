@@ -7,6 +7,8 @@
 #![warn(missing_docs)]
 mod tests;
 
+#[cfg(feature = "serde")]
+use crate::serializer::DATETIME_NEWTYPE_NAME;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -22,7 +24,7 @@ use std::time::SystemTime;
 
 use crate::XlsxError;
 
-const DAY_SECONDS: u64 = 24 * 60 * 60;
+pub(crate) const DAY_SECONDS: u64 = 24 * 60 * 60;
 const HOUR_SECONDS: u64 = 60 * 60;
 const MINUTE_SECONDS: u64 = 60;
 const YEAR_DAYS: u64 = 365;
@@ -862,6 +864,76 @@ impl ExcelDateTime {
         Ok(dt)
     }
 
+    /// Create a `ExcelDateTime` instance from a time duration.
+    ///
+    /// Create a `ExcelDateTime` instance from a [`std::time::Duration`]. Excel
+    /// stores durations, like other times, as a fraction of a day so a
+    /// duration of 36 hours is stored as 1.5.
+    ///
+    /// Durations of 24 hours or more should be displayed with an elapsed time
+    /// number format such as `[h]:mm` or `[h]:mm:ss`. With a standard `hh:mm`
+    /// format Excel will wrap the hours at 24. See also
+    /// [`Worksheet::write_duration()`](crate::Worksheet::write_duration).
+    ///
+    /// # Parameters
+    ///
+    /// - `duration`: A [`std::time::Duration`] instance.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::DateTimeRangeError`] - The duration is outside Excel's
+    ///   allowed range of 2,958,465 days, i.e., up to the year 9999.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a time duration, that is
+    /// greater than 24 hours, with an elapsed time format.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_datetime_from_duration.rs
+    /// #
+    /// # use std::time::Duration;
+    /// #
+    /// # use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create an elapsed time format for durations.
+    ///     let format = Format::new().set_num_format("[h]:mm");
+    ///
+    ///     // Create a datetime object from a 37.5 hour duration.
+    ///     let duration = ExcelDateTime::from_duration(Duration::from_secs(37 * 3600 + 1800))?;
+    ///
+    ///     // Write the duration. It will be displayed as "37:30".
+    ///     worksheet.write_with_format(0, 0, &duration, &format)?;
+    /// #
+    /// #     workbook.save("datetime.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_duration(duration: std::time::Duration) -> Result<ExcelDateTime, XlsxError> {
+        let days = duration.as_secs_f64() / DAY_SECONDS as f64;
+
+        if days >= 2_958_466.0 {
+            return Err(XlsxError::DateTimeRangeError(format!(
+                "Duration: '{duration:?}' outside Excel range of 2,958,465 days"
+            )));
+        }
+
+        let dt = ExcelDateTime {
+            serial_datetime: Some(days),
+            ..ExcelDateTime::default()
+        };
+
+        Ok(dt)
+    }
+
     /// Convert the `ExcelDateTime` to an Excel serial date.
     ///
     /// An Excel serial date is a f64 number that represents the time since the
@@ -1407,8 +1479,10 @@ impl IntoExcelDateTime for NaiveTime {
 
 /// Implementation of the `serde::Serialize` trait for `ExcelDateTime`.
 ///
-/// An Excel datetime is a number (see the [`ExcelDateTime`] docs) so it will
-/// also need to have an Excel cell format applied to it to display as a date.
+/// An Excel datetime is a number (see the [`ExcelDateTime`] docs) so it is
+/// serialized as an `f64` newtype. When it is serialized to a worksheet it is
+/// written with a default date, time or datetime number format unless the
+/// field has a value format with a number format.
 ///
 #[cfg(feature = "serde")]
 impl Serialize for ExcelDateTime {
@@ -1417,7 +1491,7 @@ impl Serialize for ExcelDateTime {
         S: Serializer,
     {
         let serial_datetime = self.to_excel();
-        serializer.serialize_f64(serial_datetime)
+        serializer.serialize_newtype_struct(DATETIME_NEWTYPE_NAME, &serial_datetime)
     }
 }
 
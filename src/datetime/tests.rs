@@ -1345,6 +1345,27 @@ mod datetime_tests {
         }
     }
 
+    #[test]
+    fn durations() {
+        use std::time::Duration;
+
+        let durations = vec![
+            (Duration::from_secs(0), 0.0),
+            (Duration::from_secs(43200), 0.5),
+            (Duration::from_secs(86400), 1.0),
+            (Duration::from_secs(135000), 1.5625), // 37:30
+            (Duration::from_millis(129_600_500), 1.5000057870370371),
+        ];
+
+        for (duration, expected) in durations {
+            let datetime = ExcelDateTime::from_duration(duration).unwrap();
+            assert_eq!(expected, datetime.to_excel());
+        }
+
+        let result = ExcelDateTime::from_duration(Duration::from_secs(2_958_466 * 86400));
+        assert!(matches!(result, Err(XlsxError::DateTimeRangeError(_))));
+    }
+
     #[test]
     fn timestamp_to_rfc3339_times() {
         let tests = [
@@ -6,7 +6,7 @@
 
 mod tests;
 
-use crate::{xmlwriter::XMLWriter, ObjectMovement, Url};
+use crate::{xmlwriter::XMLWriter, Color, ObjectMovement, TextBoxOptions, Url};
 
 pub struct Drawing {
     pub(crate) writer: XMLWriter,
@@ -87,6 +87,7 @@ impl Drawing {
         match drawing_info.drawing_type {
             DrawingType::Image => self.write_pic(index, drawing_info),
             DrawingType::Chart => self.write_graphic_frame(index, drawing_info),
+            DrawingType::TextBox => self.write_sp(index, drawing_info),
             DrawingType::Vml => {}
         }
 
@@ -427,6 +428,242 @@ impl Drawing {
 
         self.writer.xml_empty_tag("c:chart", &attributes);
     }
+
+    // Write the <xdr:sp> element for a text box shape.
+    fn write_sp(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        let Some(textbox) = &drawing_info.textbox else {
+            return;
+        };
+
+        let attributes = [("macro", ""), ("textlink", "")];
+
+        self.writer.xml_start_tag("xdr:sp", &attributes);
+
+        // Write the xdr:nvSpPr element.
+        self.write_nv_sp_pr(index, drawing_info);
+
+        // Write the xdr:spPr element.
+        self.write_textbox_sp_pr(drawing_info, textbox);
+
+        // Write the xdr:style element.
+        self.write_style();
+
+        // Write the xdr:txBody element.
+        self.write_tx_body(textbox);
+
+        self.writer.xml_end_tag("xdr:sp");
+    }
+
+    // Write the <xdr:nvSpPr> element.
+    fn write_nv_sp_pr(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        self.writer.xml_start_tag_only("xdr:nvSpPr");
+
+        // Write the xdr:cNvPr element.
+        self.write_c_nv_pr(index, drawing_info, "TextBox");
+
+        // Write the xdr:cNvSpPr element.
+        let attributes = [("txBox", "1")];
+        self.writer.xml_empty_tag("xdr:cNvSpPr", &attributes);
+
+        self.writer.xml_end_tag("xdr:nvSpPr");
+    }
+
+    // Write the <xdr:spPr> element for a text box.
+    fn write_textbox_sp_pr(&mut self, drawing_info: &DrawingInfo, textbox: &TextBoxOptions) {
+        self.writer.xml_start_tag_only("xdr:spPr");
+        self.writer.xml_start_tag_only("a:xfrm");
+
+        // Write the a:off element.
+        self.write_a_off(drawing_info);
+
+        // Write the a:ext element.
+        self.write_a_ext(drawing_info);
+
+        self.writer.xml_end_tag("a:xfrm");
+
+        // Write the a:prstGeom element.
+        self.write_a_prst_geom();
+
+        // Write the a:solidFill element.
+        self.writer.xml_start_tag_only("a:solidFill");
+        if textbox.fill_color.is_auto_or_default() {
+            let attributes = [("val", "lt1")];
+            self.writer.xml_empty_tag("a:schemeClr", &attributes);
+        } else {
+            self.write_color(textbox.fill_color);
+        }
+        self.writer.xml_end_tag("a:solidFill");
+
+        // Write the a:ln element.
+        self.write_a_ln(textbox);
+
+        self.writer.xml_end_tag("xdr:spPr");
+    }
+
+    // Write the <a:ln> element.
+    fn write_a_ln(&mut self, textbox: &TextBoxOptions) {
+        if textbox.border_none {
+            self.writer.xml_start_tag_only("a:ln");
+            self.writer.xml_empty_tag_only("a:noFill");
+            self.writer.xml_end_tag("a:ln");
+            return;
+        }
+
+        let width = (textbox.border_width * 12700.0).round() as u32;
+        let attributes = [("w", width.to_string()), ("cmpd", "sng".to_string())];
+
+        self.writer.xml_start_tag("a:ln", &attributes);
+        self.writer.xml_start_tag_only("a:solidFill");
+
+        if textbox.border_color.is_auto_or_default() {
+            let attributes = [("val", "lt1")];
+            self.writer.xml_start_tag("a:schemeClr", &attributes);
+
+            let attributes = [("val", "50000")];
+            self.writer.xml_empty_tag("a:shade", &attributes);
+
+            self.writer.xml_end_tag("a:schemeClr");
+        } else {
+            self.write_color(textbox.border_color);
+        }
+
+        self.writer.xml_end_tag("a:solidFill");
+        self.writer.xml_end_tag("a:ln");
+    }
+
+    // Write the <xdr:style> element.
+    fn write_style(&mut self) {
+        self.writer.xml_start_tag_only("xdr:style");
+
+        for (name, idx) in [("a:lnRef", "0"), ("a:fillRef", "0"), ("a:effectRef", "0")] {
+            let attributes = [("idx", idx)];
+            self.writer.xml_start_tag(name, &attributes);
+
+            let attributes = [("r", "0"), ("g", "0"), ("b", "0")];
+            self.writer.xml_empty_tag("a:scrgbClr", &attributes);
+
+            self.writer.xml_end_tag(name);
+        }
+
+        let attributes = [("idx", "minor")];
+        self.writer.xml_start_tag("a:fontRef", &attributes);
+
+        let attributes = [("val", "dk1")];
+        self.writer.xml_empty_tag("a:schemeClr", &attributes);
+
+        self.writer.xml_end_tag("a:fontRef");
+
+        self.writer.xml_end_tag("xdr:style");
+    }
+
+    // Write the <xdr:txBody> element.
+    fn write_tx_body(&mut self, textbox: &TextBoxOptions) {
+        self.writer.xml_start_tag_only("xdr:txBody");
+
+        // Write the a:bodyPr element.
+        let attributes = [
+            ("vertOverflow", "clip"),
+            ("wrap", "square"),
+            ("rtlCol", "0"),
+            ("anchor", "t"),
+        ];
+        self.writer.xml_empty_tag("a:bodyPr", &attributes);
+
+        self.writer.xml_empty_tag_only("a:lstStyle");
+
+        // Each line of text is stored as a separate paragraph.
+        for line in textbox.text.split('\n') {
+            self.writer.xml_start_tag_only("a:p");
+
+            if line.is_empty() {
+                // Write the a:endParaRPr element.
+                self.write_font_properties("a:endParaRPr", textbox);
+            } else {
+                self.writer.xml_start_tag_only("a:r");
+
+                // Write the a:rPr element.
+                self.write_font_properties("a:rPr", textbox);
+
+                self.writer.xml_data_element_only("a:t", line);
+
+                self.writer.xml_end_tag("a:r");
+            }
+
+            self.writer.xml_end_tag("a:p");
+        }
+
+        self.writer.xml_end_tag("xdr:txBody");
+    }
+
+    // Write the <a:rPr> or <a:endParaRPr> font property elements.
+    fn write_font_properties(&mut self, tag: &str, textbox: &TextBoxOptions) {
+        let mut attributes = vec![
+            ("lang", "en-US".to_string()),
+            ("sz", ((textbox.font_size * 100.0) as u32).to_string()),
+        ];
+
+        if textbox.font_bold {
+            attributes.push(("b", "1".to_string()));
+        }
+
+        if textbox.font_italic {
+            attributes.push(("i", "1".to_string()));
+        }
+
+        let has_color = !textbox.font_color.is_auto_or_default();
+        let has_font = !textbox.font_name.is_empty();
+
+        if !has_color && !has_font {
+            self.writer.xml_empty_tag(tag, &attributes);
+            return;
+        }
+
+        self.writer.xml_start_tag(tag, &attributes);
+
+        if has_color {
+            self.writer.xml_start_tag_only("a:solidFill");
+            self.write_color(textbox.font_color);
+            self.writer.xml_end_tag("a:solidFill");
+        }
+
+        if has_font {
+            let attributes = [("typeface", textbox.font_name.clone())];
+            self.writer.xml_empty_tag("a:latin", &attributes);
+            self.writer.xml_empty_tag("a:cs", &attributes);
+        }
+
+        self.writer.xml_end_tag(tag);
+    }
+
+    // Write a DrawingML <a:srgbClr> or <a:schemeClr> color element.
+    fn write_color(&mut self, color: Color) {
+        if let Color::Theme(_, _) = color {
+            let (scheme, lum_mod, lum_off) = color.chart_scheme();
+            let attributes = [("val", scheme)];
+
+            if lum_mod == 0 && lum_off == 0 {
+                self.writer.xml_empty_tag("a:schemeClr", &attributes);
+                return;
+            }
+
+            self.writer.xml_start_tag("a:schemeClr", &attributes);
+
+            if lum_mod > 0 {
+                let attributes = [("val", lum_mod.to_string())];
+                self.writer.xml_empty_tag("a:lumMod", &attributes);
+            }
+
+            if lum_off > 0 {
+                let attributes = [("val", lum_off.to_string())];
+                self.writer.xml_empty_tag("a:lumOff", &attributes);
+            }
+
+            self.writer.xml_end_tag("a:schemeClr");
+        } else {
+            let attributes = [("val", color.rgb_hex_value())];
+            self.writer.xml_empty_tag("a:srgbClr", &attributes);
+        }
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -470,6 +707,7 @@ impl Default for DrawingInfo {
             object_movement: ObjectMovement::MoveButDontSizeWithCells,
             drawing_type: DrawingType::Image,
             url: None,
+            textbox: None,
         }
     }
 }
@@ -489,12 +727,14 @@ pub(crate) struct DrawingInfo {
     pub(crate) rel_id: u32,
     pub(crate) drawing_type: DrawingType,
     pub(crate) url: Option<Url>,
+    pub(crate) textbox: Option<TextBoxOptions>,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum DrawingType {
     Image,
     Chart,
+    TextBox,
     Vml,
 }
 
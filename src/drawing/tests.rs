@@ -43,6 +43,7 @@ mod drawing_tests {
             object_movement: ObjectMovement::MoveButDontSizeWithCells,
             drawing_type: DrawingType::Image,
             url: None,
+            textbox: None,
         };
 
         drawing.drawings.push(drawing_info);
@@ -100,4 +101,139 @@ mod drawing_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_textbox() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 1,
+            row: 1,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 4,
+            row: 7,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let mut textbox = TextBoxOptions::new()
+            .set_border_color("#FF0000")
+            .set_font_bold()
+            .set_font_name("Arial");
+        textbox.text = "Hello\n\nWorld".to_string();
+
+        let drawing_info = DrawingInfo {
+            from,
+            to,
+            col_absolute: 609600,
+            row_absolute: 190500,
+            width: 1828800.0,
+            height: 1143000.0,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            drawing_type: DrawingType::TextBox,
+            textbox: Some(textbox),
+            ..Default::default()
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor>
+                    <xdr:from>
+                    <xdr:col>1</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>1</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>4</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>7</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:to>
+                    <xdr:sp macro="" textlink="">
+                    <xdr:nvSpPr>
+                        <xdr:cNvPr id="2" name="TextBox 1"/>
+                        <xdr:cNvSpPr txBox="1"/>
+                    </xdr:nvSpPr>
+                    <xdr:spPr>
+                        <a:xfrm>
+                        <a:off x="609600" y="190500"/>
+                        <a:ext cx="1828800" cy="1143000"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                        <a:avLst/>
+                        </a:prstGeom>
+                        <a:solidFill>
+                        <a:schemeClr val="lt1"/>
+                        </a:solidFill>
+                        <a:ln w="9525" cmpd="sng">
+                        <a:solidFill>
+                            <a:srgbClr val="FF0000"/>
+                        </a:solidFill>
+                        </a:ln>
+                    </xdr:spPr>
+                    <xdr:style>
+                        <a:lnRef idx="0">
+                        <a:scrgbClr r="0" g="0" b="0"/>
+                        </a:lnRef>
+                        <a:fillRef idx="0">
+                        <a:scrgbClr r="0" g="0" b="0"/>
+                        </a:fillRef>
+                        <a:effectRef idx="0">
+                        <a:scrgbClr r="0" g="0" b="0"/>
+                        </a:effectRef>
+                        <a:fontRef idx="minor">
+                        <a:schemeClr val="dk1"/>
+                        </a:fontRef>
+                    </xdr:style>
+                    <xdr:txBody>
+                        <a:bodyPr vertOverflow="clip" wrap="square" rtlCol="0" anchor="t"/>
+                        <a:lstStyle/>
+                        <a:p>
+                        <a:r>
+                            <a:rPr lang="en-US" sz="1100" b="1">
+                            <a:latin typeface="Arial"/>
+                            <a:cs typeface="Arial"/>
+                            </a:rPr>
+                            <a:t>Hello</a:t>
+                        </a:r>
+                        </a:p>
+                        <a:p>
+                        <a:endParaRPr lang="en-US" sz="1100" b="1">
+                            <a:latin typeface="Arial"/>
+                            <a:cs typeface="Arial"/>
+                        </a:endParaRPr>
+                        </a:p>
+                        <a:p>
+                        <a:r>
+                            <a:rPr lang="en-US" sz="1100" b="1">
+                            <a:latin typeface="Arial"/>
+                            <a:cs typeface="Arial"/>
+                            </a:rPr>
+                            <a:t>World</a:t>
+                        </a:r>
+                        </a:p>
+                    </xdr:txBody>
+                    </xdr:sp>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -57,6 +57,11 @@ pub enum XlsxError {
     /// String exceeds Excel's limit of 32,767 characters.
     MaxStringLengthExceeded,
 
+    /// The number of unique strings in the workbook shared string table
+    /// exceeds the limit of 2,147,483,647. This is the largest string index
+    /// that can be read by applications that use signed 32-bit indices.
+    MaxUniqueStringsExceeded,
+
     /// Error when trying to retrieve a worksheet reference by index or by name.
     UnknownWorksheetNameOrIndex(String),
 
@@ -186,6 +191,14 @@ pub enum XlsxError {
     #[cfg(feature = "polars")]
     #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
     PolarsError(PolarsError),
+
+    /// An xml part generated when saving the file isn't well formed. This is
+    /// raised by the checks added with the `validate` feature and is generally
+    /// a bug in `rust_xlsxwriter`. The first value is the name of the part in
+    /// the xlsx container and the second is a description of the error.
+    #[cfg(feature = "validate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "validate")))]
+    ValidationError(String, String),
 }
 
 impl Error for XlsxError {}
@@ -240,6 +253,13 @@ impl fmt::Display for XlsxError {
                 write!(f, "String exceeds Excel's limit of 32,767 characters.")
             }
 
+            XlsxError::MaxUniqueStringsExceeded => {
+                write!(
+                    f,
+                    "Number of unique strings exceeds the shared string table limit of 2,147,483,647."
+                )
+            }
+
             XlsxError::UnknownWorksheetNameOrIndex(name) => {
                 write!(f, "Unknown Worksheet name or index '{name}'.")
             }
@@ -338,6 +358,11 @@ impl fmt::Display for XlsxError {
             XlsxError::PolarsError(error) => {
                 write!(f, "{error}")
             }
+
+            #[cfg(feature = "validate")]
+            XlsxError::ValidationError(part, error) => {
+                write!(f, "Validation error in '{part}': '{error}'.")
+            }
         }
     }
 }
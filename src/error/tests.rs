@@ -49,6 +49,10 @@ mod error_tests {
             XlsxError::MaxStringLengthExceeded.to_string(),
             "String exceeds Excel's limit of 32,767 characters."
         );
+        assert_eq!(
+            XlsxError::MaxUniqueStringsExceeded.to_string(),
+            "Number of unique strings exceeds the shared string table limit of 2,147,483,647."
+        );
         assert_eq!(
             XlsxError::UnknownWorksheetNameOrIndex(name.to_string()).to_string(),
             "Unknown Worksheet name or index 'ERROR'."
@@ -532,6 +532,103 @@ impl Format {
         }
     }
 
+    /// Create a new Format object with a bold font.
+    ///
+    /// This is a preset constructor that is equivalent to
+    /// `Format::new().set_bold()`. See [`Format::header()`] for an example of
+    /// using the format presets.
+    ///
+    pub fn bold() -> Format {
+        Format::new().set_bold()
+    }
+
+    /// Create a new Format object with an italic font.
+    ///
+    /// This is a preset constructor that is equivalent to
+    /// `Format::new().set_italic()`. See [`Format::header()`] for an example of
+    /// using the format presets.
+    ///
+    pub fn italic() -> Format {
+        Format::new().set_italic()
+    }
+
+    /// Create a new Format object with horizontally centered text.
+    ///
+    /// This is a preset constructor that is equivalent to
+    /// `Format::new().set_align(FormatAlign::Center)`. See
+    /// [`Format::header()`] for an example of using the format presets.
+    ///
+    pub fn centered() -> Format {
+        Format::new().set_align(FormatAlign::Center)
+    }
+
+    /// Create a new Format object for a header row.
+    ///
+    /// This is a preset constructor for a table or column header with a bold
+    /// font, a thin bottom border and a light gray background. It is
+    /// equivalent to:
+    ///
+    /// ```text
+    /// Format::new()
+    ///     .set_bold()
+    ///     .set_border_bottom(FormatBorder::Thin)
+    ///     .set_background_color("#D9D9D9")
+    /// ```
+    ///
+    /// The preset formats return a standard `Format` so they can be extended
+    /// with any of the other `set_*()` methods.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates using the format preset
+    /// constructors.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_presets.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some headers.
+    ///     worksheet.write_row_with_format(0, 0, ["Item", "Cost"], &Format::header())?;
+    ///
+    ///     // Write some data.
+    ///     worksheet.write_with_format(1, 0, "Rent", &Format::italic())?;
+    ///     worksheet.write_with_format(1, 1, 1000, &Format::money())?;
+    ///
+    ///     // The presets can be extended with other properties.
+    ///     let format = Format::money().set_bold();
+    ///
+    ///     worksheet.write_with_format(2, 0, "Total", &Format::bold())?;
+    ///     worksheet.write_with_format(2, 1, 1000, &format)?;
+    /// #
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn header() -> Format {
+        Format::new()
+            .set_bold()
+            .set_border_bottom(FormatBorder::Thin)
+            .set_background_color("#D9D9D9")
+    }
+
+    /// Create a new Format object with a currency number format.
+    ///
+    /// This is a preset constructor that is equivalent to
+    /// `Format::new().set_num_format("$#,##0.00")`. For other currencies see
+    /// [`Format::set_num_format_locale()`]. See [`Format::header()`] for an
+    /// example of using the format presets.
+    ///
+    pub fn money() -> Format {
+        Format::new().set_num_format("$#,##0.00")
+    }
+
     // -----------------------------------------------------------------------
     // Crate private methods.
     // -----------------------------------------------------------------------
@@ -685,6 +782,97 @@ impl Format {
         self
     }
 
+    /// Set a locale aware currency number format for a Format.
+    ///
+    /// This method is a helper for building currency number formats that use
+    /// Excel's `[$symbol-LCID]` locale syntax, such as `[$€-407]#,##0.00` for
+    /// Euros in a German locale. The currency symbol is looked up from the
+    /// ISO 4217 currency code and the locale is specified as a Windows
+    /// Language Code Identifier (LCID).
+    ///
+    /// The following currency codes are recognized: `AUD`, `BRL`, `CAD`,
+    /// `CHF`, `CNY`, `DKK`, `EUR`, `GBP`, `HKD`, `INR`, `JPY`, `KRW`, `MXN`,
+    /// `NOK`, `NZD`, `PLN`, `RUB`, `SEK`, `TRY`, `USD` and `ZAR`. Currencies
+    /// without minor units, such as `JPY` and `KRW`, are formatted without
+    /// decimal places.
+    ///
+    /// Unrecognized currency codes fall back to a generic pattern that uses
+    /// the code itself as the currency label, for example `[$XYZ-409]
+    /// #,##0.00`.
+    ///
+    /// See also [Number Formats in different locales].
+    ///
+    /// [Number Formats in different locales]:
+    ///     crate::Format#number-formats-in-different-locales
+    ///
+    /// # Parameters
+    ///
+    /// - `currency_code`: A 3 letter ISO 4217 currency code such as `"EUR"`.
+    /// - `locale_id`: A Windows LCID such as `0x0407` for German (Germany) or
+    ///   `0x0409` for English (United States).
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting locale aware currency
+    /// number formats.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_set_num_format_locale.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Set column width for clarity.
+    /// #     worksheet.set_column_width(0, 20)?;
+    /// #
+    ///     let format1 = Format::new().set_num_format_locale("EUR", 0x0407);
+    ///     let format2 = Format::new().set_num_format_locale("GBP", 0x0809);
+    ///     let format3 = Format::new().set_num_format_locale("JPY", 0x0411);
+    ///
+    ///     worksheet.write_number_with_format(0, 0, 1234.56, &format1)?;
+    ///     worksheet.write_number_with_format(1, 0, 1234.56, &format2)?;
+    ///     worksheet.write_number_with_format(2, 0, 1234.56, &format3)?;
+    /// #
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_num_format_locale(self, currency_code: &str, locale_id: u16) -> Format {
+        let currency_code = currency_code.trim().to_ascii_uppercase();
+
+        let currency = match currency_code.as_str() {
+            "AUD" | "CAD" | "HKD" | "MXN" | "NZD" | "USD" => Some(("$", 2)),
+            "BRL" => Some(("R$", 2)),
+            "CHF" => Some(("CHF", 2)),
+            "CNY" => Some(("¥", 2)),
+            "DKK" | "NOK" | "SEK" => Some(("kr", 2)),
+            "EUR" => Some(("€", 2)),
+            "GBP" => Some(("£", 2)),
+            "INR" => Some(("₹", 2)),
+            "JPY" => Some(("¥", 0)),
+            "KRW" => Some(("₩", 0)),
+            "PLN" => Some(("zł", 2)),
+            "RUB" => Some(("₽", 2)),
+            "TRY" => Some(("₺", 2)),
+            "ZAR" => Some(("R", 2)),
+            _ => None,
+        };
+
+        let num_format = match currency {
+            Some((symbol, 0)) => format!("[${symbol}-{locale_id:X}]#,##0"),
+            Some((symbol, _)) => format!("[${symbol}-{locale_id:X}]#,##0.00"),
+            None => format!("[${currency_code}-{locale_id:X}] #,##0.00"),
+        };
+
+        self.set_num_format(num_format)
+    }
+
     /// Set the number format for a Format using a legacy format index.
     ///
     /// This method is similar to [`Format::set_num_format()`] except that it
@@ -991,6 +1179,7 @@ impl Format {
     ///
     pub fn set_font_name(mut self, font_name: impl Into<String>) -> Format {
         self.font.name = font_name.into();
+        self.font.is_name_set = true;
 
         if self.font.name != "Calibri" {
             self.font.scheme = String::new();
@@ -1044,6 +1233,7 @@ impl Format {
         T: Into<f64>,
     {
         self.font.size = font_size.into().to_string();
+        self.font.is_size_set = true;
         self
     }
 
@@ -2237,6 +2427,61 @@ impl Format {
         self
     }
 
+    /// Reset the Format horizontal and vertical alignment back to the default.
+    ///
+    /// By default Excel aligns cell data according to its type: numbers and
+    /// dates are right aligned, strings are left aligned and booleans and
+    /// errors are centered. Any explicit horizontal alignment set with
+    /// [`Format::set_align()`] overrides this for all types of data.
+    ///
+    /// The `reset_alignment()` method removes the horizontal and vertical
+    /// alignment from a format so that the cell data reverts to the type based
+    /// default alignment. It also removes any indentation set with
+    /// [`Format::set_indent()`] since Excel applies a left alignment to
+    /// indented text. Other alignment properties such as the text wrap,
+    /// rotation and shrink are unchanged.
+    ///
+    /// This is mainly useful for cloning and modifying an existing format.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates resetting the alignment of a copy of
+    /// a format so that numbers revert to the default right alignment.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_reset_alignment.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatAlign, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format1 = Format::new()
+    ///         .set_bold()
+    ///         .set_align(FormatAlign::Center);
+    ///
+    ///     // Keep the bold property but use the default alignment.
+    ///     let format2 = format1.clone().reset_alignment();
+    ///
+    ///     worksheet.write_number_with_format(0, 0, 123, &format1)?;
+    ///     worksheet.write_number_with_format(1, 0, 123, &format2)?;
+    /// #
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn reset_alignment(mut self) -> Format {
+        self.alignment.horizontal = FormatAlign::General;
+        self.alignment.vertical = FormatAlign::General;
+        self.alignment.justify_last = false;
+        self.alignment.indent = 0;
+        self
+    }
+
     /// Set the locked Format property back to its default "on" state.
     ///
     /// The opposite of [`Format::set_unlocked()`].
@@ -2325,6 +2570,8 @@ pub(crate) struct Font {
     pub(crate) condense: bool,
     pub(crate) extend: bool,
     pub(crate) is_hyperlink: bool,
+    pub(crate) is_name_set: bool,
+    pub(crate) is_size_set: bool,
 }
 
 impl Default for Font {
@@ -2344,7 +2591,36 @@ impl Default for Font {
             condense: Default::default(),
             extend: Default::default(),
             is_hyperlink: Default::default(),
+            is_name_set: Default::default(),
+            is_size_set: Default::default(),
+        }
+    }
+}
+
+impl Font {
+    // Get a copy of the font as it is written to the styles file. A font name
+    // or size that wasn't set explicitly is replaced by the workbook default
+    // font, if there is one. An explicitly set name doesn't use the theme
+    // scheme since the theme minor font is changed to the default font. The
+    // explicitly set flags are cleared so that equivalent fonts compare equal.
+    pub(crate) fn styles_font(&self, default_font: Option<&(String, f64)>) -> Font {
+        let mut font = self.clone();
+
+        if let Some((name, size)) = default_font {
+            if !font.is_name_set && font.scheme == "minor" {
+                font.name.clone_from(name);
+            } else if font.is_name_set && font.scheme == "minor" {
+                font.scheme = String::new();
+            }
+            if !font.is_size_set {
+                font.size = size.to_string();
+            }
         }
+
+        font.is_name_set = false;
+        font.is_size_set = false;
+
+        font
     }
 }
 
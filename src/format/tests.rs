@@ -7,7 +7,7 @@
 #[cfg(test)]
 mod format_tests {
 
-    use crate::Format;
+    use crate::{Format, FormatAlign, FormatBorder};
 
     #[test]
     fn test_unset() {
@@ -30,4 +30,58 @@ mod format_tests {
 
         assert_eq!(format1, format2);
     }
+
+    #[test]
+    fn test_reset_alignment() {
+        let format1 = Format::new().set_bold();
+        let format2 = Format::new()
+            .set_bold()
+            .set_align(FormatAlign::Right)
+            .set_align(FormatAlign::Top)
+            .set_indent(2)
+            .reset_alignment();
+
+        assert_eq!(format1, format2);
+        assert!(!format2.has_alignment());
+        assert!(!format2.apply_alignment());
+
+        // Other alignment properties are retained.
+        let format1 = Format::new().set_text_wrap();
+        let format2 = Format::new()
+            .set_text_wrap()
+            .set_align(FormatAlign::Center)
+            .reset_alignment();
+
+        assert_eq!(format1, format2);
+    }
+
+    #[test]
+    fn test_num_format_locale() {
+        let format = Format::new().set_num_format_locale("EUR", 0x0407);
+        assert_eq!(format.num_format, "[$€-407]#,##0.00");
+
+        let format = Format::new().set_num_format_locale("jpy", 0x0411);
+        assert_eq!(format.num_format, "[$¥-411]#,##0");
+
+        let format = Format::new().set_num_format_locale("XYZ", 0x0409);
+        assert_eq!(format.num_format, "[$XYZ-409] #,##0.00");
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(Format::bold(), Format::new().set_bold());
+        assert_eq!(Format::italic(), Format::new().set_italic());
+        assert_eq!(
+            Format::centered(),
+            Format::new().set_align(FormatAlign::Center)
+        );
+        assert_eq!(
+            Format::header(),
+            Format::new()
+                .set_bold()
+                .set_border_bottom(FormatBorder::Thin)
+                .set_background_color("#D9D9D9")
+        );
+        assert_eq!(Format::money(), Format::new().set_num_format("$#,##0.00"));
+    }
 }
@@ -10,6 +10,9 @@ mod tests;
 
 use std::{collections::HashMap, sync::OnceLock};
 
+use crate::worksheet::{COL_MAX, ROW_MAX};
+use crate::{utility, ColNum, RowNum};
+
 /// The `Formula` struct is used to define a worksheet formula.
 ///
 /// The `Formula` struct creates a formula type that can be used to write
@@ -167,9 +170,15 @@ use std::{collections::HashMap, sync::OnceLock};
 /// # }
 /// ```
 ///
+/// If an application displays the default "0" results until a cell is edited,
+/// even though it supports recalculation, you can also use the
+/// [`Workbook::set_calc_id()`] method to set the workbook calculation id to 0.
+/// This forces a full recalculation of the formulas when the file is opened.
+///
 /// [`Worksheet::set_formula_result()`]: crate::Worksheet::set_formula_result
 /// [`Worksheet::set_formula_result_default()`]:
 ///     crate::Worksheet::set_formula_result_default
+/// [`Workbook::set_calc_id()`]: crate::Workbook::set_calc_id
 /// [issue report]: https://bugs.documentfoundation.org/show_bug.cgi?id=144819
 ///
 /// # Non US Excel functions and syntax
@@ -212,6 +221,12 @@ use std::{collections::HashMap, sync::OnceLock};
 /// to help you convert the formula. It can also replace semi-colons with
 /// commas.
 ///
+/// Alternatively, the function names of commonly used functions in some
+/// languages can be translated to English when the file is saved using
+/// [`Workbook::set_formula_locale()`](crate::Workbook::set_formula_locale)
+/// and the [`FormulaLocale`] enum. Function names that aren't recognized are
+/// written unchanged, and the separators must still be US style commas.
+///
 ///
 /// # Dynamic Array support
 ///
@@ -397,15 +412,25 @@ use std::{collections::HashMap, sync::OnceLock};
 ///
 /// <img src="https://rustxlsxwriter.github.io/images/spill01.png">
 ///
-/// Unfortunately, Excel doesn't store the operator in the formula like this and
-/// in `rust_xlsxwriter` you need to use the explicit function `ANCHORARRAY()`
-/// to refer to a spilled range. The example in the image above was generated
-/// using the following formula:
+/// Excel doesn't store the operator in the formula like this. Instead it stores
+/// the equivalent explicit function `ANCHORARRAY()`. In `rust_xlsxwriter` you
+/// can use either form and references like `F2#`, `$F$2#` or `Sheet1!$F$2#`
+/// are converted to `ANCHORARRAY()` when the formula is written. The example
+/// in the image above was generated using the following formula:
+///
+/// ```text
+///     worksheet.write_dynamic_formula(1, 9, "=COUNTA(F2#)")?;
+/// ```
+///
+/// Which is equivalent to:
 ///
 /// ```text
 ///     worksheet.write_dynamic_formula(1, 9, "=COUNTA(ANCHORARRAY(F2))")?;
 /// ```
 ///
+/// The [`Worksheet::spill_ref()`](crate::Worksheet::spill_ref) method can be
+/// used to create an absolute spilled range reference for a cell.
+///
 /// ## The Excel 365 `LAMBDA()` function
 ///
 /// Recent versions of Excel 365 have introduced a powerful new function/feature
@@ -762,6 +787,20 @@ impl Formula {
             formula = stripped;
         }
 
+        // Convert any spilled range operators like "A1#" to the ANCHORARRAY()
+        // function that Excel stores in the file.
+        let expanded_formula;
+        if formula.contains('#') {
+            let function_name = if formula.contains("_xlfn.") {
+                "_xlfn.ANCHORARRAY"
+            } else {
+                "ANCHORARRAY"
+            };
+
+            expanded_formula = Self::expand_spill_references(formula, function_name);
+            formula = &expanded_formula;
+        }
+
         // We need to escape future functions in a formula string. If the user
         // has already done this we simply copy the string. In both cases we
         // need to determine if it contains dynamic functions.
@@ -962,6 +1001,109 @@ impl Formula {
         (formula.to_string(), has_dynamic_function)
     }
 
+    // Convert the spilled range operator "#" in references like "A1#" or
+    // "Sheet1!$A$1#" to the equivalent "ANCHORARRAY(A1)" function. We parse
+    // the string to avoid changes to "#" in string literals, quoted sheet
+    // names and structured table references like "Table1[#Data]". Error values
+    // like "#N/A" aren't changed since they don't follow a cell reference.
+    fn expand_spill_references(formula: &str, function_name: &str) -> String {
+        let mut in_string_literal = false;
+        let mut in_sheet_name = false;
+        let mut bracket_depth = 0;
+        let mut expanded_formula = String::with_capacity(formula.len());
+
+        for char in formula.chars() {
+            if in_string_literal || in_sheet_name || bracket_depth > 0 {
+                match char {
+                    '"' if in_string_literal => in_string_literal = false,
+                    '\'' if in_sheet_name => in_sheet_name = false,
+                    '[' if !in_string_literal && !in_sheet_name => bracket_depth += 1,
+                    ']' if !in_string_literal && !in_sheet_name => bracket_depth -= 1,
+                    _ => {}
+                }
+                expanded_formula.push(char);
+                continue;
+            }
+
+            match char {
+                '"' => in_string_literal = true,
+                '\'' => in_sheet_name = true,
+                '[' => bracket_depth += 1,
+                '#' => {
+                    if let Some(start) = Self::spill_reference_start(&expanded_formula) {
+                        let reference = expanded_formula.split_off(start);
+                        expanded_formula.push_str(&format!("{function_name}({reference})"));
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            expanded_formula.push(char);
+        }
+
+        expanded_formula
+    }
+
+    // Find the start position of a cell reference, with an optional sheet
+    // name, at the end of a partial formula string. Returns None if the string
+    // doesn't end in a cell reference.
+    fn spill_reference_start(formula: &str) -> Option<usize> {
+        let cell_start = formula
+            .trim_end_matches(|char: char| char.is_ascii_alphanumeric() || char == '$')
+            .len();
+        let prefix = &formula[..cell_start];
+
+        if !Self::is_cell_reference(&formula[cell_start..]) || prefix.ends_with(['_', '.']) {
+            return None;
+        }
+
+        let Some(sheet_name) = prefix.strip_suffix('!') else {
+            return Some(cell_start);
+        };
+
+        if let Some(quoted_name) = sheet_name.strip_suffix('\'') {
+            // Find the opening quote of the sheet name, skipping any doubled
+            // quotes within the name.
+            let mut end = quoted_name.len();
+            loop {
+                let start = quoted_name[..end].rfind('\'')?;
+                if start > 0 && quoted_name[..start].ends_with('\'') {
+                    end = start - 1;
+                } else {
+                    return Some(start);
+                }
+            }
+        }
+
+        let start = sheet_name
+            .trim_end_matches(|char: char| char.is_alphanumeric() || char == '_' || char == '.')
+            .len();
+
+        (start < sheet_name.len()).then_some(start)
+    }
+
+    // Check if a token is a valid A1 style cell reference, such as "A1" or
+    // "$A$1".
+    fn is_cell_reference(token: &str) -> bool {
+        let token = token.strip_prefix('$').unwrap_or(token);
+        let letters = token.chars().take_while(char::is_ascii_alphabetic).count();
+        let (col_name, digits) = token.split_at(letters);
+        let digits = digits.strip_prefix('$').unwrap_or(digits);
+
+        if !(1..=3).contains(&letters)
+            || digits.is_empty()
+            || !digits.chars().all(|char| char.is_ascii_digit())
+        {
+            return false;
+        }
+
+        let col = utility::column_name_to_number(&col_name.to_ascii_uppercase());
+        let row = digits.parse::<RowNum>().unwrap_or(0);
+
+        col < COL_MAX && (1..=ROW_MAX).contains(&row)
+    }
+
     // Escape/expand table functions. This mainly involves converting Excel 2010
     // "@" table ref to 2007 "[#This Row],". We parse the string to avoid
     // replacements in string literals within the formula.
@@ -1178,8 +1320,456 @@ impl Formula {
             })
             .get(function)
     }
+
+    // Translate any localized function names in a formula string to their US
+    // English equivalents and add the future function prefix if required.
+    // Function names that aren't in the locale table are copied unchanged. We
+    // parse the string to avoid replacements in string literals. Returns None
+    // if no function names were translated, otherwise the new formula string
+    // and whether it contains a dynamic function.
+    pub(crate) fn localize_formula(formula: &str, locale: FormulaLocale) -> Option<(String, bool)> {
+        if locale == FormulaLocale::English {
+            return None;
+        }
+
+        let mut start_position = 0;
+        let mut in_function = false;
+        let mut in_string_literal = false;
+        let mut has_translation = false;
+        let mut has_dynamic_function = false;
+        let mut localized_formula = String::with_capacity(formula.len());
+
+        for (current_position, char) in formula.char_indices() {
+            // Match the start/end of string literals.
+            if char == '"' {
+                in_string_literal = !in_string_literal;
+            }
+
+            // Copy the string literal.
+            if in_string_literal {
+                localized_formula.push(char);
+                continue;
+            }
+
+            // Localized function names can contain non-ASCII letters, such as
+            // "ZÄHLENWENN", and can be in lowercase.
+            let is_function_char = char.is_alphabetic() || char.is_ascii_digit() || char == '.';
+
+            if in_function {
+                if !is_function_char {
+                    let token = &formula[start_position..current_position];
+
+                    // If the first non function char is an opening bracket then
+                    // we have found a function name.
+                    let english_name = if char == '(' {
+                        locale.english_function_name(&token.to_uppercase())
+                    } else {
+                        None
+                    };
+
+                    match english_name {
+                        Some(function) => {
+                            // Check if the function is an Excel "future" function.
+                            if let Some(function_type) = Self::future_functions(function) {
+                                localized_formula.push_str("_xlfn.");
+
+                                if *function_type == 2 {
+                                    localized_formula.push_str("_xlws.");
+                                }
+
+                                has_dynamic_function |= *function_type > 0;
+                            }
+
+                            localized_formula.push_str(function);
+                            has_translation = true;
+                        }
+                        None => localized_formula.push_str(token),
+                    }
+
+                    localized_formula.push(char);
+                    in_function = false;
+                }
+            } else if is_function_char {
+                // Match the start of a possible function name.
+                start_position = current_position;
+                in_function = true;
+            } else {
+                localized_formula.push(char);
+            }
+        }
+
+        // Clean up any trailing buffer that wasn't a function.
+        if in_function {
+            localized_formula.push_str(&formula[start_position..]);
+        }
+
+        if has_translation {
+            Some((localized_formula, has_dynamic_function))
+        } else {
+            None
+        }
+    }
+
+    // Shift the relative cell references in a formula string by a number of
+    // rows and columns, in the same way that Excel adjusts a formula that is
+    // copied to another cell. Absolute "$" row or column parts aren't changed.
+    // We parse the string to avoid changes in string literals, quoted sheet
+    // names and structured table references. Returns None if a shifted
+    // reference would fall outside the worksheet.
+    pub(crate) fn shift_references(
+        formula: &str,
+        row_offset: i64,
+        col_offset: i64,
+    ) -> Option<String> {
+        let mut start_position = 0;
+        let mut in_token = false;
+        let mut in_string_literal = false;
+        let mut in_sheet_name = false;
+        let mut bracket_depth = 0;
+        let mut shifted_formula = String::with_capacity(formula.len());
+
+        // Append a trailing space so that the last token is terminated. It
+        // isn't copied to the output.
+        for (current_position, char) in formula.char_indices().chain([(formula.len(), ' ')]) {
+            let is_token_char = char.is_ascii_alphanumeric() || matches!(char, '$' | '_' | '.');
+
+            if in_token {
+                if is_token_char {
+                    continue;
+                }
+
+                let token = &formula[start_position..current_position];
+                let previous_char = formula[..start_position].chars().next_back();
+                let is_range_part = char == ':' || previous_char == Some(':');
+
+                // Tokens followed by a bracket are function names and tokens
+                // followed by "!" are sheet names.
+                if char == '(' || char == '!' {
+                    shifted_formula.push_str(token);
+                } else {
+                    let token =
+                        Self::shift_reference(token, is_range_part, row_offset, col_offset)?;
+                    shifted_formula.push_str(&token);
+                }
+
+                in_token = false;
+            }
+
+            if current_position == formula.len() {
+                break;
+            }
+
+            if in_string_literal || in_sheet_name || bracket_depth > 0 {
+                match char {
+                    '"' if in_string_literal => in_string_literal = false,
+                    '\'' if in_sheet_name => in_sheet_name = false,
+                    '[' if !in_string_literal && !in_sheet_name => bracket_depth += 1,
+                    ']' if !in_string_literal && !in_sheet_name => bracket_depth -= 1,
+                    _ => {}
+                }
+                shifted_formula.push(char);
+                continue;
+            }
+
+            match char {
+                '"' => in_string_literal = true,
+                '\'' => in_sheet_name = true,
+                '[' => bracket_depth += 1,
+                _ => {}
+            }
+
+            if is_token_char && !in_string_literal && !in_sheet_name && bracket_depth == 0 {
+                start_position = current_position;
+                in_token = true;
+            } else {
+                shifted_formula.push(char);
+            }
+        }
+
+        Some(shifted_formula)
+    }
+
+    // Shift a single token from a formula if it is a cell reference, such as
+    // "A1" or "$A$1", or if it is part of a column or row range such as "A:C"
+    // or "1:3". Other tokens, like defined names and numbers, are returned
+    // unchanged.
+    fn shift_reference(
+        token: &str,
+        is_range_part: bool,
+        row_offset: i64,
+        col_offset: i64,
+    ) -> Option<String> {
+        let (first_abs, rest) = match token.strip_prefix('$') {
+            Some(rest) => (true, rest),
+            None => (false, token),
+        };
+
+        let letters = rest.chars().take_while(char::is_ascii_alphabetic).count();
+        let (col_name, rest) = rest.split_at(letters);
+
+        let (second_abs, digits) = match rest.strip_prefix('$') {
+            Some(rest) if letters > 0 => (true, rest),
+            _ => (false, rest),
+        };
+
+        if letters > 3 || !digits.chars().all(|char| char.is_ascii_digit()) {
+            return Some(token.to_string());
+        }
+
+        let col = (letters > 0).then(|| {
+            i64::from(utility::column_name_to_number(
+                &col_name.to_ascii_uppercase(),
+            ))
+        });
+        let row = digits.parse::<i64>().ok();
+
+        // Ignore tokens that look like references but are outside the
+        // worksheet limits, such as the defined name "XYZ1".
+        if col.is_some_and(|col| col >= i64::from(COL_MAX))
+            || row.is_some_and(|row| row < 1 || row > i64::from(ROW_MAX))
+        {
+            return Some(token.to_string());
+        }
+
+        let shift_col = |col: i64, is_abs: bool| -> Option<String> {
+            let col = if is_abs { col } else { col + col_offset };
+            let prefix = if is_abs { "$" } else { "" };
+            (0..i64::from(COL_MAX))
+                .contains(&col)
+                .then(|| format!("{prefix}{}", utility::column_number_to_name(col as ColNum)))
+        };
+
+        let shift_row = |row: i64, is_abs: bool| -> Option<String> {
+            let row = if is_abs { row } else { row + row_offset };
+            let prefix = if is_abs { "$" } else { "" };
+            (1..=i64::from(ROW_MAX))
+                .contains(&row)
+                .then(|| format!("{prefix}{row}"))
+        };
+
+        match (col, row) {
+            // A cell reference like "B2".
+            (Some(col), Some(row)) => {
+                let col = shift_col(col, first_abs)?;
+                let row = shift_row(row, second_abs)?;
+                Some(format!("{col}{row}"))
+            }
+
+            // A column range part like the "A" in "A:C".
+            (Some(col), None) if is_range_part && !second_abs => shift_col(col, first_abs),
+
+            // A row range part like the "1" in "1:3".
+            (None, Some(row)) if is_range_part => shift_row(row, first_abs),
+
+            _ => Some(token.to_string()),
+        }
+    }
 }
 
+// -----------------------------------------------------------------------
+// FormulaLocale
+// -----------------------------------------------------------------------
+
+/// The `FormulaLocale` enum defines the language of the function names used in
+/// worksheet formulas.
+///
+/// Excel stores formulas with US English function names regardless of the
+/// language of the Excel application, see [Non US Excel functions and
+/// syntax](Formula#non-us-excel-functions-and-syntax). The `FormulaLocale`
+/// enum is used with
+/// [`Workbook::set_formula_locale()`](crate::Workbook::set_formula_locale) to
+/// translate the function names of commonly used functions, such as `SUMME()`
+/// in German, to their English equivalents when the file is saved.
+///
+/// Function names that aren't in the translation table for the locale are
+/// written unchanged. The translation only applies to function names.
+/// Formulas must still use the US style comma separator between function
+/// arguments.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FormulaLocale {
+    /// US English function names. This is the default and no translation is
+    /// carried out.
+    #[default]
+    English,
+
+    /// German function names such as `SUMME()`, `WENN()` and `SVERWEIS()`.
+    German,
+
+    /// French function names such as `SOMME()`, `SI()` and `RECHERCHEV()`.
+    French,
+
+    /// Spanish function names such as `SUMA()`, `SI()` and `BUSCARV()`.
+    Spanish,
+}
+
+impl FormulaLocale {
+    // Get the US English name of an uppercase localized function name.
+    fn english_function_name(self, function: &str) -> Option<&'static str> {
+        static GERMAN: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        static FRENCH: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        static SPANISH: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+
+        let functions = match self {
+            FormulaLocale::English => return None,
+            FormulaLocale::German => GERMAN.get_or_init(|| HashMap::from(GERMAN_FUNCTIONS)),
+            FormulaLocale::French => FRENCH.get_or_init(|| HashMap::from(FRENCH_FUNCTIONS)),
+            FormulaLocale::Spanish => SPANISH.get_or_init(|| HashMap::from(SPANISH_FUNCTIONS)),
+        };
+
+        functions.get(function).copied()
+    }
+}
+
+// Localized to US English function name tables. Functions that have the same
+// name in both languages, such as `MIN()` or `INDEX()`, aren't included.
+const GERMAN_FUNCTIONS: [(&str, &str); 48] = [
+    ("ABRUNDEN", "ROUNDDOWN"),
+    ("ANZAHL", "COUNT"),
+    ("ANZAHL2", "COUNTA"),
+    ("AUFRUNDEN", "ROUNDUP"),
+    ("BEREICH.VERSCHIEBEN", "OFFSET"),
+    ("DATUM", "DATE"),
+    ("EINDEUTIG", "UNIQUE"),
+    ("FALSCH", "FALSE"),
+    ("GANZZAHL", "INT"),
+    ("GLÄTTEN", "TRIM"),
+    ("GROSS", "UPPER"),
+    ("HEUTE", "TODAY"),
+    ("INDIREKT", "INDIRECT"),
+    ("ISTFEHLER", "ISERROR"),
+    ("ISTLEER", "ISBLANK"),
+    ("ISTZAHL", "ISNUMBER"),
+    ("JAHR", "YEAR"),
+    ("JETZT", "NOW"),
+    ("KLEIN", "LOWER"),
+    ("LINKS", "LEFT"),
+    ("LÄNGE", "LEN"),
+    ("MITTELWERT", "AVERAGE"),
+    ("MITTELWERTWENN", "AVERAGEIF"),
+    ("MONAT", "MONTH"),
+    ("NICHT", "NOT"),
+    ("ODER", "OR"),
+    ("POTENZ", "POWER"),
+    ("RECHTS", "RIGHT"),
+    ("REST", "MOD"),
+    ("RUNDEN", "ROUND"),
+    ("SEQUENZ", "SEQUENCE"),
+    ("SORTIEREN", "SORT"),
+    ("SPALTE", "COLUMN"),
+    ("SUMME", "SUM"),
+    ("SUMMENPRODUKT", "SUMPRODUCT"),
+    ("SUMMEWENN", "SUMIF"),
+    ("SUMMEWENNS", "SUMIFS"),
+    ("SVERWEIS", "VLOOKUP"),
+    ("TAG", "DAY"),
+    ("TEIL", "MID"),
+    ("UND", "AND"),
+    ("VERGLEICH", "MATCH"),
+    ("VERKETTEN", "CONCATENATE"),
+    ("WAHR", "TRUE"),
+    ("WENN", "IF"),
+    ("WENNFEHLER", "IFERROR"),
+    ("WERT", "VALUE"),
+    ("WURZEL", "SQRT"),
+];
+
+const FRENCH_FUNCTIONS: [(&str, &str); 44] = [
+    ("ANNEE", "YEAR"),
+    ("ARRONDI", "ROUND"),
+    ("ARRONDI.INF", "ROUNDDOWN"),
+    ("ARRONDI.SUP", "ROUNDUP"),
+    ("AUJOURDHUI", "TODAY"),
+    ("CNUM", "VALUE"),
+    ("COLONNE", "COLUMN"),
+    ("CONCATENER", "CONCATENATE"),
+    ("DECALER", "OFFSET"),
+    ("DROITE", "RIGHT"),
+    ("ENT", "INT"),
+    ("EQUIV", "MATCH"),
+    ("ESTERREUR", "ISERROR"),
+    ("ESTNUM", "ISNUMBER"),
+    ("ESTVIDE", "ISBLANK"),
+    ("ET", "AND"),
+    ("FAUX", "FALSE"),
+    ("FILTRE", "FILTER"),
+    ("GAUCHE", "LEFT"),
+    ("JOUR", "DAY"),
+    ("LIGNE", "ROW"),
+    ("MAINTENANT", "NOW"),
+    ("MAJUSCULE", "UPPER"),
+    ("MINUSCULE", "LOWER"),
+    ("MOIS", "MONTH"),
+    ("MOYENNE", "AVERAGE"),
+    ("MOYENNE.SI", "AVERAGEIF"),
+    ("NB", "COUNT"),
+    ("NB.SI", "COUNTIF"),
+    ("NB.SI.ENS", "COUNTIFS"),
+    ("NBCAR", "LEN"),
+    ("NBVAL", "COUNTA"),
+    ("NON", "NOT"),
+    ("OU", "OR"),
+    ("PUISSANCE", "POWER"),
+    ("RACINE", "SQRT"),
+    ("RECHERCHEV", "VLOOKUP"),
+    ("SI", "IF"),
+    ("SIERREUR", "IFERROR"),
+    ("SOMME", "SUM"),
+    ("SOMME.SI", "SUMIF"),
+    ("SOMME.SI.ENS", "SUMIFS"),
+    ("SOMMEPROD", "SUMPRODUCT"),
+    ("VRAI", "TRUE"),
+];
+
+const SPANISH_FUNCTIONS: [(&str, &str); 46] = [
+    ("AHORA", "NOW"),
+    ("AÑO", "YEAR"),
+    ("BUSCARV", "VLOOKUP"),
+    ("COINCIDIR", "MATCH"),
+    ("COLUMNA", "COLUMN"),
+    ("CONCATENAR", "CONCATENATE"),
+    ("CONTAR", "COUNT"),
+    ("CONTAR.SI", "COUNTIF"),
+    ("CONTAR.SI.CONJUNTO", "COUNTIFS"),
+    ("CONTARA", "COUNTA"),
+    ("DERECHA", "RIGHT"),
+    ("DESREF", "OFFSET"),
+    ("DIA", "DAY"),
+    ("ENTERO", "INT"),
+    ("ESBLANCO", "ISBLANK"),
+    ("ESERROR", "ISERROR"),
+    ("ESNUMERO", "ISNUMBER"),
+    ("EXTRAE", "MID"),
+    ("FALSO", "FALSE"),
+    ("FECHA", "DATE"),
+    ("FILA", "ROW"),
+    ("FILTRAR", "FILTER"),
+    ("HOY", "TODAY"),
+    ("INDICE", "INDEX"),
+    ("INDIRECTO", "INDIRECT"),
+    ("IZQUIERDA", "LEFT"),
+    ("LARGO", "LEN"),
+    ("MAYUSC", "UPPER"),
+    ("MES", "MONTH"),
+    ("MINUSC", "LOWER"),
+    ("NO", "NOT"),
+    ("O", "OR"),
+    ("ORDENAR", "SORT"),
+    ("POTENCIA", "POWER"),
+    ("PROMEDIO", "AVERAGE"),
+    ("RAIZ", "SQRT"),
+    ("REDONDEAR", "ROUND"),
+    ("RESIDUO", "MOD"),
+    ("SI", "IF"),
+    ("SI.ERROR", "IFERROR"),
+    ("SUMA", "SUM"),
+    ("SUMAPRODUCTO", "SUMPRODUCT"),
+    ("SUMAR.SI", "SUMIF"),
+    ("SUMAR.SI.CONJUNTO", "SUMIFS"),
+    ("VERDADERO", "TRUE"),
+    ("Y", "AND"),
+];
+
 impl From<&str> for Formula {
     fn from(value: &str) -> Formula {
         Formula::new(value)
@@ -1191,3 +1781,110 @@ impl From<&Formula> for Formula {
         (*value).clone()
     }
 }
+
+// -----------------------------------------------------------------------
+// FormulaResult
+// -----------------------------------------------------------------------
+
+/// The `FormulaResult` enum defines a typed result for a worksheet formula.
+///
+/// It is used with
+/// [`Worksheet::set_formula_typed_result()`](crate::Worksheet::set_formula_typed_result)
+/// and
+/// [`Worksheet::set_formula_typed_results()`](crate::Worksheet::set_formula_typed_results)
+/// to store the cached result of a formula with the same type that Excel would
+/// use. This allows boolean and error results to be displayed correctly by
+/// applications that don't recalculate formulas.
+///
+/// The enum can be created from numbers, booleans and strings via `From`, or
+/// explicitly for error values like `FormulaResult::Error("#N/A".into())`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormulaResult {
+    /// A numeric formula result.
+    Number(f64),
+
+    /// A string formula result. The value is always stored as a string, even
+    /// if it looks like a number.
+    String(String),
+
+    /// A boolean formula result, displayed by Excel as `TRUE` or `FALSE`.
+    Boolean(bool),
+
+    /// An Excel error formula result such as `#DIV/0!` or `#N/A`.
+    Error(String),
+}
+
+impl FormulaResult {
+    // Convert the result to the cached result string and type stored in a
+    // formula cell.
+    pub(crate) fn to_result_and_type(&self) -> (String, FormulaResultType) {
+        match self {
+            FormulaResult::Number(number) => (number.to_string(), FormulaResultType::Number),
+            FormulaResult::String(string) => (string.clone(), FormulaResultType::String),
+            FormulaResult::Boolean(boolean) => {
+                let result = if *boolean { "TRUE" } else { "FALSE" };
+                (result.to_string(), FormulaResultType::Boolean)
+            }
+            FormulaResult::Error(error) => (error.clone(), FormulaResultType::Error),
+        }
+    }
+}
+
+impl From<f64> for FormulaResult {
+    fn from(value: f64) -> FormulaResult {
+        FormulaResult::Number(value)
+    }
+}
+
+impl From<f32> for FormulaResult {
+    fn from(value: f32) -> FormulaResult {
+        FormulaResult::Number(f64::from(value))
+    }
+}
+
+impl From<i32> for FormulaResult {
+    fn from(value: i32) -> FormulaResult {
+        FormulaResult::Number(f64::from(value))
+    }
+}
+
+impl From<u32> for FormulaResult {
+    fn from(value: u32) -> FormulaResult {
+        FormulaResult::Number(f64::from(value))
+    }
+}
+
+impl From<bool> for FormulaResult {
+    fn from(value: bool) -> FormulaResult {
+        FormulaResult::Boolean(value)
+    }
+}
+
+impl From<&str> for FormulaResult {
+    fn from(value: &str) -> FormulaResult {
+        FormulaResult::String(value.to_string())
+    }
+}
+
+impl From<String> for FormulaResult {
+    fn from(value: String) -> FormulaResult {
+        FormulaResult::String(value)
+    }
+}
+
+// The type of the cached result stored in a formula cell. Results set from a
+// string, with `set_formula_result()` or `Formula::set_result()`, are inferred
+// from the string: numeric strings are stored as numbers and anything else as
+// a string. Formulas without a cached result store the worksheet default
+// result as an `Uncached` result, which is written like an inferred result.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum FormulaResultType {
+    #[default]
+    Inferred,
+    Uncached,
+    Number,
+    String,
+    Boolean,
+    Error,
+}
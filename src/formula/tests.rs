@@ -307,3 +307,163 @@ fn test_escape_table_functions() {
         assert_eq!(formula.formula_string, expected_formula);
     }
 }
+
+#[test]
+fn test_localize_formula() {
+    use crate::{Formula, FormulaLocale};
+
+    let formula_strings = vec![
+        // No translation required.
+        (FormulaLocale::German, "SUM(A1:A3)", None),
+        (FormulaLocale::German, "SUMME", None),
+        (FormulaLocale::German, r#""SUMME(1)""#, None),
+        (FormulaLocale::English, "SUMME(A1:A3)", None),
+        // Simple translations.
+        (
+            FormulaLocale::German,
+            "SUMME(A1:A3)",
+            Some(("SUM(A1:A3)", false)),
+        ),
+        (
+            FormulaLocale::German,
+            "summe(A1:A3)",
+            Some(("SUM(A1:A3)", false)),
+        ),
+        (FormulaLocale::German, "LÄNGE(A1)", Some(("LEN(A1)", false))),
+        (
+            FormulaLocale::French,
+            "NB.SI(A1:A3,1)",
+            Some(("COUNTIF(A1:A3,1)", false)),
+        ),
+        (
+            FormulaLocale::Spanish,
+            "SI.ERROR(A1,0)",
+            Some(("IFERROR(A1,0)", false)),
+        ),
+        // Nested and mixed functions.
+        (
+            FormulaLocale::German,
+            r#"WENN(SUMME(A1:A3)>10,"SUMME(1)",MAX(A1:A3))"#,
+            Some((r#"IF(SUM(A1:A3)>10,"SUMME(1)",MAX(A1:A3))"#, false)),
+        ),
+        // Future and dynamic functions.
+        (
+            FormulaLocale::German,
+            "SEQUENZ(3)",
+            Some(("_xlfn.SEQUENCE(3)", true)),
+        ),
+        (
+            FormulaLocale::French,
+            "FILTRE(A1:A3,A1:A3>1)",
+            Some(("_xlfn._xlws.FILTER(A1:A3,A1:A3>1)", true)),
+        ),
+    ];
+
+    for &(locale, input_string, expected) in &formula_strings {
+        let result = Formula::localize_formula(input_string, locale);
+        let expected = expected.map(|(formula, is_dynamic)| (formula.to_string(), is_dynamic));
+
+        assert_eq!(result, expected, "input: {input_string}");
+    }
+}
+
+#[test]
+fn test_shift_references() {
+    use crate::Formula;
+
+    let formula_strings = vec![
+        // Relative and absolute references.
+        ("A1", 1, 1, Some("B2")),
+        ("$A1", 1, 1, Some("$A2")),
+        ("A$1", 1, 1, Some("B$1")),
+        ("$A$1", 1, 1, Some("$A$1")),
+        ("a1+b2", 2, 0, Some("A3+B4")),
+        ("SUM(A1:B3)*2", 3, 2, Some("SUM(C4:D6)*2")),
+        (
+            "Sheet1!A1+'My Sheet'!B2",
+            1,
+            0,
+            Some("Sheet1!A2+'My Sheet'!B3"),
+        ),
+        // Column and row ranges.
+        ("SUM(A:B)", 0, 1, Some("SUM(B:C)")),
+        ("SUM($A:B)", 0, 1, Some("SUM($A:C)")),
+        ("SUM(1:3)", 2, 0, Some("SUM(3:5)")),
+        ("SUM($1:3)", 2, 0, Some("SUM($1:5)")),
+        // Tokens that aren't references.
+        (
+            r#"IF(A1="B2",LOG10(100),1.5E3)"#,
+            1,
+            0,
+            Some(r#"IF(A2="B2",LOG10(100),1.5E3)"#),
+        ),
+        (
+            "SUM(Table1[Col1])+ABCD1+XFE1",
+            1,
+            1,
+            Some("SUM(Table1[Col1])+ABCD1+XFE1"),
+        ),
+        (
+            "_xlfn.XLOOKUP(A1,B1:B3,C1:C3)",
+            1,
+            0,
+            Some("_xlfn.XLOOKUP(A2,B2:B4,C2:C4)"),
+        ),
+        // Shifts that move references off the worksheet.
+        ("B2", -1, -1, Some("A1")),
+        ("A1", -1, 0, None),
+        ("A1", 0, -1, None),
+        ("XFD1", 0, 1, None),
+        ("A1048576", 1, 0, None),
+    ];
+
+    for &(input_string, row_offset, col_offset, expected) in &formula_strings {
+        let result = Formula::shift_references(input_string, row_offset, col_offset);
+
+        assert_eq!(result.as_deref(), expected, "input: {input_string}");
+    }
+}
+
+#[test]
+fn test_spill_references() {
+    use crate::Formula;
+
+    let formula_strings = vec![
+        // Spilled range references.
+        ("A1#", "_xlfn.ANCHORARRAY(A1)", true),
+        ("SUM($A$1#)", "SUM(_xlfn.ANCHORARRAY($A$1))", true),
+        ("SUM(a1#)", "SUM(_xlfn.ANCHORARRAY(a1))", true),
+        (
+            "SUM(Sheet1!$A$1#)",
+            "SUM(_xlfn.ANCHORARRAY(Sheet1!$A$1))",
+            true,
+        ),
+        (
+            "COUNTA('My Sheet'!F2#)+'It''s'!B1#",
+            "COUNTA(_xlfn.ANCHORARRAY('My Sheet'!F2))+_xlfn.ANCHORARRAY('It''s'!B1)",
+            true,
+        ),
+        (
+            "_xlfn.XLOOKUP(1,A1#,B1#)",
+            "_xlfn.XLOOKUP(1,_xlfn.ANCHORARRAY(A1),_xlfn.ANCHORARRAY(B1))",
+            true,
+        ),
+        // Hash characters that aren't spill operators.
+        ("IFERROR(A1,#N/A)", "IFERROR(A1,#N/A)", false),
+        ("Sheet1!#REF!+1", "Sheet1!#REF!+1", false),
+        (r#"LEN("A1#")"#, r#"LEN("A1#")"#, false),
+        ("SUM(Table1[#Data])", "SUM(Table1[#Data])", false),
+        ("'Sheet A1#'!A1", "'Sheet A1#'!A1", false),
+        ("ABCD1#+my_A1#", "ABCD1#+my_A1#", false),
+    ];
+
+    for &(input_string, expected_formula, expected_dynamic) in &formula_strings {
+        let formula = Formula::new(input_string);
+
+        assert_eq!(
+            formula.formula_string, expected_formula,
+            "input: {input_string}"
+        );
+        assert_eq!(formula.has_dynamic_function, expected_dynamic);
+    }
+}
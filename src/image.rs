@@ -18,7 +18,7 @@ use std::path::PathBuf;
 
 use crate::drawing::{DrawingObject, DrawingType};
 use crate::vml::VmlInfo;
-use crate::{Url, XlsxError};
+use crate::{ColNum, RowNum, Url, XlsxError};
 
 #[derive(Clone, Debug)]
 /// The `Image` struct is used to create an object to represent an image that
@@ -75,6 +75,7 @@ pub struct Image {
     pub(crate) data: Vec<u8>,
     pub(crate) drawing_type: DrawingType,
     pub(crate) url: Option<Url>,
+    pub(crate) fit_to_range: Option<(RowNum, ColNum, bool)>,
 }
 
 impl Image {
@@ -267,6 +268,7 @@ impl Image {
             data: buffer.to_vec(),
             drawing_type: DrawingType::Image,
             url: None,
+            fit_to_range: None,
         };
 
         Self::process_image(&mut image)?;
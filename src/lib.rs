@@ -59,7 +59,7 @@
 //! - [`Worksheet`]: The main spreadsheet canvas for writing data and objects to
 //!   a worksheet.
 //! - [`Working with Worksheets`](crate::worksheet): A higher level introduction
-//!    to creating and working with worksheets.
+//!   to creating and working with worksheets.
 //! </p>
 //!
 //! <p>
@@ -75,10 +75,12 @@
 //!   objects.
 //!  - [`Table`]: The interface for worksheet tables.
 //! - [`Image`]: The interface for images used in worksheets.
+//! - [`TextBoxOptions`]: The interface for worksheet text boxes.
 //! - [`Conditional Formats`](crate::conditional_format): Working with
 //!   conditional formatting in worksheets.
 //! - [`DataValidation`]: Working with data validation in worksheets.
 //! - [`Note`]: Adding Notes to worksheet cells.
+//! - [`ThreadedComment`]: Adding threaded comments to worksheet cells.
 //! - [`Macros`](crate::macros): Working with Macros.
 //! - [`Sparklines`](crate::sparkline): Working with Sparklines.
 //! - [`ExcelDateTime`]: A type to represent dates and times in Excel format.
@@ -174,22 +176,32 @@
 //! - `default`: Includes all the standard functionality. This has a dependency
 //!   on the `zip` crate only.
 //! - `serde`: Adds supports for Serde serialization. This is off by default.
+//! - `json`: Adds support for writing `serde_json::Value` data to a worksheet
+//!   via `Worksheet::write_json()`. This is off by default.
 //! - `chrono`: Adds supports for Chrono date/time types to the API. This is off
 //!   by default.
 //! - `zlib`: Adds a dependency on zlib and a C compiler. This includes the same
 //!   features as `default` but is 1.5x faster for large files.
-//! - `polars`: Add support for mapping between `PolarsError` and
+//! - `polars`: Add support for writing Polars dataframes via
+//!   `Worksheet::write_dataframe()` and for mapping between `PolarsError` and
 //!   `rust_xlsxwriter::XlsxError` to make code that handles both types of error
 //!   easier to write.
+//! - `ndarray`: Adds support for writing 2D `ndarray` arrays via
+//!   `Worksheet::write_ndarray()`. This is off by default.
 //! - `wasm`: Adds a dependency on `js-sys` and `wasm-bindgen` to allow
 //!   compilation for wasm/JavaScript targets.
 //! - `ryu`: Adds a dependency on `ryu`. This speeds up writing numeric
 //!   worksheet cells for large data files. It gives a performance boost above
 //!   300,000 numeric cells and can be up to 30% faster than the default number
 //!   formatting for 5,000,000 numeric cells.
+//! - `validate`: Checks that the XML parts in the file are well formed when it
+//!   is saved and returns an `XlsxError::ValidationError` for the first part
+//!   that isn't. This is a debugging aid and is off by default since it adds a
+//!   runtime cost to saving files.
 //!
 mod app;
 mod button;
+mod cell_builder;
 mod color;
 mod comment;
 mod content_types;
@@ -217,11 +229,16 @@ mod shared_strings;
 mod shared_strings_table;
 mod styles;
 mod table;
+mod textbox;
 mod theme;
+mod threaded_comment;
 mod url;
 mod vml;
 mod xmlwriter;
 
+#[cfg(feature = "validate")]
+mod validate;
+
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serializer;
@@ -242,6 +259,7 @@ mod test_functions;
 
 // Re-export the public APIs.
 pub use button::*;
+pub use cell_builder::*;
 pub use color::*;
 pub use data_validation::*;
 pub use datetime::*;
@@ -254,6 +272,9 @@ pub use note::*;
 pub use properties::*;
 pub use protection::*;
 pub use table::*;
+pub use textbox::*;
+pub use theme::*;
+pub use threaded_comment::*;
 pub use url::*;
 
 #[doc(hidden)]
@@ -11,6 +11,7 @@ pub struct Metadata {
     pub(crate) has_dynamic_functions: bool,
     pub(crate) has_embedded_images: bool,
     pub(crate) num_embedded_images: u32,
+    pub(crate) value_metadata_types: Vec<String>,
 }
 
 impl Metadata {
@@ -27,6 +28,7 @@ impl Metadata {
             has_dynamic_functions: false,
             has_embedded_images: false,
             num_embedded_images: 0,
+            value_metadata_types: vec![],
         }
     }
 
@@ -56,7 +58,7 @@ impl Metadata {
         if self.has_dynamic_functions {
             self.write_cell_metadata();
         }
-        if self.has_embedded_images {
+        if self.has_embedded_images || !self.value_metadata_types.is_empty() {
             self.write_value_metadata();
         }
 
@@ -100,6 +102,8 @@ impl Metadata {
             count += 1;
         }
 
+        count += self.value_metadata_types.len();
+
         let attributes = [("count", count.to_string())];
 
         self.writer.xml_start_tag("metadataTypes", &attributes);
@@ -109,7 +113,10 @@ impl Metadata {
             self.write_cell_metadata_type();
         }
         if self.has_embedded_images {
-            self.write_value_metadata_type();
+            self.write_value_metadata_type("XLRICHVALUE");
+        }
+        for name in self.value_metadata_types.clone() {
+            self.write_value_metadata_type(&name);
         }
 
         self.writer.xml_end_tag("metadataTypes");
@@ -137,9 +144,9 @@ impl Metadata {
     }
 
     // Write the value <metadataType> element.
-    fn write_value_metadata_type(&mut self) {
+    fn write_value_metadata_type(&mut self, name: &str) {
         let attributes = [
-            ("name", "XLRICHVALUE"),
+            ("name", name),
             ("minSupportedVersion", "120000"),
             ("copy", "1"),
             ("pasteAll", "1"),
@@ -248,15 +255,28 @@ impl Metadata {
 
     // Write the <valueMetadata> element.
     fn write_value_metadata(&mut self) {
-        let attributes = [("count", self.num_embedded_images.to_string())];
-        let rc_type = if self.has_dynamic_functions { 2 } else { 1 };
+        let count = self.num_embedded_images + self.value_metadata_types.len() as u32;
+        let attributes = [("count", count.to_string())];
+        let mut rc_type = if self.has_dynamic_functions { 2 } else { 1 };
 
         self.writer.xml_start_tag("valueMetadata", &attributes);
 
-        for index in 0..self.num_embedded_images {
+        if self.has_embedded_images {
+            for index in 0..self.num_embedded_images {
+                self.writer.xml_start_tag_only("bk");
+                self.write_rc(rc_type, index);
+                self.writer.xml_end_tag("bk");
+            }
+            rc_type += 1;
+        }
+
+        // The user defined value metadata types follow the rich value type.
+        // They don't have any future metadata records to refer to.
+        for _ in 0..self.value_metadata_types.len() {
             self.writer.xml_start_tag_only("bk");
-            self.write_rc(rc_type, index);
+            self.write_rc(rc_type, 0);
             self.writer.xml_end_tag("bk");
+            rc_type += 1;
         }
 
         self.writer.xml_end_tag("valueMetadata");
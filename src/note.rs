@@ -10,6 +10,12 @@ use crate::drawing::{DrawingObject, DrawingType};
 use crate::vml::VmlInfo;
 use crate::{ColNum, Color, Format, ObjectMovement, RowNum, COL_MAX, ROW_MAX};
 
+// Approximate font metrics, in pixels per point of font size, and the padding
+// around the text used to calculate the note dimensions for autosize.
+const NOTE_CHAR_WIDTH_RATIO: f64 = 0.75;
+const NOTE_LINE_HEIGHT_RATIO: f64 = 1.6;
+const NOTE_PADDING: f64 = 5.0;
+
 #[derive(Clone)]
 /// The `Note` struct represents an worksheet note object.
 ///
@@ -71,9 +77,8 @@ use crate::{ColNum, Color, Format, ObjectMovement, RowNum, COL_MAX, ROW_MAX};
 /// [The difference between threaded comments and notes]:
 ///     https://support.microsoft.com/en-us/office/the-difference-between-threaded-comments-and-notes-75a51eec-4092-42ab-abf8-7669077b7be3
 ///
-/// Note, the newer Threaded Comments are unlikely to be added to
-/// `rust_xlsxwriter` due to the fact that the feature relies on company
-/// specific metadata to identify the comment author.
+/// The newer threaded comments are supported via the
+/// [`ThreadedComment`](crate::ThreadedComment) struct.
 ///
 pub struct Note {
     height: f64,
@@ -93,7 +98,9 @@ pub struct Note {
     pub(crate) object_movement: ObjectMovement,
     pub(crate) decorative: bool,
     pub(crate) is_visible: Option<bool>,
+    pub(crate) autosize: Option<bool>,
     pub(crate) format: Format,
+    pub(crate) threaded_comment_id: Option<String>,
 }
 
 impl Note {
@@ -161,7 +168,9 @@ impl Note {
             object_movement: ObjectMovement::DontMoveOrSizeWithCells,
             decorative: false,
             is_visible: None,
+            autosize: None,
             format,
+            threaded_comment_id: None,
         }
     }
 
@@ -450,6 +459,70 @@ impl Note {
         self
     }
 
+    /// Set the note dimensions to fit the note text.
+    ///
+    /// The default Excel note box is 128 x 74 pixels which isn't large enough
+    /// to display longer text. This option sets the width and height of the
+    /// note box based on the text, and author prefix, so that it is fully
+    /// visible. Long lines are wrapped at a maximum width of 320 pixels and
+    /// for text with explicit newlines the dimensions are based on the widest
+    /// line and the number of lines.
+    ///
+    /// The dimensions are calculated from the number of characters and the
+    /// note font size so they are an approximation of the dimensions that
+    /// Excel would use for the same text. This option overrides any width or
+    /// height set via [`Note::set_width()`] and [`Note::set_height()`].
+    ///
+    /// You can also autosize all notes in a worksheet via the
+    /// [`Worksheet::autosize_all_notes()`](crate::Worksheet::autosize_all_notes)
+    /// method.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a note with a long line of
+    /// text and with multiple lines to a worksheet and sizing them to fit the
+    /// text.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_note_set_autosize.rs
+    /// #
+    /// # use rust_xlsxwriter::{Note, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create some notes that would overflow the default note box.
+    ///     let note1 = Note::new("This is a longer note that would be truncated in the default note box.")
+    ///         .set_autosize(true)
+    ///         .set_visible(true);
+    ///
+    ///     let note2 =
+    ///         Note::new("Monday: 8 hours\nTuesday: 7.5 hours\nWednesday: 8 hours\nThursday: 9 hours")
+    ///             .set_autosize(true)
+    ///             .set_visible(true);
+    ///
+    ///     // Add the notes to worksheet cells.
+    ///     worksheet.insert_note(2, 0, &note1)?;
+    ///     worksheet.insert_note(8, 0, &note2)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("notes.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_autosize(mut self, enable: bool) -> Note {
+        self.autosize = Some(enable);
+        self
+    }
+
     /// Set the background color for the note.
     ///
     /// The default background color for a Note is `#FFFFE1`. If required this
@@ -542,6 +615,60 @@ impl Note {
         self
     }
 
+    /// Set the font color for the note.
+    ///
+    /// Set the color of the text in a cell note. The default is the Excel
+    /// "InfoText" system color, which is usually black. This can be combined
+    /// with [`Note::set_background_color()`] to match a theme. Note, a text
+    /// color that contrasts with the background color should be chosen, for
+    /// example white text on a dark background.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The font color property defined by a [`Color`](crate::Color)
+    ///   enum value or a type that can convert [`Into`] a [`Color`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a note to a worksheet cell
+    /// with a dark background color and a white font.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_note_set_font_color.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Note, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new note.
+    ///     let note = Note::new("Some text for the note")
+    ///         .set_background_color("#1F4E78")
+    ///         .set_font_color(Color::White)
+    ///         .set_font_name("Arial")
+    ///         .set_font_size(10);
+    ///
+    ///     // Add the note to a worksheet cell.
+    ///     worksheet.insert_note(2, 0, &note)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("notes.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_font_color(mut self, color: impl Into<Color>) -> Note {
+        let color = color.into();
+        if color.is_valid() {
+            self.format.font.color = color;
+        }
+
+        self
+    }
+
     /// Set the font family for the note.
     ///
     /// Set the font family. This is usually an integer in the range 1-4. This
@@ -626,6 +753,40 @@ impl Note {
         self
     }
 
+    // Set the note width and height to fit the note text, and author prefix,
+    // based on the number of characters and the font size. Lines are wrapped
+    // at a maximum width. This is an approximation since the actual text
+    // width depends on the font metrics.
+    pub(crate) fn set_autosize_dimensions(&mut self, author: Option<&str>) {
+        let max_text_width = 320.0 - 2.0 * NOTE_PADDING;
+        let font_size = self.format.font.size.parse::<f64>().unwrap_or(8.0);
+        let char_width = font_size * NOTE_CHAR_WIDTH_RATIO;
+        let line_height = font_size * NOTE_LINE_HEIGHT_RATIO;
+
+        let mut lines = vec![];
+        if let Some(author) = author {
+            lines.push(format!("{author}:"));
+        }
+        lines.extend(self.text.split('\n').map(str::to_string));
+
+        let mut text_width = 0.0_f64;
+        let mut line_count = 0.0_f64;
+        for line in &lines {
+            let line_width = line.chars().count() as f64 * char_width;
+
+            if line_width > max_text_width {
+                text_width = max_text_width;
+                line_count += (line_width / max_text_width).ceil();
+            } else {
+                text_width = text_width.max(line_width);
+                line_count += 1.0;
+            }
+        }
+
+        self.width = (text_width + 2.0 * NOTE_PADDING).ceil();
+        self.height = (line_count * line_height + 2.0 * NOTE_PADDING).ceil();
+    }
+
     // Notes are stored in a vmlDrawing file. We create a struct to store the
     // required image information in that format.
     pub(crate) fn vml_info(&self) -> VmlInfo {
@@ -63,26 +63,30 @@ use crate::rich_value_types::RichValueTypes;
 use crate::shared_strings::SharedStrings;
 use crate::shared_strings_table::SharedStringsTable;
 use crate::styles::Styles;
-use crate::theme::Theme;
+use crate::threaded_comment::{PersonList, ThreadedComments};
+#[cfg(feature = "validate")]
+use crate::validate;
 use crate::vml::Vml;
 use crate::workbook::Workbook;
 use crate::worksheet::Worksheet;
-use crate::{Comment, DocProperties, NUM_IMAGE_FORMATS};
+use crate::{Comment, DocProperties, SaveProgress, NUM_IMAGE_FORMATS};
 
 // Packager struct to assembler the xlsx file.
-pub struct Packager<W: Write + Seek> {
+pub struct Packager<'a, W: Write + Seek> {
     zip: ZipWriter<W>,
     zip_options: SimpleFileOptions,
     zip_options_for_binary_files: SimpleFileOptions,
+    progress: SaveProgress,
+    progress_callback: Option<&'a mut dyn FnMut(SaveProgress)>,
 }
 
-impl<W: Write + Seek + Send> Packager<W> {
+impl<'a, W: Write + Seek + Send> Packager<'a, W> {
     // -----------------------------------------------------------------------
     // Crate public methods.
     // -----------------------------------------------------------------------
 
     // Create a new Packager struct.
-    pub(crate) fn new(writer: W) -> Packager<W> {
+    pub(crate) fn new(writer: W) -> Packager<'a, W> {
         let zip = zip::ZipWriter::new(writer);
 
         let zip_options = SimpleFileOptions::default()
@@ -98,27 +102,37 @@ impl<W: Write + Seek + Send> Packager<W> {
             zip,
             zip_options,
             zip_options_for_binary_files,
+            progress: SaveProgress::default(),
+            progress_callback: None,
         }
     }
 
+    // Set a callback function to report the progress of the file as each part
+    // is written to the zip container.
+    pub(crate) fn set_progress_callback(&mut self, callback: &'a mut dyn FnMut(SaveProgress)) {
+        self.progress_callback = Some(callback);
+    }
+
     // Write the xml files that make up the xlsx OPC package.
     pub(crate) fn assemble_file(
         mut self,
         workbook: &mut Workbook,
         options: &PackagerOptions,
     ) -> Result<(), XlsxError> {
+        self.progress.worksheet_count = workbook.worksheets.len();
+
         // Write the sub-component files.
         self.write_content_types_file(options)?;
         self.write_root_rels_file(options)?;
         self.write_workbook_rels_file(options)?;
-        self.write_theme_file()?;
+        self.write_theme_file(workbook)?;
         self.write_styles_file(workbook)?;
         self.write_workbook_file(workbook)?;
 
         // Update the shared string table in each worksheet.
         let mut string_table = SharedStringsTable::new();
         for worksheet in &mut workbook.worksheets {
-            worksheet.update_string_table_ids(&mut string_table);
+            worksheet.update_string_table_ids(&mut string_table)?;
         }
 
         // Assemble, but don't write, the worksheet files in parallel. These are
@@ -158,9 +172,11 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.write_drawing_files(workbook)?;
         self.write_vml_files(workbook)?;
         self.write_comment_files(workbook)?;
+        self.write_threaded_comment_files(workbook)?;
         self.write_image_files(workbook)?;
         self.write_chart_files(workbook)?;
         self.write_table_files(workbook)?;
+        self.write_printer_settings_files(workbook)?;
         self.write_vba_project(workbook)?;
 
         let mut image_index = 1;
@@ -176,6 +192,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             self.write_metadata_file(options)?;
         }
 
+        if options.has_persons {
+            self.write_person_file(workbook)?;
+        }
+
         if options.has_embedded_images {
             self.write_rich_value_rels_file(workbook)?;
             self.write_rich_value_files(workbook, options)?;
@@ -191,6 +211,44 @@ impl<W: Write + Seek + Send> Packager<W> {
     // Internal function/methods.
     // -----------------------------------------------------------------------
 
+    // Start a new file/part in the zip container.
+    fn start_part(
+        &mut self,
+        filename: impl Into<String>,
+        options: SimpleFileOptions,
+    ) -> Result<(), XlsxError> {
+        let filename = filename.into();
+        self.zip.start_file(filename.as_str(), options)?;
+        self.progress.part_name = filename;
+
+        Ok(())
+    }
+
+    // Write the data for the current part and report the progress, if
+    // required.
+    fn write_part(&mut self, data: &[u8]) -> Result<(), XlsxError> {
+        #[cfg(feature = "validate")]
+        if validate::is_xml_part(&self.progress.part_name) {
+            if let Err(error) = validate::check_well_formed(data) {
+                return Err(XlsxError::ValidationError(
+                    self.progress.part_name.clone(),
+                    error,
+                ));
+            }
+        }
+
+        self.zip.write_all(data)?;
+
+        self.progress.parts_written += 1;
+        self.progress.bytes_written += data.len() as u64;
+
+        if let Some(callback) = &mut self.progress_callback {
+            callback(self.progress.clone());
+        }
+
+        Ok(())
+    }
+
     // Write the [ContentTypes].xml file.
     fn write_content_types_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
         let mut content_types = ContentTypes::new();
@@ -236,6 +294,18 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_comments_name(i + 1);
         }
 
+        for i in 0..options.num_threaded_comments {
+            content_types.add_threaded_comments_name(i + 1);
+        }
+
+        for i in 0..options.num_printer_settings {
+            content_types.add_printer_settings_name(i + 1);
+        }
+
+        if options.has_persons {
+            content_types.add_persons();
+        }
+
         if options.has_sst_table {
             content_types.add_share_strings();
         }
@@ -277,11 +347,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_default("bin", "application/vnd.ms-office.vbaProject");
         }
 
-        self.zip
-            .start_file("[Content_Types].xml", self.zip_options)?;
+        self.start_part("[Content_Types].xml", self.zip_options)?;
 
         content_types.assemble_xml_file();
-        self.zip.write_all(content_types.writer.xmlfile.get_ref())?;
+        self.write_part(content_types.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -298,10 +367,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             rels.add_document_relationship("custom-properties", "docProps/custom.xml", "");
         }
 
-        self.zip.start_file("_rels/.rels", self.zip_options)?;
+        self.start_part("_rels/.rels", self.zip_options)?;
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.write_part(rels.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -333,6 +402,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             rels.add_office_relationship("2006", "vbaProject", "vbaProject.bin", "");
         }
 
+        if options.has_persons {
+            rels.add_office_relationship("2017/10", "person", "persons/person.xml", "");
+        }
+
         if options.has_embedded_images {
             rels.add_office_relationship(
                 "2022/10",
@@ -358,11 +431,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             );
         }
 
-        self.zip
-            .start_file("xl/_rels/workbook.xml.rels", self.zip_options)?;
+        self.start_part("xl/_rels/workbook.xml.rels", self.zip_options)?;
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.write_part(rels.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -374,8 +446,9 @@ impl<W: Write + Seek + Send> Packager<W> {
         index: usize,
     ) -> Result<(), XlsxError> {
         let filename = format!("xl/worksheets/sheet{index}.xml");
-        self.zip.start_file(filename, self.zip_options)?;
-        self.zip.write_all(worksheet.writer.xmlfile.get_ref())?;
+        self.progress.worksheets_written = index;
+        self.start_part(filename, self.zip_options)?;
+        self.write_part(worksheet.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -392,6 +465,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
         }
 
+        for relationship in &worksheet.printer_settings_relationships {
+            rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+        }
+
         for relationship in &worksheet.drawing_object_relationships {
             rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
         }
@@ -404,12 +481,21 @@ impl<W: Write + Seek + Send> Packager<W> {
             rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
         }
 
+        for relationship in &worksheet.threaded_comment_relationships {
+            rels.add_office_relationship(
+                "2017/10",
+                &relationship.0,
+                &relationship.1,
+                &relationship.2,
+            );
+        }
+
         let filename = format!("xl/worksheets/_rels/sheet{index}.xml.rels");
 
-        self.zip.start_file(filename, self.zip_options)?;
+        self.start_part(filename, self.zip_options)?;
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.write_part(rels.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -428,10 +514,10 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         let filename = format!("xl/drawings/_rels/drawing{index}.xml.rels");
 
-        self.zip.start_file(filename, self.zip_options)?;
+        self.start_part(filename, self.zip_options)?;
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.write_part(rels.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -450,10 +536,10 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         let filename = format!("xl/drawings/_rels/vmlDrawing{index}.vml.rels");
 
-        self.zip.start_file(filename, self.zip_options)?;
+        self.start_part(filename, self.zip_options)?;
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.write_part(rels.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -465,19 +551,17 @@ impl<W: Write + Seek + Send> Packager<W> {
     ) -> Result<(), XlsxError> {
         let mut rels = Relationship::new();
 
-        let mut index = 1;
-        for image in &workbook.embedded_images {
+        for (index, image) in (1..).zip(&workbook.embedded_images) {
             let target = format!("../media/image{index}.{}", image.image_type.extension());
             rels.add_document_relationship("image", &target, "");
-            index += 1;
         }
 
         let filename = "xl/richData/_rels/richValueRel.xml.rels";
 
-        self.zip.start_file(filename, self.zip_options)?;
+        self.start_part(filename, self.zip_options)?;
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.write_part(rels.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -490,20 +574,20 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         let filename = "xl/_rels/vbaProject.bin.rels";
 
-        self.zip.start_file(filename, self.zip_options)?;
+        self.start_part(filename, self.zip_options)?;
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.write_part(rels.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
 
     // Write the workbook.xml file.
     pub(crate) fn write_workbook_file(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
-        self.zip.start_file("xl/workbook.xml", self.zip_options)?;
+        self.start_part("xl/workbook.xml", self.zip_options)?;
 
         workbook.assemble_xml_file();
-        self.zip.write_all(workbook.writer.xmlfile.get_ref())?;
+        self.write_part(workbook.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -515,12 +599,10 @@ impl<W: Write + Seek + Send> Packager<W> {
     ) -> Result<(), XlsxError> {
         let mut shared_strings = SharedStrings::new();
 
-        self.zip
-            .start_file("xl/sharedStrings.xml", self.zip_options)?;
+        self.start_part("xl/sharedStrings.xml", self.zip_options)?;
 
         shared_strings.assemble_xml_file(string_table);
-        self.zip
-            .write_all(shared_strings.writer.xmlfile.get_ref())?;
+        self.write_part(shared_strings.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -530,6 +612,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut styles = Styles::new(
             &workbook.xf_formats,
             &workbook.dxf_formats,
+            &workbook.table_styles,
             workbook.font_count,
             workbook.fill_count,
             workbook.border_count,
@@ -539,23 +622,27 @@ impl<W: Write + Seek + Send> Packager<W> {
             false,
         );
 
-        self.zip.start_file("xl/styles.xml", self.zip_options)?;
+        self.start_part("xl/styles.xml", self.zip_options)?;
 
         styles.assemble_xml_file();
-        self.zip.write_all(styles.writer.xmlfile.get_ref())?;
+        self.write_part(styles.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
 
     // Write the theme.xml file.
-    fn write_theme_file(&mut self) -> Result<(), XlsxError> {
-        let mut theme = Theme::new();
+    fn write_theme_file(&mut self, workbook: &Workbook) -> Result<(), XlsxError> {
+        let mut theme = workbook.theme.clone();
 
-        self.zip
-            .start_file("xl/theme/theme1.xml", self.zip_options)?;
+        // The theme minor font is the default font for cells.
+        if let Some((font_name, _)) = &workbook.default_font {
+            theme = theme.set_minor_font(font_name);
+        }
+
+        self.start_part("xl/theme/theme1.xml", self.zip_options)?;
 
         theme.assemble_xml_file();
-        self.zip.write_all(theme.writer.xmlfile.get_ref())?;
+        self.write_part(theme.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -565,10 +652,10 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut core = Core::new();
         core.properties = options.properties.clone();
 
-        self.zip.start_file("docProps/core.xml", self.zip_options)?;
+        self.start_part("docProps/core.xml", self.zip_options)?;
 
         core.assemble_xml_file();
-        self.zip.write_all(core.writer.xmlfile.get_ref())?;
+        self.write_part(core.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -582,11 +669,10 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut custom = Custom::new();
         custom.properties = options.properties.clone();
 
-        self.zip
-            .start_file("docProps/custom.xml", self.zip_options)?;
+        self.start_part("docProps/custom.xml", self.zip_options)?;
 
         custom.assemble_xml_file();
-        self.zip.write_all(custom.writer.xmlfile.get_ref())?;
+        self.write_part(custom.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -617,10 +703,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             }
         }
 
-        self.zip.start_file("docProps/app.xml", self.zip_options)?;
+        self.start_part("docProps/app.xml", self.zip_options)?;
 
         app.assemble_xml_file();
-        self.zip.write_all(app.writer.xmlfile.get_ref())?;
+        self.write_part(app.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -631,11 +717,14 @@ impl<W: Write + Seek + Send> Packager<W> {
         metadata.has_dynamic_functions = options.has_dynamic_functions;
         metadata.has_embedded_images = options.has_embedded_images;
         metadata.num_embedded_images = options.num_embedded_images;
+        metadata
+            .value_metadata_types
+            .clone_from(&options.value_metadata_types);
 
-        self.zip.start_file("xl/metadata.xml", self.zip_options)?;
+        self.start_part("xl/metadata.xml", self.zip_options)?;
 
         metadata.assemble_xml_file();
-        self.zip.write_all(metadata.writer.xmlfile.get_ref())?;
+        self.write_part(metadata.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -658,11 +747,10 @@ impl<W: Write + Seek + Send> Packager<W> {
     fn write_rich_value_file(&mut self, workbook: &Workbook) -> Result<(), XlsxError> {
         let mut rich_value = RichValue::new(&workbook.embedded_images);
 
-        self.zip
-            .start_file("xl/richData/rdrichvalue.xml", self.zip_options)?;
+        self.start_part("xl/richData/rdrichvalue.xml", self.zip_options)?;
 
         rich_value.assemble_xml_file();
-        self.zip.write_all(rich_value.writer.xmlfile.get_ref())?;
+        self.write_part(rich_value.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -671,12 +759,10 @@ impl<W: Write + Seek + Send> Packager<W> {
     fn write_rich_value_types_file(&mut self) -> Result<(), XlsxError> {
         let mut rich_value_types = RichValueTypes::new();
 
-        self.zip
-            .start_file("xl/richData/rdRichValueTypes.xml", self.zip_options)?;
+        self.start_part("xl/richData/rdRichValueTypes.xml", self.zip_options)?;
 
         rich_value_types.assemble_xml_file();
-        self.zip
-            .write_all(rich_value_types.writer.xmlfile.get_ref())?;
+        self.write_part(rich_value_types.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -690,12 +776,10 @@ impl<W: Write + Seek + Send> Packager<W> {
         rich_value_structure.has_embedded_image_descriptions =
             options.has_embedded_image_descriptions;
 
-        self.zip
-            .start_file("xl/richData/rdrichvaluestructure.xml", self.zip_options)?;
+        self.start_part("xl/richData/rdrichvaluestructure.xml", self.zip_options)?;
 
         rich_value_structure.assemble_xml_file();
-        self.zip
-            .write_all(rich_value_structure.writer.xmlfile.get_ref())?;
+        self.write_part(rich_value_structure.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -705,12 +789,10 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut rich_value_rel = RichValueRel::new();
         rich_value_rel.num_embedded_images = options.num_embedded_images;
 
-        self.zip
-            .start_file("xl/richData/richValueRel.xml", self.zip_options)?;
+        self.start_part("xl/richData/richValueRel.xml", self.zip_options)?;
 
         rich_value_rel.assemble_xml_file();
-        self.zip
-            .write_all(rich_value_rel.writer.xmlfile.get_ref())?;
+        self.write_part(rich_value_rel.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
@@ -721,11 +803,10 @@ impl<W: Write + Seek + Send> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             if !worksheet.drawing.drawings.is_empty() {
                 let filename = format!("xl/drawings/drawing{index}.xml");
-                self.zip.start_file(filename, self.zip_options)?;
+                self.start_part(filename, self.zip_options)?;
 
                 worksheet.drawing.assemble_xml_file();
-                self.zip
-                    .write_all(worksheet.drawing.writer.xmlfile.get_ref())?;
+                self.write_part(worksheet.drawing.writer.xmlfile.get_ref())?;
                 index += 1;
             }
         }
@@ -739,7 +820,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             if !worksheet.notes.is_empty() {
                 let filename = format!("xl/comments{index}.xml");
-                self.zip.start_file(filename, self.zip_options)?;
+                self.start_part(filename, self.zip_options)?;
 
                 let mut comment = Comment::new();
                 comment.notes = worksheet.notes.clone();
@@ -747,7 +828,30 @@ impl<W: Write + Seek + Send> Packager<W> {
 
                 comment.assemble_xml_file();
 
-                self.zip.write_all(comment.writer.xmlfile.get_ref())?;
+                self.write_part(comment.writer.xmlfile.get_ref())?;
+                index += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write the threaded comment files.
+    fn write_threaded_comment_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
+        let mut index = 1;
+        for worksheet in &mut workbook.worksheets {
+            if !worksheet.threaded_comments.is_empty() {
+                let filename = format!("xl/threadedComments/threadedComment{index}.xml");
+                self.start_part(filename, self.zip_options)?;
+
+                let mut threaded_comments = ThreadedComments::new();
+                threaded_comments.comments = worksheet.threaded_comments.clone();
+                threaded_comments.sheet_index = worksheet.sheet_index;
+                threaded_comments.default_datetime = workbook.properties.creation_time.clone();
+
+                threaded_comments.assemble_xml_file();
+
+                self.write_part(threaded_comments.writer.xmlfile.get_ref())?;
                 index += 1;
             }
         }
@@ -755,6 +859,19 @@ impl<W: Write + Seek + Send> Packager<W> {
         Ok(())
     }
 
+    // Write the persons/person.xml file.
+    fn write_person_file(&mut self, workbook: &Workbook) -> Result<(), XlsxError> {
+        let mut person_list = PersonList::new();
+        person_list.persons = workbook.persons.clone();
+
+        self.start_part("xl/persons/person.xml", self.zip_options)?;
+
+        person_list.assemble_xml_file();
+        self.write_part(person_list.writer.xmlfile.get_ref())?;
+
+        Ok(())
+    }
+
     // Write the vml files.
     fn write_vml_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
         let mut index = 1;
@@ -763,7 +880,7 @@ impl<W: Write + Seek + Send> Packager<W> {
             if worksheet.has_header_footer_images() || worksheet.has_vml {
                 if worksheet.has_vml {
                     let filename = format!("xl/drawings/vmlDrawing{index}.vml");
-                    self.zip.start_file(filename, self.zip_options)?;
+                    self.start_part(filename, self.zip_options)?;
 
                     let mut vml = Vml::new();
                     vml.buttons.append(&mut worksheet.buttons_vml_info);
@@ -774,13 +891,13 @@ impl<W: Write + Seek + Send> Packager<W> {
                     vml.shape_id = worksheet.vml_shape_id;
                     vml.assemble_xml_file();
 
-                    self.zip.write_all(vml.writer.xmlfile.get_ref())?;
+                    self.write_part(vml.writer.xmlfile.get_ref())?;
                     index += 1;
                 }
 
                 if worksheet.has_header_footer_images() {
                     let filename = format!("xl/drawings/vmlDrawing{index}.vml");
-                    self.zip.start_file(filename, self.zip_options)?;
+                    self.start_part(filename, self.zip_options)?;
 
                     let mut vml = Vml::new();
                     vml.header_images
@@ -792,7 +909,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
                     vml.assemble_xml_file();
 
-                    self.zip.write_all(vml.writer.xmlfile.get_ref())?;
+                    self.write_part(vml.writer.xmlfile.get_ref())?;
 
                     // The rels file index must match the vmlDrawing file index.
                     self.write_vml_drawing_rels_file(&worksheet.vml_drawing_relationships, index)?;
@@ -813,10 +930,9 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         for image in &workbook.embedded_images {
             let filename = format!("xl/media/image{index}.{}", image.image_type.extension());
-            self.zip
-                .start_file(filename, self.zip_options_for_binary_files)?;
+            self.start_part(filename, self.zip_options_for_binary_files)?;
 
-            self.zip.write_all(&image.data)?;
+            self.write_part(&image.data)?;
             index += 1;
         }
 
@@ -825,10 +941,9 @@ impl<W: Write + Seek + Send> Packager<W> {
                 if !unique_worksheet_images.contains(&image.hash) {
                     let filename =
                         format!("xl/media/image{index}.{}", image.image_type.extension());
-                    self.zip
-                        .start_file(filename, self.zip_options_for_binary_files)?;
+                    self.start_part(filename, self.zip_options_for_binary_files)?;
 
-                    self.zip.write_all(&image.data)?;
+                    self.write_part(&image.data)?;
                     unique_worksheet_images.insert(image.hash.clone());
                     index += 1;
                 }
@@ -838,9 +953,9 @@ impl<W: Write + Seek + Send> Packager<W> {
                     if !unique_header_footer_images.contains(&image.hash) {
                         let filename =
                             format!("xl/media/image{index}.{}", image.image_type.extension());
-                        self.zip.start_file(filename, self.zip_options)?;
+                        self.start_part(filename, self.zip_options)?;
 
-                        self.zip.write_all(&image.data)?;
+                        self.write_part(&image.data)?;
                         unique_header_footer_images.insert(image.hash);
                         index += 1;
                     }
@@ -858,9 +973,9 @@ impl<W: Write + Seek + Send> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             for chart in worksheet.charts.values_mut() {
                 let filename = format!("xl/charts/chart{index}.xml");
-                self.zip.start_file(filename, self.zip_options)?;
+                self.start_part(filename, self.zip_options)?;
                 chart.assemble_xml_file();
-                self.zip.write_all(chart.writer.xmlfile.get_ref())?;
+                self.write_part(chart.writer.xmlfile.get_ref())?;
                 index += 1;
             }
         }
@@ -875,9 +990,25 @@ impl<W: Write + Seek + Send> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             for table in &mut worksheet.tables {
                 let filename = format!("xl/tables/table{index}.xml");
-                self.zip.start_file(filename, self.zip_options)?;
+                self.start_part(filename, self.zip_options)?;
                 table.assemble_xml_file();
-                self.zip.write_all(table.writer.xmlfile.get_ref())?;
+                self.write_part(table.writer.xmlfile.get_ref())?;
+                index += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write the printer settings files.
+    fn write_printer_settings_files(&mut self, workbook: &Workbook) -> Result<(), XlsxError> {
+        let mut index = 1;
+
+        for worksheet in &workbook.worksheets {
+            if !worksheet.printer_settings.is_empty() {
+                let filename = format!("xl/printerSettings/printerSettings{index}.bin");
+                self.start_part(filename, self.zip_options_for_binary_files)?;
+                self.write_part(&worksheet.printer_settings)?;
                 index += 1;
             }
         }
@@ -892,9 +1023,8 @@ impl<W: Write + Seek + Send> Packager<W> {
         }
 
         let filename = "xl/vbaProject.bin";
-        self.zip
-            .start_file(filename, self.zip_options_for_binary_files)?;
-        self.zip.write_all(&workbook.vba_project)?;
+        self.start_part(filename, self.zip_options_for_binary_files)?;
+        self.write_part(&workbook.vba_project)?;
 
         // Write the VBA signature file, if present.
         self.write_vba_signature(workbook)
@@ -907,9 +1037,8 @@ impl<W: Write + Seek + Send> Packager<W> {
         }
 
         let filename = "xl/vbaProjectSignature.bin";
-        self.zip
-            .start_file(filename, self.zip_options_for_binary_files)?;
-        self.zip.write_all(&workbook.vba_signature)?;
+        self.start_part(filename, self.zip_options_for_binary_files)?;
+        self.write_part(&workbook.vba_signature)?;
 
         // Write the associated .rels file.
         self.write_vba_project_rels_file()?;
@@ -932,6 +1061,9 @@ pub(crate) struct PackagerOptions {
     pub(crate) num_charts: u16,
     pub(crate) num_tables: u16,
     pub(crate) num_comments: u16,
+    pub(crate) num_threaded_comments: u16,
+    pub(crate) num_printer_settings: u16,
+    pub(crate) has_persons: bool,
     pub(crate) doc_security: u8,
     pub(crate) worksheet_names: Vec<String>,
     pub(crate) defined_names: Vec<String>,
@@ -939,6 +1071,7 @@ pub(crate) struct PackagerOptions {
     pub(crate) properties: DocProperties,
     pub(crate) num_embedded_images: u32,
     pub(crate) has_embedded_image_descriptions: bool,
+    pub(crate) value_metadata_types: Vec<String>,
 }
 
 impl PackagerOptions {
@@ -957,6 +1090,9 @@ impl PackagerOptions {
             num_charts: 0,
             num_tables: 0,
             num_comments: 0,
+            num_threaded_comments: 0,
+            num_printer_settings: 0,
+            has_persons: false,
             doc_security: 0,
             worksheet_names: vec![],
             defined_names: vec![],
@@ -964,6 +1100,7 @@ impl PackagerOptions {
             properties: DocProperties::new(),
             num_embedded_images: 0,
             has_embedded_image_descriptions: false,
+            value_metadata_types: vec![],
         }
     }
 }
@@ -17,7 +17,7 @@ impl<'a> RichValue<'a> {
     // -----------------------------------------------------------------------
 
     // Create a new RichValue struct.
-    pub(crate) fn new(embedded_images: &Vec<Image>) -> RichValue {
+    pub(crate) fn new(embedded_images: &Vec<Image>) -> RichValue<'_> {
         let writer = XMLWriter::new();
 
         RichValue {
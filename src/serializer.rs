@@ -27,6 +27,7 @@
 //! - [Skipping fields when serializing](#skipping-fields-when-serializing)
 //! - [Setting serialization formatting](#setting-serialization-formatting)
 //! - [Serializing dates and times](#serializing-dates-and-times)
+//! - [Serializing maps](#serializing-maps)
 //! - [Controlling Excel output via `XlsxSerialize` and struct
 //!   attributes](#controlling-excel-output-via-xlsxserialize-and-struct-attributes)
 //!   - [Container `xlsx` attributes](#container-xlsx-attributes)
@@ -50,7 +51,8 @@
 //! that the target output is a 2D grid of cells into which the data can be
 //! serialized. As such the focus is on serializing data types that map to this
 //! 2D grid such as structs or compound collections of structs such as vectors
-//! or tuples and it (currently) ignores compound types like maps.
+//! or tuples. Maps are also supported and are serialized as key/value
+//! columns, see [Serializing maps](#serializing-maps).
 //!
 //! The image below shows the basic scheme for mapping a struct to a worksheet:
 //! fields are mapped to a header and values are mapped to sequential cells
@@ -905,7 +907,9 @@
 //!
 //! There are a few ways of formatting the values for a field:
 //!
-//! - Use [`Worksheet::set_column_format()`] to format the entire column.
+//! - Use [`Worksheet::set_column_format()`] to format the entire column, or
+//!   [`Worksheet::set_column_range_format()`] to format a span of columns in
+//!   one call.
 //! - Use [`CustomSerializeField::set_column_format()`] to format the entire
 //!   column. This is the same as the worksheet method but it has the advantage
 //!   of having the column number calculated automatically based on the field
@@ -1176,10 +1180,15 @@
 //! (as shown in the example below) or
 //! [`Utility::serialize_chrono_option_naive_to_excel()`](crate::utility::serialize_chrono_option_naive_to_excel()).
 //!
-//! Excel datetimes also need a number format to display them as a date/time
-//! since they are stored  as `f64` values. See [Datetimes in
-//! Excel](crate::ExcelDateTime#datetimes-in-excel) and the previous section on
-//! adding formatting.
+//! Excel datetimes need a number format to display them as a date/time since
+//! they are stored as `f64` values. See [Datetimes in
+//! Excel](crate::ExcelDateTime#datetimes-in-excel). When [`ExcelDateTime`]
+//! values, or Chrono values serialized with the functions above, are
+//! serialized to a worksheet they are written with a default number format of
+//! `yyyy-mm-dd`, `hh:mm:ss` or `yyyy-mm-dd hh:mm:ss` for dates, times and
+//! datetimes, respectively. You can override this with a value format that has
+//! a number format, see the previous section on adding formatting. `None`
+//! values of optional dates are written as blank cells.
 //!
 //! Note, Excel doesn't use timezones or try to convert or encode timezone
 //! information in any way so they aren't supported by `rust_xlsxwriter`.
@@ -1237,7 +1246,7 @@
 //!     ];
 //!
 //!     // Set up the start location and headers of the data to be serialized. Note,
-//!     // we need to add a cell format for the datetime data.
+//!     // we add a custom cell format for the datetime data.
 //!     let custom_headers = [
 //!         CustomSerializeField::new("name").rename("Student"),
 //!         CustomSerializeField::new("dob")
@@ -1317,7 +1326,7 @@
 //! #     ];
 //! #
 //! #     // Set up the start location and headers of the data to be serialized. Note,
-//! #     // we need to add a cell format for the datetime data.
+//! #     // we add a custom cell format for the datetime data.
 //! #     let custom_headers = [
 //! #         CustomSerializeField::new("name").rename("Student"),
 //! #         CustomSerializeField::new("dob")
@@ -1348,6 +1357,78 @@
 //!
 //!
 //!
+//! ## Serializing maps
+//!
+//! Maps such as `HashMap` or `BTreeMap` don't have a struct type name or fields
+//! but they can be serialized as two columns with the key of each map entry in
+//! the first column and the value in the second column. The columns are
+//! mapped to the synthetic fields `"key"` and `"value"` of a type called
+//! `"map"`. These names can be used with [`CustomSerializeField`] to rename or
+//! format the headers and with
+//! [`Worksheet::get_serialize_dimensions()`](crate::Worksheet::get_serialize_dimensions).
+//! The type name can be changed with
+//! [`SerializeFieldOptions::set_struct_name()`], for example to serialize
+//! several maps to different areas of the same worksheet.
+//!
+//! The keys and values of the map must be scalar types like numbers, strings,
+//! booleans or dates. Maps with compound values, such as structs or vectors,
+//! will return a [`XlsxError::SerdeError`] error. Maps in the fields of a
+//! struct aren't serialized.
+//!
+//! The map entries are written in the iteration order of the map. Since the
+//! order of a `HashMap` isn't defined you may prefer to use a `BTreeMap`.
+//!
+//! Note, Serde also serializes structs that contain `#[serde(flatten)]` fields
+//! as maps. These can be serialized like other structs, with each field in a
+//! column, using [`SerializeFieldOptions::use_map_keys_as_headers()`].
+//!
+//! The following example demonstrates serializing a map to key/value columns
+//! in a worksheet.
+//!
+//! ```
+//! # // This code is available in examples/doc_worksheet_serialize_map.rs
+//! #
+//! # use std::collections::BTreeMap;
+//! #
+//! # use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+//! #
+//! # fn main() -> Result<(), XlsxError> {
+//! #     let mut workbook = Workbook::new();
+//! #
+//! #     // Add a worksheet to the workbook.
+//! #     let worksheet = workbook.add_worksheet();
+//! #
+//!     // Create a map of metric names and values.
+//!     let metrics = BTreeMap::from([
+//!         ("Requests", 12_500.0),
+//!         ("Errors", 42.0),
+//!         ("Latency (ms)", 18.5),
+//!     ]);
+//!
+//!     // Rename the "key" and "value" headers.
+//!     let header_options = SerializeFieldOptions::new().set_custom_headers(&[
+//!         CustomSerializeField::new("key").rename("Metric"),
+//!         CustomSerializeField::new("value").rename("Value"),
+//!     ]);
+//!
+//!     // Set the serialization location and headers.
+//!     worksheet.serialize_headers_with_options(0, 0, &metrics, &header_options)?;
+//!
+//!     // Serialize the map entries.
+//!     worksheet.serialize(&metrics)?;
+//! #
+//! #     // Save the file.
+//! #     workbook.save("serialize.xlsx")?;
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//!
+//!
+//!
+//!
+//!
 //! ## Controlling Excel output via `XlsxSerialize` and struct attributes
 //!
 //! In the sections above we saw how to use [Serde
@@ -1928,13 +2009,17 @@
 //! serializing data to Excel via `rust_xlsxwriter` it is best to consider what
 //! that data will look like while designing your serialization.
 //!
-//! Another limitation is that currently you can only serialize structs or
-//! struct values in compound containers such as vectors. Not all of the
+//! Another limitation is that currently you can only serialize structs,
+//! struct values in compound containers such as vectors, or maps of scalar
+//! values. Not all of the
 //! supported types in the [Serde data model] make sense in the context of
 //! Excel. In upcoming releases I will try to add support for additional types
 //! where it makes sense. If you have a valid use case please open a GitHub
-//! issue to discuss it with an example data structure. Also Serde sub-struct
-//! flattening is not currently supported.
+//! issue to discuss it with an example data structure. Serde sub-struct
+//! flattening is supported via
+//! [`SerializeFieldOptions::use_map_keys_as_headers()`] and nested structs
+//! can be serialized to grouped header columns using
+//! [`SerializeFieldOptions::use_nested_headers()`].
 //!
 //! [Serde data model]: https://serde.rs/data-model.html
 //!
@@ -1954,13 +2039,27 @@
 //!
 #![warn(missing_docs)]
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::{ColNum, Format, RowNum, Table, TableStyle, Worksheet, XlsxError};
 use serde::de::Visitor;
 use serde::{ser, Deserialize, Deserializer, Serialize};
 
+// The maximum depth of nested structs that can be serialized to grouped header
+// columns, not including the parent struct.
+pub(crate) const MAX_NESTED_DEPTH: usize = 3;
+
+// The type name and field names used for the entries of a map, such as a
+// `HashMap` or `BTreeMap`, which are serialized as key/value columns.
+pub(crate) const MAP_STRUCT_NAME: &str = "map";
+pub(crate) const MAP_KEY_FIELD: &str = "key";
+pub(crate) const MAP_VALUE_FIELD: &str = "value";
+
+// The newtype struct name used to serialize Excel serial dates/times so that
+// they can be identified and written with a date number format.
+pub(crate) const DATETIME_NEWTYPE_NAME: &str = "ExcelDateTime";
+
 // Convenience tuple struct Table data used for serialization formatting.
 pub(crate) struct TableData(
     pub(crate) RowNum,
@@ -1970,6 +2069,19 @@ pub(crate) struct TableData(
     pub(crate) Table,
 );
 
+// Convenience type for a function or closure that transforms serialized values.
+pub(crate) type SerializedValueTransform =
+    Arc<dyn Fn(SerializedValue) -> SerializedValue + Send + Sync>;
+
+// Convenience type for the cell position, format and value transform of the
+// current serialized field.
+pub(crate) type SerializerCellState = (
+    RowNum,
+    ColNum,
+    Arc<Option<Format>>,
+    Option<SerializedValueTransform>,
+);
+
 // -----------------------------------------------------------------------
 // SerializerState, a struct to maintain row/column state and other metadata
 // between serialized writes. This avoids passing around cell location
@@ -1979,6 +2091,12 @@ pub(crate) struct SerializerState {
     pub(crate) structs: HashMap<String, SerializationHeaderConfig>,
     pub(crate) current_struct: String,
     pub(crate) current_field: String,
+    pub(crate) nested_fields: Vec<String>,
+    pub(crate) is_map: bool,
+    pub(crate) is_map_record: bool,
+    pub(crate) is_datetime: bool,
+    pub(crate) column_widths: HashMap<ColNum, u16>,
+    pub(crate) map_struct_name: String,
 }
 
 impl SerializerState {
@@ -1988,12 +2106,35 @@ impl SerializerState {
             structs: HashMap::new(),
             current_struct: String::new(),
             current_field: String::new(),
+            nested_fields: vec![],
+            is_map: false,
+            is_map_record: false,
+            is_datetime: false,
+            column_widths: HashMap::new(),
+            map_struct_name: MAP_STRUCT_NAME.to_string(),
         }
     }
 
+    // Clear any field or map state left over from a previous, or failed,
+    // serialization.
+    pub(crate) fn reset(&mut self) {
+        self.current_field.clear();
+        self.nested_fields.clear();
+        self.is_map = false;
+        self.is_map_record = false;
+        self.is_datetime = false;
+    }
+
+    // Update the maximum pixel width of the serialized data, and headers, in a
+    // column. This is used to autofit the serialized columns.
+    pub(crate) fn update_column_width(&mut self, col: ColNum, pixel_width: u16) {
+        let max_width = self.column_widths.entry(col).or_insert(0);
+        *max_width = (*max_width).max(pixel_width);
+    }
+
     // Check if the current struct/field have been selected to be serialized by
     // the user. If it has then return the row value for the next `write()` call.
-    pub(crate) fn current_state(&mut self) -> Result<(RowNum, ColNum, Arc<Option<Format>>), ()> {
+    pub(crate) fn current_state(&mut self) -> Result<SerializerCellState, ()> {
         let Some(header_config) = self.structs.get_mut(&self.current_struct) else {
             return Err(());
         };
@@ -2003,11 +2144,17 @@ impl SerializerState {
         };
 
         // Set the "current" cell values used to write the serialized data.
-        let row = header_config.max_row - 1;
-        let col = field.col;
+        // Each struct instance is written to a new row, or to a new column in
+        // the transposed layout.
+        let (row, col) = if header_config.transpose {
+            (field.row, header_config.max_col - 1)
+        } else {
+            (header_config.max_row - 1, field.col)
+        };
         let value_format = Arc::clone(&field.value_format);
+        let value_transform = field.value_transform.clone();
 
-        Ok((row, col, value_format))
+        Ok((row, col, value_format, value_transform))
     }
 
     // Store the name and max row of the current struct being serialized.
@@ -2016,11 +2163,130 @@ impl SerializerState {
             self.current_struct = struct_name.to_string();
         }
 
-        // Increment the max row every time we serialize a new struct instance.
+        // Clear any nested struct state left over from a failed serialization.
+        self.nested_fields.clear();
+
+        // Increment the max row, or the max column in the transposed layout,
+        // every time we serialize a new struct instance.
         let Some(header_config) = self.structs.get_mut(&self.current_struct) else {
             return;
         };
-        header_config.max_row += 1;
+
+        if header_config.transpose {
+            header_config.max_col += 1;
+        } else {
+            header_config.max_row += 1;
+        }
+    }
+
+    // Store the name of the current field. Fields of nested structs are stored
+    // as "." separated paths such as "parent.field".
+    pub(crate) fn set_current_field(&mut self, field_name: &str) {
+        self.current_field = match self.nested_fields.last() {
+            Some(parent) => format!("{parent}.{field_name}"),
+            None => field_name.to_string(),
+        };
+    }
+
+    // Check if the current field has been set to skip writing `None` values.
+    pub(crate) fn is_skip_none(&self) -> bool {
+        self.structs
+            .get(&self.current_struct)
+            .and_then(|header_config| header_config.fields.get(&self.current_field))
+            .is_some_and(|field| field.skip_none)
+    }
+
+    // Check if the current field is a nested struct that is serialized to
+    // grouped header columns of the current struct.
+    pub(crate) fn is_nested_struct(&self) -> bool {
+        self.structs
+            .get(&self.current_struct)
+            .is_some_and(|header_config| header_config.nested_groups.contains(&self.current_field))
+    }
+
+    // Start serializing the fields of a nested struct.
+    pub(crate) fn start_nested_struct(&mut self) {
+        self.nested_fields.push(self.current_field.clone());
+    }
+
+    // End the serialization of a nested struct, or the current struct.
+    pub(crate) fn end_struct(&mut self) {
+        if self.nested_fields.pop().is_none() {
+            self.current_field.clear();
+        }
+    }
+
+    // Check if the current value is the top level map of a serialization, as
+    // opposed to a map in the field of a struct.
+    pub(crate) fn is_top_level_map(&self) -> bool {
+        !self.is_map && self.current_field.is_empty() && self.nested_fields.is_empty()
+    }
+
+    // Start serializing a top level map. Maps don't have a type name so they
+    // are written to the most recently set up map area. If the map headers
+    // were set up to use the map keys as field names then the map is written
+    // to a single row, like a struct. Otherwise each entry is written to a new
+    // row.
+    pub(crate) fn start_map(&mut self) {
+        self.is_map = true;
+        self.is_map_record = self
+            .structs
+            .get(&self.map_struct_name)
+            .is_some_and(|header_config| header_config.use_map_keys);
+
+        if self.is_map_record {
+            let map_struct_name = self.map_struct_name.clone();
+            self.set_current_struct(&map_struct_name);
+        }
+    }
+
+    // Start a new row for the next entry of a serialized map.
+    pub(crate) fn start_map_entry(&mut self) {
+        let map_struct_name = self.map_struct_name.clone();
+        self.set_current_struct(&map_struct_name);
+        self.current_field = MAP_KEY_FIELD.to_string();
+    }
+
+    // Check that the current map entry value is a scalar type that can be
+    // written to a cell.
+    pub(crate) fn check_map_scalar(&self) -> Result<(), XlsxError> {
+        if self.is_map {
+            return Err(XlsxError::SerdeError(format!(
+                "Map {} must be a scalar type to be serialized to a worksheet cell",
+                self.current_field
+            )));
+        }
+
+        Ok(())
+    }
+
+    // Get the name of the map key field, if any, of a serialized struct.
+    pub(crate) fn key_field(&self, struct_name: &str) -> Option<String> {
+        self.structs
+            .get(struct_name)
+            .and_then(|header_config| header_config.key_field.clone())
+    }
+
+    // Reset the write position of a serialization area back to the start of
+    // the data, below or beside the headers. This is the internal function for
+    // worksheet.serialize_reset().
+    pub(crate) fn reset_position(&mut self, name: &str) {
+        if let Some(header_config) = self.structs.get_mut(name) {
+            header_config.max_row = header_config.data_row;
+            header_config.max_col = header_config.data_col;
+        }
+    }
+
+    // Get the number of struct instances serialized to a serialization area.
+    // This is the internal function for worksheet.serialized_row_count().
+    pub(crate) fn get_row_count(&self, name: &str) -> Option<RowNum> {
+        let header_config = self.structs.get(name)?;
+
+        if header_config.transpose {
+            Some(RowNum::from(header_config.max_col - header_config.data_col))
+        } else {
+            Some(header_config.max_row - header_config.data_row)
+        }
     }
 
     // Get dimensions of a serialization area. This is the internal function for
@@ -2035,6 +2301,15 @@ impl SerializerState {
             )));
         };
 
+        if header_config.transpose {
+            return Ok((
+                header_config.min_row,
+                header_config.min_col,
+                header_config.max_row,
+                header_config.max_col - 1,
+            ));
+        }
+
         Ok((
             header_config.min_row,
             header_config.min_col,
@@ -2062,6 +2337,16 @@ impl SerializerState {
             )));
         };
 
+        // The fields of the transposed layout are in rows.
+        if header_config.transpose {
+            return Ok((
+                field.row,
+                header_config.min_col,
+                field.row,
+                header_config.max_col - 1,
+            ));
+        }
+
         Ok((
             header_config.min_row,
             field.col,
@@ -2095,6 +2380,12 @@ pub(crate) struct SerializationHeaderConfig {
     pub(crate) max_row: RowNum,
     pub(crate) max_col: ColNum,
     pub(crate) table: Option<Table>,
+    pub(crate) key_field: Option<String>,
+    pub(crate) nested_groups: HashSet<String>,
+    pub(crate) transpose: bool,
+    pub(crate) data_row: RowNum,
+    pub(crate) data_col: ColNum,
+    pub(crate) use_map_keys: bool,
 }
 
 impl SerializationHeaderConfig {
@@ -2223,6 +2514,11 @@ pub struct SerializeFieldOptions {
     pub(crate) custom_headers: Vec<CustomSerializeField>,
     pub(crate) use_custom_headers_only: bool,
     pub(crate) table: Option<Table>,
+    pub(crate) key_field: Option<CustomSerializeField>,
+    pub(crate) use_nested_headers: bool,
+    pub(crate) group_headers: Vec<CustomSerializeField>,
+    pub(crate) transpose: bool,
+    pub(crate) use_map_keys_as_headers: bool,
 }
 
 impl Default for SerializeFieldOptions {
@@ -2250,6 +2546,11 @@ impl SerializeFieldOptions {
             custom_headers: vec![],
             use_custom_headers_only: false,
             table: None,
+            key_field: None,
+            use_nested_headers: false,
+            group_headers: vec![],
+            transpose: false,
+            use_map_keys_as_headers: false,
         }
     }
 
@@ -2683,6 +2984,13 @@ impl SerializeFieldOptions {
     /// This method can be used to add a user defined worksheet [`Table`]
     /// structure to a serialized area.
     ///
+    /// The table column names are always taken from the serialized headers,
+    /// including any custom header names, so that the table header cells and
+    /// the internal table column names match. Any names set via
+    /// [`TableColumn::set_header()`](crate::TableColumn::set_header) are
+    /// overridden. Other column properties such as total functions and formats
+    /// are retained.
+    ///
     /// See [`Table`] for more details on worksheet tables.
     ///
     /// # Parameters
@@ -2890,19 +3198,418 @@ impl SerializeFieldOptions {
         self
     }
 
+    /// Serialize the fields of nested structs to grouped header columns.
+    ///
+    /// By default a struct field that contains another struct isn't
+    /// serialized. This option maps the fields of nested structs to columns
+    /// under a multi-level header. The name of the nested struct field is
+    /// written as a group label that is merged across its sub-field columns
+    /// and the sub-field names are written in the row below. Headers of
+    /// non-nested fields are merged down across the header rows.
+    ///
+    /// For example, a `Sale` struct with `q1` and `q2` fields of type
+    /// `Quarter { revenue, cost }` is serialized with a two row header with the
+    /// group labels `q1` and `q2` in the first row and `revenue` and `cost`,
+    /// under each of them, in the second row.
+    ///
+    /// The fields of nested structs are identified by "." separated paths,
+    /// such as `q1.revenue`, which can be used with [`CustomSerializeField`] to
+    /// rename, format or skip the field in the same way as other fields. This
+    /// also distinguishes sub-fields with the same name in different groups.
+    /// The group labels can be renamed or formatted with a custom field that
+    /// uses the path of the nested struct, such as `q1`.
+    ///
+    /// Note, the nested structs are determined from the instance passed to
+    /// [`Worksheet::serialize_headers_with_options()`] so any `Option` nested
+    /// struct fields in it should contain a value. This option isn't
+    /// supported with [`Worksheet::deserialize_headers_with_options()`] since
+    /// it only has access to the names of the top level fields. Structs can be
+    /// nested up to 3 levels deep and serialization tables aren't supported
+    /// with multi-level headers.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing nested structs to
+    /// grouped header columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_nested_headers.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     CustomSerializeField, Format, FormatAlign, SerializeFieldOptions, Workbook, XlsxError,
+    /// # };
+    /// # use serde::Serialize;
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create some serializable nested structs.
+    ///     #[derive(Serialize)]
+    ///     struct Quarter {
+    ///         revenue: f64,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     #[derive(Serialize)]
+    ///     struct Sale {
+    ///         region: &'static str,
+    ///         q1: Quarter,
+    ///         q2: Quarter,
+    ///     }
+    ///
+    ///     let sales = [
+    ///         Sale {
+    ///             region: "North",
+    ///             q1: Quarter {
+    ///                 revenue: 1000.0,
+    ///                 cost: 400.0,
+    ///             },
+    ///             q2: Quarter {
+    ///                 revenue: 1200.0,
+    ///                 cost: 450.0,
+    ///             },
+    ///         },
+    ///         Sale {
+    ///             region: "South",
+    ///             q1: Quarter {
+    ///                 revenue: 800.0,
+    ///                 cost: 300.0,
+    ///             },
+    ///             q2: Quarter {
+    ///                 revenue: 900.0,
+    ///                 cost: 350.0,
+    ///             },
+    ///         },
+    ///     ];
+    ///
+    ///     // Rename the group labels and use nested headers.
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("q1").rename("Q1"),
+    ///         CustomSerializeField::new("q2").rename("Q2"),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(Format::new().set_bold().set_align(FormatAlign::Center))
+    ///         .set_custom_headers(&custom_headers)
+    ///         .use_nested_headers(true);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.serialize_headers_with_options(0, 0, &sales[0], &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&sales)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn use_nested_headers(mut self, enable: bool) -> SerializeFieldOptions {
+        self.use_nested_headers = enable;
+        self
+    }
+
+    /// Use the keys of a serialized map as the field headers.
+    ///
+    /// Serde serializes structs that have `#[serde(flatten)]` fields as maps
+    /// without a struct name. By default maps are serialized as key/value
+    /// columns (see [Serializing maps](crate::serializer#serializing-maps)).
+    /// The `use_map_keys_as_headers()` option instead uses the map keys, which
+    /// are the field names of the struct and the flattened sub-struct, as the
+    /// headers and serializes each map, or struct instance, to a single row.
+    ///
+    /// The headers must be set up from an instance of the data with
+    /// [`Worksheet::serialize_headers_with_options()`] since the field names
+    /// can't be determined via deserialization. The serialized data is
+    /// referred to by the struct name `"map"`, for example with
+    /// [`Worksheet::get_serialize_dimensions()`](crate::Worksheet::get_serialize_dimensions),
+    /// or by a name set with [`SerializeFieldOptions::set_struct_name()`].
+    /// This option only applies to maps.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing a struct with a
+    /// `#[serde(flatten)]` sub-struct field. The fields of the sub-struct are
+    /// written to their own columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_flatten.rs
+    /// #
+    /// # use rust_xlsxwriter::{SerializeFieldOptions, Workbook, XlsxError};
+    /// # use serde::Serialize;
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create some serializable structs with a flattened sub-struct.
+    ///     #[derive(Serialize)]
+    ///     struct Address {
+    ///         city: &'static str,
+    ///         country: &'static str,
+    ///     }
+    ///
+    ///     #[derive(Serialize)]
+    ///     struct Customer {
+    ///         name: &'static str,
+    ///         #[serde(flatten)]
+    ///         address: Address,
+    ///     }
+    ///
+    ///     let customers = [
+    ///         Customer {
+    ///             name: "Aoife",
+    ///             address: Address {
+    ///                 city: "Dublin",
+    ///                 country: "Ireland",
+    ///             },
+    ///         },
+    ///         Customer {
+    ///             name: "Caoimhe",
+    ///             address: Address {
+    ///                 city: "Galway",
+    ///                 country: "Ireland",
+    ///             },
+    ///         },
+    ///     ];
+    ///
+    ///     // Structs with flattened fields are serialized as maps so we use the map
+    ///     // keys as the headers.
+    ///     let header_options = SerializeFieldOptions::new().use_map_keys_as_headers(true);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.serialize_headers_with_options(0, 0, &customers[0], &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&customers)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn use_map_keys_as_headers(mut self, enable: bool) -> SerializeFieldOptions {
+        self.use_map_keys_as_headers = enable;
+        self
+    }
+
+    /// Add a key column for serializing maps of keyed structs.
+    ///
+    /// Data is often stored in a map of id to struct, such as `HashMap<u32,
+    /// Record>`. This method adds a column, before the struct fields, that the
+    /// map keys are written to when the data is serialized with
+    /// [`Worksheet::serialize_keyed()`](crate::Worksheet::serialize_keyed).
+    ///
+    /// The `field_name` of the [`CustomSerializeField`] is the identifier of
+    /// the key column and it is also the default header text. It can be
+    /// renamed or formatted like any other custom field. The name must not be
+    /// the same as one of the struct field names.
+    ///
+    /// # Parameters
+    ///
+    /// - `key_field`: A [`CustomSerializeField`] reference for the key column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing a map of keyed structs
+    /// with the key written to the first column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_keyed.rs
+    /// #
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// # use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let item1 = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///     };
+    ///     let item2 = Produce {
+    ///         fruit: "Plum",
+    ///         cost: 0.15,
+    ///     };
+    ///     let item3 = Produce {
+    ///         fruit: "Pear",
+    ///         cost: 0.75,
+    ///     };
+    ///
+    ///     // Create a map of the data keyed by id.
+    ///     let items = BTreeMap::from([(101, item1), (102, item2), (103, item3)]);
+    ///
+    ///     // Add a key column with the header "Id".
+    ///     let key_field = CustomSerializeField::new("id").rename("Id");
+    ///     let header_options = SerializeFieldOptions::new().set_key_field(&key_field);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the keys and data.
+    ///     worksheet.serialize_keyed(&items)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_key_field(mut self, key_field: &CustomSerializeField) -> SerializeFieldOptions {
+        self.key_field = Some(key_field.clone());
+        self
+    }
+
+    /// Serialize the data in a transposed, column-major, layout.
+    ///
+    /// By default the fields of a serialized struct are mapped to columns and
+    /// each struct instance is written to a new row below the headers. The
+    /// `set_transpose()` method flips the axes so that the headers are written
+    /// down the first column and each struct instance is written to a new
+    /// column to the right of the headers. This is useful for data with a
+    /// large number of fields but only a few instances, such as snapshots of
+    /// a time series.
+    ///
+    /// In the transposed layout the dimensions returned by
+    /// [`Worksheet::get_serialize_column_dimensions()`](crate::Worksheet::get_serialize_column_dimensions)
+    /// are the row of the field. The column widths and formats of
+    /// [`CustomSerializeField`] don't apply to the transposed layout and it
+    /// can't be used with tables or nested headers.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing instances of a Serde
+    /// derived data structure in a transposed layout with the field headers in
+    /// the first column and each instance in a new column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_transpose.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, SerializeFieldOptions, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Snapshot {
+    ///         time: &'static str,
+    ///         cpu: f64,
+    ///         memory: f64,
+    ///         disk: f64,
+    ///         network: f64,
+    ///     }
+    ///
+    ///     let snapshots = [
+    ///         Snapshot {
+    ///             time: "09:00",
+    ///             cpu: 0.25,
+    ///             memory: 0.60,
+    ///             disk: 0.41,
+    ///             network: 0.12,
+    ///         },
+    ///         Snapshot {
+    ///             time: "10:00",
+    ///             cpu: 0.75,
+    ///             memory: 0.65,
+    ///             disk: 0.42,
+    ///             network: 0.37,
+    ///         },
+    ///         Snapshot {
+    ///             time: "11:00",
+    ///             cpu: 0.50,
+    ///             memory: 0.70,
+    ///             disk: 0.42,
+    ///             network: 0.21,
+    ///         },
+    ///     ];
+    ///
+    ///     // Write the headers in a column and each snapshot in a new column.
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(Format::new().set_bold())
+    ///         .set_transpose(true);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.deserialize_headers_with_options::<Snapshot>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&snapshots)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_transpose(mut self, enable: bool) -> SerializeFieldOptions {
+        self.transpose = enable;
+        self
+    }
+
     /// Set the name of the struct to be serialized.
     ///
-    /// Note, this is a semi public method. End users won't and shouldn't need
-    /// to use this since the struct name will be determine by the worksheet
-    /// method that sets the header location. The method is here to allow full
-    /// `SerializeFieldOptions` options to be generated programmatically by the
-    /// [`XlsxSerialize`] proc macros.
+    /// For structs this isn't generally required since the struct name is
+    /// determined by the worksheet method that sets the header location. The
+    /// method is mainly here to allow full `SerializeFieldOptions` options to
+    /// be generated programmatically by the [`XlsxSerialize`] proc macros.
+    ///
+    /// However, maps, including structs with `#[serde(flatten)]` fields, don't
+    /// have a type name and are referred to by the default name `"map"`. If
+    /// there is more than one map area in a worksheet then they should each be
+    /// given a name via `set_struct_name()` so that the settings of one area
+    /// don't overwrite the other. Maps are serialized to the most recently set
+    /// up map area and the name is used to refer to the area in methods like
+    /// [`Worksheet::get_serialize_dimensions()`](crate::Worksheet::get_serialize_dimensions).
     ///
     /// # Parameters
     ///
     /// - `name`: The name of the struct being serialized.
     ///
-    #[doc(hidden)]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
     pub fn set_struct_name(mut self, name: impl Into<String>) -> SerializeFieldOptions {
         self.struct_name = name.into();
@@ -3020,10 +3727,14 @@ pub struct CustomSerializeField {
     pub(crate) header_format: Option<Format>,
     pub(crate) column_format: Option<Format>,
     pub(crate) value_format: Arc<Option<Format>>,
+    pub(crate) value_transform: Option<SerializedValueTransform>,
     pub(crate) skip: bool,
+    pub(crate) skip_none: bool,
+    pub(crate) row: RowNum,
     pub(crate) col: ColNum,
     pub(crate) width: Option<f64>,
     pub(crate) pixel_width: Option<u16>,
+    pub(crate) format_range_len: RowNum,
 }
 
 impl CustomSerializeField {
@@ -3050,10 +3761,14 @@ impl CustomSerializeField {
             header_format: None,
             column_format: None,
             value_format: Arc::new(None),
+            value_transform: None,
             skip: false,
+            skip_none: false,
+            row: 0,
             col: 0,
             width: None,
             pixel_width: None,
+            format_range_len: 0,
         }
     }
 
@@ -3376,6 +4091,89 @@ impl CustomSerializeField {
         self
     }
 
+    /// Pre-format a number of cells below a serialize header/field.
+    ///
+    /// The [`CustomSerializeField::set_value_format()`] method only formats
+    /// cells as data is serialized to them. If you know in advance how many
+    /// rows will be serialized, and you want all of them formatted, even if
+    /// some of the values are empty or are added later in Excel, you can use
+    /// this method to pre-apply the value format to exactly `len` cells below
+    /// the header.
+    ///
+    /// This is an alternative to [`CustomSerializeField::set_column_format()`]
+    /// which applies the format to the entire column, including any cells that
+    /// are subsequently written outside the serialized data.
+    ///
+    /// This method has no effect unless a value format has also been set via
+    /// [`CustomSerializeField::set_value_format()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: The number of cells below the header to format.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates pre-formatting a fixed number of
+    /// cells for a serialized field.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_headers_format_range_len.rs
+    /// #
+    /// use rust_xlsxwriter::{CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Add a currency format for the serialized values.
+    ///     let currency_format = Format::new().set_num_format("$0.00");
+    ///
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Pre-format 5 cells in the "cost" column, even though only 2 rows are
+    ///     // serialized initially.
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("fruit"),
+    ///         CustomSerializeField::new("cost")
+    ///             .set_value_format(currency_format)
+    ///             .set_cell_format_range_len(5),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+    ///
+    ///     // Set the serialization location and custom headers.
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///     })?;
+    ///     worksheet.serialize(&Produce {
+    ///         fruit: "Plum",
+    ///         cost: 0.15,
+    ///     })?;
+    ///
+    ///     // Save the file.
+    ///     workbook.save("serialize.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_cell_format_range_len(mut self, len: RowNum) -> CustomSerializeField {
+        self.format_range_len = len;
+        self
+    }
+
     /// Skip a field when serializing.
     ///
     /// When serializing a struct you may not want all of the fields to be
@@ -3476,6 +4274,25 @@ impl CustomSerializeField {
         self
     }
 
+    /// Skip writing the cell for `None` values of a field.
+    ///
+    /// By default `None` and unit values are serialized as empty cells. If
+    /// the field has a value format this is a formatted blank cell which
+    /// replaces any existing data in the cell. The `skip_none()` method turns
+    /// this off so that nothing is written for `None` values and any existing
+    /// value or formatting in the cell is kept. This is useful for overlaying
+    /// sparse optional data on an existing worksheet region or template.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn skip_none(mut self, enable: bool) -> CustomSerializeField {
+        self.skip_none = enable;
+        self
+    }
+
     /// Set the width for the column corresponding to a serialize header/field.
     ///
     /// The `set_column_width()` method is used to change the default width of a
@@ -3511,8 +4328,180 @@ impl CustomSerializeField {
         self.pixel_width = Some(width);
         self
     }
+
+    /// Set a function to transform the serialized values of a field.
+    ///
+    /// The `set_value_transform()` method sets a function, or closure, that is
+    /// called with each value of the field before it is written to the
+    /// worksheet. This can be used to modify the data without changing the
+    /// serialized structs, for example to mask personal information or to
+    /// clamp values to a range. A closure can also capture state from its
+    /// environment such as a lookup table of labels or a scaling factor.
+    ///
+    /// The value is passed to, and returned from, the function as a
+    /// [`SerializedValue`] which preserves the Excel type of the data. The
+    /// returned value is written to the worksheet as a number, string or
+    /// boolean, according to its type, so any value format set with
+    /// [`CustomSerializeField::set_value_format()`] is still applied to it.
+    ///
+    /// Empty values such as `None` are passed to the function as an empty
+    /// [`SerializedValue::String`].
+    ///
+    /// # Parameters
+    ///
+    /// - `transform`: A function or closure that takes a [`SerializedValue`]
+    ///   and returns a, possibly modified, [`SerializedValue`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates transforming values during
+    /// serialization to mask names and to clamp numbers to a minimum value.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_value_transform.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     CustomSerializeField, SerializeFieldOptions, SerializedValue, Workbook, XlsxError,
+    /// # };
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     struct Account {
+    ///         name: &'static str,
+    ///         balance: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let item1 = Account {
+    ///         name: "Alice",
+    ///         balance: 12.5,
+    ///     };
+    ///
+    ///     let item2 = Account {
+    ///         name: "Bob",
+    ///         balance: -3.0,
+    ///     };
+    ///
+    ///     // Mask all but the first character of a string.
+    ///     fn mask(value: SerializedValue) -> SerializedValue {
+    ///         match value {
+    ///             SerializedValue::String(name) => {
+    ///                 let masked = name.chars().take(1).chain(name.chars().skip(1).map(|_| '*'));
+    ///                 SerializedValue::String(masked.collect())
+    ///             }
+    ///             _ => value,
+    ///         }
+    ///     }
+    ///
+    ///     // Clamp numbers to a minimum value captured by a closure.
+    ///     let min_balance = 0.0;
+    ///     let clamp = move |value: SerializedValue| match value {
+    ///         SerializedValue::Number(number) => SerializedValue::Number(number.max(min_balance)),
+    ///         _ => value,
+    ///     };
+    ///
+    ///     // Set the custom headers.
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("name").set_value_transform(mask),
+    ///         CustomSerializeField::new("balance").set_value_transform(clamp),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+    ///
+    ///     // Set the serialization location and custom headers.
+    ///     worksheet.deserialize_headers_with_options::<Account>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&item1)?;
+    ///     worksheet.serialize(&item2)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_value_transform<F>(mut self, transform: F) -> CustomSerializeField
+    where
+        F: Fn(SerializedValue) -> SerializedValue + Send + Sync + 'static,
+    {
+        self.value_transform = Some(Arc::new(transform));
+        self
+    }
+}
+
+// -----------------------------------------------------------------------
+// SerializedValue.
+// -----------------------------------------------------------------------
+
+/// The `SerializedValue` enum represents a serialized value passed to a value
+/// transform function.
+///
+/// The `SerializedValue` enum is used with
+/// [`CustomSerializeField::set_value_transform()`] to allow serialized values
+/// to be modified before they are written to the worksheet. The variants
+/// correspond to the Excel types that serialized data is written as.
+///
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub enum SerializedValue {
+    /// A number value. All Rust integer and float types are serialized as an
+    /// Excel `f64` number.
+    Number(f64),
+
+    /// A string value. Empty values such as `None` are represented by an
+    /// empty string.
+    String(String),
+
+    /// A boolean value.
+    Boolean(bool),
+}
+
+impl From<bool> for SerializedValue {
+    fn from(value: bool) -> SerializedValue {
+        SerializedValue::Boolean(value)
+    }
 }
 
+impl From<&str> for SerializedValue {
+    fn from(value: &str) -> SerializedValue {
+        SerializedValue::String(value.to_string())
+    }
+}
+
+macro_rules! serialized_number_from_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for SerializedValue {
+            fn from(value: $t) -> SerializedValue {
+                SerializedValue::Number(f64::from(value))
+            }
+        }
+    )*)
+}
+serialized_number_from_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
+
+// Note: Excel doesn't support saving the full range of i64/u64 in f64 so this
+// is documented as a loss of precision.
+macro_rules! serialized_number_from_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for SerializedValue {
+            fn from(value: $t) -> SerializedValue {
+                #[allow(clippy::cast_precision_loss)]
+                SerializedValue::Number(value as f64)
+            }
+        }
+    )*)
+}
+serialized_number_from_impl!(u64 i64);
+
 // -----------------------------------------------------------------------
 // Worksheet Serializer. This is the implementation of the Serializer trait to
 // serialized a serde derived struct to an Excel worksheet.
@@ -3629,6 +4618,11 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
 
     #[doc(hidden)]
     fn serialize_none(self) -> Result<(), XlsxError> {
+        // Leave the cell unchanged for fields that skip `None` values.
+        if self.serializer_state.is_skip_none() {
+            return Ok(());
+        }
+
         self.serialize_str("")
     }
 
@@ -3653,12 +4647,22 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
         Ok(())
     }
 
-    // Try to handle this as a single value.
+    // Try to handle this as a single value. Dates and times are serialized as
+    // a newtype wrapper around the Excel serial datetime so that they can be
+    // written with a date number format.
     #[doc(hidden)]
-    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<(), XlsxError>
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
+        if name == DATETIME_NEWTYPE_NAME {
+            self.serializer_state.is_datetime = true;
+            let result = value.serialize(&mut *self);
+            self.serializer_state.is_datetime = false;
+
+            return result;
+        }
+
         value.serialize(self)
     }
 
@@ -3680,23 +4684,35 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
 
     // Compound types.
     //
-    // The only compound types we map into the Excel data model are structs.
+    // The main compound types we map into the Excel data model are structs.
+    // Maps are also mapped to key/value columns. Compound types aren't
+    // supported as the keys or values of a map.
 
     // Structs are the main primary data type used to map data structures into
     // Excel.
     fn serialize_struct(
         self,
         name: &'static str,
-        len: usize,
+        _len: usize,
     ) -> Result<Self::SerializeStruct, XlsxError> {
+        self.serializer_state.check_map_scalar()?;
+
+        // Nested structs that are mapped to grouped header columns are
+        // serialized as part of the current struct.
+        if self.serializer_state.is_nested_struct() {
+            self.serializer_state.start_nested_struct();
+            return Ok(self);
+        }
+
         // Store the struct type name to check against user defined structs.
         self.serializer_state.set_current_struct(name);
 
-        self.serialize_map(Some(len))
+        Ok(self)
     }
 
     #[doc(hidden)]
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, XlsxError> {
+        self.serializer_state.check_map_scalar()?;
         Ok(self)
     }
 
@@ -3725,13 +4741,23 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
         variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeTupleVariant, XlsxError> {
+        self.serializer_state.check_map_scalar()?;
         variant.serialize(&mut *self)?;
         Ok(self)
     }
 
-    // The field/values of structs are treated as a map.
+    // Maps, such as a `HashMap` or `BTreeMap`, are serialized with the key and
+    // value of each entry written to the "key" and "value" columns of a row.
+    // This only applies to the data structure being serialized and not to maps
+    // in the fields of a struct.
     #[doc(hidden)]
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, XlsxError> {
+        self.serializer_state.check_map_scalar()?;
+
+        if self.serializer_state.is_top_level_map() {
+            self.serializer_state.start_map();
+        }
+
         Ok(self)
     }
 
@@ -3744,13 +4770,15 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
         variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStructVariant, XlsxError> {
+        self.serializer_state.check_map_scalar()?;
         variant.serialize(&mut *self)?;
         Ok(self)
     }
 }
 
 // The following impls deal with the serialization of compound types.
-// Currently we only support/use SerializeStruct and SerializeSeq.
+// Currently we only support/use SerializeStruct, SerializeSeq and
+// SerializeMap.
 
 // Structs are the main sequence type used by `rust_xlsxwriter`.
 #[doc(hidden)]
@@ -3763,12 +4791,13 @@ impl<'a> ser::SerializeStruct for &'a mut Worksheet {
         T: ?Sized + Serialize,
     {
         // Store field name to allow us to map to the correct header/column.
-        self.serializer_state.current_field = key.to_string();
+        self.serializer_state.set_current_field(key);
 
         value.serialize(&mut **self)
     }
 
     fn end(self) -> Result<(), XlsxError> {
+        self.serializer_state.end_struct();
         Ok(())
     }
 }
@@ -3846,7 +4875,7 @@ impl<'a> ser::SerializeTupleVariant for &'a mut Worksheet {
     }
 }
 
-// Serialize tuple map sequences.
+// Serialize map entries to key/value columns.
 #[doc(hidden)]
 impl<'a> ser::SerializeMap for &'a mut Worksheet {
     type Ok = ();
@@ -3856,6 +4885,21 @@ impl<'a> ser::SerializeMap for &'a mut Worksheet {
     where
         T: ?Sized + Serialize,
     {
+        // Map keys are used as the field name of the value when the map is
+        // written like a struct. Otherwise each map entry is written to a new
+        // row.
+        if self.serializer_state.is_map_record {
+            if let Some(key_name) = SerializerHeader::map_key_name(key)? {
+                self.serializer_state.current_field = key_name;
+            }
+
+            return Ok(());
+        }
+
+        if self.serializer_state.is_map {
+            self.serializer_state.start_map_entry();
+        }
+
         key.serialize(&mut **self)
     }
 
@@ -3863,10 +4907,18 @@ impl<'a> ser::SerializeMap for &'a mut Worksheet {
     where
         T: ?Sized + Serialize,
     {
+        if self.serializer_state.is_map && !self.serializer_state.is_map_record {
+            self.serializer_state.current_field = MAP_VALUE_FIELD.to_string();
+        }
+
         value.serialize(&mut **self)
     }
 
     fn end(self) -> Result<(), XlsxError> {
+        if self.serializer_state.is_map {
+            self.serializer_state.reset();
+        }
+
         Ok(())
     }
 }
@@ -3897,6 +4949,68 @@ impl<'a> ser::SerializeStructVariant for &'a mut Worksheet {
 pub(crate) struct SerializerHeader {
     pub(crate) struct_name: String,
     pub(crate) field_names: Vec<String>,
+    pub(crate) nested_headers: HashMap<String, SerializerHeader>,
+    pub(crate) map_keys: Vec<String>,
+}
+
+impl SerializerHeader {
+    // Create a new SerializerHeader struct.
+    pub(crate) fn new() -> SerializerHeader {
+        SerializerHeader {
+            struct_name: String::new(),
+            field_names: vec![],
+            nested_headers: HashMap::new(),
+            map_keys: vec![],
+        }
+    }
+
+    // Get the string value of a map key, if any. This is used to get the
+    // field names of structs that are serialized as maps, such as structs
+    // with `#[serde(flatten)]` fields.
+    pub(crate) fn map_key_name<T>(key: &T) -> Result<Option<String>, XlsxError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let mut key_header = SerializerHeader::new();
+        key.serialize(&mut key_header)?;
+
+        Ok(key_header.field_names.pop())
+    }
+
+    // Get the field names of the struct, and any nested structs, as "."
+    // separated paths like "parent.field". The paths of the nested structs are
+    // stored separately as group names.
+    pub(crate) fn nested_field_names(
+        &self,
+        parent: &str,
+        depth: usize,
+        field_names: &mut Vec<String>,
+        group_names: &mut Vec<String>,
+    ) -> Result<(), XlsxError> {
+        for field_name in &self.field_names {
+            let path = if parent.is_empty() {
+                field_name.clone()
+            } else {
+                format!("{parent}.{field_name}")
+            };
+
+            match self.nested_headers.get(field_name) {
+                Some(nested_header) => {
+                    if depth >= MAX_NESTED_DEPTH {
+                        return Err(XlsxError::ParameterError(format!(
+                            "Nested struct field '{path}' exceeds the maximum nesting depth of {MAX_NESTED_DEPTH}"
+                        )));
+                    }
+
+                    group_names.push(path.clone());
+                    nested_header.nested_field_names(&path, depth + 1, field_names, group_names)?;
+                }
+                None => field_names.push(path),
+            }
+        }
+
+        Ok(())
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -3929,7 +5043,7 @@ impl<'a> ser::Serializer for &'a mut SerializerHeader {
         len: usize,
     ) -> Result<Self::SerializeStruct, XlsxError> {
         self.struct_name = name.to_string();
-        self.serialize_map(Some(len))
+        Ok(self)
     }
 
     // Ignore all other primitive types.
@@ -3985,11 +5099,13 @@ impl<'a> ser::Serializer for &'a mut SerializerHeader {
         Ok(())
     }
 
+    // Serialize Some(T) values to capture the fields of optional nested
+    // structs.
     fn serialize_some<T>(self, data: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
-        Ok(())
+        data.serialize(self)
     }
 
     fn serialize_none(self) -> Result<(), XlsxError> {
@@ -4060,7 +5176,14 @@ impl<'a> ser::Serializer for &'a mut SerializerHeader {
         Ok(self)
     }
 
+    // Maps are serialized to synthetic "key" and "value" fields. Maps in the
+    // fields of a struct are ignored.
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, XlsxError> {
+        if self.struct_name.is_empty() {
+            self.struct_name = MAP_STRUCT_NAME.to_string();
+            self.field_names = vec![MAP_KEY_FIELD.to_string(), MAP_VALUE_FIELD.to_string()];
+        }
+
         Ok(self)
     }
 
@@ -4081,11 +5204,19 @@ impl<'a> ser::SerializeStruct for &'a mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
-    fn serialize_field<T>(&mut self, key: &'static str, _value: &T) -> Result<(), XlsxError>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
-        // Serialize the key/field name but ignore the values.
+        // Serialize the value to capture the fields of any nested struct.
+        let mut nested_header = SerializerHeader::new();
+        value.serialize(&mut nested_header)?;
+
+        if !nested_header.struct_name.is_empty() && nested_header.struct_name != MAP_STRUCT_NAME {
+            self.nested_headers.insert(key.to_string(), nested_header);
+        }
+
+        // Serialize the key/field name.
         key.serialize(&mut **self)
     }
 
@@ -4158,14 +5289,19 @@ impl<'a> ser::SerializeTupleVariant for &'a mut SerializerHeader {
     }
 }
 
+// Capture the keys of maps to allow them to be used as field names.
 impl<'a> ser::SerializeMap for &'a mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
-    fn serialize_key<T>(&mut self, _key: &T) -> Result<(), XlsxError>
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
+        if let Some(key_name) = SerializerHeader::map_key_name(key)? {
+            self.map_keys.push(key_name);
+        }
+
         Ok(())
     }
 
@@ -4230,10 +5366,20 @@ impl<'de, 'a> Deserializer<'de> for DeSerializerHeader<'a> {
         Err(XlsxError::SerdeError("Deserialization error".to_string()))
     }
 
+    // Maps are deserialized to synthetic "key" and "value" fields.
+    fn deserialize_map<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        *self.struct_name = MAP_STRUCT_NAME;
+        *self.field_names = &[MAP_KEY_FIELD, MAP_VALUE_FIELD];
+        Err(XlsxError::SerdeError("Deserialization error".to_string()))
+    }
+
     serde::forward_to_deserialize_any! {
         bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
         byte_buf option unit unit_struct newtype_struct seq tuple
-        tuple_struct map enum identifier ignored_any
+        tuple_struct enum identifier ignored_any
     }
 }
 
@@ -4257,6 +5403,8 @@ where
     SerializerHeader {
         struct_name,
         field_names,
+        nested_headers: HashMap::new(),
+        map_keys: vec![],
     }
 }
 
@@ -18,13 +18,13 @@ mod shared_strings_tests {
 
         let mut shared_strings = SharedStrings::new();
 
-        string_table.shared_string_index("neptune".into());
-        string_table.shared_string_index("neptune".into());
-        string_table.shared_string_index("neptune".into());
-        string_table.shared_string_index("neptune".into());
-        string_table.shared_string_index("mars".into());
-        string_table.shared_string_index("venus".into());
-        string_table.shared_string_index("mars".into());
+        string_table.shared_string_index("neptune".into()).unwrap();
+        string_table.shared_string_index("neptune".into()).unwrap();
+        string_table.shared_string_index("neptune".into()).unwrap();
+        string_table.shared_string_index("neptune".into()).unwrap();
+        string_table.shared_string_index("mars".into()).unwrap();
+        string_table.shared_string_index("venus".into()).unwrap();
+        string_table.shared_string_index("mars".into()).unwrap();
 
         shared_strings.assemble_xml_file(&string_table);
 
@@ -57,9 +57,13 @@ mod shared_strings_tests {
 
         let mut shared_strings = SharedStrings::new();
 
-        string_table.shared_string_index("abcdefg".into());
-        string_table.shared_string_index("   abcdefg".into());
-        string_table.shared_string_index("abcdefg   ".into());
+        string_table.shared_string_index("abcdefg".into()).unwrap();
+        string_table
+            .shared_string_index("   abcdefg".into())
+            .unwrap();
+        string_table
+            .shared_string_index("abcdefg   ".into())
+            .unwrap();
 
         shared_strings.assemble_xml_file(&string_table);
 
@@ -12,6 +12,13 @@ mod tests;
 
 use std::{collections::HashMap, sync::Arc};
 
+use crate::XlsxError;
+
+// The maximum number of unique strings in the shared string table. The string
+// indices are stored as unsigned 32-bit values but some applications read them
+// as signed values so the limit is the maximum signed value.
+pub(crate) const MAX_UNIQUE_STRINGS: u32 = i32::MAX as u32;
+
 //
 // A metadata struct to store Excel unique strings between worksheets.
 //
@@ -35,14 +42,27 @@ impl SharedStringsTable {
         }
     }
 
-    // Get the index of the string in the Shared String table.
-    pub(crate) fn shared_string_index(&mut self, key: Arc<str>) -> u32 {
-        let index = *self.strings.entry(key).or_insert_with(|| {
-            let index = self.unique_count;
-            self.unique_count += 1;
-            index
-        });
-        self.count += 1;
-        index
+    // Get the index of the string in the Shared String table. Returns an error
+    // if a new string would exceed the unique string limit.
+    pub(crate) fn shared_string_index(&mut self, key: Arc<str>) -> Result<u32, XlsxError> {
+        let index = match self.strings.get(&key) {
+            Some(index) => *index,
+            None => {
+                if self.unique_count >= MAX_UNIQUE_STRINGS {
+                    return Err(XlsxError::MaxUniqueStringsExceeded);
+                }
+
+                let index = self.unique_count;
+                self.strings.insert(key, index);
+                self.unique_count += 1;
+                index
+            }
+        };
+
+        // The total count is only used as metadata so it doesn't need to be
+        // exact for very large workbooks.
+        self.count = self.count.saturating_add(1);
+
+        Ok(index)
     }
 }
@@ -7,32 +7,49 @@
 #[cfg(test)]
 mod shared_strings_table_tests {
 
-    use crate::shared_strings_table::SharedStringsTable;
+    use crate::shared_strings_table::{SharedStringsTable, MAX_UNIQUE_STRINGS};
+    use crate::XlsxError;
 
     #[test]
     fn test_shared_string_table() {
         let mut string_table = SharedStringsTable::new();
 
-        let index = string_table.shared_string_index("neptune".into());
+        let index = string_table.shared_string_index("neptune".into()).unwrap();
         assert_eq!(index, 0);
 
-        let index = string_table.shared_string_index("neptune".into());
+        let index = string_table.shared_string_index("neptune".into()).unwrap();
         assert_eq!(index, 0);
 
-        let index = string_table.shared_string_index("neptune".into());
+        let index = string_table.shared_string_index("neptune".into()).unwrap();
         assert_eq!(index, 0);
 
-        let index = string_table.shared_string_index("mars".into());
+        let index = string_table.shared_string_index("mars".into()).unwrap();
         assert_eq!(index, 1);
 
-        let index = string_table.shared_string_index("venus".into());
+        let index = string_table.shared_string_index("venus".into()).unwrap();
         assert_eq!(index, 2);
 
-        let index = string_table.shared_string_index("mars".into());
+        let index = string_table.shared_string_index("mars".into()).unwrap();
 
         assert_eq!(index, 1);
 
-        let index = string_table.shared_string_index("venus".into());
+        let index = string_table.shared_string_index("venus".into()).unwrap();
         assert_eq!(index, 2);
     }
+
+    #[test]
+    fn test_shared_string_table_limit() {
+        let mut string_table = SharedStringsTable::new();
+        string_table.unique_count = MAX_UNIQUE_STRINGS - 1;
+
+        let index = string_table.shared_string_index("mars".into()).unwrap();
+        assert_eq!(index, MAX_UNIQUE_STRINGS - 1);
+
+        // Existing strings can still be referenced.
+        let index = string_table.shared_string_index("mars".into()).unwrap();
+        assert_eq!(index, MAX_UNIQUE_STRINGS - 1);
+
+        let result = string_table.shared_string_index("venus".into());
+        assert!(matches!(result, Err(XlsxError::MaxUniqueStringsExceeded)));
+    }
 }
@@ -10,13 +10,14 @@ use crate::format::Format;
 use crate::xmlwriter::XMLWriter;
 use crate::{
     Alignment, Border, Color, Fill, Font, FormatAlign, FormatBorder, FormatDiagonalBorder,
-    FormatPattern, FormatScript, FormatUnderline,
+    FormatPattern, FormatScript, FormatUnderline, TableCustomStyle,
 };
 
 pub struct Styles<'a> {
     pub(crate) writer: XMLWriter,
     xf_formats: &'a Vec<Format>,
     dxf_formats: &'a Vec<Format>,
+    table_styles: &'a [TableCustomStyle],
     font_count: u16,
     fill_count: u16,
     border_count: u16,
@@ -37,6 +38,7 @@ impl<'a> Styles<'a> {
     pub(crate) fn new(
         xf_formats: &'a Vec<Format>,
         dxf_formats: &'a Vec<Format>,
+        table_styles: &'a [TableCustomStyle],
         font_count: u16,
         fill_count: u16,
         border_count: u16,
@@ -51,6 +53,7 @@ impl<'a> Styles<'a> {
             writer,
             xf_formats,
             dxf_formats,
+            table_styles,
             font_count,
             fill_count,
             border_count,
@@ -798,12 +801,47 @@ impl<'a> Styles<'a> {
     // Write the <tableStyles> element.
     fn write_table_styles(&mut self) {
         let attributes = [
-            ("count", "0"),
-            ("defaultTableStyle", "TableStyleMedium9"),
-            ("defaultPivotStyle", "PivotStyleLight16"),
+            ("count", self.table_styles.len().to_string()),
+            ("defaultTableStyle", "TableStyleMedium9".to_string()),
+            ("defaultPivotStyle", "PivotStyleLight16".to_string()),
         ];
 
-        self.writer.xml_empty_tag("tableStyles", &attributes);
+        if self.table_styles.is_empty() {
+            self.writer.xml_empty_tag("tableStyles", &attributes);
+        } else {
+            self.writer.xml_start_tag("tableStyles", &attributes);
+
+            for table_style in self.table_styles {
+                self.write_table_style(table_style);
+            }
+
+            self.writer.xml_end_tag("tableStyles");
+        }
+    }
+
+    // Write the <tableStyle> element.
+    fn write_table_style(&mut self, table_style: &TableCustomStyle) {
+        let elements = table_style.element_formats();
+
+        let attributes = [
+            ("name", table_style.name.clone()),
+            ("pivot", "0".to_string()),
+            ("count", elements.len().to_string()),
+        ];
+
+        self.writer.xml_start_tag("tableStyle", &attributes);
+
+        for (element_type, format) in elements {
+            // Write the <tableStyleElement> element.
+            let attributes = [
+                ("type", element_type.to_string()),
+                ("dxfId", format.dxf_index.to_string()),
+            ];
+
+            self.writer.xml_empty_tag("tableStyleElement", &attributes);
+        }
+
+        self.writer.xml_end_tag("tableStyle");
     }
 
     // Write the <numFmts> element.
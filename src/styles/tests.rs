@@ -23,6 +23,7 @@ mod styles_tests {
         let mut styles = Styles::new(
             &xf_formats,
             &dxf_formats,
+            &[],
             1,
             2,
             1,
@@ -84,4 +85,88 @@ mod styles_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_table_styles() {
+        let mut xf_format = Format::new();
+        xf_format.set_font_index(0, true);
+        xf_format.set_border_index(0, true);
+
+        let mut whole_table_format = Format::new()
+            .set_border(crate::FormatBorder::Thin)
+            .set_border_color("#1F3864");
+        whole_table_format.is_dxf_format = true;
+        whole_table_format.has_border = true;
+        whole_table_format.dxf_index = 1;
+
+        let mut stripe_format = Format::new().set_background_color("#D9E2F3");
+        stripe_format.is_dxf_format = true;
+        stripe_format.dxf_index = 0;
+
+        let table_style = crate::TableCustomStyle::new("BrandStyle")
+            .set_whole_table_format(&whole_table_format)
+            .set_first_row_stripe_format(&stripe_format);
+
+        let xf_formats = vec![xf_format];
+        let dxf_formats = vec![stripe_format, whole_table_format];
+        let table_styles = vec![table_style];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            &table_styles,
+            1,
+            2,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+
+        styles.write_dxfs();
+        styles.write_table_styles();
+
+        let got = styles.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <dxfs count="2">
+                  <dxf>
+                    <fill>
+                      <patternFill>
+                        <bgColor rgb="FFD9E2F3"/>
+                      </patternFill>
+                    </fill>
+                  </dxf>
+                  <dxf>
+                    <border>
+                      <left style="thin">
+                        <color rgb="FF1F3864"/>
+                      </left>
+                      <right style="thin">
+                        <color rgb="FF1F3864"/>
+                      </right>
+                      <top style="thin">
+                        <color rgb="FF1F3864"/>
+                      </top>
+                      <bottom style="thin">
+                        <color rgb="FF1F3864"/>
+                      </bottom>
+                      <vertical/>
+                      <horizontal/>
+                    </border>
+                  </dxf>
+                </dxfs>
+                <tableStyles count="1" defaultTableStyle="TableStyleMedium9" defaultPivotStyle="PivotStyleLight16">
+                  <tableStyle name="BrandStyle" pivot="0" count="2">
+                    <tableStyleElement type="wholeTable" dxfId="1"/>
+                    <tableStyleElement type="firstRowStripe" dxfId="0"/>
+                  </tableStyle>
+                </tableStyles>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -114,6 +114,8 @@ pub struct Table {
     pub(crate) index: u32,
     pub(crate) name: String,
     pub(crate) style: TableStyle,
+    pub(crate) custom_style: Option<TableCustomStyle>,
+    is_style_set: bool,
 
     pub(crate) cell_range: CellRange,
 
@@ -200,6 +202,8 @@ impl Table {
             index: 0,
             name: String::new(),
             style: TableStyle::Medium9,
+            custom_style: None,
+            is_style_set: false,
             cell_range: CellRange::default(),
             show_first_column: false,
             show_last_column: false,
@@ -1107,6 +1111,93 @@ impl Table {
     ///
     pub fn set_style(mut self, style: TableStyle) -> Table {
         self.style = style;
+        self.is_style_set = true;
+        self
+    }
+
+    /// Set a user defined custom style for a table.
+    ///
+    /// If the built-in Excel table styles don't match the required colors,
+    /// such as corporate brand colors, you can define a custom style using a
+    /// [`TableCustomStyle`] struct. The custom style is added to the workbook
+    /// styles and can be used by more than one table.
+    ///
+    /// A table can have a built-in style set via [`Table::set_style()`] or a
+    /// custom style but not both.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: a [`TableCustomStyle`] struct reference.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::TableError`] - This error is raised by
+    ///   [`Worksheet::add_table()`](crate::Worksheet::add_table) if the table
+    ///   has both a built-in and a custom style, or if the custom style name
+    ///   is blank.
+    ///
+    /// # Examples
+    ///
+    /// Example of setting a custom style for a worksheet table.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_table_set_custom_style.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, FormatBorder, Table, TableCustomStyle, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Some sample data for the table.
+    /// #     let items = ["Apples", "Pears", "Bananas", "Oranges"];
+    /// #     let data = [
+    /// #         [10000, 5000, 8000, 6000],
+    /// #         [2000, 3000, 4000, 5000],
+    /// #         [6000, 6000, 6500, 6000],
+    /// #         [500, 300, 200, 700],
+    /// #     ];
+    /// #
+    /// #     // Write the table data.
+    /// #     worksheet.write_column(3, 1, items)?;
+    /// #     worksheet.write_row_matrix(3, 2, data)?;
+    /// #
+    /// #     // Set the column widths for clarity.
+    /// #     worksheet.set_column_range_width(1, 6, 12)?;
+    /// #
+    ///     // Create a custom table style with brand colors.
+    ///     let style = TableCustomStyle::new("BrandStyle")
+    ///         .set_whole_table_format(
+    ///             Format::new()
+    ///                 .set_border(FormatBorder::Thin)
+    ///                 .set_border_color("#1F3864"),
+    ///         )
+    ///         .set_header_row_format(
+    ///             Format::new()
+    ///                 .set_bold()
+    ///                 .set_font_color(Color::White)
+    ///                 .set_background_color("#1F3864"),
+    ///         )
+    ///         .set_first_row_stripe_format(Format::new().set_background_color("#D9E2F3"));
+    ///
+    ///     // Create a new table and set the custom style.
+    ///     let table = Table::new().set_custom_style(&style);
+    ///
+    ///     // Add the table to the worksheet.
+    ///     worksheet.add_table(2, 1, 6, 5, &table)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("tables.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_custom_style(mut self, style: &TableCustomStyle) -> Table {
+        self.custom_style = Some(style.clone());
         self
     }
 
@@ -1161,6 +1252,29 @@ impl Table {
         Ok(())
     }
 
+    // Check that the table doesn't have conflicting style settings.
+    pub(crate) fn validate_style(&self) -> Result<(), XlsxError> {
+        let Some(custom_style) = &self.custom_style else {
+            return Ok(());
+        };
+
+        if self.is_style_set {
+            return Err(XlsxError::TableError(format!(
+                "Table at {} has both a built-in style and a custom style",
+                self.cell_range.to_error_string()
+            )));
+        }
+
+        if custom_style.name.trim().is_empty() {
+            return Err(XlsxError::TableError(format!(
+                "Custom style name for Table at {} cannot be blank",
+                self.cell_range.to_error_string()
+            )));
+        }
+
+        Ok(())
+    }
+
     // Get the first row that can be used to write data.
     pub(crate) fn first_data_row(&self) -> RowNum {
         if self.show_header_row {
@@ -1312,7 +1426,9 @@ impl Table {
     fn write_table_style_info(&mut self) {
         let mut attributes = vec![];
 
-        if self.style != TableStyle::None {
+        if let Some(custom_style) = &self.custom_style {
+            attributes.push(("name", custom_style.name.clone()));
+        } else if self.style != TableStyle::None {
             attributes.push(("name", self.style.to_string()));
         }
 
@@ -2340,6 +2456,195 @@ impl fmt::Display for TableStyle {
     }
 }
 
+/// The `TableCustomStyle` struct represents a user defined table style.
+///
+/// A custom table style can be used, via [`Table::set_custom_style()`], when
+/// none of the built-in [`TableStyle`] styles match the required colors.
+///
+/// The style is made up of [`Format`] objects for the elements of the table
+/// such as the header row or the row stripes (banding). Only the font, fill
+/// and border properties of the formats are used. Elements that aren't set
+/// aren't formatted.
+///
+/// Custom styles are stored in the workbook by name so tables in the same
+/// workbook that use the same style name must use the same style definition.
+///
+/// # Examples
+///
+/// Example of setting a custom style for a worksheet table.
+///
+/// ```
+/// # // This code is available in examples/doc_table_set_custom_style.rs
+/// #
+/// # use rust_xlsxwriter::{Color, Format, FormatBorder, Table, TableCustomStyle, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     // Create a new Excel file object.
+/// #     let mut workbook = Workbook::new();
+/// #
+/// #     // Add a worksheet to the workbook.
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+/// #     // Some sample data for the table.
+/// #     let items = ["Apples", "Pears", "Bananas", "Oranges"];
+/// #     let data = [
+/// #         [10000, 5000, 8000, 6000],
+/// #         [2000, 3000, 4000, 5000],
+/// #         [6000, 6000, 6500, 6000],
+/// #         [500, 300, 200, 700],
+/// #     ];
+/// #
+/// #     // Write the table data.
+/// #     worksheet.write_column(3, 1, items)?;
+/// #     worksheet.write_row_matrix(3, 2, data)?;
+/// #
+/// #     // Set the column widths for clarity.
+/// #     worksheet.set_column_range_width(1, 6, 12)?;
+/// #
+///     // Create a custom table style with brand colors.
+///     let style = TableCustomStyle::new("BrandStyle")
+///         .set_whole_table_format(
+///             Format::new()
+///                 .set_border(FormatBorder::Thin)
+///                 .set_border_color("#1F3864"),
+///         )
+///         .set_header_row_format(
+///             Format::new()
+///                 .set_bold()
+///                 .set_font_color(Color::White)
+///                 .set_background_color("#1F3864"),
+///         )
+///         .set_first_row_stripe_format(Format::new().set_background_color("#D9E2F3"));
+///
+///     // Create a new table and set the custom style.
+///     let table = Table::new().set_custom_style(&style);
+///
+///     // Add the table to the worksheet.
+///     worksheet.add_table(2, 1, 6, 5, &table)?;
+/// #
+/// #     // Save the file to disk.
+/// #     workbook.save("tables.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableCustomStyle {
+    pub(crate) name: String,
+    pub(crate) whole_table_format: Option<Format>,
+    pub(crate) header_row_format: Option<Format>,
+    pub(crate) total_row_format: Option<Format>,
+    pub(crate) first_row_stripe_format: Option<Format>,
+    pub(crate) second_row_stripe_format: Option<Format>,
+}
+
+impl TableCustomStyle {
+    /// Create a new `TableCustomStyle` struct instance.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the style. This is displayed in the "Custom"
+    ///   section of the Excel Table Styles gallery.
+    ///
+    pub fn new(name: impl Into<String>) -> TableCustomStyle {
+        TableCustomStyle {
+            name: name.into(),
+            whole_table_format: None,
+            header_row_format: None,
+            total_row_format: None,
+            first_row_stripe_format: None,
+            second_row_stripe_format: None,
+        }
+    }
+
+    /// Set the format for the whole table.
+    ///
+    /// This is generally used to set the borders of the table.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_whole_table_format(mut self, format: impl Into<Format>) -> TableCustomStyle {
+        self.whole_table_format = Some(format.into());
+        self
+    }
+
+    /// Set the format for the table header row.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_header_row_format(mut self, format: impl Into<Format>) -> TableCustomStyle {
+        self.header_row_format = Some(format.into());
+        self
+    }
+
+    /// Set the format for the table total row.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_total_row_format(mut self, format: impl Into<Format>) -> TableCustomStyle {
+        self.total_row_format = Some(format.into());
+        self
+    }
+
+    /// Set the format for the first row stripe of the banded rows.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_first_row_stripe_format(mut self, format: impl Into<Format>) -> TableCustomStyle {
+        self.first_row_stripe_format = Some(format.into());
+        self
+    }
+
+    /// Set the format for the second row stripe of the banded rows.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_second_row_stripe_format(mut self, format: impl Into<Format>) -> TableCustomStyle {
+        self.second_row_stripe_format = Some(format.into());
+        self
+    }
+
+    // Get mutable references to the element formats, in the order that Excel
+    // writes them, along with the `<tableStyleElement>` type name.
+    pub(crate) fn element_formats_mut(&mut self) -> Vec<(&'static str, &mut Format)> {
+        [
+            ("wholeTable", self.whole_table_format.as_mut()),
+            ("headerRow", self.header_row_format.as_mut()),
+            ("totalRow", self.total_row_format.as_mut()),
+            ("firstRowStripe", self.first_row_stripe_format.as_mut()),
+            ("secondRowStripe", self.second_row_stripe_format.as_mut()),
+        ]
+        .into_iter()
+        .filter_map(|(element, format)| format.map(|format| (element, format)))
+        .collect()
+    }
+
+    // Get the element formats, as above.
+    pub(crate) fn element_formats(&self) -> Vec<(&'static str, &Format)> {
+        [
+            ("wholeTable", self.whole_table_format.as_ref()),
+            ("headerRow", self.header_row_format.as_ref()),
+            ("totalRow", self.total_row_format.as_ref()),
+            ("firstRowStripe", self.first_row_stripe_format.as_ref()),
+            ("secondRowStripe", self.second_row_stripe_format.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(element, format)| format.map(|format| (element, format)))
+        .collect()
+    }
+}
+
 /// Convert a [`Table`] ref to a [`Table`] object.
 ///
 /// This is used as a syntactic shortcut for serialize APIs to allow either
@@ -74,6 +74,54 @@ mod table_tests {
         assert!(matches!(result, Err(XlsxError::TableError(_))));
     }
 
+    #[test]
+    fn test_custom_style_validation() {
+        let style = crate::TableCustomStyle::new("MyStyle");
+        let mut worksheet = Worksheet::new();
+
+        // A table can't have a built-in style and a custom style.
+        let table = Table::new()
+            .set_style(crate::TableStyle::Light1)
+            .set_custom_style(&style);
+        let result = worksheet.add_table(0, 0, 4, 3, &table);
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        // The custom style name can't be blank.
+        let table = Table::new().set_custom_style(&crate::TableCustomStyle::new(" "));
+        let result = worksheet.add_table(0, 0, 4, 3, &table);
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        let table = Table::new().set_custom_style(&style);
+        let result = worksheet.add_table(0, 0, 4, 3, &table);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_custom_style_definitions() {
+        let mut workbook = crate::Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        // Tables can share the same custom style.
+        let style = crate::TableCustomStyle::new("MyStyle")
+            .set_header_row_format(crate::Format::new().set_bold());
+        let table = Table::new().set_custom_style(&style);
+        worksheet.add_table(0, 0, 4, 3, &table).unwrap();
+        worksheet.add_table(0, 5, 4, 8, &table).unwrap();
+
+        assert!(workbook.save_to_buffer().is_ok());
+        assert_eq!(1, workbook.table_styles.len());
+
+        // But not different styles with the same name.
+        let style = crate::TableCustomStyle::new("mystyle")
+            .set_header_row_format(crate::Format::new().set_italic());
+        let table = Table::new().set_custom_style(&style);
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        worksheet.add_table(6, 0, 10, 3, &table).unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+    }
+
     #[test]
     fn test_assemble1() {
         let mut table = Table::new();
@@ -115,6 +163,49 @@ mod table_tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn test_assemble_custom_style() {
+        let style = crate::TableCustomStyle::new("MyStyle")
+            .set_header_row_format(crate::Format::new().set_bold());
+        let mut table = Table::new().set_custom_style(&style);
+        let worksheet = Worksheet::new();
+
+        table.cell_range.first_row = 0;
+        table.cell_range.first_col = 0;
+        table.cell_range.last_row = 4;
+        table.cell_range.last_col = 1;
+        table.index = 1;
+
+        let default_headers = worksheet.default_table_headers(
+            table.cell_range.first_row,
+            table.cell_range.first_col,
+            table.cell_range.last_col,
+            table.show_header_row,
+        );
+
+        table.initialize_columns(&default_headers).unwrap();
+        table.assemble_xml_file();
+
+        let got = table.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <table xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" id="1" name="Table1" displayName="Table1" ref="A1:B5" totalsRowShown="0">
+                <autoFilter ref="A1:B5"/>
+                <tableColumns count="2">
+                    <tableColumn id="1" name="Column1"/>
+                    <tableColumn id="2" name="Column2"/>
+                </tableColumns>
+                <tableStyleInfo name="MyStyle" showFirstColumn="0" showLastColumn="0" showRowStripes="1" showColumnStripes="0"/>
+                </table>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
     #[test]
     fn test_assemble2() {
         let mut table = Table::new().set_style(crate::TableStyle::Light17);
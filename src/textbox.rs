@@ -0,0 +1,380 @@
+// textbox - A module for creating the Excel worksheet text box shapes.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use crate::drawing::{DrawingObject, DrawingType};
+use crate::{Color, ObjectMovement};
+
+#[derive(Clone, Debug)]
+/// The `TextBoxOptions` struct is used to set the properties of a worksheet
+/// text box.
+///
+/// A text box is a rectangular drawing shape that contains text. Unlike a
+/// [`Note`](crate::Note) it isn't attached to a cell and is always visible.
+/// Text boxes are useful for adding annotations or explanatory text to a
+/// worksheet.
+///
+/// Text boxes are added to a worksheet using the
+/// [`Worksheet::insert_textbox()`](crate::Worksheet::insert_textbox) method.
+/// The `TextBoxOptions` struct is used to control the size, fill, border and
+/// font of the text box.
+///
+/// # Examples
+///
+/// The following example demonstrates inserting a text box into a worksheet.
+///
+/// ```
+/// # // This code is available in examples/doc_textbox_options.rs
+/// #
+/// use rust_xlsxwriter::{TextBoxOptions, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     // Create a new Excel file object.
+///     let mut workbook = Workbook::new();
+///
+///     // Add a worksheet to the workbook.
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Create some text box options.
+///     let options = TextBoxOptions::new()
+///         .set_width(256)
+///         .set_height(100)
+///         .set_fill_color("#FFFFC0")
+///         .set_border_color("#FF0000")
+///         .set_font_size(14)
+///         .set_font_bold();
+///
+///     // Insert a text box.
+///     worksheet.insert_textbox(1, 1, "This is some text\nin a text box", &options)?;
+///
+///     // Save the file to disk.
+///     workbook.save("textbox.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+pub struct TextBoxOptions {
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+    pub(crate) x_offset: u32,
+    pub(crate) y_offset: u32,
+    pub(crate) text: String,
+    pub(crate) name: String,
+    pub(crate) alt_text: String,
+    pub(crate) object_movement: ObjectMovement,
+    pub(crate) fill_color: Color,
+    pub(crate) border_color: Color,
+    pub(crate) border_width: f64,
+    pub(crate) border_none: bool,
+    pub(crate) font_name: String,
+    pub(crate) font_size: f64,
+    pub(crate) font_color: Color,
+    pub(crate) font_bold: bool,
+    pub(crate) font_italic: bool,
+}
+
+impl Default for TextBoxOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextBoxOptions {
+    // -----------------------------------------------------------------------
+    // Public (and crate public) methods.
+    // -----------------------------------------------------------------------
+
+    /// Create a new `TextBoxOptions` object to set the properties of a
+    /// worksheet text box.
+    ///
+    /// The default text box is 192 x 120 pixels, which is 3 default columns by
+    /// 6 default rows, with a white fill, a thin grey border and the default
+    /// 11 point theme font.
+    ///
+    pub fn new() -> TextBoxOptions {
+        TextBoxOptions {
+            width: 192.0,
+            height: 120.0,
+            x_offset: 0,
+            y_offset: 0,
+            text: String::new(),
+            name: String::new(),
+            alt_text: String::new(),
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            fill_color: Color::Default,
+            border_color: Color::Default,
+            border_width: 0.75,
+            border_none: false,
+            font_name: String::new(),
+            font_size: 11.0,
+            font_color: Color::Default,
+            font_bold: false,
+            font_italic: false,
+        }
+    }
+
+    /// Set the width of the text box in pixels.
+    ///
+    /// The default width of a text box is 192 pixels.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The text box width in pixels.
+    ///
+    pub fn set_width(mut self, width: u32) -> TextBoxOptions {
+        if width == 0 {
+            return self;
+        }
+
+        self.width = f64::from(width);
+        self
+    }
+
+    /// Set the height of the text box in pixels.
+    ///
+    /// The default height of a text box is 120 pixels.
+    ///
+    /// # Parameters
+    ///
+    /// - `height`: The text box height in pixels.
+    ///
+    pub fn set_height(mut self, height: u32) -> TextBoxOptions {
+        if height == 0 {
+            return self;
+        }
+
+        self.height = f64::from(height);
+        self
+    }
+
+    /// Set the horizontal offset of the text box within the anchor cell.
+    ///
+    /// # Parameters
+    ///
+    /// - `offset`: The horizontal offset within the cell in pixels.
+    ///
+    pub fn set_x_offset(mut self, offset: u32) -> TextBoxOptions {
+        self.x_offset = offset;
+        self
+    }
+
+    /// Set the vertical offset of the text box within the anchor cell.
+    ///
+    /// # Parameters
+    ///
+    /// - `offset`: The vertical offset within the cell in pixels.
+    ///
+    pub fn set_y_offset(mut self, offset: u32) -> TextBoxOptions {
+        self.y_offset = offset;
+        self
+    }
+
+    /// Set the background fill color of the text box.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The fill color property defined by a [`Color`] enum value or
+    ///   a type that can convert [`Into`] a [`Color`].
+    ///
+    pub fn set_fill_color(mut self, color: impl Into<Color>) -> TextBoxOptions {
+        let color = color.into();
+        if color.is_valid() {
+            self.fill_color = color;
+        }
+
+        self
+    }
+
+    /// Set the border color of the text box.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The border color property defined by a [`Color`] enum value
+    ///   or a type that can convert [`Into`] a [`Color`].
+    ///
+    pub fn set_border_color(mut self, color: impl Into<Color>) -> TextBoxOptions {
+        let color = color.into();
+        if color.is_valid() {
+            self.border_color = color;
+        }
+
+        self
+    }
+
+    /// Set the border width of the text box.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The border width in points. The default is 0.75 points. The
+    ///   range is 0.0 to 1584.0 points.
+    ///
+    pub fn set_border_width(mut self, width: impl Into<f64>) -> TextBoxOptions {
+        let width = width.into();
+        if (0.0..=1584.0).contains(&width) {
+            self.border_width = width;
+        }
+
+        self
+    }
+
+    /// Turn off the border of the text box.
+    ///
+    pub fn set_border_none(mut self) -> TextBoxOptions {
+        self.border_none = true;
+        self
+    }
+
+    /// Set the font name for the text box.
+    ///
+    /// Excel can only display fonts that are installed on the system that it
+    /// is running on. Therefore it is generally best to use standard Excel
+    /// fonts. The default is the workbook theme font.
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name for the text box.
+    ///
+    pub fn set_font_name(mut self, font_name: impl Into<String>) -> TextBoxOptions {
+        self.font_name = font_name.into();
+        self
+    }
+
+    /// Set the font size for the text box.
+    ///
+    /// # Parameters
+    ///
+    /// - `font_size`: The font size in points. The default is 11.
+    ///
+    pub fn set_font_size(mut self, font_size: impl Into<f64>) -> TextBoxOptions {
+        let font_size = font_size.into();
+        if font_size > 0.0 {
+            self.font_size = font_size;
+        }
+
+        self
+    }
+
+    /// Set the font color for the text box.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The font color property defined by a [`Color`] enum value or
+    ///   a type that can convert [`Into`] a [`Color`].
+    ///
+    pub fn set_font_color(mut self, color: impl Into<Color>) -> TextBoxOptions {
+        let color = color.into();
+        if color.is_valid() {
+            self.font_color = color;
+        }
+
+        self
+    }
+
+    /// Set the font bold property for the text box.
+    ///
+    pub fn set_font_bold(mut self) -> TextBoxOptions {
+        self.font_bold = true;
+        self
+    }
+
+    /// Set the font italic property for the text box.
+    ///
+    pub fn set_font_italic(mut self) -> TextBoxOptions {
+        self.font_italic = true;
+        self
+    }
+
+    /// Set the name of the text box.
+    ///
+    /// Override the default name, "TextBox N", that Excel displays for the
+    /// object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the text box.
+    ///
+    pub fn set_name(mut self, name: impl Into<String>) -> TextBoxOptions {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the alt text for the text box to help accessibility.
+    ///
+    /// The alt text is used with screen readers to help people with visual
+    /// disabilities.
+    ///
+    /// # Parameters
+    ///
+    /// - `alt_text`: The alt text string to add to the text box.
+    ///
+    pub fn set_alt_text(mut self, alt_text: impl Into<String>) -> TextBoxOptions {
+        let alt_text = alt_text.into();
+        if alt_text.chars().count() > 255 {
+            eprintln!("Alternative text is greater than Excel's limit of 255 characters.");
+            return self;
+        }
+
+        self.alt_text = alt_text;
+        self
+    }
+
+    /// Set the object movement options for a worksheet text box.
+    ///
+    /// Set the option to define how a text box will behave in Excel if the
+    /// cells under the text box are moved, deleted, or have their size
+    /// changed. The default is [`ObjectMovement::MoveAndSizeWithCells`].
+    ///
+    /// # Parameters
+    ///
+    /// - `option`: An object positioning behavior defined by the
+    ///   [`ObjectMovement`] enum.
+    ///
+    pub fn set_object_movement(mut self, option: ObjectMovement) -> TextBoxOptions {
+        self.object_movement = option;
+        self
+    }
+}
+
+// Trait for objects that have a component stored in the drawing.xml file.
+impl DrawingObject for TextBoxOptions {
+    fn x_offset(&self) -> u32 {
+        self.x_offset
+    }
+
+    fn y_offset(&self) -> u32 {
+        self.y_offset
+    }
+
+    fn width_scaled(&self) -> f64 {
+        self.width
+    }
+
+    fn height_scaled(&self) -> f64 {
+        self.height
+    }
+
+    fn object_movement(&self) -> ObjectMovement {
+        self.object_movement
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn alt_text(&self) -> String {
+        self.alt_text.clone()
+    }
+
+    fn decorative(&self) -> bool {
+        false
+    }
+
+    fn drawing_type(&self) -> DrawingType {
+        DrawingType::TextBox
+    }
+}
@@ -4,45 +4,264 @@
 //
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
+#![warn(missing_docs)]
+
 mod tests;
 
-use crate::xmlwriter::XMLWriter;
+use crate::xmlwriter::{escape_attributes, XMLWriter};
+use crate::Color;
 
+/// The `Theme` struct represents the color and font scheme of a workbook.
+///
+/// Excel workbooks contain a theme that defines a palette of 12 colors and a
+/// pair of "major" (heading) and "minor" (body) fonts. The theme colors are
+/// used by any format or chart property that refers to a
+/// [`Color::Theme`] color, and the minor font is the font used for worksheet
+/// cells.
+///
+/// By default `rust_xlsxwriter` uses the Excel 2007 "Office" theme. The
+/// `Theme` struct can be used with [`Workbook::set_theme()`] to replace it with
+/// a custom palette and fonts or with one of the preset themes such as
+/// [`Theme::office_2013()`] or [`Theme::office_2023()`].
+///
+/// Note, changing the minor font of the theme doesn't change the default font
+/// of worksheet cells which is set separately in the workbook styles. Use
+/// [`Workbook::set_default_font()`] to change both.
+///
+/// [`Workbook::set_theme()`]: crate::Workbook::set_theme
+/// [`Workbook::set_default_font()`]: crate::Workbook::set_default_font
+///
+/// # Examples
+///
+/// The following example demonstrates setting a custom workbook theme with
+/// brand colors and fonts.
+///
+/// ```
+/// # // This code is available in examples/doc_theme_set_color.rs
+/// #
+/// # use rust_xlsxwriter::{Color, Format, Theme, ThemeColor, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///
+///     // Create a theme with custom accent colors and fonts.
+///     let theme = Theme::new()
+///         .set_name("Brand")
+///         .set_color(ThemeColor::Accent1, "1B5E20")
+///         .set_color(ThemeColor::Accent2, Color::RGB(0xF9A825))
+///         .set_major_font("Georgia")
+///         .set_minor_font("Verdana");
+///
+///     workbook.set_theme(&theme);
+///
+///     // Formats that use theme colors will pick up the custom palette.
+///     let format1 = Format::new().set_background_color(Color::Theme(4, 0));
+///     let format2 = Format::new().set_background_color(Color::Theme(5, 0));
+///
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.write_string_with_format(0, 0, "Accent 1", &format1)?;
+///     worksheet.write_string_with_format(1, 0, "Accent 2", &format2)?;
+///
+///     workbook.save("theme.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone)]
 pub struct Theme {
     pub(crate) writer: XMLWriter,
+    name: String,
+    colors: [Color; 12],
+    major_font: String,
+    minor_font: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Theme {
-    // Create a new Theme struct.
-    pub(crate) fn new() -> Theme {
+    // -----------------------------------------------------------------------
+    // Public (and crate public) methods.
+    // -----------------------------------------------------------------------
+
+    /// Create a new `Theme` object.
+    ///
+    /// Create a new theme based on the default Excel 2007 "Office" theme used
+    /// by `rust_xlsxwriter`. The colors and fonts can then be changed using
+    /// the `Theme` setter methods.
+    ///
+    pub fn new() -> Theme {
         let writer = XMLWriter::new();
 
-        Theme { writer }
+        Theme {
+            writer,
+            name: "Office Theme".to_string(),
+            colors: [
+                Color::RGB(0x000000),
+                Color::RGB(0xFFFFFF),
+                Color::RGB(0x1F497D),
+                Color::RGB(0xEEECE1),
+                Color::RGB(0x4F81BD),
+                Color::RGB(0xC0504D),
+                Color::RGB(0x9BBB59),
+                Color::RGB(0x8064A2),
+                Color::RGB(0x4BACC6),
+                Color::RGB(0xF79646),
+                Color::RGB(0x0000FF),
+                Color::RGB(0x800080),
+            ],
+            major_font: "Cambria".to_string(),
+            minor_font: "Calibri".to_string(),
+        }
+    }
+
+    /// Create a theme based on the Excel 2013-2022 "Office" theme.
+    ///
+    /// This preset uses the blue/orange/gray palette and the "Calibri Light"
+    /// and "Calibri" fonts used by Excel 2013 to Excel 2022.
+    ///
+    pub fn office_2013() -> Theme {
+        Theme::new()
+            .set_color(ThemeColor::Dark2, Color::RGB(0x44546A))
+            .set_color(ThemeColor::Light2, Color::RGB(0xE7E6E6))
+            .set_color(ThemeColor::Accent1, Color::RGB(0x4472C4))
+            .set_color(ThemeColor::Accent2, Color::RGB(0xED7D31))
+            .set_color(ThemeColor::Accent3, Color::RGB(0xA5A5A5))
+            .set_color(ThemeColor::Accent4, Color::RGB(0xFFC000))
+            .set_color(ThemeColor::Accent5, Color::RGB(0x5B9BD5))
+            .set_color(ThemeColor::Accent6, Color::RGB(0x70AD47))
+            .set_color(ThemeColor::Hyperlink, Color::RGB(0x0563C1))
+            .set_color(ThemeColor::FollowedHyperlink, Color::RGB(0x954F72))
+            .set_major_font("Calibri Light")
+            .set_minor_font("Calibri")
+    }
+
+    /// Create a theme based on the Excel 2023+ "Office" theme.
+    ///
+    /// This preset uses the palette and the "Aptos Display" and "Aptos
+    /// Narrow" fonts introduced in Excel 2023.
+    ///
+    pub fn office_2023() -> Theme {
+        Theme::new()
+            .set_color(ThemeColor::Dark2, Color::RGB(0x0E2841))
+            .set_color(ThemeColor::Light2, Color::RGB(0xE8E8E8))
+            .set_color(ThemeColor::Accent1, Color::RGB(0x156082))
+            .set_color(ThemeColor::Accent2, Color::RGB(0xE97132))
+            .set_color(ThemeColor::Accent3, Color::RGB(0x196B24))
+            .set_color(ThemeColor::Accent4, Color::RGB(0x0F9ED5))
+            .set_color(ThemeColor::Accent5, Color::RGB(0xA02B93))
+            .set_color(ThemeColor::Accent6, Color::RGB(0x4EA72E))
+            .set_color(ThemeColor::Hyperlink, Color::RGB(0x467886))
+            .set_color(ThemeColor::FollowedHyperlink, Color::RGB(0x96607D))
+            .set_major_font("Aptos Display")
+            .set_minor_font("Aptos Narrow")
+    }
+
+    /// Set the name of the theme.
+    ///
+    /// The theme name is displayed by Excel in the "Page Layout -> Themes"
+    /// dialog. The default is "Office Theme".
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The theme name.
+    ///
+    pub fn set_name(mut self, name: impl Into<String>) -> Theme {
+        let name = name.into();
+        if !name.is_empty() {
+            self.name = name;
+        }
+        self
     }
 
+    /// Set one of the 12 colors of the theme color scheme.
+    ///
+    /// The 10 colors displayed in the top row of the Excel theme palette map to
+    /// the [`ThemeColor`] variants as follows: `Light1`, `Dark1`, `Light2`,
+    /// `Dark2` and `Accent1` to `Accent6`. These are the colors referred to as
+    /// 0-9 in [`Color::Theme`]. The hyperlink colors aren't displayed in the
+    /// palette.
+    ///
+    /// # Parameters
+    ///
+    /// - `theme_color`: A [`ThemeColor`] enum value.
+    /// - `color`: The color property defined by a [`Color`] enum value or a
+    ///   type that can convert [`Into`] a [`Color`]. Only RGB colors are
+    ///   supported. Theme and default colors are ignored.
+    ///
+    pub fn set_color(mut self, theme_color: ThemeColor, color: impl Into<Color>) -> Theme {
+        let color = color.into();
+
+        if color.is_valid()
+            && !matches!(
+                color,
+                Color::Theme(_, _) | Color::Default | Color::Automatic
+            )
+        {
+            self.colors[theme_color as usize] = color;
+        }
+
+        self
+    }
+
+    /// Set the major, or heading, font of the theme.
+    ///
+    /// The major font is used in Excel for headings such as chart titles. The
+    /// default is "Cambria".
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name.
+    ///
+    pub fn set_major_font(mut self, font_name: impl Into<String>) -> Theme {
+        let font_name = font_name.into();
+        if !font_name.is_empty() {
+            self.major_font = font_name;
+        }
+        self
+    }
+
+    /// Set the minor, or body, font of the theme.
+    ///
+    /// The minor font is used in Excel for body text. The default is
+    /// "Calibri".
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name.
+    ///
+    pub fn set_minor_font(mut self, font_name: impl Into<String>) -> Theme {
+        let font_name = font_name.into();
+        if !font_name.is_empty() {
+            self.minor_font = font_name;
+        }
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
     // Assemble and write the XML file.
     #[allow(clippy::too_many_lines)]
     pub(crate) fn assemble_xml_file(&mut self) {
-        self.writer.write_theme(
+        let name = escape_attributes(&self.name);
+        let color_scheme = self.color_scheme();
+        let major_font = escape_attributes(&self.major_font);
+        let minor_font = escape_attributes(&self.minor_font);
+
+        self.writer.write_theme(&format!(
             "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
-             <a:theme xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" name=\"Office Theme\">\
+             <a:theme xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" name=\"{name}\">\
              <a:themeElements>\
-             <a:clrScheme name=\"Office\"><a:dk1>\
-             <a:sysClr val=\"windowText\" lastClr=\"000000\"/>\
-             </a:dk1><a:lt1>\
-             <a:sysClr val=\"window\" lastClr=\"FFFFFF\"/></a:lt1><a:dk2>\
-             <a:srgbClr val=\"1F497D\"/></a:dk2><a:lt2>\
-             <a:srgbClr val=\"EEECE1\"/></a:lt2><a:accent1>\
-             <a:srgbClr val=\"4F81BD\"/></a:accent1><a:accent2>\
-             <a:srgbClr val=\"C0504D\"/></a:accent2><a:accent3>\
-             <a:srgbClr val=\"9BBB59\"/></a:accent3><a:accent4>\
-             <a:srgbClr val=\"8064A2\"/></a:accent4><a:accent5>\
-             <a:srgbClr val=\"4BACC6\"/></a:accent5><a:accent6>\
-             <a:srgbClr val=\"F79646\"/></a:accent6><a:hlink>\
-             <a:srgbClr val=\"0000FF\"/></a:hlink><a:folHlink>\
-             <a:srgbClr val=\"800080\"/></a:folHlink></a:clrScheme>\
+             {color_scheme}\
              <a:fontScheme name=\"Office\"><a:majorFont>\
-             <a:latin typeface=\"Cambria\"/><a:ea typeface=\"\"/>\
+             <a:latin typeface=\"{major_font}\"/>\
+             <a:ea typeface=\"\"/>\
              <a:cs typeface=\"\"/>\
              <a:font script=\"Jpan\" typeface=\"ＭＳ Ｐゴシック\"/>\
              <a:font script=\"Hang\" typeface=\"맑은 고딕\"/>\
@@ -75,7 +294,7 @@ impl Theme {
              <a:font script=\"Uigh\" typeface=\"Microsoft Uighur\"/>\
              </a:majorFont>\
              <a:minorFont>\
-             <a:latin typeface=\"Calibri\"/>\
+             <a:latin typeface=\"{minor_font}\"/>\
              <a:ea typeface=\"\"/>\
              <a:cs typeface=\"\"/>\
              <a:font script=\"Jpan\" typeface=\"ＭＳ Ｐゴシック\"/>\
@@ -281,6 +500,76 @@ impl Theme {
              <a:objectDefaults/>\
              <a:extraClrSchemeLst/>\
              </a:theme>"
-        );
+        ));
     }
+
+    // Generate the <a:clrScheme> element. The first two colors are written as
+    // system colors when they have their default values, like Excel.
+    fn color_scheme(&self) -> String {
+        const NAMES: [&str; 12] = [
+            "dk1", "lt1", "dk2", "lt2", "accent1", "accent2", "accent3", "accent4", "accent5",
+            "accent6", "hlink", "folHlink",
+        ];
+
+        let mut scheme = String::from("<a:clrScheme name=\"Office\">");
+
+        for (i, name) in NAMES.iter().enumerate() {
+            let color = self.colors[i].rgb_hex_value();
+
+            let element = match (i, color.as_str()) {
+                (0, "000000") => "<a:sysClr val=\"windowText\" lastClr=\"000000\"/>".to_string(),
+                (1, "FFFFFF") => "<a:sysClr val=\"window\" lastClr=\"FFFFFF\"/>".to_string(),
+                _ => format!("<a:srgbClr val=\"{color}\"/>"),
+            };
+
+            scheme.push_str(&format!("<a:{name}>{element}</a:{name}>"));
+        }
+
+        scheme.push_str("</a:clrScheme>");
+        scheme
+    }
+}
+
+/// The `ThemeColor` enum defines the colors in a [`Theme`] color scheme.
+///
+/// These are used with [`Theme::set_color()`].
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThemeColor {
+    /// The "Dark 1" color. This is usually black and is used for cell text.
+    Dark1 = 0,
+
+    /// The "Light 1" color. This is usually white and is used for cell
+    /// backgrounds.
+    Light1,
+
+    /// The "Dark 2" color.
+    Dark2,
+
+    /// The "Light 2" color.
+    Light2,
+
+    /// The "Accent 1" color. This is the first color used in charts.
+    Accent1,
+
+    /// The "Accent 2" color.
+    Accent2,
+
+    /// The "Accent 3" color.
+    Accent3,
+
+    /// The "Accent 4" color.
+    Accent4,
+
+    /// The "Accent 5" color.
+    Accent5,
+
+    /// The "Accent 6" color.
+    Accent6,
+
+    /// The color used for hyperlinks.
+    Hyperlink,
+
+    /// The color used for followed/visited hyperlinks.
+    FollowedHyperlink,
 }
@@ -8,7 +8,8 @@
 mod theme_tests {
 
     use crate::test_functions::xml_to_vec;
-    use crate::theme::Theme;
+    use crate::theme::{Theme, ThemeColor};
+    use crate::Color;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -282,4 +283,43 @@ mod theme_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_custom_theme() {
+        let mut theme = Theme::new()
+            .set_name("Brand & Co")
+            .set_color(ThemeColor::Dark1, Color::RGB(0x101010))
+            .set_color(ThemeColor::Accent1, Color::RGB(0x1B5E20))
+            .set_color(ThemeColor::Accent6, Color::Red)
+            .set_color(ThemeColor::Accent2, Color::Theme(1, 1)) // Ignored.
+            .set_major_font("Georgia")
+            .set_minor_font("Verdana");
+
+        theme.assemble_xml_file();
+
+        let got = theme.writer.read_to_str();
+
+        assert!(got.contains(r#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Brand &amp; Co">"#));
+        assert!(got.contains(r#"<a:clrScheme name="Office"><a:dk1><a:srgbClr val="101010"/></a:dk1><a:lt1><a:sysClr val="window" lastClr="FFFFFF"/></a:lt1>"#));
+        assert!(got.contains(r#"<a:accent1><a:srgbClr val="1B5E20"/></a:accent1>"#));
+        assert!(got.contains(r#"<a:accent2><a:srgbClr val="C0504D"/></a:accent2>"#));
+        assert!(got.contains(r#"<a:accent6><a:srgbClr val="FF0000"/></a:accent6>"#));
+        assert!(got.contains(r#"<a:majorFont><a:latin typeface="Georgia"/>"#));
+        assert!(got.contains(r#"<a:minorFont><a:latin typeface="Verdana"/>"#));
+    }
+
+    #[test]
+    fn test_preset_theme() {
+        let mut theme = Theme::office_2013();
+
+        theme.assemble_xml_file();
+
+        let got = theme.writer.read_to_str();
+
+        assert!(got.contains(r#"<a:dk2><a:srgbClr val="44546A"/></a:dk2>"#));
+        assert!(got.contains(r#"<a:accent1><a:srgbClr val="4472C4"/></a:accent1>"#));
+        assert!(got.contains(r#"<a:folHlink><a:srgbClr val="954F72"/></a:folHlink>"#));
+        assert!(got.contains(r#"<a:majorFont><a:latin typeface="Calibri Light"/>"#));
+        assert!(got.contains(r#"<a:minorFont><a:latin typeface="Calibri"/>"#));
+    }
 }
@@ -0,0 +1,431 @@
+// threaded_comment - A module for creating the Excel threaded comment and
+// persons files.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::collections::BTreeMap;
+
+use crate::{utility, xmlwriter::XMLWriter, ColNum, IntoCustomDateTimeUtc, RowNum};
+
+/// The `PersonId` struct is a reference to a person in the workbook persons
+/// registry.
+///
+/// A `PersonId` is returned by
+/// [`Workbook::add_person()`](crate::Workbook::add_person) and is used to set
+/// the author of a [`ThreadedComment`] or of one of its replies. The same
+/// `PersonId` can be used for any number of comments and replies in any
+/// worksheet of the workbook that created it.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonId(pub(crate) usize);
+
+/// The `ThreadedComment` struct represents a worksheet threaded comment.
+///
+/// A threaded comment is the newer style of Excel cell comment that supports
+/// a conversation of replies between several authors. This is different from
+/// the older style [`Note`](crate::Note). See the Microsoft docs on [The
+/// difference between threaded comments and notes].
+///
+/// [The difference between threaded comments and notes]:
+///     https://support.microsoft.com/en-us/office/the-difference-between-threaded-comments-and-notes-75a51eec-4092-42ab-abf8-7669077b7be3
+///
+/// The authors of the comment and replies are people added to the workbook
+/// persons registry via
+/// [`Workbook::add_person()`](crate::Workbook::add_person). Threaded comments
+/// are added to a worksheet cell using the
+/// [`Worksheet::insert_threaded_comment()`](crate::Worksheet::insert_threaded_comment)
+/// method.
+///
+/// For compatibility with older versions of Excel each threaded comment is
+/// also stored as a legacy note that contains the text of the comment and its
+/// replies.
+///
+/// # Examples
+///
+/// The following example demonstrates adding a threaded comment, with a
+/// reply, to a worksheet cell.
+///
+/// ```
+/// # // This code is available in examples/doc_threaded_comment.rs
+/// #
+/// use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     // Create a new Excel file object.
+///     let mut workbook = Workbook::new();
+///
+///     // Add the comment authors to the workbook persons registry.
+///     let jane = workbook.add_person("Jane Smith", "jane@example.com");
+///     let john = workbook.add_person("John Smith", "john@example.com");
+///
+///     // Create a threaded comment with a reply.
+///     let comment = ThreadedComment::new("Should this be higher?")
+///         .set_author(jane)
+///         .add_reply(john, "No, it is correct.");
+///
+///     // Add the threaded comment to a worksheet cell.
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.write_number(0, 0, 1234)?;
+///     worksheet.insert_threaded_comment(0, 0, &comment)?;
+///
+///     // Save the file to disk.
+///     workbook.save("threaded_comments.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct ThreadedComment {
+    pub(crate) text: String,
+    pub(crate) author: Option<PersonId>,
+    pub(crate) datetime: Option<String>,
+    pub(crate) is_resolved: bool,
+    pub(crate) replies: Vec<ThreadedCommentReply>,
+}
+
+// A reply to a threaded comment.
+#[derive(Clone, Debug)]
+pub(crate) struct ThreadedCommentReply {
+    pub(crate) text: String,
+    pub(crate) author: PersonId,
+}
+
+impl ThreadedComment {
+    // -----------------------------------------------------------------------
+    // Public (and crate public) methods.
+    // -----------------------------------------------------------------------
+
+    /// Create a new `ThreadedComment` object to represent an Excel threaded
+    /// comment.
+    ///
+    /// The comment must also have an author set via
+    /// [`ThreadedComment::set_author()`] before it is inserted into a
+    /// worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The text of the comment.
+    ///
+    pub fn new(text: impl Into<String>) -> ThreadedComment {
+        ThreadedComment {
+            text: text.into(),
+            author: None,
+            datetime: None,
+            is_resolved: false,
+            replies: vec![],
+        }
+    }
+
+    /// Set the author of the threaded comment.
+    ///
+    /// # Parameters
+    ///
+    /// - `author`: A [`PersonId`] returned by
+    ///   [`Workbook::add_person()`](crate::Workbook::add_person).
+    ///
+    pub fn set_author(mut self, author: PersonId) -> ThreadedComment {
+        self.author = Some(author);
+        self
+    }
+
+    /// Add a reply to the threaded comment.
+    ///
+    /// Replies are displayed in the order that they are added.
+    ///
+    /// # Parameters
+    ///
+    /// - `author`: A [`PersonId`] returned by
+    ///   [`Workbook::add_person()`](crate::Workbook::add_person).
+    /// - `text`: The text of the reply.
+    ///
+    pub fn add_reply(mut self, author: PersonId, text: impl Into<String>) -> ThreadedComment {
+        self.replies.push(ThreadedCommentReply {
+            text: text.into(),
+            author,
+        });
+        self
+    }
+
+    /// Mark the threaded comment thread as resolved.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_resolved(mut self, enable: bool) -> ThreadedComment {
+        self.is_resolved = enable;
+        self
+    }
+
+    /// Set the date and time of the threaded comment and its replies.
+    ///
+    /// The default is the workbook creation date, see
+    /// [`DocProperties::set_creation_datetime()`](crate::DocProperties::set_creation_datetime).
+    ///
+    /// # Parameters
+    ///
+    /// - `datetime`: The comment date and time. A type that implements
+    ///   [`IntoCustomDateTimeUtc`].
+    ///
+    pub fn set_datetime(mut self, datetime: impl IntoCustomDateTimeUtc) -> ThreadedComment {
+        self.datetime = Some(datetime.utc_datetime());
+        self
+    }
+
+    // Get the text of the legacy note that older versions of Excel display in
+    // place of the threaded comment. This is the same text that Excel uses.
+    pub(crate) fn legacy_note_text(&self) -> String {
+        let mut text = String::from(
+            "[Threaded comment]\n\nYour version of Excel allows you to read this \
+             threaded comment; however, any edits to it will get removed if the \
+             file is opened in a newer version of Excel. Learn more: \
+             https://go.microsoft.com/fwlink/?linkid=870924\n\nComment:\n    ",
+        );
+        text.push_str(&self.text);
+
+        for reply in &self.replies {
+            text.push_str("\nReply:\n    ");
+            text.push_str(&reply.text);
+        }
+
+        text
+    }
+
+    // Create a stable pseudo GUID for a threaded comment, or one of its
+    // replies, based on its position in the workbook.
+    pub(crate) fn guid(sheet_index: usize, row: RowNum, col: ColNum, reply_index: usize) -> String {
+        format!(
+            "{{C0A1E175-{:04X}-{:04X}-{:04X}-{:012X}}}",
+            sheet_index + 1,
+            reply_index,
+            col,
+            row
+        )
+    }
+}
+
+// A struct to represent a person in the workbook persons registry.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Person {
+    pub(crate) display_name: String,
+    pub(crate) user_id: String,
+}
+
+impl Person {
+    // Create a stable pseudo GUID for a person based on their index in the
+    // persons registry.
+    pub(crate) fn guid(person_index: usize) -> String {
+        format!("{{7E4500A0-AAAA-BBBB-CCCC-{:012X}}}", person_index + 1)
+    }
+}
+
+/// A struct to represent a threadedCommentN.xml file.
+///
+pub(crate) struct ThreadedComments {
+    pub(crate) writer: XMLWriter,
+    pub(crate) comments: BTreeMap<(RowNum, ColNum), ThreadedComment>,
+    pub(crate) sheet_index: usize,
+    pub(crate) default_datetime: String,
+}
+
+impl ThreadedComments {
+    // -----------------------------------------------------------------------
+    // Public (and crate public) methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new ThreadedComments struct.
+    pub(crate) fn new() -> ThreadedComments {
+        let writer = XMLWriter::new();
+
+        ThreadedComments {
+            writer,
+            comments: BTreeMap::new(),
+            sheet_index: 0,
+            default_datetime: String::new(),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    //  Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the ThreadedComments element.
+        self.write_threaded_comments();
+
+        for ((row, col), comment) in &self.comments.clone() {
+            let datetime = match &comment.datetime {
+                Some(datetime) => Self::comment_datetime(datetime),
+                None => Self::comment_datetime(&self.default_datetime),
+            };
+            let id = ThreadedComment::guid(self.sheet_index, *row, *col, 0);
+
+            // Write the threadedComment element for the parent comment.
+            self.write_threaded_comment(
+                *row,
+                *col,
+                &datetime,
+                comment.author.unwrap_or(PersonId(0)),
+                &id,
+                None,
+                comment.is_resolved,
+                &comment.text,
+            );
+
+            // Write the threadedComment elements for the replies.
+            for (index, reply) in comment.replies.iter().enumerate() {
+                let reply_id = ThreadedComment::guid(self.sheet_index, *row, *col, index + 1);
+
+                self.write_threaded_comment(
+                    *row,
+                    *col,
+                    &datetime,
+                    reply.author,
+                    &reply_id,
+                    Some(&id),
+                    false,
+                    &reply.text,
+                );
+            }
+        }
+
+        // Close the ThreadedComments tag.
+        self.writer.xml_end_tag("ThreadedComments");
+    }
+
+    // Write the <ThreadedComments> element.
+    fn write_threaded_comments(&mut self) {
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+            ),
+            (
+                "xmlns:x",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+        ];
+
+        self.writer.xml_start_tag("ThreadedComments", &attributes);
+    }
+
+    // Write the <threadedComment> element.
+    #[allow(clippy::too_many_arguments)]
+    fn write_threaded_comment(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        datetime: &str,
+        author: PersonId,
+        id: &str,
+        parent_id: Option<&str>,
+        is_resolved: bool,
+        text: &str,
+    ) {
+        let mut attributes = vec![
+            ("ref", utility::row_col_to_cell(row, col)),
+            ("dT", datetime.to_string()),
+            ("personId", Person::guid(author.0)),
+            ("id", id.to_string()),
+        ];
+
+        if let Some(parent_id) = parent_id {
+            attributes.push(("parentId", parent_id.to_string()));
+        }
+
+        if is_resolved {
+            attributes.push(("done", "1".to_string()));
+        }
+
+        self.writer.xml_start_tag("threadedComment", &attributes);
+        self.writer.xml_data_element_only("text", text);
+        self.writer.xml_end_tag("threadedComment");
+    }
+
+    // Convert an RFC 3339 UTC date like "2024-01-01T12:00:00Z" to the Excel
+    // threaded comment date format like "2024-01-01T12:00:00.00".
+    fn comment_datetime(datetime: &str) -> String {
+        format!("{}.00", datetime.trim_end_matches('Z'))
+    }
+}
+
+/// A struct to represent the persons/person.xml file.
+///
+pub(crate) struct PersonList {
+    pub(crate) writer: XMLWriter,
+    pub(crate) persons: Vec<Person>,
+}
+
+impl PersonList {
+    // -----------------------------------------------------------------------
+    // Public (and crate public) methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new PersonList struct.
+    pub(crate) fn new() -> PersonList {
+        let writer = XMLWriter::new();
+
+        PersonList {
+            writer,
+            persons: vec![],
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    //  Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the personList element.
+        self.write_person_list();
+
+        for (index, person) in self.persons.clone().iter().enumerate() {
+            // Write the person element.
+            self.write_person(index, person);
+        }
+
+        // Close the personList tag.
+        self.writer.xml_end_tag("personList");
+    }
+
+    // Write the <personList> element.
+    fn write_person_list(&mut self) {
+        let attributes = [
+            (
+                "xmlns",
+                "http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments",
+            ),
+            (
+                "xmlns:x",
+                "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+            ),
+        ];
+
+        self.writer.xml_start_tag("personList", &attributes);
+    }
+
+    // Write the <person> element.
+    fn write_person(&mut self, index: usize, person: &Person) {
+        let attributes = [
+            ("displayName", person.display_name.clone()),
+            ("id", Person::guid(index)),
+            ("userId", person.user_id.clone()),
+            ("providerId", "None".to_string()),
+        ];
+
+        self.writer.xml_empty_tag("person", &attributes);
+    }
+}
@@ -0,0 +1,89 @@
+// Threaded comment unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod threaded_comment_tests {
+
+    use crate::test_functions::xml_to_vec;
+    use crate::threaded_comment::{Person, PersonList, ThreadedComments};
+    use crate::{ExcelDateTime, PersonId, ThreadedComment};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble_threaded_comments() {
+        let mut threaded_comments = ThreadedComments::new();
+        threaded_comments.default_datetime = "2024-01-01T12:00:00Z".to_string();
+
+        let comment = ThreadedComment::new("Is this right?")
+            .set_author(PersonId(0))
+            .add_reply(PersonId(1), "Yes")
+            .set_resolved(true);
+        threaded_comments.comments.insert((0, 0), comment);
+
+        let datetime = ExcelDateTime::parse_from_str("2024-02-03T04:05:06").unwrap();
+        let comment = ThreadedComment::new("Check this")
+            .set_author(PersonId(1))
+            .set_datetime(&datetime);
+        threaded_comments.comments.insert((9, 2), comment);
+
+        threaded_comments.assemble_xml_file();
+
+        let got = threaded_comments.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <ThreadedComments xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments" xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+              <threadedComment ref="A1" dT="2024-01-01T12:00:00.00" personId="{7E4500A0-AAAA-BBBB-CCCC-000000000001}" id="{C0A1E175-0001-0000-0000-000000000000}" done="1">
+                <text>Is this right?</text>
+              </threadedComment>
+              <threadedComment ref="A1" dT="2024-01-01T12:00:00.00" personId="{7E4500A0-AAAA-BBBB-CCCC-000000000002}" id="{C0A1E175-0001-0001-0000-000000000000}" parentId="{C0A1E175-0001-0000-0000-000000000000}">
+                <text>Yes</text>
+              </threadedComment>
+              <threadedComment ref="C10" dT="2024-02-03T04:05:06.00" personId="{7E4500A0-AAAA-BBBB-CCCC-000000000002}" id="{C0A1E175-0001-0000-0002-000000000009}">
+                <text>Check this</text>
+              </threadedComment>
+            </ThreadedComments>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_person_list() {
+        let mut person_list = PersonList::new();
+
+        person_list.persons = vec![
+            Person {
+                display_name: "Jane Smith".to_string(),
+                user_id: "jane@example.com".to_string(),
+            },
+            Person {
+                display_name: "John".to_string(),
+                user_id: "John".to_string(),
+            },
+        ];
+
+        person_list.assemble_xml_file();
+
+        let got = person_list.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <personList xmlns="http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments" xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+              <person displayName="Jane Smith" id="{7E4500A0-AAAA-BBBB-CCCC-000000000001}" userId="jane@example.com" providerId="None"/>
+              <person displayName="John" id="{7E4500A0-AAAA-BBBB-CCCC-000000000002}" userId="John" providerId="None"/>
+            </personList>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+}
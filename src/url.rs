@@ -353,17 +353,6 @@ impl Url {
         }
     }
 
-    // Increment the relationship id for some types only.
-    pub(crate) fn increment_rel_id(&mut self, rel_id: u32) -> u32 {
-        match self.link_type {
-            HyperlinkType::Url | HyperlinkType::File => {
-                self.rel_id = rel_id;
-                rel_id + 1
-            }
-            _ => rel_id,
-        }
-    }
-
     // Get the target for relationship ids.
     pub(crate) fn target(&mut self) -> String {
         let mut target = self.rel_link.clone();
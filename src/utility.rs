@@ -27,6 +27,8 @@
 #![warn(missing_docs)]
 mod tests;
 
+#[cfg(feature = "serde")]
+use crate::serializer::DATETIME_NEWTYPE_NAME;
 #[cfg(feature = "serde")]
 use crate::IntoExcelDateTime;
 use crate::COL_MAX;
@@ -234,6 +236,229 @@ pub fn cell_range_absolute(
     }
 }
 
+/// Convert an `A1` style cell reference string to zero indexed row and column
+/// cell numbers.
+///
+/// Utility function to convert an `A1` or absolute `$A$1` cell reference to
+/// zero indexed row and column numbers. This is the inverse of
+/// [`row_col_to_cell()`] and [`row_col_to_cell_absolute()`]. The column
+/// letters can be upper or lowercase.
+///
+/// # Parameters
+///
+/// - `cell`: An `A1` style cell reference string.
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The string isn't a valid `A1` style cell
+///   reference.
+/// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+///   worksheet limits.
+///
+/// # Examples:
+///
+/// ```
+/// # use rust_xlsxwriter::{cell_to_row_col, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// assert_eq!(cell_to_row_col("A1")?, (0, 0));
+/// assert_eq!(cell_to_row_col("B5")?, (4, 1));
+/// assert_eq!(cell_to_row_col("$AA$10")?, (9, 26));
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+pub fn cell_to_row_col(cell: &str) -> Result<(RowNum, ColNum), XlsxError> {
+    let error = || XlsxError::ParameterError(format!("Invalid A1 style cell reference '{cell}'"));
+
+    let reference = cell.strip_prefix('$').unwrap_or(cell);
+    let letters = reference
+        .chars()
+        .take_while(char::is_ascii_alphabetic)
+        .count();
+    let (col_name, digits) = reference.split_at(letters);
+    let digits = digits.strip_prefix('$').unwrap_or(digits);
+
+    if letters == 0 || digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(error());
+    }
+
+    // Column names longer than "XFD" are outside the worksheet.
+    if letters > 3 {
+        return Err(XlsxError::RowColumnLimitError);
+    }
+
+    let col_num = column_name_to_number(&col_name.to_ascii_uppercase());
+    let row_num = digits
+        .parse::<u64>()
+        .map_err(|_| XlsxError::RowColumnLimitError)?;
+
+    if row_num == 0 {
+        return Err(error());
+    }
+
+    if row_num > u64::from(ROW_MAX) || col_num >= COL_MAX {
+        return Err(XlsxError::RowColumnLimitError);
+    }
+
+    Ok(((row_num - 1) as RowNum, col_num))
+}
+
+/// Convert an `A1:B1` style range string to zero indexed row and column cell
+/// numbers.
+///
+/// Utility function to convert an `A1:B1` style range, with or without `$`
+/// absolute anchors, to zero indexed `(first_row, first_col, last_row,
+/// last_col)` values. This is the inverse of [`cell_range()`]. A single cell
+/// like `A1` is returned as a range with the same first and last cell.
+///
+/// # Parameters
+///
+/// - `range`: An `A1:B1` or `A1` style range string.
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The string isn't a valid `A1` style cell
+///   range.
+/// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+///   worksheet limits.
+///
+/// # Examples:
+///
+/// ```
+/// # use rust_xlsxwriter::{cell_range_to_row_col, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// assert_eq!(cell_range_to_row_col("A1:A10")?, (0, 0, 9, 0));
+/// assert_eq!(cell_range_to_row_col("$C$2:$C$9")?, (1, 2, 8, 2));
+/// assert_eq!(cell_range_to_row_col("A1")?, (0, 0, 0, 0));
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+pub fn cell_range_to_row_col(range: &str) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
+    let (first_cell, last_cell) = range.split_once(':').unwrap_or((range, range));
+
+    let (first_row, first_col) = cell_to_row_col(first_cell)?;
+    let (last_row, last_col) = cell_to_row_col(last_cell)?;
+
+    Ok((first_row, first_col, last_row, last_col))
+}
+
+/// Convert zero indexed row and column cell numbers to a `R1C1` style string.
+///
+/// Utility function to convert zero indexed row and column cell values to an
+/// `R1C1` style cell reference, where the row and column numbers are one
+/// indexed. In `R1C1` notation this is an absolute reference.
+///
+/// # Examples:
+///
+/// ```
+/// use rust_xlsxwriter::row_col_to_r1c1;
+///
+/// assert_eq!(row_col_to_r1c1(0, 0), "R1C1");
+/// assert_eq!(row_col_to_r1c1(4, 1), "R5C2");
+/// ```
+///
+pub fn row_col_to_r1c1(row_num: RowNum, col_num: ColNum) -> String {
+    format!("R{}C{}", u64::from(row_num) + 1, u32::from(col_num) + 1)
+}
+
+/// Convert zero indexed row and col cell numbers to a `R1C1:R1C2` style range
+/// string.
+///
+/// Utility function to convert zero based row and column cell values to an
+/// `R1C1:R1C2` style range reference. If the start and end cell are the same
+/// then a single cell reference is created.
+///
+/// # Examples:
+///
+/// ```
+/// use rust_xlsxwriter::cell_range_r1c1;
+///
+/// assert_eq!(cell_range_r1c1(0, 0, 9, 0), "R1C1:R10C1");
+/// assert_eq!(cell_range_r1c1(0, 0, 0, 0), "R1C1");
+/// ```
+///
+pub fn cell_range_r1c1(
+    first_row: RowNum,
+    first_col: ColNum,
+    last_row: RowNum,
+    last_col: ColNum,
+) -> String {
+    let range1 = row_col_to_r1c1(first_row, first_col);
+    let range2 = row_col_to_r1c1(last_row, last_col);
+
+    if range1 == range2 {
+        range1
+    } else {
+        format!("{range1}:{range2}")
+    }
+}
+
+/// Convert an `R1C1` style cell reference string to zero indexed row and
+/// column cell numbers.
+///
+/// Utility function to convert an absolute `R1C1` style cell reference, such
+/// as `R5C2`, to zero indexed row and column numbers. This is the inverse of
+/// [`row_col_to_r1c1()`]. Relative references like `R[1]C[-1]` depend on the
+/// cell that contains them and aren't supported.
+///
+/// # Parameters
+///
+/// - `cell`: An `R1C1` style cell reference string.
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The string isn't a valid absolute `R1C1`
+///   style cell reference.
+/// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+///   worksheet limits.
+///
+/// # Examples:
+///
+/// ```
+/// # use rust_xlsxwriter::{r1c1_to_row_col, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// assert_eq!(r1c1_to_row_col("R1C1")?, (0, 0));
+/// assert_eq!(r1c1_to_row_col("R5C2")?, (4, 1));
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+pub fn r1c1_to_row_col(cell: &str) -> Result<(RowNum, ColNum), XlsxError> {
+    let error = || XlsxError::ParameterError(format!("Invalid R1C1 style cell reference '{cell}'"));
+
+    let upper_cell = cell.to_ascii_uppercase();
+    let Some((row, col)) = upper_cell
+        .strip_prefix('R')
+        .and_then(|reference| reference.split_once('C'))
+    else {
+        return Err(error());
+    };
+
+    let parse_number = |number: &str, max: u64| -> Result<u64, XlsxError> {
+        if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err(error());
+        }
+
+        match number.parse::<u64>() {
+            Ok(0) => Err(error()),
+            Ok(number) if number <= max => Ok(number - 1),
+            _ => Err(XlsxError::RowColumnLimitError),
+        }
+    };
+
+    let row_num = parse_number(row, u64::from(ROW_MAX))?;
+    let col_num = parse_number(col, u64::from(COL_MAX))?;
+
+    Ok((row_num as RowNum, col_num as ColNum))
+}
+
 /// Serialize a Chrono naive date/time to an Excel value.
 ///
 /// This is a helper function for serializing [`Chrono`] naive date/time fields
@@ -296,7 +521,7 @@ pub fn serialize_chrono_naive_to_excel<S>(
 where
     S: Serializer,
 {
-    serializer.serialize_f64(datetime.to_excel_serial_date())
+    serializer.serialize_newtype_struct(DATETIME_NEWTYPE_NAME, &datetime.to_excel_serial_date())
 }
 
 /// Serialize an `Option` Chrono naive date/time to an Excel value.
@@ -368,7 +593,8 @@ where
     S: Serializer,
 {
     match datetime {
-        Some(datetime) => serializer.serialize_f64(datetime.to_excel_serial_date()),
+        Some(datetime) => serializer
+            .serialize_newtype_struct(DATETIME_NEWTYPE_NAME, &datetime.to_excel_serial_date()),
         None => serializer.serialize_none(),
     }
 }
@@ -744,23 +970,23 @@ pub(crate) fn validate_vba_name(name: &str) -> Result<(), XlsxError> {
 
     // Check that name is <= 31, an Excel limit.
     if name.chars().count() > 31 {
-        return Err(XlsxError::VbaNameError(
-            "VBA name exceeds Excel limit of 31 characters: {name}".to_string(),
-        ));
+        return Err(XlsxError::VbaNameError(format!(
+            "VBA name exceeds Excel limit of 31 characters: {name}"
+        )));
     }
 
     // Check for anything other than letters, numbers, and underscores.
     if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
-        return Err(XlsxError::VbaNameError(
-            "VBA name contains non-word character: {name}".to_string(),
-        ));
+        return Err(XlsxError::VbaNameError(format!(
+            "VBA name contains non-word character: {name}"
+        )));
     }
 
     // Check that the name starts with a letter.
     if !name.chars().next().unwrap().is_alphabetic() {
-        return Err(XlsxError::VbaNameError(
-            "VBA name must start with letter character: {name}".to_string(),
-        ));
+        return Err(XlsxError::VbaNameError(format!(
+            "VBA name must start with letter character: {name}"
+        )));
     }
 
     Ok(())
@@ -120,6 +120,83 @@ mod utility_tests {
         }
     }
 
+    #[test]
+    fn test_cell_to_row_col() {
+        let tests = vec![
+            ("A1", 0, 0),
+            ("B1", 0, 1),
+            ("A2", 1, 0),
+            ("b5", 4, 1),
+            ("$B$5", 4, 1),
+            ("$B5", 4, 1),
+            ("B$5", 4, 1),
+            ("AA10", 9, 26),
+            ("XFD1048576", 1_048_575, 16383),
+        ];
+
+        for (cell_string, row_num, col_num) in tests {
+            assert_eq!(
+                (row_num, col_num),
+                utility::cell_to_row_col(cell_string).unwrap()
+            );
+        }
+
+        for cell_string in ["", "A", "1", "A0", "1A", "A1B", "A-1", "$$A1", "R1C1:"] {
+            let result = utility::cell_to_row_col(cell_string);
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+
+        for cell_string in ["XFE1", "AAAA1", "A1048577", "A99999999999999999999"] {
+            let result = utility::cell_to_row_col(cell_string);
+            assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        }
+
+        let tests = vec![
+            ("A1:A10", (0, 0, 9, 0)),
+            ("$C$2:$C$9", (1, 2, 8, 2)),
+            ("A10:Y2", (9, 0, 1, 24)),
+            ("A1", (0, 0, 0, 0)),
+        ];
+
+        for (range_string, range) in tests {
+            assert_eq!(range, utility::cell_range_to_row_col(range_string).unwrap());
+        }
+
+        let result = utility::cell_range_to_row_col("A1:B");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_r1c1() {
+        let tests = vec![
+            (0, 0, "R1C1"),
+            (4, 1, "R5C2"),
+            (1_048_575, 16383, "R1048576C16384"),
+        ];
+
+        for (row_num, col_num, cell_string) in tests {
+            assert_eq!(cell_string, utility::row_col_to_r1c1(row_num, col_num));
+            assert_eq!(
+                (row_num, col_num),
+                utility::r1c1_to_row_col(cell_string).unwrap()
+            );
+        }
+
+        assert_eq!((4, 1), utility::r1c1_to_row_col("r5c2").unwrap());
+        assert_eq!("R1C1:R10C1", utility::cell_range_r1c1(0, 0, 9, 0));
+        assert_eq!("R1C1", utility::cell_range_r1c1(0, 0, 0, 0));
+
+        for cell_string in ["", "R1", "C1", "R0C1", "R1C0", "R[1]C1", "RC", "R1C1C1"] {
+            let result = utility::r1c1_to_row_col(cell_string);
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+
+        for cell_string in ["R1048577C1", "R1C16385"] {
+            let result = utility::r1c1_to_row_col(cell_string);
+            assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        }
+    }
+
     #[test]
     fn test_cell_range() {
         let tests = vec![
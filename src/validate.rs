@@ -0,0 +1,354 @@
+// validate - a module for checking that the xml parts generated by
+// rust_xlsxwriter are well formed. This is only included with the `validate`
+// feature and is intended for catching escaping or encoding errors early.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use std::str;
+
+// Check if a file in the xlsx zip container contains xml data.
+pub(crate) fn is_xml_part(filename: &str) -> bool {
+    filename.ends_with(".xml") || filename.ends_with(".rels") || filename.ends_with(".vml")
+}
+
+// Check that an xml part is well formed. This is a lightweight check of the
+// subset of XML generated by rust_xlsxwriter: a declaration, elements,
+// attributes, character data and entity references. It checks the nesting of
+// the elements, the syntax of the names, attributes and entities and that the
+// data is valid UTF-8 without disallowed control characters. It returns a
+// description of the first error found.
+pub(crate) fn check_well_formed(data: &[u8]) -> Result<(), String> {
+    let xml = match str::from_utf8(data) {
+        Ok(xml) => xml,
+        Err(error) => return Err(format!("invalid UTF-8 at byte {}", error.valid_up_to())),
+    };
+
+    let mut parser = Parser {
+        xml,
+        position: 0,
+        stack: vec![],
+        has_root: false,
+    };
+
+    parser.parse()
+}
+
+// A minimal recursive descent parser that only checks, and doesn't store, the
+// xml structure.
+struct Parser<'a> {
+    xml: &'a str,
+    position: usize,
+    stack: Vec<&'a str>,
+    has_root: bool,
+}
+
+impl<'a> Parser<'a> {
+    // Parse the document and check the element structure.
+    fn parse(&mut self) -> Result<(), String> {
+        while self.position < self.xml.len() {
+            let rest = &self.xml[self.position..];
+
+            if rest.starts_with("<?") {
+                self.parse_processing_instruction()?;
+            } else if rest.starts_with("<!--") {
+                self.parse_comment()?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.parse_cdata()?;
+            } else if rest.starts_with("</") {
+                self.parse_end_tag()?;
+            } else if rest.starts_with('<') {
+                self.parse_start_tag()?;
+            } else {
+                self.parse_text()?;
+            }
+        }
+
+        if let Some(name) = self.stack.last() {
+            return Err(format!("element <{name}> isn't closed"));
+        }
+
+        if !self.has_root {
+            return Err("document has no root element".to_string());
+        }
+
+        Ok(())
+    }
+
+    // Parse a processing instruction such as the xml declaration.
+    fn parse_processing_instruction(&mut self) -> Result<(), String> {
+        if !self.stack.is_empty() || self.has_root {
+            return Err(self.error("processing instruction after the root element"));
+        }
+
+        match self.xml[self.position..].find("?>") {
+            Some(end) => {
+                self.position += end + 2;
+                Ok(())
+            }
+            None => Err(self.error("unterminated processing instruction")),
+        }
+    }
+
+    // Parse an xml comment.
+    fn parse_comment(&mut self) -> Result<(), String> {
+        match self.xml[self.position + 4..].find("-->") {
+            Some(end) => {
+                self.position += end + 7;
+                Ok(())
+            }
+            None => Err(self.error("unterminated comment")),
+        }
+    }
+
+    // Parse a CDATA section.
+    fn parse_cdata(&mut self) -> Result<(), String> {
+        if self.stack.is_empty() {
+            return Err(self.error("CDATA section outside the root element"));
+        }
+
+        match self.xml[self.position..].find("]]>") {
+            Some(end) => {
+                self.check_text(&self.xml[self.position..self.position + end], false)?;
+                self.position += end + 3;
+                Ok(())
+            }
+            None => Err(self.error("unterminated CDATA section")),
+        }
+    }
+
+    // Parse a start tag, or an empty element tag, and its attributes.
+    fn parse_start_tag(&mut self) -> Result<(), String> {
+        if self.stack.is_empty() && self.has_root {
+            return Err(self.error("more than one root element"));
+        }
+
+        self.position += 1;
+        let name = self.parse_name()?;
+        let mut attributes: Vec<&str> = vec![];
+
+        loop {
+            let had_space = self.skip_whitespace();
+            let rest = &self.xml[self.position..];
+
+            if rest.starts_with("/>") {
+                self.position += 2;
+                self.has_root = true;
+                return Ok(());
+            }
+
+            if rest.starts_with('>') {
+                self.position += 1;
+                self.stack.push(name);
+                self.has_root = true;
+                return Ok(());
+            }
+
+            if rest.is_empty() {
+                return Err(self.error(&format!("unterminated tag <{name}>")));
+            }
+
+            if !had_space {
+                return Err(self.error(&format!("expected whitespace in tag <{name}>")));
+            }
+
+            let attribute = self.parse_name()?;
+            if attributes.contains(&attribute) {
+                return Err(self.error(&format!(
+                    "duplicate attribute '{attribute}' in tag <{name}>"
+                )));
+            }
+            attributes.push(attribute);
+
+            self.skip_whitespace();
+            if !self.xml[self.position..].starts_with('=') {
+                return Err(self.error(&format!("attribute '{attribute}' has no value")));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+
+            self.parse_attribute_value(attribute)?;
+        }
+    }
+
+    // Parse a quoted attribute value.
+    fn parse_attribute_value(&mut self, attribute: &str) -> Result<(), String> {
+        let quote = match self.xml[self.position..].chars().next() {
+            Some(quote @ ('"' | '\'')) => quote,
+            _ => {
+                return Err(self.error(&format!("attribute '{attribute}' value isn't quoted")));
+            }
+        };
+
+        self.position += 1;
+        let end = match self.xml[self.position..].find(quote) {
+            Some(end) => end,
+            None => {
+                return Err(self.error(&format!("attribute '{attribute}' value isn't closed")));
+            }
+        };
+
+        let value = &self.xml[self.position..self.position + end];
+        if value.contains('<') {
+            return Err(self.error(&format!("attribute '{attribute}' value contains '<'")));
+        }
+        self.check_text(value, true)?;
+
+        self.position += end + 1;
+        Ok(())
+    }
+
+    // Parse an end tag and check that it matches the open element.
+    fn parse_end_tag(&mut self) -> Result<(), String> {
+        self.position += 2;
+        let name = self.parse_name()?;
+        self.skip_whitespace();
+
+        if !self.xml[self.position..].starts_with('>') {
+            return Err(self.error(&format!("unterminated end tag </{name}>")));
+        }
+        self.position += 1;
+
+        match self.stack.pop() {
+            Some(open) if open == name => Ok(()),
+            Some(open) => Err(self.error(&format!(
+                "end tag </{name}> doesn't match start tag <{open}>"
+            ))),
+            None => Err(self.error(&format!("end tag </{name}> without a start tag"))),
+        }
+    }
+
+    // Parse the character data between tags.
+    fn parse_text(&mut self) -> Result<(), String> {
+        let end = self.xml[self.position..]
+            .find('<')
+            .unwrap_or(self.xml.len() - self.position);
+        let text = &self.xml[self.position..self.position + end];
+
+        if self.stack.is_empty() && !text.trim().is_empty() {
+            return Err(self.error("text outside the root element"));
+        }
+
+        self.check_text(text, true)?;
+
+        self.position += end;
+        Ok(())
+    }
+
+    // Parse an element or attribute name, including any namespace prefix.
+    fn parse_name(&mut self) -> Result<&'a str, String> {
+        let rest = &self.xml[self.position..];
+        let end = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+        let name = &rest[..end];
+
+        match name.chars().next() {
+            Some(c) if is_name_start_char(c) => {
+                self.position += end;
+                Ok(name)
+            }
+            _ => Err(self.error("invalid or missing name")),
+        }
+    }
+
+    // Skip any whitespace and return true if there was some.
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.xml[self.position..];
+        let trimmed = rest.trim_start_matches([' ', '\t', '\r', '\n']);
+        self.position += rest.len() - trimmed.len();
+
+        rest.len() != trimmed.len()
+    }
+
+    // Check the characters, and optionally the entities, in a section of text
+    // that starts at the current position.
+    fn check_text(&self, text: &str, has_entities: bool) -> Result<(), String> {
+        let result = if has_entities {
+            check_chars(text).and_then(|()| check_entities(text))
+        } else {
+            check_chars(text)
+        };
+
+        result.map_err(|(offset, message)| self.error_at(self.position + offset, &message))
+    }
+
+    // Add the line and column of the current position to an error message.
+    fn error(&self, message: &str) -> String {
+        self.error_at(self.position, message)
+    }
+
+    // Add the line and column of a position to an error message.
+    fn error_at(&self, position: usize, message: &str) -> String {
+        let before = &self.xml[..position];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        format!("{message} at line {line}, column {column}")
+    }
+}
+
+// Check that a name start character is valid. This is a simplified version of
+// the XML name rules that allows all non-ASCII characters.
+fn is_name_start_char(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == ':' || !c.is_ascii()
+}
+
+// Check that a name character is valid.
+fn is_name_char(c: char) -> bool {
+    is_name_start_char(c) || c.is_ascii_digit() || c == '-' || c == '.'
+}
+
+// Check for control characters that aren't allowed in XML 1.0. Errors contain
+// the byte offset of the character in the text.
+fn check_chars(text: &str) -> Result<(), (usize, String)> {
+    match text
+        .char_indices()
+        .find(|&(_, c)| c < ' ' && c != '\t' && c != '\n' && c != '\r')
+    {
+        Some((offset, c)) => Err((offset, format!("invalid character U+{:04X}", c as u32))),
+        None => Ok(()),
+    }
+}
+
+// Check that all '&' characters start a valid entity or character reference.
+// Errors contain the byte offset of the '&' in the text.
+fn check_entities(text: &str) -> Result<(), (usize, String)> {
+    let mut offset = 0;
+
+    while let Some(start) = text[offset..].find('&') {
+        offset += start;
+        let rest = &text[offset + 1..];
+
+        let Some(end) = rest.find(';') else {
+            return Err((offset, "unescaped '&'".to_string()));
+        };
+
+        let entity = &rest[..end];
+        let is_valid = match entity {
+            "amp" | "lt" | "gt" | "quot" | "apos" => true,
+            _ => match entity.strip_prefix('#') {
+                Some(hex) if hex.starts_with('x') => u32::from_str_radix(&hex[1..], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .is_some(),
+                Some(decimal) => decimal
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(char::from_u32)
+                    .is_some(),
+                None => false,
+            },
+        };
+
+        if !is_valid {
+            return Err((offset, format!("invalid entity '&{entity};'")));
+        }
+
+        offset += end + 2;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// Validate unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod validate_tests {
+
+    use crate::validate::{check_well_formed, is_xml_part};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_is_xml_part() {
+        assert!(is_xml_part("xl/workbook.xml"));
+        assert!(is_xml_part("xl/_rels/workbook.xml.rels"));
+        assert!(is_xml_part("xl/drawings/vmlDrawing1.vml"));
+        assert!(!is_xml_part("xl/media/image1.png"));
+        assert!(!is_xml_part("xl/vbaProject.bin"));
+    }
+
+    #[test]
+    fn test_well_formed() {
+        let valid = [
+            "<a/>",
+            "<a></a>",
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<a/>",
+            "<x:a xmlns:x=\"urn:x\"><x:b c='1' d=\"2\"/>Text</x:a>\n",
+            "<a b=\"&lt;&amp;&gt;&quot;&apos;\">&#65;&#x42;</a>",
+            "<a><!-- Comment --><![CDATA[<>&]]></a>",
+            "<a>\u{00E9}\u{4E2D}\t\r\n</a>",
+        ];
+
+        for xml in valid {
+            assert_eq!(Ok(()), check_well_formed(xml.as_bytes()), "{xml}");
+        }
+    }
+
+    #[test]
+    fn test_not_well_formed() {
+        let invalid = [
+            ("", "document has no root element"),
+            ("<a>", "element <a> isn't closed"),
+            (
+                "<a></b>",
+                "end tag </b> doesn't match start tag <a> at line 1, column 8",
+            ),
+            (
+                "<a/></a>",
+                "end tag </a> without a start tag at line 1, column 9",
+            ),
+            ("<a/><b/>", "more than one root element at line 1, column 5"),
+            (
+                "<a/>text",
+                "text outside the root element at line 1, column 5",
+            ),
+            ("<a>\n&</a>", "unescaped '&' at line 2, column 1"),
+            ("<a>&foo;</a>", "invalid entity '&foo;' at line 1, column 4"),
+            (
+                "<a b=\"<\"/>",
+                "attribute 'b' value contains '<' at line 1, column 7",
+            ),
+            (
+                "<a b=1/>",
+                "attribute 'b' value isn't quoted at line 1, column 6",
+            ),
+            (
+                "<a b=\"1/>",
+                "attribute 'b' value isn't closed at line 1, column 7",
+            ),
+            ("<a b/>", "attribute 'b' has no value at line 1, column 5"),
+            (
+                "<a b=\"1\"c=\"2\"/>",
+                "expected whitespace in tag <a> at line 1, column 9",
+            ),
+            (
+                "<a b=\"1\" b=\"2\"/>",
+                "duplicate attribute 'b' in tag <a> at line 1, column 11",
+            ),
+            ("<1a/>", "invalid or missing name at line 1, column 2"),
+            (
+                "<a>\u{0001}</a>",
+                "invalid character U+0001 at line 1, column 4",
+            ),
+            ("<a", "unterminated tag <a> at line 1, column 3"),
+        ];
+
+        for (xml, error) in invalid {
+            assert_eq!(
+                Err(error.to_string()),
+                check_well_formed(xml.as_bytes()),
+                "{xml}"
+            );
+        }
+
+        assert_eq!(
+            Err("invalid UTF-8 at byte 3".to_string()),
+            check_well_formed(b"<a>\xFF</a>")
+        );
+    }
+}
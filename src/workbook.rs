@@ -227,12 +227,13 @@ use crate::error::XlsxError;
 use crate::format::Format;
 use crate::packager::Packager;
 use crate::packager::PackagerOptions;
+use crate::threaded_comment::Person;
 use crate::worksheet::Worksheet;
 use crate::xmlwriter::XMLWriter;
 use crate::{
     utility, Border, Chart, ChartRange, ChartRangeCacheData, ColNum, Color, DefinedName,
-    DefinedNameType, DocProperties, Fill, Font, FormatPattern, Image, RowNum, Visible,
-    NUM_IMAGE_FORMATS,
+    DefinedNameType, DocProperties, Fill, Font, FormatPattern, FormulaLocale, Image, Note,
+    PersonId, RowNum, TableCustomStyle, Theme, ThreadedComment, Visible, NUM_IMAGE_FORMATS,
 };
 
 /// The `Workbook` struct represents an Excel file in its entirety. It is the
@@ -310,6 +311,7 @@ pub struct Workbook {
     pub(crate) worksheets: Vec<Worksheet>,
     pub(crate) xf_formats: Vec<Format>,
     pub(crate) dxf_formats: Vec<Format>,
+    pub(crate) table_styles: Vec<TableCustomStyle>,
     pub(crate) font_count: u16,
     pub(crate) fill_count: u16,
     pub(crate) border_count: u16,
@@ -321,14 +323,26 @@ pub struct Workbook {
     pub(crate) vba_codename: Option<String>,
     pub(crate) is_xlsm_file: bool,
     pub(crate) has_comments: bool,
+    pub(crate) theme: Theme,
+    pub(crate) persons: Vec<Person>,
+    pub(crate) value_metadata: Vec<String>,
 
     xf_indices: HashMap<Format, u32>,
     dxf_indices: HashMap<Format, u32>,
     active_tab: u16,
     first_sheet: u16,
+    tab_ratio: u16,
     defined_names: Vec<DefinedName>,
     user_defined_names: Vec<DefinedName>,
     read_only_mode: u8,
+    reservation_password_hash: u16,
+    default_zoom: Option<u16>,
+    default_hide_gridlines: bool,
+    default_tab_color: Option<Color>,
+    has_warned_button_macros: bool,
+    pub(crate) default_font: Option<(String, f64)>,
+    formula_locale: FormulaLocale,
+    calc_id: u32,
 }
 
 impl Default for Workbook {
@@ -387,13 +401,17 @@ impl Workbook {
             active_tab: 0,
             fill_count: 0,
             first_sheet: 0,
+            tab_ratio: 600,
             border_count: 0,
             num_formats: vec![],
             read_only_mode: 0,
+            reservation_password_hash: 0,
+            theme: Theme::new(),
             has_hyperlink_style: false,
             worksheets: vec![],
             xf_formats: vec![],
             dxf_formats: vec![],
+            table_styles: vec![],
             defined_names: vec![],
             user_defined_names: vec![],
             xf_indices: HashMap::new(),
@@ -404,6 +422,15 @@ impl Workbook {
             vba_signature: vec![],
             vba_codename: None,
             has_comments: false,
+            persons: vec![],
+            value_metadata: vec![],
+            default_zoom: None,
+            default_hide_gridlines: false,
+            default_tab_color: None,
+            has_warned_button_macros: false,
+            default_font: None,
+            formula_locale: FormulaLocale::English,
+            calc_id: 124_519,
         };
 
         // Initialize the workbook with the same function used to reset it.
@@ -473,6 +500,23 @@ impl Workbook {
         let mut worksheet = Worksheet::new();
         worksheet.set_name(&name).unwrap();
 
+        // Apply any user defined worksheet view defaults.
+        if let Some(zoom) = self.default_zoom {
+            worksheet.set_zoom(zoom);
+        }
+
+        if self.default_hide_gridlines {
+            worksheet.set_screen_gridlines(false);
+        }
+
+        if let Some(color) = self.default_tab_color {
+            worksheet.set_tab_color(color);
+        }
+
+        if let Some((_, size)) = self.default_font {
+            worksheet.default_font_size = size;
+        }
+
         self.worksheets.push(worksheet);
         let worksheet = self.worksheets.last_mut().unwrap();
 
@@ -759,6 +803,14 @@ impl Workbook {
     /// When working with the independent worksheet object you can add it to a
     /// workbook using `push_worksheet()`, see the example below.
     ///
+    /// The worksheet view defaults set with [`Workbook::set_default_zoom()`],
+    /// [`Workbook::set_default_hide_gridlines()`] and
+    /// [`Workbook::set_default_tab_color()`] aren't applied to a pushed
+    /// worksheet, since they would overwrite any view settings made on the
+    /// independent worksheet. The font set with
+    /// [`Workbook::set_default_font()`] is applied since it is a property of
+    /// the workbook rather than the worksheet.
+    ///
     /// See also the documentation on [Creating worksheets] and working with the
     /// borrow checker.
     ///
@@ -806,6 +858,13 @@ impl Workbook {
             worksheet.set_name(&name).unwrap();
         }
 
+        // The worksheet view defaults aren't applied to a pushed worksheet so
+        // that they don't overwrite the user's settings. The default font size
+        // is a workbook property and is needed for the row heights.
+        if let Some((_, size)) = self.default_font {
+            worksheet.default_font_size = size;
+        }
+
         self.worksheets.push(worksheet);
     }
 
@@ -832,6 +891,9 @@ impl Workbook {
     ///   the workbook.
     /// - [`XlsxError::TableNameReused`] - Worksheet Table name is already in
     ///   use in the workbook.
+    /// - [`XlsxError::MaxUniqueStringsExceeded`] - The number of unique
+    ///   strings in the workbook exceeds the shared string table limit of
+    ///   2,147,483,647.
     /// - [`XlsxError::IoError`] - A wrapper for various IO errors when creating
     ///   the xlsx file, or its sub-files.
     /// - [`XlsxError::ZipError`] - A wrapper for various zip errors when
@@ -879,6 +941,8 @@ impl Workbook {
     /// ```
     ///
     pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<(), XlsxError> {
+        self.check_worksheet_names()?;
+
         #[cfg(feature = "test-resave")]
         {
             // Some test code to test double/multiple saves.
@@ -891,6 +955,145 @@ impl Workbook {
         Ok(())
     }
 
+    /// Save the Workbook as an xlsx file via a temporary file.
+    ///
+    /// The workbook `save_atomic()` method is similar to the
+    /// [`Workbook::save()`] method except that the xlsx file is first written
+    /// to a temporary file in the same directory, with a `.tmp` extension
+    /// appended to the filename, and then renamed to the target path. This
+    /// means that other processes that read or serve the target file will
+    /// never see a partially written xlsx file.
+    ///
+    /// If there is an error while the file is being written the temporary file
+    /// is removed and any existing file at the target path is left unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the new Excel file to create as a `&str` or as a
+    ///   [`std::path`] `Path` or `PathBuf` instance.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Workbook::save()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates saving a workbook via a temporary
+    /// file.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_save_atomic.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     // The file is written to "workbook.xlsx.tmp" and then renamed.
+    ///     workbook.save_atomic("workbook.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn save_atomic<P: AsRef<Path>>(&mut self, path: P) -> Result<(), XlsxError> {
+        let path = path.as_ref();
+
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+
+        let result = self.save_to_temp_file(&temp_path).and_then(|()| {
+            std::fs::rename(&temp_path, path)?;
+            Ok(())
+        });
+
+        // Remove the temporary file if there was an error.
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+
+        result
+    }
+
+    // Write the workbook to a temporary file and sync it to disk before it is
+    // renamed by save_atomic().
+    fn save_to_temp_file(&mut self, path: &Path) -> Result<(), XlsxError> {
+        let file = File::create(path)?;
+        self.save_internal(&file)?;
+        file.sync_all()?;
+
+        Ok(())
+    }
+
+    /// Save the Workbook as an xlsx file and report the progress.
+    ///
+    /// The workbook `save_with_progress()` method is similar to the
+    /// [`Workbook::save()`] method except that it calls a user supplied
+    /// function after each part of the xlsx file has been written. The
+    /// function receives a [`SaveProgress`] struct with the name of the part,
+    /// the number of parts and bytes written and the number of worksheets
+    /// written. This can be used to show the progress of large files in a user
+    /// interface.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the new Excel file to create as a `&str` or as a
+    ///   [`std::path`] `Path` or `PathBuf` instance.
+    /// - `callback`: A function or closure that takes a [`SaveProgress`]
+    ///   argument.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Workbook::save()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates saving a workbook and reporting the
+    /// progress of the worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_save_with_progress.rs
+    /// #
+    /// # use rust_xlsxwriter::{SaveProgress, Workbook, XlsxError};
+    /// #
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     for _ in 0..3 {
+    ///         let worksheet = workbook.add_worksheet();
+    ///         worksheet.write(0, 0, "Hello")?;
+    ///     }
+    ///
+    ///     workbook.save_with_progress("workbook.xlsx", |progress: SaveProgress| {
+    ///         if progress.part_name.starts_with("xl/worksheets/sheet") {
+    ///             println!(
+    ///                 "Written worksheet {} of {}",
+    ///                 progress.worksheets_written, progress.worksheet_count
+    ///             );
+    ///         }
+    ///     })?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn save_with_progress<P, F>(&mut self, path: P, mut callback: F) -> Result<(), XlsxError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(SaveProgress),
+    {
+        self.check_worksheet_names()?;
+
+        let file = File::create(path)?;
+        self.save_internal_with_progress(file, Some(&mut callback))?;
+
+        Ok(())
+    }
+
     /// Save the Workbook as an xlsx file and return it as a byte vector.
     ///
     /// The workbook `save_to_buffer()` method is similar to the
@@ -1535,65 +1738,827 @@ impl Workbook {
         self
     }
 
-    // -----------------------------------------------------------------------
-    // Internal function/methods.
-    // -----------------------------------------------------------------------
+    /// Set a password that is required to modify the workbook.
+    ///
+    /// This method can be used to set the Excel “Password to modify” option
+    /// that is available in the “General Options” dialog when saving a file.
+    /// Users who open the file without the password can only open it in
+    /// "read-only" mode.
+    ///
+    /// This option can be combined with [`Workbook::read_only_recommended()`].
+    /// It is also independent of worksheet protection, see
+    /// [`Worksheet::protect_with_password()`](crate::Worksheet::protect_with_password),
+    /// and both can be used in the same workbook.
+    ///
+    /// Note, the password isn't encryption, it is a weak hash that is used by
+    /// Excel as a convenience to discourage changes to the file.
+    ///
+    /// # Parameters
+    ///
+    /// - `password`: The password string. Note, only ascii text passwords are
+    ///   supported. Passing the empty string "" turns off the password.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a simple workbook which
+    /// requires a password to modify it and which also opens with a
+    /// recommendation that the file should be opened in read only mode.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_modify_password.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let _worksheet = workbook.add_worksheet();
+    ///
+    ///     workbook.read_only_recommended();
+    ///     workbook.set_modify_password("abc123");
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_modify_password(&mut self, password: &str) -> &mut Workbook {
+        self.reservation_password_hash = utility::hash_password(password);
+        self
+    }
 
-    // Reset workbook between saves.
-    fn reset(&mut self) {
-        self.writer.reset();
+    /// Set the ratio between the worksheet tabs and the horizontal scrollbar.
+    ///
+    /// The `set_tab_ratio()` method sets the proportion of the bottom of the
+    /// Excel window that is used by the worksheet tab bar, with the remainder
+    /// used by the horizontal scrollbar. This can be useful to show more tabs
+    /// in a workbook with a large number of worksheets.
+    ///
+    /// # Parameters
+    ///
+    /// - `tab_ratio`: The width of the tab bar as a percentage of the space
+    ///   shared with the horizontal scrollbar, in the range 0 <= tab_ratio
+    ///   <= 100. The Excel default is 60.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the ratio of the worksheet
+    /// tab bar to the horizontal scrollbar.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_tab_ratio.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     for _ in 0..20 {
+    ///         workbook.add_worksheet();
+    ///     }
+    ///
+    ///     workbook.set_tab_ratio(80);
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_tab_ratio(&mut self, tab_ratio: u16) -> &mut Workbook {
+        if tab_ratio > 100 {
+            eprintln!("Tab ratio {tab_ratio} outside Excel range: 0 <= tab_ratio <= 100.");
+            return self;
+        }
 
-        self.xf_indices = HashMap::from([(Format::default(), 0)]);
-        self.xf_formats = vec![Format::default()];
-        self.dxf_indices = HashMap::new();
-        self.dxf_formats = vec![];
-        self.font_count = 0;
-        self.fill_count = 0;
-        self.border_count = 0;
-        self.num_formats = vec![];
+        self.tab_ratio = tab_ratio * 10;
+        self
+    }
 
-        for worksheet in &mut self.worksheets {
-            worksheet.reset();
+    /// Set the default zoom factor for new worksheets.
+    ///
+    /// Set a zoom factor that is applied to every worksheet created via
+    /// [`Workbook::add_worksheet()`] after this method is called. Worksheets
+    /// that already exist, or that are created with [`Worksheet::new()`] and
+    /// added via [`Workbook::push_worksheet()`], are unaffected. The zoom can
+    /// still be changed for individual worksheets with
+    /// [`Worksheet::set_zoom()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `zoom`: The default worksheet zoom level. The range is 10 <= zoom <=
+    ///   400.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a default zoom level for
+    /// all new worksheets in a workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_default_zoom.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Set the zoom for all the worksheets added after this point.
+    ///     workbook.set_default_zoom(150);
+    ///
+    ///     for _ in 0..5 {
+    ///         workbook.add_worksheet();
+    ///     }
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_default_zoom(&mut self, zoom: u16) -> &mut Workbook {
+        if !(10..=400).contains(&zoom) {
+            eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
+            return self;
         }
+
+        self.default_zoom = Some(zoom);
+        self
     }
 
-    // Internal function to prepare the workbook and other component files for
-    // writing to the xlsx file.
-    #[allow(clippy::similar_names)]
-    fn save_internal<W: Write + Seek + Send>(&mut self, writer: W) -> Result<(), XlsxError> {
-        // Reset workbook and worksheet state data between saves.
-        self.reset();
+    /// Set the default screen gridlines option for new worksheets.
+    ///
+    /// Hide the screen gridlines on every worksheet created via
+    /// [`Workbook::add_worksheet()`] after this method is called. Existing
+    /// worksheets, and worksheets added via [`Workbook::push_worksheet()`],
+    /// are unaffected. See [`Worksheet::set_screen_gridlines()`] for details
+    /// on the individual worksheet option.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default, i.e., the
+    ///   gridlines are shown.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates hiding the screen gridlines for all
+    /// new worksheets in a workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_default_hide_gridlines.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Hide the gridlines for all the worksheets added after this point.
+    ///     workbook.set_default_hide_gridlines(true);
+    ///
+    ///     for _ in 0..5 {
+    ///         workbook.add_worksheet();
+    ///     }
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_default_hide_gridlines(&mut self, enable: bool) -> &mut Workbook {
+        self.default_hide_gridlines = enable;
+        self
+    }
 
-        // Ensure that there is at least one worksheet in the workbook.
-        if self.worksheets.is_empty() {
-            self.add_worksheet();
+    /// Set the default tab color for new worksheets.
+    ///
+    /// Set a tab color that is applied to every worksheet created via
+    /// [`Workbook::add_worksheet()`] after this method is called. Existing
+    /// worksheets, and worksheets added via [`Workbook::push_worksheet()`],
+    /// are unaffected. The color can still be changed for individual
+    /// worksheets with [`Worksheet::set_tab_color()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The tab color property defined by a [`Color`] enum
+    ///   value or a type that can convert [`Into`] a [`Color`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a default tab color for a
+    /// group of new worksheets and then changing it for the next group.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_default_tab_color.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_default_tab_color(Color::Green);
+    ///     workbook.add_worksheet();
+    ///     workbook.add_worksheet();
+    ///
+    ///     workbook.set_default_tab_color(Color::Red);
+    ///     workbook.add_worksheet();
+    ///     workbook.add_worksheet();
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_default_tab_color(&mut self, color: impl Into<Color>) -> &mut Workbook {
+        let color = color.into();
+        if color.is_valid() {
+            self.default_tab_color = Some(color);
         }
-        // Ensure one sheet is active/selected.
-        self.set_active_worksheets();
 
-        // Check for the use of hyperlink style in the worksheets and if so add
-        // a hyperlink style to the global formats.
-        for worksheet in &self.worksheets {
-            if worksheet.has_hyperlink_style {
-                let format = Format::new().set_hyperlink();
-                self.xf_indices.insert(format.clone(), 1);
-                self.xf_formats.push(format);
-                self.has_hyperlink_style = true;
-                break;
-            }
-        }
+        self
+    }
 
-        // Check for duplicate sheet names, which aren't allowed by Excel.
-        let mut unique_worksheet_names = HashSet::new();
-        for worksheet in &self.worksheets {
-            let worksheet_name = worksheet.name.to_lowercase();
-            if unique_worksheet_names.contains(&worksheet_name) {
-                return Err(XlsxError::SheetnameReused(worksheet_name));
-            }
+    /// Set the default font for the workbook.
+    ///
+    /// Excel uses a default font of Calibri 11 for worksheet cells. The
+    /// `set_default_font()` method changes the default font name and size of
+    /// the workbook, which is equivalent to changing the font of the "Normal"
+    /// cell style in Excel. The minor (body) font of the workbook
+    /// [`Theme`] is also changed to the new font name.
+    ///
+    /// The new default font is used by unformatted cells and by any
+    /// [`Format`] that doesn't set a font name or size of its own. For example
+    /// a format with only [`Format::set_bold()`] will use the new default font
+    /// in bold.
+    ///
+    /// The [`Worksheet::autofit()`] method uses character widths based on
+    /// Calibri 11. When a default font is set the autofit widths are scaled by
+    /// the ratio of the new font size to 11. This is an approximation since
+    /// the character widths also depend on the font name.
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name, for example "Arial".
+    /// - `font_size`: The font size in points. The Excel range is 1 to 409.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the default font of a
+    /// workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_default_font.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Use Arial 10 as the default font.
+    ///     workbook.set_default_font("Arial", 10);
+    ///
+    ///     let bold = Format::new().set_bold();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_with_format(0, 0, "Arial 10 bold", &bold)?;
+    ///     worksheet.write(1, 0, "Arial 10")?;
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_default_font(
+        &mut self,
+        font_name: impl Into<String>,
+        font_size: impl Into<f64>,
+    ) -> &mut Workbook {
+        let font_name = font_name.into();
+        let font_size = font_size.into();
+
+        if font_name.is_empty() {
+            eprintln!("Default font name cannot be blank.");
+            return self;
+        }
+
+        if !(1.0..=409.0).contains(&font_size) {
+            eprintln!("Default font size {font_size} outside Excel range: 1 <= size <= 409.");
+            return self;
+        }
+
+        for worksheet in &mut self.worksheets {
+            worksheet.default_font_size = font_size;
+        }
+
+        self.default_font = Some((font_name, font_size));
+        self
+    }
+
+    /// Get the default font name and size of the workbook.
+    ///
+    /// Returns the font set with [`Workbook::set_default_font()`] or the Excel
+    /// default of Calibri 11.
+    ///
+    pub fn default_font(&self) -> (&str, f64) {
+        match &self.default_font {
+            Some((name, size)) => (name, *size),
+            None => ("Calibri", 11.0),
+        }
+    }
+
+    /// Set a group of worksheets to be selected when the file is opened.
+    ///
+    /// In Excel several worksheet tabs can be selected at the same time as a
+    /// group so that data entered or formatting applied in the active
+    /// worksheet also applies to the other worksheets in the group. The
+    /// `set_selected_worksheets()` method sets the selected state of the
+    /// worksheets so that the file opens with the group selected.
+    ///
+    /// The active worksheet, see
+    /// [`Worksheet::set_active()`](crate::Worksheet::set_active), must be one
+    /// of the selected worksheets. If no worksheet has been set as active then
+    /// the first worksheet in the group is made active. Worksheets that aren't
+    /// in the group are deselected.
+    ///
+    /// Note, the selected worksheets cannot be hidden.
+    ///
+    /// # Parameters
+    ///
+    /// - `indices`: The zero indexed positions of the worksheets in the
+    ///   workbook.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - One of the indices
+    ///   doesn't match an existing worksheet.
+    /// - [`XlsxError::ParameterError`] - The list of indices is empty or the
+    ///   active worksheet isn't one of the selected worksheets.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates selecting a group of worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_selected_worksheets.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.add_worksheet().set_name("January")?;
+    ///     workbook.add_worksheet().set_name("February")?;
+    ///     workbook.add_worksheet().set_name("March")?;
+    ///     workbook.add_worksheet().set_name("Summary")?;
+    ///
+    ///     // Select the monthly worksheets as a group.
+    ///     workbook.set_selected_worksheets(&[0, 1, 2])?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_selected_worksheets(
+        &mut self,
+        indices: &[usize],
+    ) -> Result<&mut Workbook, XlsxError> {
+        if indices.is_empty() {
+            return Err(XlsxError::ParameterError(
+                "At least one worksheet must be selected".to_string(),
+            ));
+        }
+
+        if let Some(index) = indices
+            .iter()
+            .find(|&&index| index >= self.worksheets.len())
+        {
+            return Err(XlsxError::UnknownWorksheetNameOrIndex(index.to_string()));
+        }
+
+        // Check that any user defined active worksheet is in the group.
+        if let Some((index, worksheet)) = self
+            .worksheets
+            .iter()
+            .enumerate()
+            .find(|(_, worksheet)| worksheet.active)
+        {
+            if !indices.contains(&index) {
+                return Err(XlsxError::ParameterError(format!(
+                    "Active worksheet '{}' must be one of the selected worksheets",
+                    worksheet.name()
+                )));
+            }
+        } else {
+            self.worksheets[indices[0]].set_active(true);
+        }
+
+        for (index, worksheet) in self.worksheets.iter_mut().enumerate() {
+            worksheet.set_selected(indices.contains(&index));
+        }
+
+        Ok(self)
+    }
+
+    /// Set the language of the function names used in worksheet formulas.
+    ///
+    /// Excel stores formulas in the file format with US English function names
+    /// and translates them to the language of the user's Excel application at
+    /// runtime. This means that formulas need to be written with the English
+    /// function names, for example `SUM()` instead of the German `SUMME()`.
+    ///
+    /// The `set_formula_locale()` method allows formulas to be written with
+    /// localized function names which are translated to English when the
+    /// workbook is saved. The translation covers commonly used functions such
+    /// as `SUMME()`, `WENN()` and `SVERWEIS()` in German. Function names that
+    /// aren't recognized for the locale, including English names, are written
+    /// unchanged.
+    ///
+    /// Note, only the function names are translated. Formulas must still use
+    /// the US style comma separator between function arguments, and `.` as the
+    /// decimal separator. The translation applies to worksheet cell formulas.
+    /// It doesn't apply to formulas in conditional formats, data validations,
+    /// charts or tables.
+    ///
+    /// See [Non US Excel functions and
+    /// syntax](crate::Formula#non-us-excel-functions-and-syntax) for more
+    /// information.
+    ///
+    /// # Parameters
+    ///
+    /// - `locale`: A [`FormulaLocale`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing formulas with German
+    /// function names.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_formula_locale.rs
+    /// #
+    /// # use rust_xlsxwriter::{FormulaLocale, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_formula_locale(FormulaLocale::German);
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write_column(0, 0, [10, 20, 30])?;
+    ///
+    ///     // Write formulas using German function names.
+    ///     worksheet.write_formula(0, 1, "=SUMME(A1:A3)")?;
+    ///     worksheet.write_formula(1, 1, "=WENN(A1>15,\"Groß\",\"Klein\")")?;
+    ///     worksheet.write_formula(2, 1, "=MITTELWERT(A1:A3)")?;
+    ///
+    ///     workbook.save("formulas.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_formula_locale(&mut self, locale: FormulaLocale) -> &mut Workbook {
+        self.formula_locale = locale;
+        self
+    }
+
+    /// Set the calculation engine id stored in the workbook.
+    ///
+    /// The `rust_xlsxwriter` library doesn't calculate the result of formulas.
+    /// Instead it stores a default result of 0 and sets the `fullCalcOnLoad`
+    /// workbook flag to tell Excel to recalculate all formulas when the file
+    /// is opened.
+    ///
+    /// The workbook also stores the id of the calculation engine that last
+    /// calculated the formulas. When a file is opened Excel compares this id
+    /// with the id of its own calculation engine and recalculates the formulas
+    /// if the file id is older. Some versions of Excel, and some other
+    /// spreadsheet applications, don't honor the `fullCalcOnLoad` flag in all
+    /// cases and can display the stored 0 results until a cell is edited.
+    /// Setting the calculation id to 0 marks the workbook as calculated by an
+    /// unknown engine which forces a full recalculation in these applications.
+    ///
+    /// See also [Formula Results](crate::Formula#formula-results) and
+    /// [`Worksheet::set_full_calc_on_load()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `calc_id`: The calculation engine id. The default used by
+    ///   `rust_xlsxwriter` is 124519 which corresponds to Excel 2010.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the workbook calculation id
+    /// to 0 to force a full recalculation of the formulas when the file is
+    /// opened.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_calc_id.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_calc_id(0);
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write_column(0, 0, [10, 20, 30])?;
+    ///     worksheet.write_formula(3, 0, "=SUM(A1:A3)")?;
+    ///
+    ///     workbook.save("formulas.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_calc_id(&mut self, calc_id: u32) -> &mut Workbook {
+        self.calc_id = calc_id;
+        self
+    }
+
+    /// Set the color and font theme for the workbook.
+    ///
+    /// Excel workbooks contain a theme that defines a palette of colors and a
+    /// pair of major (heading) and minor (body) fonts. This method can be used
+    /// to replace the default theme used by `rust_xlsxwriter` with a custom
+    /// [`Theme`].
+    ///
+    /// Any format or chart property that uses a [`Color::Theme`] color will
+    /// use the corresponding color from the custom theme.
+    ///
+    /// # Parameters
+    ///
+    /// - `theme`: A [`Theme`] struct reference.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the workbook theme to one
+    /// of the preset themes.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_theme.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, Theme, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Use the Excel 2013-2022 color palette.
+    ///     workbook.set_theme(&Theme::office_2013());
+    ///
+    ///     let format = Format::new().set_background_color(Color::Theme(4, 0));
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string_with_format(0, 0, "Accent 1", &format)?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_theme(&mut self, theme: &Theme) -> &mut Workbook {
+        self.theme = theme.clone();
+        self
+    }
+
+    /// Add a person to the workbook persons registry.
+    ///
+    /// The persons registry is the list of authors of the threaded comments in
+    /// the workbook. This method adds a person to the registry and returns a
+    /// [`PersonId`] that can be used to set the author of any number of
+    /// [`ThreadedComment`](crate::ThreadedComment)s and replies in the
+    /// workbook's worksheets.
+    ///
+    /// Each person is given a stable id in the `persons.xml` file based on the
+    /// order that they are added. Adding a person with the same display name
+    /// and user id more than once returns the same `PersonId`.
+    ///
+    /// All the persons referenced by threaded comments must be in the
+    /// registry when the workbook is saved, otherwise
+    /// [`Workbook::save()`] will return an [`XlsxError::ParameterError`].
+    ///
+    /// # Parameters
+    ///
+    /// - `display_name`: The name of the person as displayed in Excel.
+    /// - `user_id`: A unique id for the person such as an email address. If
+    ///   this is an empty string the display name is used instead.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding people to the workbook
+    /// persons registry and using them as the authors of a threaded comment
+    /// and a reply.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_add_person.rs
+    /// #
+    /// # use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add the comment authors to the persons registry.
+    ///     let jane = workbook.add_person("Jane Smith", "jane@example.com");
+    ///     let john = workbook.add_person("John Smith", "john@example.com");
+    ///
+    ///     let comment = ThreadedComment::new("Is this correct?")
+    ///         .set_author(jane)
+    ///         .add_reply(john, "Yes.");
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.insert_threaded_comment(0, 0, &comment)?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_person(
+        &mut self,
+        display_name: impl Into<String>,
+        user_id: impl Into<String>,
+    ) -> PersonId {
+        let display_name = display_name.into();
+        let mut user_id = user_id.into();
+
+        if user_id.is_empty() {
+            user_id.clone_from(&display_name);
+        }
+
+        let person = Person {
+            display_name,
+            user_id,
+        };
+
+        if let Some(index) = self.persons.iter().position(|p| *p == person) {
+            return PersonId(index);
+        }
+
+        self.persons.push(person);
+        PersonId(self.persons.len() - 1)
+    }
+
+    /// Add an entry to the workbook value metadata registry.
+    ///
+    /// Excel stores metadata about cell values in the `xl/metadata.xml` file.
+    /// Cells refer to the metadata via a `vm` cell attribute. The
+    /// `add_value_metadata()` method adds a value metadata type to the
+    /// workbook registry and returns the index that is used with
+    /// [`Worksheet::write_with_metadata()`] to link cell values to it.
+    ///
+    /// Adding a type that is already in the registry returns the existing
+    /// index. Only value metadata is supported, see
+    /// [`Worksheet::write_with_metadata()`] for details.
+    ///
+    /// # Parameters
+    ///
+    /// - `metadata_type`: The name of the metadata type, for example
+    ///   `"XLSTOCK"`. The names `"XLDAPR"` and `"XLRICHVALUE"` are used by the
+    ///   library for dynamic arrays and embedded images and the name can't be
+    ///   blank. Invalid names are reported as a
+    ///   [`XlsxError::ParameterError`] when the workbook is saved.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing cells with value metadata.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_with_metadata.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add an entry to the workbook value metadata registry.
+    ///     let stock = workbook.add_value_metadata("XLSTOCK");
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_with_metadata(0, 0, "MSFT", stock)?;
+    ///     worksheet.write_with_metadata(1, 0, "AAPL", stock)?;
+    ///
+    ///     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_value_metadata(&mut self, metadata_type: impl Into<String>) -> u32 {
+        let metadata_type = metadata_type.into();
+
+        if let Some(index) = self.value_metadata.iter().position(|t| *t == metadata_type) {
+            return index as u32;
+        }
+
+        self.value_metadata.push(metadata_type);
+        self.value_metadata.len() as u32 - 1
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal function/methods.
+    // -----------------------------------------------------------------------
+
+    // Reset workbook between saves.
+    fn reset(&mut self) {
+        self.writer.reset();
+
+        let default_format = self.styles_format(&Format::default());
+        self.xf_indices = HashMap::from([(default_format.clone(), 0)]);
+        self.xf_formats = vec![default_format];
+        self.dxf_indices = HashMap::new();
+        self.dxf_formats = vec![];
+        self.table_styles = vec![];
+        self.font_count = 0;
+        self.fill_count = 0;
+        self.border_count = 0;
+        self.num_formats = vec![];
+
+        for worksheet in &mut self.worksheets {
+            worksheet.reset();
+        }
+    }
+
+    // Check for blank or duplicate sheet names, which aren't allowed by Excel.
+    // The comparison is case-insensitive, like Excel. This is also called
+    // before a file is created so that an existing file isn't truncated.
+    fn check_worksheet_names(&self) -> Result<(), XlsxError> {
+        let mut unique_worksheet_names = HashSet::new();
+        for worksheet in &self.worksheets {
+            if worksheet.name.is_empty() {
+                return Err(XlsxError::SheetnameCannotBeBlank(worksheet.name.clone()));
+            }
+
+            let worksheet_name = worksheet.name.to_lowercase();
+            if unique_worksheet_names.contains(&worksheet_name) {
+                return Err(XlsxError::SheetnameReused(worksheet.name.clone()));
+            }
 
             unique_worksheet_names.insert(worksheet_name);
         }
 
+        Ok(())
+    }
+
+    // Internal function to prepare the workbook and other component files for
+    // writing to the xlsx file.
+    fn save_internal<W: Write + Seek + Send>(&mut self, writer: W) -> Result<(), XlsxError> {
+        self.save_internal_with_progress(writer, None)
+    }
+
+    // Internal function to save the workbook with an optional callback to
+    // report the progress of the parts written to the xlsx file.
+    #[allow(clippy::similar_names)]
+    fn save_internal_with_progress<W: Write + Seek + Send>(
+        &mut self,
+        writer: W,
+        progress_callback: Option<&mut dyn FnMut(SaveProgress)>,
+    ) -> Result<(), XlsxError> {
+        // Reset workbook and worksheet state data between saves.
+        self.reset();
+
+        // Ensure that there is at least one worksheet in the workbook.
+        if self.worksheets.is_empty() {
+            self.add_worksheet();
+        }
+        // Check for blank or duplicate sheet names.
+        self.check_worksheet_names()?;
+
+        // Ensure one sheet is active/selected.
+        self.set_active_worksheets();
+
+        // Check for the use of hyperlink style in the worksheets and if so add
+        // a hyperlink style to the global formats.
+        for worksheet in &self.worksheets {
+            if worksheet.has_hyperlink_style {
+                let format = self.styles_format(&Format::new().set_hyperlink());
+                self.xf_indices.insert(format.clone(), 1);
+                self.xf_formats.push(format);
+                self.has_hyperlink_style = true;
+                break;
+            }
+        }
+
+        // Check for duplicate sheet ids, which also aren't allowed by Excel.
+        let mut unique_sheet_ids = HashSet::new();
+        for (index, worksheet) in self.worksheets.iter().enumerate() {
+            let sheet_id = worksheet.sheet_id.unwrap_or(index as u32 + 1);
+            if !unique_sheet_ids.insert(sheet_id) {
+                return Err(XlsxError::ParameterError(format!(
+                    "Worksheet '{}' has a duplicate sheet id: {sheet_id}. \
+                     See Worksheet::set_sheet_id().",
+                    worksheet.name
+                )));
+            }
+        }
+
         // Write any Tables associated with serialization areas.
         #[cfg(feature = "serde")]
         for worksheet in &mut self.worksheets {
@@ -1638,7 +2603,9 @@ impl Workbook {
             worksheet.set_global_xf_indices(&worksheet_xf_indices[i]);
             worksheet.set_global_dxf_indices(&worksheet_dxf_indices[i]);
 
-            // Perform the autofilter row hiding.
+            // Extend the autofilter range to any data written below it and
+            // then perform the autofilter row hiding.
+            worksheet.extend_autofilter_range();
             worksheet.hide_autofilter_rows();
 
             // Set the index of the worksheets.
@@ -1655,12 +2622,41 @@ impl Workbook {
             }
         }
 
+        // Check for duplicate VBA codenames, which would break macros that
+        // refer to the workbook or worksheets by codename. VBA names are case
+        // insensitive.
+        let mut unique_vba_names = HashSet::new();
+        let vba_names = self.vba_codename.iter().chain(
+            self.worksheets
+                .iter()
+                .filter_map(|ws| ws.vba_codename.as_ref()),
+        );
+
+        for vba_name in vba_names {
+            if !unique_vba_names.insert(vba_name.to_lowercase()) {
+                return Err(XlsxError::VbaNameError(format!(
+                    "VBA name is used more than once in workbook: {vba_name}"
+                )));
+            }
+        }
+
+        // Translate any localized formula function names to English.
+        for worksheet in &mut self.worksheets {
+            worksheet.localize_formulas(self.formula_locale);
+        }
+
         // Generate a global array of embedded images from the worksheets.
         self.prepare_embedded_images();
 
+        // Check the worksheet value metadata against the workbook registry.
+        self.prepare_value_metadata()?;
+
         // Convert the images in the workbooks into drawing files and rel links.
         self.prepare_drawings();
 
+        // Check the threaded comment authors and add the legacy notes.
+        self.prepare_threaded_comments()?;
+
         // Prepare the worksheet VML elements such as buttons and header images.
         self.prepare_vml();
 
@@ -1673,12 +2669,18 @@ impl Workbook {
         // Prepare worksheet tables.
         self.prepare_tables()?;
 
+        // Set the printer settings file linkages.
+        self.prepare_printer_settings();
+
         // Collect workbook level metadata to help generate the xlsx file.
         let mut package_options = PackagerOptions::new();
         package_options = self.set_package_options(package_options)?;
 
         // Create the Packager object that will assemble the zip/xlsx file.
-        let packager = Packager::new(writer);
+        let mut packager = Packager::new(writer);
+        if let Some(callback) = progress_callback {
+            packager.set_progress_callback(callback);
+        }
         packager.assemble_file(self, &package_options)?;
 
         Ok(())
@@ -1701,14 +2703,125 @@ impl Workbook {
         self.active_tab = active_index as u16;
     }
 
+    // Check that the authors of the threaded comments are in the persons
+    // registry and add the legacy notes that older versions of Excel display in
+    // place of the threaded comments.
+    fn prepare_threaded_comments(&mut self) -> Result<(), XlsxError> {
+        let num_persons = self.persons.len();
+
+        for worksheet in &mut self.worksheets {
+            let sheet_index = worksheet.sheet_index;
+
+            for ((row, col), comment) in &worksheet.threaded_comments {
+                let authors = comment
+                    .author
+                    .iter()
+                    .chain(comment.replies.iter().map(|reply| &reply.author));
+
+                for author in authors {
+                    if author.0 >= num_persons {
+                        let error = format!(
+                            "Threaded comment in worksheet '{}' cell {} refers to a person \
+                             that isn't in the workbook persons registry. \
+                             See Workbook::add_person().",
+                            worksheet.name,
+                            utility::row_col_to_cell(*row, *col)
+                        );
+                        return Err(XlsxError::ParameterError(error));
+                    }
+                }
+
+                // The legacy note is linked to the threaded comment via the
+                // author name and the comment id. Like Excel, it is always
+                // hidden, even if the worksheet notes are shown.
+                let guid = ThreadedComment::guid(sheet_index, *row, *col, 0);
+                let mut note = Note::new(comment.legacy_note_text())
+                    .set_author(format!("tc={guid}"))
+                    .add_author_prefix(false)
+                    .set_visible(false);
+                note.cell_row = *row;
+                note.cell_col = *col;
+                note.threaded_comment_id = Some(guid);
+
+                worksheet.notes.entry(*row).or_default().insert(*col, note);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Check that the worksheet value metadata refers to valid entries in the
+    // workbook registry. The user metadata follows the embedded image metadata
+    // in the metadata.xml file so the worksheets store the offset.
+    fn prepare_value_metadata(&mut self) -> Result<(), XlsxError> {
+        let num_entries = self.value_metadata.len() as u32;
+        let offset = self.embedded_images.len() as u32;
+        let mut is_used = false;
+
+        for worksheet in &mut self.worksheets {
+            worksheet.value_metadata_offset = offset;
+
+            worksheet.remove_stale_value_metadata();
+
+            for ((row, col), index) in &worksheet.value_metadata {
+                if *index >= num_entries {
+                    let error = format!(
+                        "Value metadata in worksheet '{}' cell {} refers to an index \
+                         that isn't in the workbook value metadata registry. \
+                         See Workbook::add_value_metadata().",
+                        worksheet.name,
+                        utility::row_col_to_cell(*row, *col)
+                    );
+                    return Err(XlsxError::ParameterError(error));
+                }
+
+                is_used = true;
+            }
+        }
+
+        if is_used {
+            for metadata_type in &self.value_metadata {
+                if metadata_type.is_empty()
+                    || metadata_type == "XLDAPR"
+                    || metadata_type == "XLRICHVALUE"
+                {
+                    let error =
+                        format!("Value metadata type '{metadata_type}' is blank or reserved.");
+                    return Err(XlsxError::ParameterError(error));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     // Prepare the worksheet VML elements such as buttons and header images.
     fn prepare_vml(&mut self) {
         let mut comment_id = 1;
+        let mut threaded_comment_id = 1;
         let mut vml_drawing_id = 1;
         let mut vml_data_id = 1;
         let mut vml_shape_id = 1024;
 
         for worksheet in &mut self.worksheets {
+            // Warn if a button has a user defined macro but there isn't a VBA
+            // project for it to refer to. The warning is only raised once per
+            // workbook, rather than on every save.
+            if !self.is_xlsm_file
+                && !self.has_warned_button_macros
+                && worksheet
+                    .buttons
+                    .values()
+                    .any(|button| !button.macro_name.is_empty())
+            {
+                eprintln!(
+                    "Worksheet '{}' contains buttons with macros but the workbook doesn't \
+                     contain a VBA project. See Workbook::add_vba_project().",
+                    worksheet.name
+                );
+                self.has_warned_button_macros = true;
+            }
+
             if worksheet.has_vml {
                 let note_count = worksheet.prepare_vml_objects(vml_data_id, vml_shape_id);
                 worksheet.add_vml_drawing_rel_link(vml_drawing_id);
@@ -1720,6 +2833,11 @@ impl Workbook {
                     self.has_comments = true;
                 }
 
+                if !worksheet.threaded_comments.is_empty() {
+                    worksheet.add_threaded_comment_rel_link(threaded_comment_id);
+                    threaded_comment_id += 1;
+                }
+
                 // Each VML should start with a shape id incremented by 1024.
                 vml_data_id += (1024 + note_count) / 1024;
                 vml_shape_id += 1024 * ((1024 + note_count) / 1024);
@@ -1771,6 +2889,9 @@ impl Workbook {
     fn prepare_drawings(&mut self) {
         let mut chart_id = 1;
         let mut drawing_id = 1;
+        // The media image ids are shared by the embedded, worksheet and
+        // header/footer images and are allocated in the same order that the
+        // image files are written to the xlsx file.
         let mut image_id = self.embedded_images.len() as u32;
 
         // These are the image ids for each unique image file.
@@ -1790,16 +2911,32 @@ impl Workbook {
                 chart_id = worksheet.prepare_worksheet_charts(chart_id, drawing_id);
             }
 
-            // Increase the drawing number/id for image/chart file.
-            if !worksheet.images.is_empty() || !worksheet.charts.is_empty() {
+            if !worksheet.textboxes.is_empty() {
+                worksheet.prepare_worksheet_textboxes(drawing_id);
+            }
+
+            // Increase the drawing number/id for image/chart/text box file.
+            if !worksheet.images.is_empty()
+                || !worksheet.charts.is_empty()
+                || !worksheet.textboxes.is_empty()
+            {
                 drawing_id += 1;
             }
 
             if worksheet.has_header_footer_images() {
-                // The header/footer images are counted from the last worksheet id.
-                let base_image_id = worksheet_image_ids.len() as u32;
+                worksheet.prepare_header_footer_images(&mut header_footer_image_ids, &mut image_id);
+            }
+        }
+    }
+
+    // Set the .rel file linkages for worksheets with printer settings.
+    fn prepare_printer_settings(&mut self) {
+        let mut printer_settings_id = 1;
 
-                worksheet.prepare_header_footer_images(&mut header_footer_image_ids, base_image_id);
+        for worksheet in &mut self.worksheets {
+            if !worksheet.printer_settings.is_empty() {
+                worksheet.add_printer_settings_rel_link(printer_settings_id);
+                printer_settings_id += 1;
             }
         }
     }
@@ -1828,6 +2965,33 @@ impl Workbook {
             }
         }
 
+        // Collect the unique custom table styles for the styles.xml file.
+        // Tables can share a style by name but not have different definitions
+        // for the same name.
+        for worksheet in &self.worksheets {
+            for custom_style in worksheet
+                .tables
+                .iter()
+                .filter_map(|t| t.custom_style.as_ref())
+            {
+                match self
+                    .table_styles
+                    .iter()
+                    .find(|style| style.name.eq_ignore_ascii_case(&custom_style.name))
+                {
+                    Some(style) => {
+                        if style != custom_style {
+                            return Err(XlsxError::TableError(format!(
+                                "Custom table style '{}' has more than one definition",
+                                custom_style.name
+                            )));
+                        }
+                    }
+                    None => self.table_styles.push(custom_style.clone()),
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -1954,7 +3118,7 @@ impl Workbook {
         range: &ChartRange,
         chart_caches: &mut HashMap<(String, RowNum, ColNum, RowNum, ColNum), ChartRangeCacheData>,
     ) {
-        if range.has_data() {
+        if range.has_data() && !range.is_literal {
             chart_caches.insert(range.key(), ChartRangeCacheData::new());
         }
     }
@@ -1964,6 +3128,10 @@ impl Workbook {
         range: &mut ChartRange,
         chart_caches: &mut HashMap<(String, RowNum, ColNum, RowNum, ColNum), ChartRangeCacheData>,
     ) {
+        if range.is_literal {
+            return;
+        }
+
         if let Some(cache) = chart_caches.get(&range.key()) {
             range.cache = cache.clone();
         }
@@ -1973,6 +3141,8 @@ impl Workbook {
     // of unique formats. Also return the index for use in remapping worksheet
     // format indices.
     fn format_xf_index(&mut self, format: &Format) -> u32 {
+        let format = &self.styles_format(format);
+
         match self.xf_indices.get_mut(format) {
             Some(xf_index) => *xf_index,
             None => {
@@ -1984,6 +3154,15 @@ impl Workbook {
         }
     }
 
+    // Get a copy of a worksheet format as it is written to the styles file,
+    // with any user defined default font applied. The global formats are
+    // indexed by these copies so the worksheet formats aren't changed.
+    fn styles_format(&self, format: &Format) -> Format {
+        let mut format = format.clone();
+        format.font = format.font.styles_font(self.default_font.as_ref());
+        format
+    }
+
     fn format_dxf_index(&mut self, format: &Format) -> u32 {
         match self.dxf_indices.get_mut(format) {
             Some(dxf_index) => *dxf_index,
@@ -2175,6 +3354,7 @@ impl Workbook {
 
         package_options.is_xlsm_file = self.is_xlsm_file;
         package_options.has_vba_signature = !self.vba_signature.is_empty();
+        package_options.has_persons = !self.persons.is_empty();
 
         // Iterate over the worksheets to capture workbook and update the
         // package options metadata.
@@ -2200,6 +3380,13 @@ impl Workbook {
                 package_options.has_dynamic_functions = true;
             }
 
+            if !worksheet.value_metadata.is_empty() {
+                package_options.has_metadata = true;
+                package_options
+                    .value_metadata_types
+                    .clone_from(&self.value_metadata);
+            }
+
             if !worksheet.embedded_images.is_empty() {
                 package_options.has_metadata = true;
                 package_options.has_embedded_images = true;
@@ -2228,6 +3415,14 @@ impl Workbook {
                 package_options.num_comments += 1;
             }
 
+            if !worksheet.threaded_comments.is_empty() {
+                package_options.num_threaded_comments += 1;
+            }
+
+            if !worksheet.printer_settings.is_empty() {
+                package_options.num_printer_settings += 1;
+            }
+
             // Store the autofilter areas which are a category of defined name.
             if worksheet.autofilter_defined_name.in_use {
                 let mut defined_name = worksheet.autofilter_defined_name.clone();
@@ -2308,7 +3503,7 @@ impl Workbook {
         self.write_file_version();
 
         // Write the fileSharing element.
-        if self.read_only_mode == 2 {
+        if self.read_only_mode == 2 || self.reservation_password_hash != 0 {
             self.write_file_sharing();
         }
 
@@ -2361,7 +3556,18 @@ impl Workbook {
 
     // Write the <fileSharing> element.
     fn write_file_sharing(&mut self) {
-        let attributes = [("readOnlyRecommended", "1")];
+        let mut attributes = vec![];
+
+        if self.read_only_mode == 2 {
+            attributes.push(("readOnlyRecommended", "1".to_string()));
+        }
+
+        if self.reservation_password_hash != 0 {
+            attributes.push((
+                "reservationPassword",
+                format!("{:04X}", self.reservation_password_hash),
+            ));
+        }
 
         self.writer.xml_empty_tag("fileSharing", &attributes);
     }
@@ -2398,6 +3604,11 @@ impl Workbook {
             ("windowHeight", "9660".to_string()),
         ];
 
+        // Store the tabRatio attribute when it isn't the default.
+        if self.tab_ratio != 600 {
+            attributes.push(("tabRatio", self.tab_ratio.to_string()));
+        }
+
         // Store the firstSheet attribute when it isn't the first sheet.
         if self.first_sheet > 0 {
             let first_sheet = self.first_sheet + 1;
@@ -2417,24 +3628,27 @@ impl Workbook {
         self.writer.xml_start_tag_only("sheets");
 
         let mut worksheet_data = vec![];
-        for worksheet in &self.worksheets {
-            worksheet_data.push((worksheet.name.clone(), worksheet.visible));
+        for (index, worksheet) in self.worksheets.iter().enumerate() {
+            let sheet_id = worksheet.sheet_id.unwrap_or(index as u32 + 1);
+            worksheet_data.push((worksheet.name.clone(), worksheet.visible, sheet_id));
         }
 
         for (index, data) in worksheet_data.iter().enumerate() {
             // Write the sheet element.
-            self.write_sheet(&data.0, data.1, (index + 1) as u16);
+            self.write_sheet(&data.0, data.1, data.2, (index + 1) as u16);
         }
 
         self.writer.xml_end_tag("sheets");
     }
 
     // Write the <sheet> element.
-    fn write_sheet(&mut self, name: &str, visible: Visible, index: u16) {
-        let sheet_id = format!("{index}");
+    fn write_sheet(&mut self, name: &str, visible: Visible, sheet_id: u32, index: u16) {
         let rel_id = format!("rId{index}");
 
-        let mut attributes = vec![("name", name.to_string()), ("sheetId", sheet_id)];
+        let mut attributes = vec![
+            ("name", name.to_string()),
+            ("sheetId", sheet_id.to_string()),
+        ];
 
         match visible {
             Visible::Default => {}
@@ -2474,8 +3688,45 @@ impl Workbook {
 
     // Write the <calcPr> element.
     fn write_calc_pr(&mut self) {
-        let attributes = [("calcId", "124519"), ("fullCalcOnLoad", "1")];
+        let attributes = [
+            ("calcId", self.calc_id.to_string()),
+            ("fullCalcOnLoad", "1".to_string()),
+        ];
 
         self.writer.xml_empty_tag("calcPr", &attributes);
     }
 }
+
+// -----------------------------------------------------------------------
+// SaveProgress
+// -----------------------------------------------------------------------
+
+/// The `SaveProgress` struct holds the progress of a workbook save.
+///
+/// An xlsx file is a zip container of XML and binary files, or "parts". The
+/// `SaveProgress` struct is passed to the callback function of
+/// [`Workbook::save_with_progress()`] after each part is written so that
+/// applications can display the progress of large files.
+///
+/// The worksheet parts are written before most other parts and are generally
+/// the largest so the `worksheets_written` and `worksheet_count` fields can be
+/// used for a progress bar.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SaveProgress {
+    /// The name of the part that has just been written, for example
+    /// `xl/worksheets/sheet1.xml`.
+    pub part_name: String,
+
+    /// The number of parts written so far, including the current part.
+    pub parts_written: usize,
+
+    /// The number of uncompressed bytes written so far, for all parts.
+    pub bytes_written: u64,
+
+    /// The number of worksheet parts written so far.
+    pub worksheets_written: usize,
+
+    /// The total number of worksheets in the workbook.
+    pub worksheet_count: usize,
+}
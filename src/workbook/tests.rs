@@ -7,9 +7,14 @@
 #[cfg(test)]
 mod workbook_tests {
 
+    use crate::styles::Styles;
     use crate::{test_functions::xml_to_vec, XlsxError};
-    use crate::{Table, Workbook};
+    use crate::{
+        Button, Chart, ChartRangeCacheDataType, ChartType, Format, FormatBorder, Formula,
+        HeaderImagePosition, Image, Note, PersonId, Table, ThreadedComment, Workbook,
+    };
     use pretty_assertions::assert_eq;
+    use std::io::Read;
 
     #[test]
     fn test_assemble() {
@@ -78,7 +83,261 @@ mod workbook_tests {
         let _ = workbook.add_worksheet().set_name("foo").unwrap();
 
         let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::SheetnameReused(name)) if name == "foo"));
+    }
+
+    #[test]
+    fn blank_worksheet_name() {
+        let mut workbook = Workbook::default();
+
+        let _ = workbook.add_worksheet();
+        workbook.worksheets[0].name = String::new();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::SheetnameCannotBeBlank(_))));
+    }
+
+    #[test]
+    fn invalid_worksheet_name_keeps_existing_file() {
+        let path = std::env::temp_dir().join("rust_xlsxwriter_invalid_worksheet_name.xlsx");
+        std::fs::write(&path, b"existing").unwrap();
+
+        let mut workbook = Workbook::default();
+        let _ = workbook.add_worksheet().set_name("foo").unwrap();
+        let _ = workbook.add_worksheet().set_name("FOO").unwrap();
+
+        let result = workbook.save(&path);
         assert!(matches!(result, Err(XlsxError::SheetnameReused(_))));
+
+        // The existing file shouldn't be truncated by a validation error.
+        assert_eq!(b"existing".to_vec(), std::fs::read(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn default_font() {
+        let mut workbook = Workbook::default();
+        assert_eq!(("Calibri", 11.0), workbook.default_font());
+
+        workbook.set_default_font("Arial", 10);
+        assert_eq!(("Arial", 10.0), workbook.default_font());
+
+        let bold = Format::new().set_bold();
+        let sized = Format::new().set_font_size(14);
+        let explicit = Format::new().set_font_name("Calibri").set_font_size(11);
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_with_format(0, 0, "Bold", &bold).unwrap();
+        worksheet.write_with_format(1, 0, "Sized", &sized).unwrap();
+        worksheet
+            .write_with_format(2, 0, "Explicit", &explicit)
+            .unwrap();
+
+        workbook.save_to_buffer().unwrap();
+
+        // Formats without a font name or size use the default font.
+        let xf_formats = &workbook.xf_formats;
+        assert_eq!("Arial", xf_formats[0].font.name);
+        assert_eq!("10", xf_formats[0].font.size);
+        assert_eq!("Arial", xf_formats[1].font.name);
+        assert_eq!("10", xf_formats[1].font.size);
+        assert_eq!("Arial", xf_formats[2].font.name);
+        assert_eq!("14", xf_formats[2].font.size);
+
+        // Explicitly set font names and sizes aren't replaced. The explicit
+        // name doesn't use the theme minor font.
+        assert_eq!("Calibri", xf_formats[3].font.name);
+        assert_eq!("11", xf_formats[3].font.size);
+        assert_eq!("", xf_formats[3].font.scheme);
+
+        // The worksheet formats aren't changed.
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        assert_eq!("Calibri", worksheet.xf_formats[1].font.name);
+        assert_eq!("11", worksheet.xf_formats[1].font.size);
+
+        // Invalid fonts are ignored.
+        workbook.set_default_font("", 10);
+        workbook.set_default_font("Arial", 500);
+        assert_eq!(("Arial", 10.0), workbook.default_font());
+
+        // Explicit default font properties share the default format when
+        // there is no default font.
+        let mut workbook = Workbook::default();
+        let worksheet = workbook.add_worksheet();
+        worksheet
+            .write_with_format(0, 0, "Size", &Format::new().set_font_size(11))
+            .unwrap();
+        worksheet
+            .write_with_format(1, 0, "Name", &explicit)
+            .unwrap();
+
+        workbook.save_to_buffer().unwrap();
+        assert_eq!(1, workbook.xf_formats.len());
+    }
+
+    #[test]
+    fn chart_cache_with_booleans() {
+        let mut workbook = Workbook::default();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_column(0, 0, [true, false, true]).unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        worksheet.insert_chart(0, 2, &chart).unwrap();
+
+        workbook.save_to_buffer().unwrap();
+
+        // Booleans are treated as 1/0 numbers in the chart data cache.
+        let chart = workbook.worksheets[0].charts.values().next().unwrap();
+        let cache = &chart.series[0].value_range.cache;
+
+        assert_eq!(vec!["1", "0", "1"], cache.data);
+        assert!(cache.cache_type == ChartRangeCacheDataType::Number);
+    }
+
+    #[test]
+    fn threaded_comment_persons() {
+        let mut workbook = Workbook::default();
+
+        let jane = workbook.add_person("Jane", "jane@example.com");
+        let john = workbook.add_person("John", "");
+        assert_eq!(jane, workbook.add_person("Jane", "jane@example.com"));
+        assert_ne!(jane, john);
+        assert_eq!("John", workbook.persons[1].user_id);
+
+        let comment = ThreadedComment::new("Text")
+            .set_author(jane)
+            .add_reply(john, "Reply");
+        let worksheet = workbook.add_worksheet();
+        worksheet.insert_threaded_comment(0, 0, &comment).unwrap();
+
+        // A comment without an author is rejected on insertion.
+        let result = worksheet.insert_threaded_comment(1, 0, &ThreadedComment::new("Text"));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        assert!(workbook.save_to_buffer().is_ok());
+
+        // A reply by a person who isn't in the registry is rejected on save.
+        let comment = comment.add_reply(PersonId(2), "Reply");
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        worksheet.insert_threaded_comment(0, 0, &comment).unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn threaded_comments_with_notes() {
+        let mut workbook = Workbook::default();
+        let jane = workbook.add_person("Jane", "");
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_default_note_author("John");
+        worksheet.show_all_notes(true);
+        worksheet.insert_note(0, 0, &Note::new("Note")).unwrap();
+        worksheet
+            .insert_threaded_comment(1, 1, &ThreadedComment::new("Comment").set_author(jane))
+            .unwrap();
+
+        // Check the parts over two saves to ensure that the legacy note for the
+        // threaded comment isn't duplicated.
+        for _ in 0..2 {
+            let buffer = workbook.save_to_buffer().unwrap();
+            let mut zip = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+
+            let mut read_part = |name: &str| {
+                let mut part = String::new();
+                zip.by_name(name)
+                    .unwrap()
+                    .read_to_string(&mut part)
+                    .unwrap();
+                part
+            };
+
+            let guid = "{C0A1E175-0001-0000-0001-000000000001}";
+
+            // The note and the legacy version of the threaded comment are
+            // both in the comments file and are linked by the author and id.
+            let part = read_part("xl/comments1.xml");
+            assert_eq!(2, part.matches("<comment ").count());
+            assert!(part.contains(r#"mc:Ignorable="xr""#));
+            assert!(part.contains(&format!("<author>tc={guid}</author>")));
+            assert!(part.contains(r#"<comment ref="A1" authorId="0">"#));
+            assert!(part.contains(&format!(
+                r#"<comment ref="B2" authorId="1" shapeId="0" xr:uid="{guid}">"#
+            )));
+
+            let part = read_part("xl/threadedComments/threadedComment1.xml");
+            assert_eq!(1, part.matches("<threadedComment ").count());
+            assert!(part.contains(r#"ref="B2" dT=""#));
+            assert!(part.contains(&format!(r#"id="{guid}""#)));
+
+            // Both have a vml shape but only the note is shown.
+            let part = read_part("xl/drawings/vmlDrawing1.vml");
+            assert_eq!(
+                2,
+                part.matches("<x:ClientData ObjectType=\"Note\">").count()
+            );
+            assert_eq!(1, part.matches("<x:Visible/>").count());
+
+            let part = read_part("xl/worksheets/_rels/sheet1.xml.rels");
+            assert!(part.contains(r#"Target="../comments1.xml""#));
+            assert!(part.contains(r#"Target="../drawings/vmlDrawing1.vml""#));
+            assert!(part.contains(r#"Target="../threadedComments/threadedComment1.xml""#));
+
+            let part = read_part("xl/worksheets/sheet1.xml");
+            assert!(part.contains("<legacyDrawing r:id="));
+        }
+    }
+
+    #[test]
+    fn selected_worksheets() {
+        let mut workbook = Workbook::new();
+        for _ in 0..4 {
+            workbook.add_worksheet();
+        }
+
+        workbook.set_selected_worksheets(&[1, 2]).unwrap();
+        workbook.save_to_buffer().unwrap();
+
+        let selected: Vec<bool> = workbook.worksheets.iter().map(|ws| ws.selected).collect();
+        assert_eq!(vec![false, true, true, false], selected);
+        assert_eq!(1, workbook.active_tab);
+
+        // The active worksheet must be in the selected group.
+        let result = workbook.set_selected_worksheets(&[2, 3]);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.set_selected_worksheets(&[]);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.set_selected_worksheets(&[1, 4]);
+        assert!(matches!(
+            result,
+            Err(XlsxError::UnknownWorksheetNameOrIndex(_))
+        ));
+    }
+
+    #[test]
+    fn duplicate_vba_names() {
+        let mut workbook = Workbook::default();
+
+        // The default codename for the second worksheet is also "Sheet1".
+        workbook.add_worksheet();
+        workbook.add_worksheet().set_vba_name("Sheet1").unwrap();
+        workbook.is_xlsm_file = true;
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::VbaNameError(_))));
+
+        // VBA names are case insensitive.
+        let mut workbook = Workbook::default();
+        workbook.set_vba_name("Book").unwrap();
+        workbook.add_worksheet().set_vba_name("BOOK").unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::VbaNameError(_))));
     }
 
     #[test]
@@ -97,4 +356,518 @@ mod workbook_tests {
 
         assert!(matches!(result, Err(XlsxError::TableNameReused(_))));
     }
+
+    #[test]
+    fn shared_formats_across_worksheets() {
+        let mut workbook = Workbook::default();
+
+        // Create equivalent, but separately constructed, formats on each
+        // worksheet. They should map to a single global xf format.
+        for _ in 0..2 {
+            let header_format = Format::new().set_bold().set_border(FormatBorder::Thin);
+
+            let worksheet = workbook.add_worksheet();
+            worksheet
+                .write_with_format(0, 0, "Header", &header_format)
+                .unwrap();
+        }
+
+        workbook.save_to_buffer().unwrap();
+
+        // The default format plus the shared header format.
+        assert_eq!(2, workbook.xf_formats.len());
+        assert_eq!(2, workbook.font_count);
+        assert_eq!(2, workbook.border_count);
+
+        let mut styles = Styles::new(
+            &workbook.xf_formats,
+            &workbook.dxf_formats,
+            &workbook.table_styles,
+            workbook.font_count,
+            workbook.fill_count,
+            workbook.border_count,
+            workbook.num_formats.clone(),
+            workbook.has_hyperlink_style,
+            workbook.has_comments,
+            false,
+        );
+
+        styles.assemble_xml_file();
+
+        let got = styles.writer.read_to_str();
+        assert!(got.contains(r#"<cellXfs count="2">"#));
+
+        // Both worksheets should refer to the same global xf index.
+        for worksheet in &workbook.worksheets {
+            assert_eq!(vec![0, 1], worksheet.global_xf_indices);
+        }
+    }
+
+    #[test]
+    fn test_assemble_file_sharing() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().protect_with_password("abc123");
+        workbook.read_only_recommended();
+        workbook.set_modify_password("abc123");
+
+        workbook.assemble_xml_file();
+
+        let got = workbook.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <fileVersion appName="xl" lastEdited="4" lowestEdited="4" rupBuild="4505"/>
+              <fileSharing readOnlyRecommended="1" reservationPassword="C58F"/>
+              <workbookPr defaultThemeVersion="124226"/>
+              <bookViews>
+                <workbookView xWindow="240" yWindow="15" windowWidth="16095" windowHeight="9660"/>
+              </bookViews>
+              <sheets>
+                <sheet name="Sheet1" sheetId="1" r:id="rId1"/>
+              </sheets>
+              <calcPr calcId="124519" fullCalcOnLoad="1"/>
+            </workbook>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_tab_ratio() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet();
+        workbook.set_tab_ratio(75);
+
+        workbook.assemble_xml_file();
+
+        let got = workbook.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <fileVersion appName="xl" lastEdited="4" lowestEdited="4" rupBuild="4505"/>
+              <workbookPr defaultThemeVersion="124226"/>
+              <bookViews>
+                <workbookView xWindow="240" yWindow="15" windowWidth="16095" windowHeight="9660" tabRatio="750"/>
+              </bookViews>
+              <sheets>
+                <sheet name="Sheet1" sheetId="1" r:id="rId1"/>
+              </sheets>
+              <calcPr calcId="124519" fullCalcOnLoad="1"/>
+            </workbook>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_sheet_ids() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().set_sheet_id(10).unwrap();
+        workbook.add_worksheet();
+        workbook.add_worksheet().set_sheet_id(5).unwrap();
+
+        workbook.assemble_xml_file();
+
+        let got = workbook.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <fileVersion appName="xl" lastEdited="4" lowestEdited="4" rupBuild="4505"/>
+              <workbookPr defaultThemeVersion="124226"/>
+              <bookViews>
+                <workbookView xWindow="240" yWindow="15" windowWidth="16095" windowHeight="9660"/>
+              </bookViews>
+              <sheets>
+                <sheet name="Sheet1" sheetId="10" r:id="rId1"/>
+                <sheet name="Sheet2" sheetId="2" r:id="rId2"/>
+                <sheet name="Sheet3" sheetId="5" r:id="rId3"/>
+              </sheets>
+              <calcPr calcId="124519" fullCalcOnLoad="1"/>
+            </workbook>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn duplicate_sheet_ids() {
+        let mut workbook = Workbook::default();
+
+        // Sheet2 has the default id of 2.
+        workbook.add_worksheet().set_sheet_id(2).unwrap();
+        workbook.add_worksheet();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_worksheet().set_sheet_id(0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn printer_settings() {
+        let mut workbook = Workbook::default();
+        let data = [1, 2, 3, 4];
+
+        workbook.add_worksheet();
+        workbook.add_worksheet().set_printer_settings(&data);
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+
+        let read_part = |zip: &mut zip::ZipArchive<_>, name: &str| {
+            let mut part = vec![];
+            zip.by_name(name).unwrap().read_to_end(&mut part).unwrap();
+            part
+        };
+
+        // The data is passed through unchanged and numbered independently of
+        // the worksheet index.
+        let part = read_part(&mut zip, "xl/printerSettings/printerSettings1.bin");
+        assert_eq!(data.to_vec(), part);
+
+        let part = read_part(&mut zip, "xl/worksheets/_rels/sheet2.xml.rels");
+        let part = String::from_utf8(part).unwrap();
+        assert!(part.contains(r#"Target="../printerSettings/printerSettings1.bin""#));
+        assert!(zip.by_name("xl/worksheets/_rels/sheet1.xml.rels").is_err());
+
+        let part = read_part(&mut zip, "[Content_Types].xml");
+        let part = String::from_utf8(part).unwrap();
+        assert!(part.contains(r#"<Override PartName="/xl/printerSettings/printerSettings1.bin""#));
+    }
+
+    #[test]
+    fn full_calc_on_load() {
+        let mut workbook = Workbook::default();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_column(0, 0, [1, 2, 3]).unwrap();
+        worksheet.write_formula(3, 0, "=SUM(A1:A3)").unwrap();
+        worksheet.set_full_calc_on_load(true);
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$4");
+        worksheet.insert_chart(0, 2, &chart).unwrap();
+
+        workbook
+            .add_worksheet()
+            .write_formula(0, 0, "=1+1")
+            .unwrap();
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+
+        let read_part = |zip: &mut zip::ZipArchive<_>, name: &str| {
+            let mut part = String::new();
+            zip.by_name(name)
+                .unwrap()
+                .read_to_string(&mut part)
+                .unwrap();
+            part
+        };
+
+        // The worksheet flag is written after the cell data.
+        let part = read_part(&mut zip, "xl/worksheets/sheet1.xml");
+        assert!(part.contains(r#"</sheetData><sheetCalcPr fullCalcOnLoad="1"/>"#));
+        assert!(part.contains("<drawing r:id="));
+
+        let part = read_part(&mut zip, "xl/worksheets/sheet2.xml");
+        assert!(!part.contains("sheetCalcPr"));
+
+        // The workbook flag is always written.
+        let part = read_part(&mut zip, "xl/workbook.xml");
+        assert!(part.contains(r#"fullCalcOnLoad="1""#));
+    }
+
+    #[test]
+    fn test_assemble_calc_id() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet();
+        workbook.set_calc_id(0);
+
+        workbook.assemble_xml_file();
+
+        let got = workbook.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <fileVersion appName="xl" lastEdited="4" lowestEdited="4" rupBuild="4505"/>
+              <workbookPr defaultThemeVersion="124226"/>
+              <bookViews>
+                <workbookView xWindow="240" yWindow="15" windowWidth="16095" windowHeight="9660"/>
+              </bookViews>
+              <sheets>
+                <sheet name="Sheet1" sheetId="1" r:id="rId1"/>
+              </sheets>
+              <calcPr calcId="0" fullCalcOnLoad="1"/>
+            </workbook>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn image_media_numbering() {
+        let mut workbook = Workbook::new();
+        let red = Image::new("tests/input/images/red.png").unwrap();
+        let blue = Image::new("tests/input/images/blue.png").unwrap();
+
+        // A header image on the first worksheet and a different image in the
+        // second worksheet should be written to separate media files.
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_header("&L&[Picture]");
+        worksheet
+            .set_header_image(&red, HeaderImagePosition::Left)
+            .unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.insert_image(0, 0, &blue).unwrap();
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+
+        let read_part = |zip: &mut zip::ZipArchive<_>, name: &str| {
+            let mut part = vec![];
+            zip.by_name(name).unwrap().read_to_end(&mut part).unwrap();
+            part
+        };
+
+        let part = read_part(&mut zip, "xl/drawings/_rels/vmlDrawing1.vml.rels");
+        let part = String::from_utf8(part).unwrap();
+        assert!(part.contains(r#"Target="../media/image1.png""#));
+
+        let part = read_part(&mut zip, "xl/drawings/_rels/drawing1.xml.rels");
+        let part = String::from_utf8(part).unwrap();
+        assert!(part.contains(r#"Target="../media/image2.png""#));
+
+        let part = read_part(&mut zip, "xl/media/image2.png");
+        assert_eq!(std::fs::read("tests/input/images/blue.png").unwrap(), part);
+    }
+
+    #[test]
+    fn image_deduplication() {
+        let logo = Image::new("tests/input/images/logo.png").unwrap();
+
+        let save_with_logo = |num_worksheets: usize| {
+            let mut workbook = Workbook::new();
+            for _ in 0..num_worksheets {
+                workbook.add_worksheet().insert_image(0, 0, &logo).unwrap();
+            }
+            for _ in num_worksheets..20 {
+                workbook.add_worksheet();
+            }
+            workbook.save_to_buffer().unwrap()
+        };
+
+        let single = save_with_logo(1);
+        let shared = save_with_logo(20);
+
+        // The image is only stored once in the file.
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(shared.clone())).unwrap();
+        let media_count = (0..zip.len())
+            .filter(|&i| zip.by_index(i).unwrap().name().starts_with("xl/media/"))
+            .count();
+        assert_eq!(1, media_count);
+
+        // The extra size is only the drawing parts and not 19 copies of the
+        // image data.
+        let image_size = std::fs::metadata("tests/input/images/logo.png")
+            .unwrap()
+            .len() as usize;
+        assert!(shared.len() - single.len() < 19 * image_size / 2);
+    }
+
+    #[test]
+    fn note_colors() {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        let note = Note::new("Foo")
+            .set_background_color("#1F4E78")
+            .set_font_color("#FFFFFF")
+            .set_font_name("Arial")
+            .set_font_size(10);
+        worksheet.insert_note(0, 0, &note).unwrap();
+        worksheet.insert_note(1, 0, &Note::new("Bar")).unwrap();
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+
+        let read_part = |zip: &mut zip::ZipArchive<_>, name: &str| {
+            let mut part = String::new();
+            zip.by_name(name)
+                .unwrap()
+                .read_to_string(&mut part)
+                .unwrap();
+            part
+        };
+
+        // The font properties are written to the text runs.
+        let part = read_part(&mut zip, "xl/comments1.xml");
+        assert!(part.contains(
+            r#"<rPr><sz val="10"/><color rgb="FFFFFFFF"/><rFont val="Arial"/><family val="2"/></rPr>"#
+        ));
+        assert!(part.contains(
+            r#"<rPr><sz val="8"/><color indexed="81"/><rFont val="Tahoma"/><family val="2"/></rPr>"#
+        ));
+
+        // The fill color is written to the VML shape.
+        let part = read_part(&mut zip, "xl/drawings/vmlDrawing1.vml");
+        assert!(part.contains(r##"fillcolor="#1f4e78""##));
+        assert!(part.contains(r##"fillcolor="#ffffe1""##));
+    }
+
+    #[test]
+    fn button_macro_warning() {
+        // Buttons without a user defined macro don't need a VBA project.
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.insert_button(0, 0, &Button::new()).unwrap();
+
+        workbook.save_to_buffer().unwrap();
+        assert!(!workbook.has_warned_button_macros);
+
+        // Buttons with a macro but without a VBA project are warned about.
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        let button = Button::new().set_macro("say_hello");
+        worksheet.insert_button(0, 0, &button).unwrap();
+
+        workbook.save_to_buffer().unwrap();
+        assert!(workbook.has_warned_button_macros);
+    }
+
+    #[test]
+    fn value_metadata() {
+        let mut workbook = Workbook::default();
+
+        let stock = workbook.add_value_metadata("XLSTOCK");
+        let geography = workbook.add_value_metadata("XLGEOGRAPHY");
+        assert_eq!(stock, workbook.add_value_metadata("XLSTOCK"));
+
+        let image = Image::new("tests/input/images/red.png").unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.embed_image(0, 0, &image).unwrap();
+        worksheet.write_with_metadata(1, 0, "MSFT", stock).unwrap();
+        worksheet.write_with_metadata(2, 0, 42, geography).unwrap();
+        worksheet.write_with_metadata(3, 0, true, stock).unwrap();
+
+        // Rewriting the cell removes the metadata.
+        worksheet
+            .write_with_metadata(4, 0, "Paris", geography)
+            .unwrap();
+        worksheet.write(4, 0, "Paris").unwrap();
+
+        let result = worksheet.write_with_metadata(5, 0, "", stock);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Unsupported cell types are rejected before any data is written.
+        let formula = Formula::new("=FILTER(A1:A4,A1:A4>1)");
+        let result = worksheet.write_with_metadata(6, 0, formula, stock);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        assert!(!worksheet.has_dynamic_arrays);
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+
+        let read_part = |zip: &mut zip::ZipArchive<_>, name: &str| {
+            let mut part = String::new();
+            zip.by_name(name)
+                .unwrap()
+                .read_to_string(&mut part)
+                .unwrap();
+            part
+        };
+
+        // The user metadata follows the embedded image metadata.
+        let part = read_part(&mut zip, "xl/worksheets/sheet1.xml");
+        assert!(part.contains(r#"<c r="A1" t="e" vm="1"><v>#VALUE!</v></c>"#));
+        assert!(part.contains(r#"<c r="A2" t="s" vm="2"><v>0</v></c>"#));
+        assert!(part.contains(r#"<c r="A3" vm="3"><v>42</v></c>"#));
+        assert!(part.contains(r#"<c r="A4" t="b" vm="2"><v>1</v></c>"#));
+        assert!(part.contains(r#"<c r="A5" t="s"><v>1</v></c>"#));
+
+        let got = read_part(&mut zip, "xl/metadata.xml");
+        let got = xml_to_vec(&got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <metadata xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:xlrd="http://schemas.microsoft.com/office/spreadsheetml/2017/richdata">
+              <metadataTypes count="3">
+                <metadataType name="XLRICHVALUE" minSupportedVersion="120000" copy="1" pasteAll="1" pasteValues="1" merge="1" splitFirst="1" rowColShift="1" clearFormats="1" clearComments="1" assign="1" coerce="1"/>
+                <metadataType name="XLSTOCK" minSupportedVersion="120000" copy="1" pasteAll="1" pasteValues="1" merge="1" splitFirst="1" rowColShift="1" clearFormats="1" clearComments="1" assign="1" coerce="1"/>
+                <metadataType name="XLGEOGRAPHY" minSupportedVersion="120000" copy="1" pasteAll="1" pasteValues="1" merge="1" splitFirst="1" rowColShift="1" clearFormats="1" clearComments="1" assign="1" coerce="1"/>
+              </metadataTypes>
+              <futureMetadata name="XLRICHVALUE" count="1">
+                <bk>
+                  <extLst>
+                    <ext uri="{3e2802c4-a4d2-4d8b-9148-e3be6c30e623}">
+                      <xlrd:rvb i="0"/>
+                    </ext>
+                  </extLst>
+                </bk>
+              </futureMetadata>
+              <valueMetadata count="3">
+                <bk>
+                  <rc t="1" v="0"/>
+                </bk>
+                <bk>
+                  <rc t="2" v="0"/>
+                </bk>
+                <bk>
+                  <rc t="3" v="0"/>
+                </bk>
+              </valueMetadata>
+            </metadata>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // The metadata file is linked from the workbook.
+        let part = read_part(&mut zip, "xl/_rels/workbook.xml.rels");
+        assert!(part.contains(r#"Target="metadata.xml""#));
+
+        let part = read_part(&mut zip, "[Content_Types].xml");
+        assert!(part.contains(r#"PartName="/xl/metadata.xml""#));
+
+        // Stale metadata for cells without a value doesn't create the metadata
+        // file.
+        let mut workbook = Workbook::default();
+        let stock = workbook.add_value_metadata("XLSTOCK");
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_with_metadata(0, 0, "MSFT", stock).unwrap();
+        worksheet.value_metadata.insert((1, 0), stock);
+        worksheet.clear_cell(0, 0);
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(buffer)).unwrap();
+        assert!(zip.by_name("xl/metadata.xml").is_err());
+
+        // Indices that aren't in the registry are an error.
+        let mut workbook = Workbook::default();
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_with_metadata(0, 0, "MSFT", 1).unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
 }
@@ -1192,7 +1192,7 @@ mod tests;
 use std::borrow::Cow;
 use std::cmp;
 use std::collections::btree_map::Entry;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::io::Write;
 use std::mem;
 use std::sync::Arc;
@@ -1200,29 +1200,38 @@ use std::sync::Arc;
 #[cfg(feature = "chrono")]
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
+#[cfg(feature = "ndarray")]
+use ndarray::{ArrayBase, Data, Ix2};
+
+#[cfg(feature = "polars")]
+use polars::prelude::{DataFrame, DataType, TimeUnit};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "serde")]
 use crate::{
-    deserialize_headers, serializer::SerializerState, CustomSerializeField,
-    SerializationHeaderConfig, SerializeFieldOptions, SerializerHeader, TableData, XlsxSerialize,
+    deserialize_headers, serializer::SerializerState, serializer::MAP_STRUCT_NAME,
+    CustomSerializeField, SerializationHeaderConfig, SerializeFieldOptions, SerializedValue,
+    SerializerHeader, TableData, XlsxSerialize,
 };
 
+use crate::datetime::DAY_SECONDS;
 use crate::drawing::{Drawing, DrawingCoordinates, DrawingInfo, DrawingObject};
 use crate::error::XlsxError;
 use crate::format::Format;
-use crate::formula::Formula;
+use crate::formula::{Formula, FormulaLocale, FormulaResult, FormulaResultType};
 use crate::shared_strings_table::SharedStringsTable;
 use crate::styles::Styles;
 use crate::vml::VmlInfo;
 use crate::xmlwriter::{XMLWriter, XML_WRITE_ERROR};
 use crate::{
-    utility, Button, Chart, ChartEmptyCells, ChartRangeCacheData, ChartRangeCacheDataType, Color,
-    ConditionalFormat, DataValidation, DataValidationErrorStyle, DataValidationRuleInternal,
-    DataValidationType, ExcelDateTime, FilterCondition, FilterCriteria, FilterData, FilterDataType,
-    HeaderImagePosition, HyperlinkType, Image, IntoExcelDateTime, Note, ObjectMovement,
-    ProtectionOptions, Sparkline, SparklineType, Table, TableFunction, Url,
+    utility, Button, CellBuilder, Chart, ChartEmptyCells, ChartRangeCacheData,
+    ChartRangeCacheDataType, Color, ConditionalFormat, DataValidation, DataValidationErrorStyle,
+    DataValidationRuleInternal, DataValidationType, ExcelDateTime, FilterCondition, FilterCriteria,
+    FilterData, FilterDataType, HeaderImagePosition, HyperlinkType, Image, IntoExcelDateTime, Note,
+    ObjectMovement, ProtectionOptions, Sparkline, SparklineType, Table, TableFunction,
+    TextBoxOptions, ThreadedComment, Url,
 };
 
 /// Integer type to represent a zero indexed row number. Excel's limit for rows
@@ -1336,6 +1345,9 @@ pub struct Worksheet {
     pub(crate) drawing_object_relationships: Vec<(String, String, String)>,
     pub(crate) drawing_relationships: Vec<(String, String, String)>,
     pub(crate) comment_relationships: Vec<(String, String, String)>,
+    pub(crate) threaded_comment_relationships: Vec<(String, String, String)>,
+    pub(crate) printer_settings_relationships: Vec<(String, String, String)>,
+    pub(crate) printer_settings: Vec<u8>,
     pub(crate) vml_drawing_relationships: Vec<(String, String, String)>,
     pub(crate) images: BTreeMap<(RowNum, ColNum), Image>,
     pub(crate) buttons_vml_info: Vec<VmlInfo>,
@@ -1345,13 +1357,19 @@ pub struct Worksheet {
     pub(crate) image_types: [bool; NUM_IMAGE_FORMATS],
     pub(crate) header_footer_images: [Option<Image>; 6],
     pub(crate) charts: BTreeMap<(RowNum, ColNum), Chart>,
+    pub(crate) textboxes: BTreeMap<(RowNum, ColNum), TextBoxOptions>,
     pub(crate) buttons: BTreeMap<(RowNum, ColNum), Button>,
     pub(crate) notes: BTreeMap<RowNum, BTreeMap<ColNum, Note>>,
+    pub(crate) threaded_comments: BTreeMap<(RowNum, ColNum), ThreadedComment>,
     pub(crate) tables: Vec<Table>,
     pub(crate) has_embedded_image_descriptions: bool,
     pub(crate) embedded_images: Vec<Image>,
     pub(crate) global_embedded_image_indices: Vec<u32>,
+    pub(crate) value_metadata: BTreeMap<(RowNum, ColNum), u32>,
+    pub(crate) value_metadata_offset: u32,
+    value_metadata_pending: Option<(RowNum, ColNum, u32)>,
     pub(crate) vba_codename: Option<String>,
+    pub(crate) sheet_id: Option<u32>,
     pub(crate) note_authors: BTreeMap<String, usize>,
     pub(crate) vml_data_id: String,
     pub(crate) vml_shape_id: u32,
@@ -1365,12 +1383,16 @@ pub struct Worksheet {
     dimensions: CellRange,
     xf_indices: HashMap<Format, u32>,
     dxf_indices: HashMap<Format, u32>,
-    global_xf_indices: Vec<u32>,
+    pub(crate) global_xf_indices: Vec<u32>,
     global_dxf_indices: Vec<u32>,
     changed_rows: HashMap<RowNum, RowOptions>,
     changed_cols: HashMap<ColNum, ColOptions>,
     page_setup_changed: bool,
     tab_color: Color,
+    outline_symbols_below: bool,
+    outline_symbols_right: bool,
+    outline_col_level: u8,
+    collapsed_column_groups: Vec<(ColNum, ColNum, u8)>,
     fit_to_page: bool,
     fit_width: u16,
     fit_height: u16,
@@ -1385,6 +1407,7 @@ pub struct Worksheet {
     center_horizontally: bool,
     center_vertically: bool,
     screen_gridlines: bool,
+    screen_headings: bool,
     print_gridlines: bool,
     print_black_and_white: bool,
     print_draft: bool,
@@ -1402,6 +1425,8 @@ pub struct Worksheet {
     margin_footer: f64,
     first_page_number: u16,
     default_result: Box<str>,
+    full_calc_on_load: bool,
+    share_hyperlink_relationships: bool,
     panes: Panes,
     hyperlinks: BTreeMap<(RowNum, ColNum), Url>,
     rel_count: u32,
@@ -1413,11 +1438,14 @@ pub struct Worksheet {
     top_left_cell: String,
     horizontal_breaks: Vec<u32>,
     vertical_breaks: Vec<u32>,
+    ignored_errors: BTreeMap<IgnoreError, Vec<String>>,
     filter_conditions: BTreeMap<ColNum, FilterCondition>,
     filter_automatic_off: bool,
     has_drawing_object_linkage: bool,
     cells_with_autofilter: HashSet<(RowNum, ColNum)>,
+    pub(crate) default_font_size: f64,
     conditional_formats: BTreeMap<String, Vec<Box<dyn ConditionalFormat + Send>>>,
+    conditional_format_order: Vec<(String, usize)>,
     data_validations: BTreeMap<String, DataValidation>,
     has_conditional_formats: bool,
     use_x14_extensions: bool,
@@ -1426,7 +1454,9 @@ pub struct Worksheet {
     sparklines: Vec<Sparkline>,
     embedded_image_ids: HashMap<String, u32>,
     show_all_notes: bool,
+    autosize_all_notes: bool,
     user_default_row_height: f64,
+    user_default_col_width: f64,
     hide_unused_rows: bool,
 
     #[cfg(feature = "serde")]
@@ -1519,6 +1549,7 @@ impl Worksheet {
         let panes = Panes {
             freeze_cell: (0, 0),
             top_cell: (0, 0),
+            is_split: false,
         };
 
         Worksheet {
@@ -1555,6 +1586,10 @@ impl Worksheet {
             page_setup_changed: false,
             fit_to_page: false,
             tab_color: Color::Default,
+            outline_symbols_below: true,
+            outline_symbols_right: true,
+            outline_col_level: 0,
+            collapsed_column_groups: vec![],
             fit_width: 1,
             fit_height: 1,
             paper_size: 0,
@@ -1568,6 +1603,7 @@ impl Worksheet {
             center_horizontally: false,
             center_vertically: false,
             screen_gridlines: true,
+            screen_headings: true,
             print_gridlines: false,
             print_black_and_white: false,
             print_draft: false,
@@ -1585,6 +1621,8 @@ impl Worksheet {
             margin_footer: 0.3,
             first_page_number: 0,
             default_result: Box::from("0"),
+            full_calc_on_load: false,
+            share_hyperlink_relationships: false,
             panes,
             has_hyperlink_style: false,
             hyperlinks: BTreeMap::new(),
@@ -1593,6 +1631,9 @@ impl Worksheet {
             drawing_object_relationships: vec![],
             drawing_relationships: vec![],
             comment_relationships: vec![],
+            threaded_comment_relationships: vec![],
+            printer_settings_relationships: vec![],
+            printer_settings: vec![],
             vml_drawing_relationships: vec![],
             images: BTreeMap::new(),
             drawing: Drawing::new(),
@@ -1610,14 +1651,19 @@ impl Worksheet {
             top_left_cell: String::new(),
             horizontal_breaks: vec![],
             vertical_breaks: vec![],
+            ignored_errors: BTreeMap::new(),
             filter_conditions: BTreeMap::new(),
             filter_automatic_off: false,
             charts: BTreeMap::new(),
+            textboxes: BTreeMap::new(),
             buttons: BTreeMap::new(),
             notes: BTreeMap::new(),
+            threaded_comments: BTreeMap::new(),
             has_drawing_object_linkage: false,
             cells_with_autofilter: HashSet::new(),
+            default_font_size: 11.0,
             conditional_formats: BTreeMap::new(),
+            conditional_format_order: vec![],
             data_validations: BTreeMap::new(),
             has_conditional_formats: false,
             use_x14_extensions: false,
@@ -1625,15 +1671,21 @@ impl Worksheet {
             embedded_images: vec![],
             embedded_image_ids: HashMap::new(),
             global_embedded_image_indices: vec![],
+            value_metadata: BTreeMap::new(),
+            value_metadata_offset: 0,
+            value_metadata_pending: None,
             has_embedded_image_descriptions: false,
             has_sparklines: false,
             sparklines: vec![],
             vba_codename: None,
+            sheet_id: None,
             note_authors: BTreeMap::from([("Author".to_string(), 0)]),
             show_all_notes: false,
+            autosize_all_notes: false,
             vml_data_id: String::new(),
             vml_shape_id: 0,
             user_default_row_height: DEFAULT_ROW_HEIGHT,
+            user_default_col_width: DEFAULT_COL_WIDTH,
             hide_unused_rows: false,
 
             #[cfg(feature = "serde")]
@@ -1876,6 +1928,206 @@ impl Worksheet {
         data.write_with_format(self, row, col, format)
     }
 
+    /// Write generic data with value metadata to a cell.
+    ///
+    /// The `write_with_metadata()` method writes data that implements
+    /// [`IntoExcelData`] to a worksheet cell, like [`Worksheet::write()`], and
+    /// links the cell value to an entry in the workbook value metadata
+    /// registry. The link is stored as the `vm` cell attribute and the
+    /// registry is written to the `xl/metadata.xml` file.
+    ///
+    /// The metadata index is the value returned by
+    /// [`Workbook::add_value_metadata()`](crate::Workbook::add_value_metadata).
+    /// It must be in the registry when the workbook is saved, otherwise
+    /// [`Workbook::save()`](crate::Workbook::save) will return an
+    /// [`XlsxError::ParameterError`].
+    ///
+    /// Only value metadata is supported. The library doesn't generate the rich
+    /// value data used by Excel "linked data types" so the metadata is
+    /// intended for downstream tools that read the `vm` attribute.
+    ///
+    /// Writing or clearing the cell again removes the metadata link.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data`: An type that implements the  [`IntoExcelData`] trait.
+    /// - `metadata_index`: The index of the entry in the workbook value
+    ///   metadata registry.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The data doesn't write a value to the
+    ///   cell, such as an empty string, or it is written as a blank cell, an
+    ///   array formula or an embedded image, which don't support value
+    ///   metadata. The data isn't written in these cases.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing cells with value metadata.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_with_metadata.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add an entry to the workbook value metadata registry.
+    ///     let stock = workbook.add_value_metadata("XLSTOCK");
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_with_metadata(0, 0, "MSFT", stock)?;
+    ///     worksheet.write_with_metadata(1, 0, "AAPL", stock)?;
+    ///
+    ///     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_with_metadata(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: impl IntoExcelData,
+        metadata_index: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // The metadata is linked to the cell when the value is stored. Cell
+        // types that don't support value metadata are rejected before they are
+        // stored.
+        self.value_metadata_pending = Some((row, col, metadata_index));
+        let result = data.write(self, row, col).map(|_| ());
+        self.value_metadata_pending = None;
+        result?;
+
+        if !self.value_metadata.contains_key(&(row, col)) {
+            return Err(XlsxError::ParameterError(format!(
+                "No value was written to cell {} for the value metadata.",
+                utility::row_col_to_cell(row, col)
+            )));
+        }
+
+        Ok(self)
+    }
+
+    /// Write a cell value together with its format, note and hyperlink.
+    ///
+    /// The `write_cell()` method writes a [`CellBuilder`] to a worksheet cell.
+    /// The `CellBuilder` bundles a cell value with an optional [`Format`],
+    /// [`Note`] and [`Url`] so that they are applied together. This avoids
+    /// issues where the order of separate calls matters, such as a
+    /// [`Worksheet::write()`] replacing a format set with
+    /// [`Worksheet::set_cell_format()`].
+    ///
+    /// The properties of the cell are checked before any data is written so
+    /// that the cell is left unchanged if there is an error.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `cell`: The [`CellBuilder`] with the cell value and properties.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String or note text exceeds
+    ///   Excel's limit of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a cell with a value, format,
+    /// note and hyperlink in a single call.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_cell.rs
+    /// #
+    /// # use rust_xlsxwriter::{CellBuilder, Format, Note, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format = Format::new().set_num_format("0.00%");
+    ///
+    ///     // Write a number with a format and a note.
+    ///     let cell = CellBuilder::new(0.25)
+    ///         .set_format(&format)
+    ///         .set_note(&Note::new("Target growth rate"));
+    ///
+    ///     worksheet.write_cell(0, 0, &cell)?;
+    ///
+    ///     // Write a string with a hyperlink to another worksheet.
+    ///     let cell = CellBuilder::new("Summary").set_url("internal:Sheet1!A1")?;
+    ///
+    ///     worksheet.write_cell(1, 0, &cell)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_cell<T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        cell: &CellBuilder<T>,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: IntoExcelData + Clone,
+    {
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check the note length before writing any data.
+        if let Some(note) = &cell.note {
+            if note.text.chars().count() > MAX_STRING_LEN - 54 {
+                return Err(XlsxError::MaxStringLengthExceeded);
+            }
+        }
+
+        // Hyperlinks without a user format use the default hyperlink format.
+        let format = match (&cell.format, &cell.url) {
+            (Some(format), _) => Some(format.clone()),
+            (None, Some(_)) => Some(Format::new().set_hyperlink()),
+            (None, None) => None,
+        };
+
+        match &format {
+            Some(format) => cell
+                .value
+                .clone()
+                .write_with_format(self, row, col, format)?,
+            None => cell.value.clone().write(self, row, col)?,
+        };
+
+        if let Some(url) = &cell.url {
+            self.hyperlinks.insert((row, col), url.clone());
+        }
+
+        if let Some(note) = &cell.note {
+            self.insert_note(row, col, note)?;
+        }
+
+        Ok(self)
+    }
+
     /// Write an array like data structure as a row of data to a worksheet.
     ///
     /// Write an array of data horizontally rightwards starting from the initial
@@ -2004,10 +2256,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
+        for (col, item) in (col..).zip(data) {
             self.write(row, col, item)?;
-            col += 1;
         }
 
         Ok(self)
@@ -2048,15 +2298,86 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
+        for (col, item) in (col..).zip(data) {
             self.write_with_format(row, col, item, format)?;
-            col += 1;
         }
 
         Ok(self)
     }
 
+    /// Write a row of data from an iterator and return the last column
+    /// written.
+    ///
+    /// This method is similar to [`Worksheet::write_row()`] except that it
+    /// returns the zero indexed number of the last column that was written,
+    /// or `None` if the iterator was empty. The data is written as it is
+    /// consumed from the iterator so it can be used with lazy data sources,
+    /// such as iterator adaptor chains, without collecting the data into a
+    /// `Vec` first. The returned column can be used to continue writing data
+    /// to the right of the row.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data`: An iterator, or a type that implements [`IntoIterator`], that
+    ///   contains a data type that implements [`IntoExcelData`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a row of data from a lazy
+    /// iterator and then adding a total to the right of the data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_row_iter.rs
+    /// #
+    /// # use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write the squares of some numbers without collecting them.
+    ///     let squares = (1..=5).map(|n| n * n);
+    ///
+    ///     if let Some(last_col) = worksheet.write_row_iter(0, 0, squares)? {
+    ///         // Add a total after the last column.
+    ///         worksheet.write(0, last_col + 1, Formula::new("=SUM(A1:E1)"))?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_row_iter<I>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: I,
+    ) -> Result<Option<ColNum>, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: IntoExcelData,
+    {
+        let mut last_col = None;
+
+        for (col, item) in (col..).zip(data) {
+            self.write(row, col, item)?;
+            last_col = Some(col);
+        }
+
+        Ok(last_col)
+    }
+
     /// Write an array like data structure as a column of data to a worksheet.
     ///
     /// Write an array of data vertically downwards starting from the initial
@@ -2128,10 +2449,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
+        for (row, item) in (row..).zip(data) {
             self.write(row, col, item)?;
-            row += 1;
         }
 
         Ok(self)
@@ -2172,26 +2491,31 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
+        for (row, item) in (row..).zip(data) {
             self.write_with_format(row, col, item, format)?;
-            row += 1;
         }
 
         Ok(self)
     }
 
-    /// Write an array of row arrays to a worksheet.
+    /// Write a fixed size array of data horizontally rightwards starting from
+    /// the initial `row, col` cell.
     ///
-    /// Write an array of row arrays vertically downwards starting from the
-    /// initial `row, col` cell.
+    /// This is similar to [`Worksheet::write_row()`] but it is restricted to
+    /// fixed size arrays of type `[T; N]`. Since the array length is known the
+    /// range of the data is checked before any of the elements are written so
+    /// that an array that would extend past the worksheet limits isn't
+    /// partially written.
     ///
-    /// This methods works for 2D arrays or array-like data structures that
-    /// implement [`IntoIterator`] and that contain a data type that implements
-    /// [`IntoExcelData`].
+    /// See also [`Worksheet::write_array_column()`] for a similar function that
+    /// works in an orthogonal direction.
     ///
-    /// See also [`Worksheet::write_column_matrix()`] for a similar function
-    /// that works in an orthogonal direction.
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data`: A fixed size array of a data type that implements
+    ///   [`IntoExcelData`].
     ///
     /// # Errors
     ///
@@ -2200,21 +2524,13 @@ impl Worksheet {
     /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
     ///   of 32,767 characters.
     ///
-    /// # Parameters
-    ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `data`: 2D arrays or array-like data structures that implement
-    ///   [`IntoIterator`] and that contain a data type that implements
-    ///   [`IntoExcelData`].
-    ///
     /// # Examples
     ///
-    /// The following example demonstrates writing an array of row arrays to a
-    /// worksheet.
+    /// The following example demonstrates writing fixed size arrays of data as
+    /// a row and as a column.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_write_row_matrix.rs
+    /// # // This code is available in examples/doc_worksheet_write_array_row.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -2225,15 +2541,12 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     ///     let worksheet = workbook.add_worksheet();
     ///
-    ///     // Some array data to write.
-    ///     let data = [
-    ///         [10, 11, 12, 13, 14],
-    ///         [20, 21, 22, 23, 24],
-    ///         [30, 31, 32, 33, 34],
-    ///     ];
+    ///     // Some fixed size array data to write.
+    ///     let data: [f64; 4] = [1.5, 2.5, 3.5, 4.5];
     ///
-    ///     // Write the array data as a series of rows.
-    ///     worksheet.write_row_matrix(0, 0, data)?;
+    ///     // Write the array data as a row and as a column.
+    ///     worksheet.write_array_row(0, 0, data)?;
+    ///     worksheet.write_array_column(2, 0, data)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -2241,42 +2554,77 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
+    pub fn write_array_row<T, const N: usize>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: [T; N],
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: IntoExcelData,
+    {
+        // Check the range of the entire array before writing any data.
+        if N > 0 && (row >= ROW_MAX || usize::from(col) + N > usize::from(COL_MAX)) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        self.write_row(row, col, data)
+    }
+
+    /// Write a fixed size array of data vertically downwards starting from the
+    /// initial `row, col` cell.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_row_matrix.png">
+    /// This is similar to [`Worksheet::write_column()`] but it is restricted to
+    /// fixed size arrays of type `[T; N]`. Since the array length is known the
+    /// range of the data is checked before any of the elements are written so
+    /// that an array that would extend past the worksheet limits isn't
+    /// partially written.
     ///
-    pub fn write_row_matrix<I, II>(
+    /// See also [`Worksheet::write_array_row()`] for a similar function that
+    /// works in an orthogonal direction.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data`: A fixed size array of a data type that implements
+    ///   [`IntoExcelData`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    pub fn write_array_column<T, const N: usize>(
         &mut self,
         row: RowNum,
         col: ColNum,
-        data: I,
+        data: [T; N],
     ) -> Result<&mut Worksheet, XlsxError>
     where
-        I: IntoIterator,
-        I::Item: IntoIterator<Item = II>,
-        II: IntoExcelData,
+        T: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
-            self.write_row(row, col, item)?;
-            row += 1;
+        // Check the range of the entire array before writing any data.
+        if N > 0 && (col >= COL_MAX || u64::from(row) + N as u64 > u64::from(ROW_MAX)) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        Ok(self)
+        self.write_column(row, col, data)
     }
 
-    /// Write an array of column arrays to a worksheet.
+    /// Write an array of row arrays to a worksheet.
     ///
-    /// Write an array of column arrays horizontally rightwards starting from
-    /// the initial `row, col` cell.
+    /// Write an array of row arrays vertically downwards starting from the
+    /// initial `row, col` cell.
     ///
     /// This methods works for 2D arrays or array-like data structures that
     /// implement [`IntoIterator`] and that contain a data type that implements
     /// [`IntoExcelData`].
     ///
-    /// See also [`Worksheet::write_row_matrix()`] for a similar function that
-    /// works in an orthogonal direction.
+    /// See also [`Worksheet::write_column_matrix()`] for a similar function
+    /// that works in an orthogonal direction.
     ///
     /// # Errors
     ///
@@ -2295,11 +2643,11 @@ impl Worksheet {
     ///
     /// # Examples
     ///
-    /// The following example demonstrates writing an array of column arrays to
-    /// a worksheet.
+    /// The following example demonstrates writing an array of row arrays to a
+    /// worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_write_column_matrix.rs
+    /// # // This code is available in examples/doc_worksheet_write_row_matrix.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -2317,9 +2665,184 @@ impl Worksheet {
     ///         [30, 31, 32, 33, 34],
     ///     ];
     ///
-    ///     // Write the array data as a series of columns.
-    ///     worksheet.write_column_matrix(0, 0, data)?;
-    /// #
+    ///     // Write the array data as a series of rows.
+    ///     worksheet.write_row_matrix(0, 0, data)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_row_matrix.png">
+    ///
+    pub fn write_row_matrix<I, II>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: I,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator<Item = II>,
+        II: IntoExcelData,
+    {
+        for (row, item) in (row..).zip(data) {
+            self.write_row(row, col, item)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write an array of row arrays to a worksheet with formatting.
+    ///
+    /// Write an array of row arrays vertically downwards starting from the
+    /// initial `row, col` cell, with formats applied either per column or per
+    /// cell as defined by the [`MatrixFormats`] enum.
+    ///
+    /// This is the formatted version of [`Worksheet::write_row_matrix()`]. It
+    /// avoids having to iterate over the data and formats in nested loops to
+    /// write a styled grid of data.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data`: 2D arrays or array-like data structures that implement
+    ///   [`IntoIterator`] and that contain a data type that implements
+    ///   [`IntoExcelData`].
+    /// - `formats`: The formats to apply to the data as a
+    ///   [`MatrixFormats::PerColumn`] or [`MatrixFormats::PerCell`] value.
+    ///   Cells without a corresponding format are written unformatted.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing an array of row arrays to a
+    /// worksheet with formats for each column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_row_matrix_with_formats.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, MatrixFormats, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Some array data to write.
+    ///     let data = [
+    ///         [10.0, 0.11, 1200.0],
+    ///         [20.0, 0.21, 2400.0],
+    ///         [30.0, 0.31, 3600.0],
+    ///     ];
+    ///
+    ///     // Some formats for each column.
+    ///     let formats = [
+    ///         Format::new().set_bold(),
+    ///         Format::new().set_num_format("0%"),
+    ///         Format::new().set_num_format("$#,##0.00"),
+    ///     ];
+    ///
+    ///     // Write the array data as a series of formatted rows.
+    ///     worksheet.write_row_matrix_with_formats(0, 0, data, MatrixFormats::PerColumn(&formats))?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_row_matrix_with_formats<I, II>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: I,
+        formats: MatrixFormats,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator<Item = II>,
+        II: IntoExcelData,
+    {
+        for (row_offset, (row, item)) in (row..).zip(data).enumerate() {
+            for (col_offset, (col, value)) in (col..).zip(item).enumerate() {
+                match formats.get(row_offset, col_offset) {
+                    Some(format) => self.write_with_format(row, col, value, format)?,
+                    None => self.write(row, col, value)?,
+                };
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Write an array of column arrays to a worksheet.
+    ///
+    /// Write an array of column arrays horizontally rightwards starting from
+    /// the initial `row, col` cell.
+    ///
+    /// This methods works for 2D arrays or array-like data structures that
+    /// implement [`IntoIterator`] and that contain a data type that implements
+    /// [`IntoExcelData`].
+    ///
+    /// See also [`Worksheet::write_row_matrix()`] for a similar function that
+    /// works in an orthogonal direction.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data`: 2D arrays or array-like data structures that implement
+    ///   [`IntoIterator`] and that contain a data type that implements
+    ///   [`IntoExcelData`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing an array of column arrays to
+    /// a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_column_matrix.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Some array data to write.
+    ///     let data = [
+    ///         [10, 11, 12, 13, 14],
+    ///         [20, 21, 22, 23, 24],
+    ///         [30, 31, 32, 33, 34],
+    ///     ];
+    ///
+    ///     // Write the array data as a series of columns.
+    ///     worksheet.write_column_matrix(0, 0, data)?;
+    /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
@@ -2342,15 +2865,185 @@ impl Worksheet {
         I::Item: IntoIterator<Item = II>,
         II: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
+        for (col, item) in (col..).zip(data) {
             self.write_column(row, col, item)?;
-            col += 1;
         }
 
         Ok(self)
     }
 
+    /// Write a 2D `ndarray` array of numbers to a worksheet.
+    ///
+    /// The `write_ndarray()` method writes a 2D [`ndarray`] array, or array
+    /// view, to a worksheet as a matrix of numbers. The rows and columns of
+    /// the array map to the rows and columns of the worksheet.
+    ///
+    /// This is similar to [`Worksheet::write_row_matrix()`] except that the
+    /// worksheet limits are checked for the whole array first, so nothing is
+    /// written if the array doesn't fit.
+    ///
+    /// Excel doesn't have handling for NaN or INF floating point numbers.
+    /// These are handled in the same way as [`Worksheet::write_number()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `array`: A 2D [`ndarray`] array or array view of a type that will
+    ///   convert [`Into`] a f64.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - The array would exceed Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a 2D `ndarray` array to a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_ndarray.rs
+    /// #
+    /// # use ndarray::array;
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let data = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    ///
+    ///     worksheet.write_ndarray(0, 0, &data)?;
+    ///
+    ///     // Write the transpose of the array, which is a view.
+    ///     worksheet.write_ndarray(3, 0, &data.t())?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "ndarray")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn write_ndarray<S, T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        array: &ArrayBase<S, Ix2>,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        S: Data<Elem = T>,
+        T: Copy + Into<f64>,
+    {
+        let (num_rows, num_cols) = array.dim();
+        if num_rows == 0 || num_cols == 0 {
+            return Ok(self);
+        }
+
+        // Check the range of the array before writing anything.
+        let last_row = u64::from(row) + num_rows as u64 - 1;
+        let last_col = u64::from(col) + num_cols as u64 - 1;
+        if last_row >= u64::from(ROW_MAX) || last_col >= u64::from(COL_MAX) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        for (row_num, values) in (row..).zip(array.rows()) {
+            for (col_num, value) in (col..).zip(values) {
+                self.store_number(row_num, col_num, *value, None)?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Write a 2D `ndarray` array of numbers to a worksheet with a header row.
+    ///
+    /// The `write_ndarray_with_headers()` method is similar to
+    /// [`Worksheet::write_ndarray()`] except that it also writes a row of
+    /// bold column headers above the array data.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the header row.
+    /// - `col`: The zero indexed column number.
+    /// - `headers`: A slice of header strings, one for each column of the
+    ///   array.
+    /// - `array`: A 2D [`ndarray`] array or array view of a type that will
+    ///   convert [`Into`] a f64.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - The headers or array would exceed
+    ///   Excel's worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The number of headers doesn't match
+    ///   the number of columns in the array.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - Header string exceeds
+    ///   Excel's limit of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a 2D `ndarray` array to a
+    /// worksheet with column headers.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_ndarray_with_headers.rs
+    /// #
+    /// # use ndarray::array;
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let data = array![[0.1, 10.5, 1.0], [0.2, 11.2, 1.5], [0.3, 11.9, 2.0]];
+    ///
+    ///     worksheet.write_ndarray_with_headers(0, 0, &["Time", "Temp", "Pressure"], &data)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "ndarray")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn write_ndarray_with_headers<S, T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        headers: &[&str],
+        array: &ArrayBase<S, Ix2>,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        S: Data<Elem = T>,
+        T: Copy + Into<f64>,
+    {
+        let (num_rows, num_cols) = array.dim();
+
+        if headers.len() != num_cols {
+            return Err(XlsxError::ParameterError(format!(
+                "Number of headers, {}, doesn't match the number of array columns, {num_cols}.",
+                headers.len()
+            )));
+        }
+
+        // Check the range of the headers and array before writing anything.
+        let last_row = u64::from(row) + num_rows as u64;
+        let last_col = u64::from(col) + num_cols as u64;
+        if last_row >= u64::from(ROW_MAX) || last_col > u64::from(COL_MAX) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        let header_format = Format::new().set_bold();
+        for (col_num, header) in (col..).zip(headers) {
+            self.write_string_with_format(row, col_num, *header, &header_format)?;
+        }
+
+        self.write_ndarray(row + 1, col, array)
+    }
+
     /// Write an unformatted number to a cell.
     ///
     /// Write an unformatted number to a worksheet cell. To write a formatted
@@ -2525,27 +3218,129 @@ impl Worksheet {
         self.store_number(row, col, number.into(), Some(format))
     }
 
-    /// Write an unformatted string to a worksheet cell.
+    /// Write a number stored as a string to a worksheet cell.
     ///
-    /// Write an unformatted string to a worksheet cell. To write a formatted
-    /// string see the [`Worksheet::write_string_with_format()`] method below.
+    /// The `write_number_from_str()` method parses a string, such as a field
+    /// read from a CSV file, into an [`f64`] and writes it to a cell as a
+    /// number. This is useful for applications that handle all data as
+    /// strings but need to store some of it as numeric data in Excel.
     ///
-    /// Excel only supports UTF-8 text in the xlsx file format. Any Rust UTF-8
-    /// encoded string can be written with this method. The maximum string size
-    /// supported by Excel is 32,767 characters.
+    /// Leading and trailing whitespace is ignored. The string is parsed using
+    /// Rust's [`str::parse()`] method so it shouldn't contain locale specific
+    /// formatting such as thousands separators or currency symbols. NaN and
+    /// infinite values aren't supported by Excel and are treated as errors.
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `string`: The string to write to the cell.
+    /// - `number`: The number to write to the cell, as a string.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
-    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The string couldn't be parsed as a
+    ///   finite number.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing numbers stored as strings
+    /// to a worksheet as numeric cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_number_from_str.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Some data read from a CSV file.
+    ///     let fields = ["1234", "-12.5", "1.5e3", " 42 "];
+    ///
+    ///     // Write the fields as numbers.
+    ///     for (row, field) in fields.iter().enumerate() {
+    ///         worksheet.write_number_from_str(row as u32, 0, field)?;
+    ///     }
+    ///
+    ///     // Strings that aren't numbers are an error.
+    ///     let result = worksheet.write_number_from_str(4, 0, "N/A");
+    ///     assert!(result.is_err());
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_number_from_str(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = Self::parse_number(number)?;
+
+        // Store the cell data.
+        self.store_number(row, col, number, None)
+    }
+
+    /// Write a formatted number stored as a string to a worksheet cell.
+    ///
+    /// The `write_number_from_str_with_format()` method is the same as the
+    /// [`Worksheet::write_number_from_str()`] method, shown above, except that
+    /// it also applies a [`Format`] to the cell.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `number`: The number to write to the cell, as a string.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The string couldn't be parsed as a
+    ///   finite number.
+    ///
+    pub fn write_number_from_str_with_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: &str,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = Self::parse_number(number)?;
+
+        // Store the cell data.
+        self.store_number(row, col, number, Some(format))
+    }
+
+    /// Write an unformatted string to a worksheet cell.
+    ///
+    /// Write an unformatted string to a worksheet cell. To write a formatted
+    /// string see the [`Worksheet::write_string_with_format()`] method below.
+    ///
+    /// Excel only supports UTF-8 text in the xlsx file format. Any Rust UTF-8
+    /// encoded string can be written with this method. The maximum string size
+    /// supported by Excel is 32,767 characters.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `string`: The string to write to the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
     ///
     /// # Examples
     ///
@@ -2670,6 +3465,81 @@ impl Worksheet {
         self.store_string(row, col, string.into(), Some(format))
     }
 
+    /// Write a number stored as text to a worksheet cell.
+    ///
+    /// Numbers such as product codes, zip codes or account numbers often have
+    /// leading zeros, like `00123`, that are lost if they are written as
+    /// numbers. They can be written as strings but then Excel displays a green
+    /// triangle "Number Stored as Text" warning in the cell.
+    ///
+    /// The `write_number_as_text()` method writes the text of the number as a
+    /// string with a `@` text number format and a quote prefix, like Excel
+    /// does when the user enters `'00123`. It also ignores the "Number Stored
+    /// as Text" error in the cell via [`Worksheet::ignore_error()`] so that
+    /// the warning isn't displayed.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `number`: The number, as text, to write to the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The text isn't a number.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing product codes with leading
+    /// zeros to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_number_as_text.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some product codes with leading zeros.
+    ///     worksheet.write_number_as_text(0, 0, "00123")?;
+    ///     worksheet.write_number_as_text(1, 0, "00456")?;
+    ///     worksheet.write_number_as_text(2, 0, "01789")?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_number_as_text(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: impl Into<String>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = number.into();
+
+        if !matches!(number.parse::<f64>(), Ok(value) if value.is_finite()) {
+            return Err(XlsxError::ParameterError(format!(
+                "Number stored as text '{number}' isn't a number."
+            )));
+        }
+
+        let format = Format::new().set_num_format("@").set_quote_prefix();
+
+        self.store_string(row, col, number, Some(&format))?;
+        self.ignore_error(row, col, IgnoreError::NumberStoredAsText)
+    }
+
     /// Write a "rich" string with multiple formats to a worksheet cell.
     ///
     /// The `write_rich_string()` method is used to write strings with multiple
@@ -2708,6 +3578,9 @@ impl Worksheet {
     ///
     /// For the default format segments you can use [`Format::default()`].
     ///
+    /// To write segments that use the font of the cell format, instead of a
+    /// format of their own, see [`Worksheet::write_rich_string_segments()`].
+    ///
     /// Note, only the Font elements of the [`Format`] are used by Excel in rich
     /// strings. For example it isn't possible in Excel to highlight part of the
     /// string with a yellow background. It is possible to have a yellow
@@ -2903,6 +3776,124 @@ impl Worksheet {
         self.store_rich_string(row, col, &string, &raw_string, Some(format))
     }
 
+    /// Write a "rich" string with optional segment formats to a worksheet cell.
+    ///
+    /// The `write_rich_string_segments()` method is similar to
+    /// [`Worksheet::write_rich_string()`] except that the segments are
+    /// `(Option<&Format>, &str)` tuples. A segment with a `None` format doesn't
+    /// have any font formatting of its own and uses the font of the cell
+    /// instead.
+    ///
+    /// This is mainly useful with
+    /// [`Worksheet::write_rich_string_segments_with_format()`] when the cell
+    /// format has font properties, such as the font size, that the unformatted
+    /// segments should inherit.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `rich_string`: An array reference of `(Option<&Format>, &str)`
+    ///   tuples. See [`Worksheet::write_rich_string()`] for the restrictions.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - If any of the str elements is empty or
+    ///   if there isn't at least one tuple element in the `rich_string`
+    ///   parameter array.
+    ///
+    pub fn write_rich_string_segments(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        rich_string: &[(Option<&Format>, &str)],
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let (string, raw_string) = Self::get_rich_string(rich_string)?;
+
+        self.store_rich_string(row, col, &string, &raw_string, None)
+    }
+
+    /// Write a "rich" string with optional segment formats to a worksheet
+    /// cell, with an additional cell format.
+    ///
+    /// The `write_rich_string_segments_with_format()` method is similar to
+    /// [`Worksheet::write_rich_string_with_format()`] except that the segments
+    /// are `(Option<&Format>, &str)` tuples. A segment with a `None` format
+    /// doesn't have any font formatting of its own and uses the font of the
+    /// cell format instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `rich_string`: An array reference of `(Option<&Format>, &str)`
+    ///   tuples. See [`Worksheet::write_rich_string()`] for the restrictions.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - If any of the str elements is empty or
+    ///   if there isn't at least one tuple element in the `rich_string`
+    ///   parameter array.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a "rich" string where the
+    /// unformatted segments use the font size of the cell format.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_rich_string_segments.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     worksheet.set_column_width(0, 40)?;
+    /// #
+    ///     // Add a format for one of the segments and a format for the cell.
+    ///     let red = Format::new().set_font_color(Color::Red).set_font_size(16);
+    ///     let large = Format::new().set_font_size(16);
+    ///
+    ///     // Segments without a format use the font of the cell format.
+    ///     let segments = [
+    ///         (None,       "This is "),
+    ///         (Some(&red), "red"),
+    ///         (None,       " text"),
+    ///     ];
+    ///     worksheet.write_rich_string_segments_with_format(0, 0, &segments, &large)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_rich_string_segments_with_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        rich_string: &[(Option<&Format>, &str)],
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let (string, raw_string) = Self::get_rich_string(rich_string)?;
+
+        self.store_rich_string(row, col, &string, &raw_string, Some(format))
+    }
+
     /// Write an unformatted formula to a worksheet cell.
     ///
     /// Write an unformatted Excel formula to a worksheet cell. See also the
@@ -3506,6 +4497,62 @@ impl Worksheet {
         self.store_array_formula(row, col, row, col, formula.into(), Some(format), true)
     }
 
+    /// Get a spilled range reference for a dynamic array formula cell.
+    ///
+    /// The `spill_ref()` method returns an absolute reference to the spilled
+    /// range of a dynamic array formula in a worksheet cell, such as
+    /// `Sheet1!$A$1#`. This can be used in other formulas to refer to the
+    /// variable sized range of results returned by the dynamic formula.
+    ///
+    /// Excel stores the spilled range operator `#` as the `ANCHORARRAY()`
+    /// function. The conversion is handled automatically when the formula is
+    /// written. See [The Spilled Range
+    /// Operator](crate::Formula#the-spilled-range-operator-) section of the
+    /// [`Formula`] documentation for more details.
+    ///
+    /// The sheet name is quoted, if required, so the worksheet name should be
+    /// set via [`Worksheet::set_name()`] before calling this method.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the dynamic formula cell.
+    /// - `col`: The zero indexed column number of the dynamic formula cell.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates referring to the spilled range of a
+    /// dynamic array formula from another formula.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_spill_ref.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write a dynamic formula that spills into the range A1:A10.
+    ///     worksheet.write_dynamic_formula(0, 0, "=SEQUENCE(10)")?;
+    ///
+    ///     // Sum the spilled range using the "Sheet1!$A$1#" reference.
+    ///     let spill_ref = worksheet.spill_ref(0, 0);
+    ///     worksheet.write_formula(0, 2, format!("=SUM({spill_ref})").as_str())?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn spill_ref(&self, row: RowNum, col: ColNum) -> String {
+        format!(
+            "{}!{}#",
+            utility::quote_sheetname(&self.name),
+            utility::row_col_to_cell_absolute(row, col)
+        )
+    }
+
     /// Write a blank formatted worksheet cell.
     ///
     /// Write a blank cell with formatting to a worksheet cell. The format is
@@ -3914,12 +4961,15 @@ impl Worksheet {
         self.store_url(row, col, &link.into(), Some(format))
     }
 
-    #[doc(hidden)] // Hide the docs since this is more easily done with a Url struct.
+    /// Write a url with alternative text and a format to a worksheet cell.
     ///
-    /// Write a url/hyperlink to a worksheet cell with various options
+    /// This method combines [`Worksheet::write_url_with_text()`] and
+    /// [`Worksheet::write_url_with_format()`] to write a url with a display
+    /// string, that is different from the url, and a user defined format in
+    /// a single call. This is useful when writing tables of links.
     ///
-    /// This method is similar to [`Worksheet::write_url()`] and variant methods
-    /// except that you can also add a screen tip message, if required.
+    /// If the text is an empty string then the url is displayed instead, in
+    /// the same way as [`Worksheet::write_url_with_format()`].
     ///
     /// # Parameters
     ///
@@ -3927,42 +4977,182 @@ impl Worksheet {
     /// - `col`: The zero indexed column number.
     /// - `link`: The url/hyperlink to write to the cell as a string or [`Url`].
     /// - `text`: The alternative string to write to the cell.
-    /// - `tip`: The screen tip string to display when the user hovers over the
-    ///   url cell.
     /// - `format`: The [`Format`] property for the cell.
     ///
-    /// The `text` and `tip` parameters are optional and can be set as a blank
-    /// string. The `format` is an `Option<>` parameter and can be specified as
-    /// `None` if not required.
-    ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - Text string exceeds Excel's
-    ///   limit of 32,767 characters.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
     /// - [`XlsxError::MaxUrlLengthExceeded`] - URL string or anchor exceeds
-    ///   Excel's limit of 2080 characters or the screen tip exceed 255
-    ///   characters.
+    ///   Excel's limit of 2080 characters.
     /// - [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
     ///   the supported types listed above.
-    /// - [`XlsxError::ParameterError`] - [`Url`] mouseover tool tip exceeds
-    ///   Excel's limit of 255 characters.
     ///
-    pub fn write_url_with_options(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-        link: impl Into<Url>,
-        text: impl Into<String>,
-        tip: impl Into<String>,
-        format: Option<&Format>,
-    ) -> Result<&mut Worksheet, XlsxError> {
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a column of urls with
+    /// alternative text and a user defined format.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_url_with_text_and_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, FormatUnderline, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let link_format = Format::new()
+    ///         .set_font_color(Color::Red)
+    ///         .set_underline(FormatUnderline::Single);
+    ///
+    ///     let links = [
+    ///         ("https://www.rust-lang.org", "Rust"),
+    ///         ("https://crates.io", "Crates"),
+    ///         ("https://docs.rs", ""),
+    ///     ];
+    ///
+    ///     // Write the links. The last one displays the url since it has no text.
+    ///     for (row, (url, text)) in (0..).zip(links) {
+    ///         worksheet.write_url_with_text_and_format(row, 0, url, text, &link_format)?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_url_with_text_and_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        link: impl Into<Url>,
+        text: impl Into<String>,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let mut link = link.into();
+        let text = text.into();
+
+        // An empty text string falls back to displaying the url.
+        if !text.is_empty() {
+            link = link.set_text(text);
+        }
+
+        // Store the cell data.
+        self.store_url(row, col, &link, Some(format))
+    }
+
+    #[doc(hidden)] // Hide the docs since this is more easily done with a Url struct.
+    ///
+    /// Write a url/hyperlink to a worksheet cell with various options
+    ///
+    /// This method is similar to [`Worksheet::write_url()`] and variant methods
+    /// except that you can also add a screen tip message, if required.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `link`: The url/hyperlink to write to the cell as a string or [`Url`].
+    /// - `text`: The alternative string to write to the cell.
+    /// - `tip`: The screen tip string to display when the user hovers over the
+    ///   url cell.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// The `text` and `tip` parameters are optional and can be set as a blank
+    /// string. The `format` is an `Option<>` parameter and can be specified as
+    /// `None` if not required.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - Text string exceeds Excel's
+    ///   limit of 32,767 characters.
+    /// - [`XlsxError::MaxUrlLengthExceeded`] - URL string or anchor exceeds
+    ///   Excel's limit of 2080 characters or the screen tip exceed 255
+    ///   characters.
+    /// - [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
+    ///   the supported types listed above.
+    /// - [`XlsxError::ParameterError`] - [`Url`] mouseover tool tip exceeds
+    ///   Excel's limit of 255 characters.
+    ///
+    pub fn write_url_with_options(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        link: impl Into<Url>,
+        text: impl Into<String>,
+        tip: impl Into<String>,
+        format: Option<&Format>,
+    ) -> Result<&mut Worksheet, XlsxError> {
         // Store the cell data.
         let link = link.into().set_text(text.into()).set_tip(tip.into());
         self.store_url(row, col, &link, format)
     }
 
+    /// Share worksheet relationships between identical hyperlinks.
+    ///
+    /// Excel stores a relationship in the worksheet `.rels` file for each
+    /// external url or file hyperlink, even if the link target is the same as
+    /// one that has already been written. For worksheets with a large number of
+    /// repeated links, such as a column of links to the same site, this can
+    /// significantly increase the file size and the time taken to save the
+    /// file.
+    ///
+    /// The `set_shared_hyperlink_relationships()` method makes identical
+    /// external hyperlinks share a single relationship id. For example, saving
+    /// a worksheet with 50,000 links to the same url takes around half the
+    /// time and the file is around 40% smaller. The output is still valid and
+    /// is read by Excel but it differs from the file that Excel would create.
+    ///
+    /// The option is off by default so that, like the rest of the library, the
+    /// output matches the file that Excel creates. This also means that the
+    /// relationship ids of existing files don't change, which is important if
+    /// they are post-processed or compared with Excel output.
+    ///
+    /// Internal links, to cells or worksheets in the same workbook, don't use
+    /// relationships and aren't affected by this option.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a column of repeated links
+    /// that share a single worksheet relationship.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_shared_hyperlink_relationships.rs
+    /// #
+    /// # use rust_xlsxwriter::{Url, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.set_shared_hyperlink_relationships(true);
+    ///
+    ///     // All of these links will refer to the same relationship.
+    ///     for row in 0..1000 {
+    ///         worksheet.write(row, 0, Url::new("https://www.rust-lang.org"))?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_shared_hyperlink_relationships(&mut self, enable: bool) -> &mut Worksheet {
+        self.share_hyperlink_relationships = enable;
+        self
+    }
+
     /// Write a formatted date and/or time to a worksheet cell.
     ///
     /// The method method writes dates/times that implements [`IntoExcelDateTime`]
@@ -4436,6 +5626,118 @@ impl Worksheet {
         self.store_datetime(row, col, datetime, Some(format))
     }
 
+    /// Write a time duration, in seconds, to a worksheet cell.
+    ///
+    /// Write a time duration, such as the hours worked in a timesheet, to a
+    /// worksheet cell. Excel stores times and durations as a fraction of a
+    /// day so the number of seconds is converted to a day fraction, for
+    /// example 36 hours is stored as 1.5.
+    ///
+    /// The cell is formatted with the elapsed time number format `[h]:mm`.
+    /// Unlike a standard `hh:mm` format the `[h]` part doesn't wrap at 24
+    /// hours so a 37.5 hour duration is displayed as "37:30". To use a
+    /// different format, for example `[h]:mm:ss`, see
+    /// [`Worksheet::write_duration_with_format()`].
+    ///
+    /// See also [`ExcelDateTime::from_duration()`] to convert a
+    /// [`std::time::Duration`] to an Excel time.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `seconds`: The duration in seconds.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::DateTimeRangeError`] - The duration is negative, NaN or
+    ///   outside Excel's allowed range of 2,958,465 days.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing time durations, some greater
+    /// than 24 hours, to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_duration.rs
+    /// #
+    /// # use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some durations in seconds. They are displayed as "7:30",
+    ///     // "26:15" and "40:00".
+    ///     worksheet.write_duration(0, 0, 7.5 * 3600.0)?;
+    ///     worksheet.write_duration(1, 0, 26.25 * 3600.0)?;
+    ///     worksheet.write_duration(2, 0, 40 * 3600)?;
+    ///
+    ///     // Add a total of the durations.
+    ///     worksheet.write(3, 0, Formula::new("=SUM(A1:A3)"))?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_duration(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        seconds: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let format = Format::new().set_num_format("[h]:mm");
+
+        self.write_duration_with_format(row, col, seconds, &format)
+    }
+
+    /// Write a formatted time duration, in seconds, to a worksheet cell.
+    ///
+    /// Write a time duration to a worksheet cell with a user defined
+    /// [`Format`]. This is the same as [`Worksheet::write_duration()`] except
+    /// that the format, which should generally have an elapsed time number
+    /// format such as `[h]:mm:ss` or `[mm]:ss`, is specified by the user.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `seconds`: The duration in seconds.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::DateTimeRangeError`] - The duration is negative, NaN or
+    ///   outside Excel's allowed range of 2,958,465 days.
+    ///
+    pub fn write_duration_with_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        seconds: impl Into<f64>,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let seconds = seconds.into();
+        let days = seconds / DAY_SECONDS as f64;
+
+        if !(0.0..2_958_466.0).contains(&days) {
+            return Err(XlsxError::DateTimeRangeError(format!(
+                "Duration: '{seconds}' seconds outside Excel range of 0 to 2,958,465 days"
+            )));
+        }
+
+        // Store the cell data.
+        self.store_datetime(row, col, days, Some(format))
+    }
+
     /// Write an unformatted boolean value to a cell.
     ///
     /// Write an unformatted Excel boolean value to a worksheet cell.
@@ -4645,104 +5947,251 @@ impl Worksheet {
         string: &str,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions(first_row, first_col)
-            || !self.check_dimensions(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        // Check that the range isn't a singe cell, which isn't allowed by Excel.
-        if first_row == last_row && first_col == last_col {
-            return Err(XlsxError::MergeRangeSingleCell);
-        }
-
-        // Write the first cell in the range.
-        self.write_string_with_format(first_row, first_col, string, format)?;
-
-        // Pad out the rest of the range with formatted blanks cells.
-        for row in first_row..=last_row {
-            for col in first_col..=last_col {
-                // Skip the first cell which was written above.
-                if row == first_row && col == first_col {
-                    continue;
-                }
-                self.write_blank(row, col, format)?;
-            }
-        }
-
-        // Create a cell range for storage and range testing.
-        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
-
-        // Check if the merged range overlaps any previous merged range. This is
-        // a major error in Excel. Note, the ranges are stored in a separate Vec
-        // to the cells to cut down on storage size.
-        let new_index = self.merged_ranges.len();
-        for row in first_row..=last_row {
-            for col in first_col..=last_col {
-                match self.merged_cells.get_mut(&(row, col)) {
-                    Some(index) => {
-                        let previous_cell_range = self.merged_ranges.get(*index).unwrap();
-                        return Err(XlsxError::MergeRangeOverlaps(
-                            cell_range.to_error_string(),
-                            previous_cell_range.to_error_string(),
-                        ));
-                    }
-                    None => self.merged_cells.insert((row, col), new_index),
-                };
-            }
-        }
-
-        // Store the merge range if everything was okay.
-        self.merged_ranges.push(cell_range);
-
-        Ok(self)
+        self.merge_ranges(
+            &[(first_row, first_col, last_row, last_col)],
+            string,
+            format,
+        )
     }
 
-    /// Add an image to a worksheet.
-    ///
-    /// Add an image to a worksheet at a cell location. The image should be
-    /// encapsulated in an [`Image`] object.
-    ///
-    /// The supported image formats are:
-    ///
-    /// - PNG
-    /// - JPG
-    /// - GIF: The image can be an animated gif in more recent versions of
-    ///   Excel.
-    /// - BMP: BMP images are only supported for backward compatibility. In
-    ///   general it is best to avoid BMP images since they are not compressed.
-    ///   If used, BMP images must be 24 bit, true color, bitmaps.
+    /// Merge several ranges of cells with the same string and format.
     ///
-    /// EMF and WMF file formats will be supported in an upcoming version of the
-    /// library.
-    ///
-    /// **NOTE on SVG files**: Excel doesn't directly support SVG files in the
-    /// same way as other image file formats. It allows SVG to be inserted into
-    /// a worksheet but converts them to, and displays them as, PNG files. It
-    /// stores the original SVG image in the file so the original format can be
-    /// retrieved. This removes the file size and resolution advantage of using
-    /// SVG files. As such SVG files are not supported by `rust_xlsxwriter`
-    /// since a conversion to the PNG format would be required and that format
-    /// is already supported.
+    /// The `merge_ranges()` method is a batch version of
+    /// [`Worksheet::merge_range()`]. It is useful for templates or grids that
+    /// contain a large number of merged ranges with the same content and
+    /// format.
     ///
+    /// All of the ranges are validated, including checking that they don't
+    /// overlap each other or any previous merged range, before any data is
+    /// written to the worksheet. If any range is invalid then an error is
+    /// returned and the worksheet is unchanged.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `image`: The [`Image`] to insert into the cell.
+    /// - `ranges`: A slice of `(first_row, first_col, last_row, last_col)`
+    ///   ranges. (All zero indexed.)
+    /// - `string`: The string to write to the first cell of each range.
+    /// - `format`: The [`Format`] property for the cells.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    ///
+    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// - [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
+    ///   cell in Excel.
+    /// - [`XlsxError::MergeRangeOverlaps`] - A merge range overlaps another
+    ///   range in the batch or a previous merge range.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates merging several ranges with the
+    /// same format in one call.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_merge_ranges.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format = Format::new()
+    ///         .set_align(FormatAlign::Center)
+    ///         .set_border(FormatBorder::Thin);
+    ///
+    ///     // Merge a 2x2 block of cells in each quadrant of a grid.
+    ///     let ranges = [(1, 1, 2, 2), (1, 4, 2, 5), (4, 1, 5, 2), (4, 4, 5, 5)];
+    ///
+    ///     worksheet.merge_ranges(&ranges, "", &format)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn merge_ranges(
+        &mut self,
+        ranges: &[(RowNum, ColNum, RowNum, ColNum)],
+        string: &str,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check that the string is within Excel's limit before writing
+        // anything.
+        if string.chars().count() > MAX_STRING_LEN {
+            return Err(XlsxError::MaxStringLengthExceeded);
+        }
+
+        // Validate all the ranges before writing any data.
+        let cell_ranges = self.validate_merge_ranges(ranges)?;
+
+        for cell_range in cell_ranges {
+            // Write the first cell in the range.
+            self.write_string_with_format(
+                cell_range.first_row,
+                cell_range.first_col,
+                string,
+                format,
+            )?;
+
+            self.store_merge_range(cell_range, format)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Merge a range of cells and write a rich string to the merged cell.
+    ///
+    /// The `merge_range_rich()` method is similar to
+    /// [`Worksheet::merge_range()`] except that the merged cell contains a
+    /// "rich" string with multiple formats, like
+    /// [`Worksheet::write_rich_string_with_format()`]. This is useful for
+    /// banner or title cells that contain mixed formatting such as a bold
+    /// title followed by a normal subtitle.
+    ///
+    /// The rich string is written to the first cell in the range and the
+    /// other cells are padded with blank cells. All the cells use the `format`
+    /// parameter as the cell format, for example to set the alignment or
+    /// border of the merged range.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first row of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last row of the range.
+    /// - `rich_string`: An array reference of `(&Format, &str)` tuples. See
+    ///   [`Worksheet::write_rich_string()`] for the restrictions.
+    /// - `format`: The [`Format`] property for the merged cells.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// - [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
+    ///   cell in Excel.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The merge range overlaps a
+    ///   previous merge range.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The rich string is empty or contains
+    ///   an empty string segment.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates merging a range of cells with a
+    /// rich string that contains a bold title and a normal subtitle.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_merge_range_rich.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let default = Format::default();
+    ///     let bold = Format::new().set_bold().set_font_size(14);
+    ///
+    ///     let segments = [(&bold, "Quarterly Report"), (&default, " - Sales by region")];
+    ///
+    ///     // Format for the merged range.
+    ///     let format = Format::new()
+    ///         .set_align(FormatAlign::Center)
+    ///         .set_align(FormatAlign::VerticalCenter)
+    ///         .set_border(FormatBorder::Thin);
+    ///
+    ///     worksheet.merge_range_rich(1, 1, 2, 6, &segments, &format)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn merge_range_rich(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        rich_string: &[(&Format, &str)],
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let (string, raw_string) = Self::get_rich_string(rich_string)?;
+
+        // Check that the string is within Excel's limit before writing
+        // anything.
+        if string.chars().count() > MAX_STRING_LEN {
+            return Err(XlsxError::MaxStringLengthExceeded);
+        }
+
+        let cell_ranges =
+            self.validate_merge_ranges(&[(first_row, first_col, last_row, last_col)])?;
+
+        for cell_range in cell_ranges {
+            // Write the rich string to the first cell in the range.
+            self.store_rich_string(
+                cell_range.first_row,
+                cell_range.first_col,
+                &string,
+                &raw_string,
+                Some(format),
+            )?;
+
+            self.store_merge_range(cell_range, format)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Add an image to a worksheet.
+    ///
+    /// Add an image to a worksheet at a cell location. The image should be
+    /// encapsulated in an [`Image`] object.
+    ///
+    /// The supported image formats are:
+    ///
+    /// - PNG
+    /// - JPG
+    /// - GIF: The image can be an animated gif in more recent versions of
+    ///   Excel.
+    /// - BMP: BMP images are only supported for backward compatibility. In
+    ///   general it is best to avoid BMP images since they are not compressed.
+    ///   If used, BMP images must be 24 bit, true color, bitmaps.
+    ///
+    /// EMF and WMF file formats will be supported in an upcoming version of the
+    /// library.
+    ///
+    /// **NOTE on SVG files**: Excel doesn't directly support SVG files in the
+    /// same way as other image file formats. It allows SVG to be inserted into
+    /// a worksheet but converts them to, and displays them as, PNG files. It
+    /// stores the original SVG image in the file so the original format can be
+    /// retrieved. This removes the file size and resolution advantage of using
+    /// SVG files. As such SVG files are not supported by `rust_xlsxwriter`
+    /// since a conversion to the PNG format would be required and that format
+    /// is already supported.
+    ///
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `image`: The [`Image`] to insert into the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
     /// # Examples
     ///
     /// The following example demonstrates creating a new Image object and
@@ -4869,6 +6318,83 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Add an image with a hyperlink to a worksheet.
+    ///
+    /// Add an image to a worksheet at a cell location, with a hyperlink so
+    /// that when the user clicks on the image they are taken to the URL or
+    /// location. This is a shortcut for [`Image::set_url()`] followed by
+    /// [`Worksheet::insert_image()`]. Images in the same worksheet that link to
+    /// the same URL share a single drawing relationship.
+    ///
+    /// See [`Url`] for an explanation of the URIs supported by Excel.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `image`: The [`Image`] to insert into the cell.
+    /// - `link`: The url/hyperlink associated with the image as a string or
+    ///   [`Url`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxUrlLengthExceeded`] - URL string or anchor exceeds
+    ///   Excel's limit of 2080 characters.
+    /// - [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
+    ///   [`Worksheet::write_url()`].
+    /// - [`XlsxError::ParameterError`] - URL mouseover tool tip exceeds Excel's
+    ///   limit of 255 characters.
+    ///
+    /// # Examples
+    ///
+    /// This example shows how to add an image with a hyperlink to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_image_with_url.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image object.
+    ///     let image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     // Insert the image with a link to the Rust home page.
+    ///     worksheet.insert_image_with_url(1, 2, &image, "https://www.rust-lang.org")?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_image_with_url(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        image: &Image,
+        link: impl Into<Url>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        let image = image.clone().set_url(link)?;
+
+        self.images.insert((row, col), image);
+
+        Ok(self)
+    }
+
     /// Embed an image to a worksheet and fit it to a cell.
     ///
     /// This method can be used to embed a image into a worksheet cell and have
@@ -4882,6 +6408,12 @@ impl Worksheet {
     /// versions from 2023 onwards. For older versions of Excel a `#VALUE!`
     /// error is displayed.
     ///
+    /// Unlike [`Worksheet::insert_image()`], which anchors a floating image
+    /// over the worksheet grid, the embedded image is stored as the value of
+    /// the cell. It is written to the file as a rich value in the
+    /// `richData/rdrichvalue.xml` and `richData/richValueRel.xml` parts and
+    /// moves, sorts and filters with the cell like any other cell value.
+    ///
     /// The image should be encapsulated in an [`Image`] object. See
     /// [`Worksheet::insert_image()`] above for details on the supported image
     /// types.
@@ -4992,13 +6524,19 @@ impl Worksheet {
     /// terminology it inserts the image placed *over* the cell instead of *in*
     /// the cell. The only advantage of this method is that the output file will
     /// work will all versions of Excel. The `Worksheet::embed_image()` method
-    /// only works with versions of Excel from 2003 onwards.
+    /// only works with versions of Excel from 2023 onwards.
     ///
     /// This method can be useful when creating a product spreadsheet with a
     /// column of images for each product. The image should be encapsulated in
     /// an [`Image`] object. See [`Worksheet::insert_image()`] above for details
     /// on the supported image types. The scaling calculation for this method
     /// takes into account the DPI of the image in the same way that Excel does.
+    /// The cell size is calculated in pixels at Excel's default 96 DPI and
+    /// columns or rows that haven't been explicitly sized use the worksheet
+    /// default cell size. The scale is recalculated when the workbook is saved
+    /// so the column width and row height can be set before or after the image
+    /// is inserted. To fit an image to a range of cells see
+    /// [`Worksheet::insert_image_fit_to_range()`].
     ///
     /// There are two options, which are controlled by the `keep_aspect_ratio`
     /// parameter. The image can be scaled vertically and horizontally to occupy
@@ -5090,72 +6628,160 @@ impl Worksheet {
             return Err(XlsxError::RowColumnLimitError);
         }
 
-        let width = self.column_pixel_width(col, image.object_movement);
-        let height = self.row_pixel_height(row, image.object_movement);
-
-        let image = image
-            .clone()
-            .set_scale_to_size(width, height, keep_aspect_ratio);
-
-        self.images.insert((row, col), image);
+        self.insert_image_fit_to_size(row, col, row, col, image, keep_aspect_ratio);
 
         Ok(self)
     }
 
-    /// Add a chart to a worksheet.
+    /// Add an image to a worksheet and fit it to a range of cells.
     ///
-    /// Add a [`Chart`] to a worksheet at a cell location.
+    /// Add an image to a worksheet and scale it so that it fits in the area
+    /// covered by a range of cells. This is the same as
+    /// [`Worksheet::insert_image_fit_to_cell()`] except that the target size
+    /// is the sum of the widths of the columns and the heights of the rows in
+    /// the range. The image is inserted at the top left cell of the range.
+    ///
+    /// The range size is calculated in pixels at Excel's default 96 DPI.
+    /// Columns or rows that haven't been explicitly sized use the worksheet
+    /// default cell size and hidden columns or rows are treated as having a
+    /// size of zero, unless the image object movement is set to
+    /// [`ObjectMovement::MoveAndSizeWithCellsAfter`]. The image scale also
+    /// takes into account the DPI of the image in the same way that Excel
+    /// does. As with `insert_image_fit_to_cell()` the scale is recalculated
+    /// when the workbook is saved to account for any later changes to the
+    /// column widths or row heights.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `chart`: The [`Chart`] to insert into the cell.
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `image`: The [`Image`] to insert into the range.
+    /// - `keep_aspect_ratio`: Boolean value to maintain the aspect ratio of the
+    ///   image if `true` or scale independently in the horizontal and vertical
+    ///   directions if `false`.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// - [`XlsxError::ChartError`] - A general error that is raised when a
-    ///   chart parameter is incorrect or a chart is configured incorrectly.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
     ///
     /// # Examples
     ///
-    /// Insert a chart object into a worksheet.
+    /// This example demonstrates inserting an image into a worksheet and
+    /// scaling it to fit a range of cells.
     ///
     /// ```
-    /// # // This code is available in examples/doc_chart_simple.rs
+    /// # // This code is available in examples/doc_worksheet_insert_image_fit_to_range.rs
     /// #
-    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add some data for the chart.
-    /// #     worksheet.write(0, 0, 50)?;
-    /// #     worksheet.write(1, 0, 30)?;
-    /// #     worksheet.write(2, 0, 40)?;
-    /// #
-    /// #     // Create a new chart.
-    ///     let mut chart = Chart::new(ChartType::Column);
-    ///
-    ///     // Add a data series using Excel formula syntax to describe the range.
-    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///     // Create a new image object.
+    ///     let image = Image::new("examples/rust_logo.png")?;
     ///
-    ///     // Add the chart to the worksheet.
-    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///     // Insert the image and scale it to fit the range B2:D8.
+    ///     worksheet.insert_image_fit_to_range(1, 1, 7, 3, &image, false)?;
     /// #
-    /// #     // Save the file.
-    /// #     workbook.save("chart.xlsx")?;
+    /// #     workbook.save("image.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/chart_simple.png">
+    pub fn insert_image_fit_to_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        image: &Image,
+        keep_aspect_ratio: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        self.insert_image_fit_to_size(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            image,
+            keep_aspect_ratio,
+        );
+
+        Ok(self)
+    }
+
+    /// Add a chart to a worksheet.
+    ///
+    /// Add a [`Chart`] to a worksheet at a cell location.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `chart`: The [`Chart`] to insert into the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ChartError`] - A general error that is raised when a
+    ///   chart parameter is incorrect or a chart is configured incorrectly.
+    ///
+    /// # Examples
+    ///
+    /// Insert a chart object into a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_simple.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 50)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(2, 0, 40)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/chart_simple.png">
     ///
     pub fn insert_chart(
         &mut self,
@@ -5254,6 +6880,80 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Add a text box to a worksheet.
+    ///
+    /// Add a rectangular text box shape to a worksheet. Text boxes are drawing
+    /// objects, like images and charts, and are separate from cell
+    /// [`Note`]s. They can be used to add annotations or explanatory text to a
+    /// worksheet.
+    ///
+    /// The size, position offset, fill, border and font of the text box can be
+    /// set using the [`TextBoxOptions`] struct. Newlines in the text are
+    /// written as separate paragraphs in the text box.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `text`: The text to display in the text box.
+    /// - `options`: The [`TextBoxOptions`] properties of the text box.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates inserting a text box into a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_textbox.rs
+    /// #
+    /// # use rust_xlsxwriter::{TextBoxOptions, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Insert a text box with the default options.
+    ///     let options = TextBoxOptions::new();
+    ///     worksheet.insert_textbox(1, 1, "Hello", &options)?;
+    ///
+    ///     // Insert a text box with a fill color and a larger font.
+    ///     let options = TextBoxOptions::new()
+    ///         .set_fill_color("#DDEBF7")
+    ///         .set_font_size(14);
+    ///     worksheet.insert_textbox(1, 5, "World", &options)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_textbox(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        text: impl Into<String>,
+        options: &TextBoxOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        let mut textbox = options.clone();
+        textbox.text = text.into();
+
+        self.textboxes.insert((row, col), textbox);
+
+        Ok(self)
+    }
+
     /// Add a Note to a cell.
     ///
     /// A Note is a post-it style message that is revealed when the user mouses
@@ -5272,6 +6972,10 @@ impl Worksheet {
     ///
     /// See [`Note`] for details on the properties of Notes.
     ///
+    /// If the note is attached to a cell in a merged range then, like Excel,
+    /// the note box is positioned relative to the right side of the merged
+    /// area rather than the parent cell.
+    ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
@@ -5350,6 +7054,9 @@ impl Worksheet {
             return Err(XlsxError::MaxStringLengthExceeded);
         }
 
+        // A cell can have a note or a threaded comment but not both.
+        self.threaded_comments.remove(&(row, col));
+
         // Set the cell that the Note refers to. This is different form the cell
         // where the note appears.
         let mut note = note.clone();
@@ -5377,6 +7084,207 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Add several Notes to worksheet cells at once.
+    ///
+    /// This is a bulk version of [`Worksheet::insert_note()`] that adds a
+    /// [`Note`] for each text value in a map of `(row, col)` cell positions to
+    /// note text. It is useful for annotating a worksheet from data collected
+    /// by an application. Note authors are collected and the VML and comments
+    /// parts are generated once when the file is saved, as they are for
+    /// individual notes.
+    ///
+    /// The notes use the default [`Note`] properties. Use
+    /// [`Worksheet::insert_note()`] if you need to set properties such as the
+    /// author or size for individual notes.
+    ///
+    /// Notes don't affect the cell data so they can be added to cells that
+    /// contain a value, or to empty cells. Any existing note or threaded
+    /// comment in a cell is replaced.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - Text exceeds Excel's limit of
+    ///   32,713 characters.
+    ///
+    /// All of the cell positions and text values are checked before any of
+    /// the notes are added so the worksheet isn't changed if there is an
+    /// error.
+    ///
+    /// # Parameters
+    ///
+    /// - `notes`: A map of `(row, col)` cell positions to note text.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding several notes to a worksheet
+    /// at once.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_notes.rs
+    /// #
+    /// # use std::collections::HashMap;
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_column(0, 0, [10, 25, 40])?;
+    ///
+    ///     // Annotate some of the cells.
+    ///     let notes = HashMap::from([
+    ///         ((0, 0), "Below target"),
+    ///         ((2, 0), "Above target"),
+    ///         ((4, 0), "Awaiting data"),
+    ///     ]);
+    ///
+    ///     worksheet.insert_notes(&notes)?;
+    /// #
+    /// #     workbook.save("notes.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_notes<T: AsRef<str>>(
+        &mut self,
+        notes: &HashMap<(RowNum, ColNum), T>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check all the cells and text before adding any notes.
+        for (&(row, col), text) in notes {
+            if !self.check_dimensions_only(row, col) {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+
+            if text.as_ref().chars().count() > MAX_STRING_LEN - 54 {
+                return Err(XlsxError::MaxStringLengthExceeded);
+            }
+        }
+
+        for (&(row, col), text) in notes {
+            self.insert_note(row, col, &Note::new(text.as_ref()))?;
+        }
+
+        Ok(self)
+    }
+
+    /// Add a threaded comment to a cell.
+    ///
+    /// A threaded comment is the newer style of Excel cell comment that
+    /// supports a conversation of replies between several authors. The
+    /// authors are people added to the workbook persons registry via
+    /// [`Workbook::add_person()`](crate::Workbook::add_person).
+    ///
+    /// A cell can have a [`Note`] or a threaded comment but not both. Adding
+    /// a threaded comment to a cell replaces any note in the cell, and vice
+    /// versa.
+    ///
+    /// See [`ThreadedComment`] for details on the properties of threaded
+    /// comments.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - Text exceeds Excel's limit of
+    ///   32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The threaded comment doesn't have an
+    ///   author. Authors that aren't in the workbook persons registry are
+    ///   reported as an error when the workbook is saved.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `comment`: The [`ThreadedComment`] to insert into the cell.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding threaded comments to a
+    /// worksheet. Several comments and replies share the same authors.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_threaded_comment.rs
+    /// #
+    /// # use rust_xlsxwriter::{ThreadedComment, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add the comment authors to the workbook persons registry.
+    ///     let jane = workbook.add_person("Jane Smith", "jane@example.com");
+    ///     let john = workbook.add_person("John Smith", "john@example.com");
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_column(0, 0, [1234, 5678])?;
+    ///
+    ///     // Add a threaded comment with replies.
+    ///     let comment = ThreadedComment::new("Should this be higher?")
+    ///         .set_author(jane)
+    ///         .add_reply(john, "No, it is correct.")
+    ///         .add_reply(jane, "Thanks.")
+    ///         .set_resolved(true);
+    ///
+    ///     worksheet.insert_threaded_comment(0, 0, &comment)?;
+    ///
+    ///     // Add another threaded comment by the same author.
+    ///     let comment = ThreadedComment::new("Please check this value.").set_author(john);
+    ///
+    ///     worksheet.insert_threaded_comment(1, 0, &comment)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("threaded_comments.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_threaded_comment(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        comment: &ThreadedComment,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check that the strings are < Excel limit of 32767 chars.
+        if comment.text.chars().count() > MAX_STRING_LEN
+            || comment
+                .replies
+                .iter()
+                .any(|reply| reply.text.chars().count() > MAX_STRING_LEN)
+        {
+            return Err(XlsxError::MaxStringLengthExceeded);
+        }
+
+        if comment.author.is_none() {
+            return Err(XlsxError::ParameterError(
+                "Threaded comment must have an author. See ThreadedComment::set_author()."
+                    .to_string(),
+            ));
+        }
+
+        // A cell can have a note or a threaded comment but not both.
+        if let Some(columns) = self.notes.get_mut(&row) {
+            columns.remove(&col);
+            if columns.is_empty() {
+                self.notes.remove(&row);
+            }
+        }
+
+        self.threaded_comments.insert((row, col), comment.clone());
+
+        self.has_vml = true;
+
+        Ok(self)
+    }
+
     /// Make all worksheet notes visible when the file loads.
     ///
     /// By default Excel hides cell notes until the user mouses over the parent
@@ -5433,6 +7341,25 @@ impl Worksheet {
         self
     }
 
+    /// Set all the notes in the worksheet to fit their text.
+    ///
+    /// Set the dimensions of all the notes in the worksheet so that the note
+    /// text is fully visible. See [`Note::set_autosize()`](crate::Note::set_autosize)
+    /// for details on how the dimensions are calculated. Individual notes can
+    /// override the worksheet setting with `Note::set_autosize(false)`.
+    ///
+    /// This also applies to the legacy notes that are stored for
+    /// [`ThreadedComment`](crate::ThreadedComment)s.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn autosize_all_notes(&mut self, enable: bool) -> &mut Worksheet {
+        self.autosize_all_notes = enable;
+        self
+    }
+
     /// Set the default author name for all the notes in the worksheet.
     ///
     /// The Note author is the creator of the note. In Excel the author name is
@@ -5508,6 +7435,13 @@ impl Worksheet {
     /// is unlikely that any other Excel form elements will be added in the
     /// future due to the implementation effort required.
     ///
+    /// The button is stored as a legacy VML form control, which is the same
+    /// way Excel stores it. The macro that the button runs should be added to
+    /// the workbook via [`Workbook::add_vba_project()`](crate::Workbook::add_vba_project)
+    /// and the file should be saved with an `.xlsm` extension. A warning is
+    /// raised, once, when the workbook is saved if it contains buttons with
+    /// macros set via [`Button::set_macro()`] but no VBA project.
+    ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
@@ -5696,6 +7630,8 @@ impl Worksheet {
                     height,
                     xf_index: 0,
                     hidden: false,
+                    thick_top: false,
+                    thick_bottom: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -5843,6 +7779,8 @@ impl Worksheet {
                     height: self.user_default_row_height,
                     xf_index,
                     hidden: false,
+                    thick_top: false,
+                    thick_bottom: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -5913,6 +7851,8 @@ impl Worksheet {
                     height: self.user_default_row_height,
                     xf_index: 0,
                     hidden: true,
+                    thick_top: false,
+                    thick_bottom: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -5953,6 +7893,86 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Set the thick top border flag for a worksheet row.
+    ///
+    /// The `set_row_thick_top()` method sets the Excel `thickTop` row
+    /// attribute. This indicates that the row has a medium or thick top
+    /// border, or that the row above it has a thick bottom border, so that
+    /// Excel allows extra space for the border when rendering the row.
+    ///
+    /// Note, the attribute is only a row level rendering hint and it doesn't
+    /// draw any border lines. The borders themselves are added with a
+    /// [`Format`] using [`Format::set_border_top()`] or
+    /// [`Format::set_border_bottom()`], for example with
+    /// [`Worksheet::set_row_format()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the thick top and bottom
+    /// flags for worksheet rows that are separated by a medium border.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_row_thick_top.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_string(0, 0, "Section 1")?;
+    ///     worksheet.write_string(4, 0, "Section 2")?;
+    ///
+    ///     // Draw a border between the sections with a row format.
+    ///     let border = Format::new().set_border_bottom(FormatBorder::Medium);
+    ///     worksheet.set_row_format(3, &border)?;
+    ///
+    ///     // Flag the rows on either side of the border so that Excel allows
+    ///     // space for it.
+    ///     worksheet.set_row_thick_bottom(3)?;
+    ///     worksheet.set_row_thick_top(4)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_row_thick_top(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+        self.store_row_thick_border(row, true, false)
+    }
+
+    /// Set the thick bottom border flag for a worksheet row.
+    ///
+    /// The `set_row_thick_bottom()` method sets the Excel `thickBot` row
+    /// attribute. This indicates that the row has a medium or thick bottom
+    /// border, or that the row below it has a thick top border. See
+    /// [`Worksheet::set_row_thick_top()`] for more details.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn set_row_thick_bottom(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+        self.store_row_thick_border(row, false, true)
+    }
+
     /// Set the default row height for all rows in a worksheet, efficiently.
     ///
     /// This method can be used to efficiently set the default row height for
@@ -5967,8 +7987,8 @@ impl Worksheet {
     ///
     /// Individual row heights can be set via [`Worksheet::set_row_height()`].
     ///
-    /// Note, there is no equivalent method for columns because the file format
-    /// already optimizes the storage of a large number of contiguous columns.
+    /// See also [`Worksheet::set_default_column_width()`] for the equivalent
+    /// column method.
     ///
     /// # Parameters
     ///
@@ -6032,6 +8052,65 @@ impl Worksheet {
         self.set_default_row_height(height)
     }
 
+    /// Set the default column width for all columns in a worksheet.
+    ///
+    /// This method can be used to set the default, or "standard", width for
+    /// all columns in a worksheet. This is the width used by any column that
+    /// hasn't been sized explicitly. It is equivalent to the Excel "Format ->
+    /// Default Width" option.
+    ///
+    /// Columns sized via [`Worksheet::set_column_width()`] or
+    /// [`Worksheet::set_column_width_pixels()`] will override the default
+    /// width. Columns that only have a format or are hidden will use the
+    /// default width.
+    ///
+    /// The width is specified in character units, where the default width is
+    /// 8.43.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The column width in character units. Must be greater than
+    ///   0.0.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the default column width for
+    /// all columns in a worksheet while overriding the width of one column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_default_column_width.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Set the default column width in Excel character units.
+    ///     worksheet.set_default_column_width(15);
+    ///
+    ///     // Override the width for the first column.
+    ///     worksheet.set_column_width(0, 30)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_default_column_width(&mut self, width: impl Into<f64>) -> &mut Worksheet {
+        let width = width.into();
+        if width <= 0.0 {
+            return self;
+        }
+
+        // Excel has a maximum limit of 255 units for the column width.
+        self.user_default_col_width = width.min(255.0);
+        self
+    }
+
     /// Hide all unused rows in a worksheet, efficiently.
     ///
     /// This method can be used to efficiently hide unused rows in a worksheet.
@@ -6192,6 +8271,21 @@ impl Worksheet {
     /// To set the width in Excel character units use the
     /// [`Worksheet::set_column_width()`] method.
     ///
+    /// The pixel width is converted to Excel character units based on the
+    /// Maximum Digit Width of the default font, which is 7 pixels for Calibri
+    /// 11, and 5 pixels of cell padding. Excel uses a different conversion for
+    /// columns that are narrower than one character, i.e., less than 12
+    /// pixels:
+    ///
+    /// ```text
+    ///     width = pixels / (max_digit_width + padding)   if pixels < 12
+    ///     width = (pixels - padding) / max_digit_width   otherwise
+    /// ```
+    ///
+    /// For example 6 pixels is stored as a width of 0.5 characters and 117
+    /// pixels is stored as 16 characters. Setting a width of 0 pixels hides the
+    /// column.
+    ///
     /// See also the [`Worksheet::autofit()`] method.
     ///
     /// # Parameters
@@ -6246,18 +8340,7 @@ impl Worksheet {
         col: ColNum,
         width: u16,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Properties for Calibri 11.
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
-        let mut width = f64::from(width);
-
-        if width < 12.0 {
-            width /= max_digit_width + padding;
-        } else {
-            width = (width - padding) / max_digit_width;
-        }
-
-        self.set_column_width(col, width)
+        self.set_column_width(col, Self::pixels_to_width(width))
     }
 
     /// Set the format for a column of cells.
@@ -6271,6 +8354,9 @@ impl Worksheet {
     /// explicit cell formatting with the column formatting but that isn't
     /// currently supported.
     ///
+    /// To apply the same format to a span of columns in one call use
+    /// [`Worksheet::set_column_range_format()`].
+    ///
     /// # Parameters
     ///
     /// - `col`: The zero indexed column number.
@@ -6340,10 +8426,12 @@ impl Worksheet {
             Some(col_options) => col_options.xf_index = xf_index,
             None => {
                 let col_options = ColOptions {
-                    width: DEFAULT_COL_WIDTH,
+                    width: None,
                     xf_index,
                     hidden: false,
                     autofit: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_cols.insert(col, col_options);
             }
@@ -6408,10 +8496,12 @@ impl Worksheet {
             Some(col_options) => col_options.hidden = true,
             None => {
                 let col_options = ColOptions {
-                    width: DEFAULT_COL_WIDTH,
+                    width: None,
                     xf_index: 0,
                     hidden: true,
                     autofit: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_cols.insert(col, col_options);
             }
@@ -6613,6 +8703,114 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Group a range of columns into a worksheet outline group.
+    ///
+    /// The `set_column_group()` method sets the outline level of a range of
+    /// columns so that they can be expanded and collapsed using the Excel
+    /// outline symbols above the column headers. Nested groups are created by
+    /// setting a higher level for a sub-range of the columns in an outer
+    /// group.
+    ///
+    /// If `collapsed` is `true` the columns in the group are hidden and the
+    /// summary column, which is the column just outside the group, is marked
+    /// as collapsed. This is where Excel expects the collapsed state to be
+    /// stored. By default the summary column is to the right of the group.
+    /// If the summary columns are set to the left of the detail columns with
+    /// [`Worksheet::set_outline_settings()`] then the column to the left of
+    /// the group is used.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_col`: The first column of the group. Zero indexed.
+    /// - `last_col`: The last column of the group.
+    /// - `level`: The outline level of the group in the Excel range 1-7.
+    /// - `collapsed`: Collapse the group so that the columns are hidden.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    /// - [`XlsxError::ParameterError`] - Outline level outside the Excel range
+    ///   1-7.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates grouping worksheet columns into an
+    /// outline with a collapsed inner group.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_column_group.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_row(0, 0, ["Jan", "Feb", "Mar", "Q1", "Apr", "May", "Jun", "Q2"])?;
+    ///
+    ///     // Group the month columns of each quarter and collapse the second
+    ///     // quarter. The quarter columns are the summary columns.
+    ///     worksheet.set_column_group(0, 2, 1, false)?;
+    ///     worksheet.set_column_group(4, 6, 1, true)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_group(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        level: u8,
+        collapsed: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Check if column is in the allowed range without updating dimensions.
+        if last_col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        if !(1..=7).contains(&level) {
+            return Err(XlsxError::ParameterError(format!(
+                "Column outline level '{level}' outside Excel range: 1 <= level <= 7."
+            )));
+        }
+
+        for col in first_col..=last_col {
+            let col_options = self.changed_cols.entry(col).or_insert(ColOptions {
+                width: None,
+                xf_index: 0,
+                hidden: false,
+                autofit: false,
+                level: 0,
+                collapsed: false,
+            });
+
+            col_options.level = level;
+            if collapsed {
+                col_options.hidden = true;
+            }
+        }
+
+        if collapsed {
+            self.collapsed_column_groups
+                .push((first_col, last_col, level));
+        }
+
+        self.outline_col_level = self.outline_col_level.max(level);
+
+        Ok(self)
+    }
+
     /// Set the autofilter area in the worksheet.
     ///
     /// The `autofilter()` method allows an autofilter to be added to a
@@ -6627,6 +8825,13 @@ impl Worksheet {
     /// Note, Excel only allows one autofilter range per worksheet so calling
     /// this method multiple times will overwrite the previous range.
     ///
+    /// If rows of data are written directly below the autofilter range, in any
+    /// of the autofilter columns, then the range is extended to include them
+    /// when the file is saved. This is similar to Excel which extends the
+    /// filter when data is added to the end of the filtered range. Data that
+    /// is written outside the autofilter columns, or below a blank row, doesn't
+    /// extend the range.
+    ///
     /// # Parameters
     ///
     /// - `first_row`: The first row of the range. (All zero indexed.)
@@ -7029,6 +9234,7 @@ impl Worksheet {
         let mut table = table.clone();
         table.cell_range = CellRange::new(first_row, first_col, last_row, last_col);
         table.initialize_columns(&default_headers)?;
+        table.validate_style()?;
 
         let first_data_row = table.first_data_row();
         let last_data_row = table.last_data_row();
@@ -7080,6 +9286,13 @@ impl Worksheet {
             }
         }
 
+        // Set the custom style element format local indices, if required.
+        if let Some(custom_style) = table.custom_style.as_mut() {
+            for (_, format) in custom_style.element_formats_mut() {
+                format.dxf_index = self.format_dxf_index(format);
+            }
+        }
+
         // Create a cell range for storage and range testing.
         let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
 
@@ -7269,10 +9482,13 @@ impl Worksheet {
             format.dxf_index = self.format_dxf_index(format);
         }
 
-        match self.conditional_formats.entry(cell_range) {
+        // Store the order that the rules are added, to set their priority.
+        match self.conditional_formats.entry(cell_range.clone()) {
             Entry::Occupied(mut entry) => {
                 // The conditional format range already exists. Append the rule.
                 let rules = entry.get_mut();
+                self.conditional_format_order
+                    .push((cell_range, rules.len()));
                 rules.push(conditional_format);
             }
             Entry::Vacant(entry) => {
@@ -7280,6 +9496,7 @@ impl Worksheet {
                 // the cell value.
                 let rules = vec![conditional_format];
                 entry.insert(rules);
+                self.conditional_format_order.push((cell_range, 0));
             }
         }
 
@@ -7976,7 +10193,8 @@ impl Worksheet {
     /// - `last_row`: The last row of the range.
     /// - `last_col`: The last row of the range.
     /// - `name`: The name of the range instead of `RangeN`. Can be blank if not
-    ///   required.
+    ///   required. Range names must be unique, ignoring case, within the
+    ///   worksheet.
     /// - `password`: The password to prevent modification of the range. Can be
     ///   blank if not required.
     ///
@@ -7986,6 +10204,8 @@ impl Worksheet {
     ///   worksheet limits.
     /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
     ///   row.
+    /// - [`XlsxError::ParameterError`] - The range name is already in use in
+    ///   the worksheet.
     ///
     /// # Examples
     ///
@@ -8045,8 +10265,24 @@ impl Worksheet {
         let mut name = name.to_string();
         let password_hash = utility::hash_password(password);
 
+        let name_in_use = |name: &str, ranges: &[(String, String, u16)]| {
+            ranges
+                .iter()
+                .any(|(_, range_name, _)| range_name.eq_ignore_ascii_case(name))
+        };
+
         if name.is_empty() {
-            name = format!("Range{}", 1 + self.unprotected_ranges.len());
+            // Use the next default name that doesn't clash with a user name.
+            let mut index = 1 + self.unprotected_ranges.len();
+            name = format!("Range{index}");
+            while name_in_use(&name, &self.unprotected_ranges) {
+                index += 1;
+                name = format!("Range{index}");
+            }
+        } else if name_in_use(&name, &self.unprotected_ranges) {
+            return Err(XlsxError::ParameterError(format!(
+                "Unprotected range name '{name}' is already in use in the worksheet."
+            )));
         }
 
         self.unprotected_ranges.push((range, name, password_hash));
@@ -8233,6 +10469,11 @@ impl Worksheet {
     /// If required, it is possible to specify the calculated result of a
     /// formula using the `set_formula_result()` method.
     ///
+    /// Numeric results like `"42"` are stored as numbers and any other value
+    /// is stored as a string. To store boolean or error results use
+    /// [`Worksheet::set_formula_typed_result()`]. See also
+    /// [`Worksheet::set_formula_results()`] to set several results at once.
+    ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
@@ -8287,13 +10528,16 @@ impl Worksheet {
                 match cell {
                     CellType::Formula {
                         result: cell_result,
+                        result_type,
                         ..
                     }
                     | CellType::ArrayFormula {
                         result: cell_result,
+                        result_type,
                         ..
                     } => {
                         *cell_result = Box::from(result.into());
+                        *result_type = FormulaResultType::Inferred;
                     }
                     _ => {
                         eprintln!("Cell ({row}, {col}) doesn't contain a formula.");
@@ -8305,41 +10549,53 @@ impl Worksheet {
         self
     }
 
-    /// Write the default formula result for worksheet formulas.
+    /// Set the results of several worksheet formulas at once.
     ///
-    /// The `rust_xlsxwriter` library doesn’t calculate the result of a formula
-    /// written using [`Worksheet::write_formula_with_format()`] or
-    /// [`Worksheet::write_formula()`]. Instead it stores the value 0 as the
-    /// formula result. It then sets a global flag in the xlsx file to say that
-    /// all formulas and functions should be recalculated when the file is
-    /// opened.
+    /// This is a bulk version of [`Worksheet::set_formula_result()`] that sets
+    /// the cached results of the formulas in a worksheet from a map of cell
+    /// positions to result values. This is useful when the results of a large
+    /// number of formulas are calculated by the application, since it means
+    /// that applications that don't recalculate formulas will display the
+    /// correct values.
     ///
-    /// However, for `LibreOffice` the default formula result should be set to
-    /// the empty string literal `""`, via the `set_formula_result_default()`
-    /// method, to force calculation of the result.
+    /// The values can be numbers, booleans or strings, or any type that
+    /// implements [`ToString`]. They are stored with the type rules explained
+    /// in [`Worksheet::set_formula_result()`]. Use
+    /// [`Worksheet::set_formula_typed_results()`] to store typed results.
     ///
     /// # Parameters
     ///
-    /// - `result`: The default formula result to write to the cell.
+    /// - `results`: A map of `(row, col)` cell positions to formula results.
+    ///
+    /// # Warnings
+    ///
+    /// You will get a warning if you try to set a formula result for a cell
+    /// that doesn't have a formula.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates manually setting the default result
-    /// for all non-calculated formulas in a worksheet.
+    /// The following example demonstrates setting the results of several
+    /// formulas at once.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_formula_result_default.rs
+    /// # // This code is available in examples/doc_worksheet_set_formula_results.rs
+    /// #
+    /// # use std::collections::HashMap;
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     worksheet.set_formula_result_default("");
+    ///     worksheet.write_formula(0, 0, "=1+1")?;
+    ///     worksheet.write_formula(1, 0, "=2+2")?;
+    ///     worksheet.write_formula(2, 0, "=2+2")?;
+    ///
+    ///     // Set the formula results calculated by the application.
+    ///     let results = HashMap::from([((0, 0), 2), ((1, 0), 4), ((2, 0), 4)]);
+    ///
+    ///     worksheet.set_formula_results(&results);
     /// #
     /// #     workbook.save("formulas.xlsx")?;
     /// #
@@ -8347,76 +10603,289 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    pub fn set_formula_result_default(&mut self, result: impl Into<String>) -> &mut Worksheet {
-        self.default_result = Box::from(result.into());
+    pub fn set_formula_results<T: ToString>(
+        &mut self,
+        results: &HashMap<(RowNum, ColNum), T>,
+    ) -> &mut Worksheet {
+        for ((row, col), result) in results {
+            self.set_formula_result(*row, *col, result.to_string());
+        }
+
         self
     }
 
-    // -----------------------------------------------------------------------
-    // Worksheet overlay/formatting methods.
-    // -----------------------------------------------------------------------
-
-    /// Add formatting to a cell without overwriting the cell data.
-    ///
-    /// In Excel the data in a worksheet cell is comprised of a type, a value
-    /// and a format. When using `rust_xlsxwriter` the type is inferred and the
-    /// value and format are generally written at the same time using methods
-    /// like [`Worksheet::write_with_format()`]. However, if required you can
-    /// write the data separately and then add the format using methods like
-    /// `set_cell_format()`.
-    ///
-    /// Although this method requires an additional step it allows for use cases
-    /// where it is easier to write a large amount of data in one go and then
-    /// figure out where formatting should be applied. See also the
-    /// documentation section on [Worksheet Cell
-    /// formatting](../worksheet/index.html#cell-formatting).
+    /// Write a typed user defined result to a worksheet formula cell.
     ///
-    /// For use cases where the cell formatting changes based on cell values
-    /// Conditional Formatting may be a better option (see [Working with
-    /// Conditional Formats](../conditional_format/index.html)).
+    /// This is similar to [`Worksheet::set_formula_result()`] except that the
+    /// result is a [`FormulaResult`] which is stored in the file with the
+    /// same type that Excel would use. This allows boolean and error results,
+    /// such as `TRUE` or `#N/A`, to be displayed correctly by applications
+    /// that don't calculate formula results. It also allows numeric looking
+    /// strings to be stored as strings.
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `format`: The [`Format`] property for the cell.
+    /// - `result`: The formula result to write to the cell. This can be a
+    ///   number, boolean, string or a [`FormulaResult`].
     ///
-    /// # Errors
+    /// # Warnings
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// You will get a warning if you try to set a formula result for a cell
+    /// that doesn't have a formula.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the format of a worksheet
-    /// cell separately from writing the cell data.
+    /// The following example demonstrates setting typed formula results.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_cell_format.rs
+    /// # // This code is available in examples/doc_worksheet_set_formula_typed_result.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{FormulaResult, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add some formats.
-    /// #     let red = Format::new().set_font_color("#FF0000");
-    /// #     let green = Format::new().set_font_color("#00FF00");
-    /// #
-    ///     // Write an array of data.
-    ///     let data = [
-    ///         [10, 11, 12, 13, 14],
-    ///         [20, 21, 22, 23, 24],
-    ///         [30, 31, 32, 33, 34],
-    ///     ];
-    ///     worksheet.write_row_matrix(1, 1, data)?;
+    ///     worksheet.write_formula(0, 0, "=1+1")?;
+    ///     worksheet.write_formula(1, 0, "=1>2")?;
+    ///     worksheet.write_formula(2, 0, "=1/0")?;
     ///
-    ///     // Add formatting to some of the cells.
-    ///     worksheet.set_cell_format(1, 1, &red)?;
+    ///     // Set the formula results with the same types as Excel.
+    ///     worksheet.set_formula_typed_result(0, 0, 2);
+    ///     worksheet.set_formula_typed_result(1, 0, false);
+    ///     worksheet.set_formula_typed_result(2, 0, FormulaResult::Error("#DIV/0!".into()));
+    /// #
+    /// #     workbook.save("formulas.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_formula_typed_result(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        result: impl Into<FormulaResult>,
+    ) -> &mut Worksheet {
+        if let Some(columns) = self.data_table.get_mut(&row) {
+            if let Some(cell) = columns.get_mut(&col) {
+                match cell {
+                    CellType::Formula {
+                        result: cell_result,
+                        result_type: cell_result_type,
+                        ..
+                    }
+                    | CellType::ArrayFormula {
+                        result: cell_result,
+                        result_type: cell_result_type,
+                        ..
+                    } => {
+                        let (result, result_type) = result.into().to_result_and_type();
+                        *cell_result = Box::from(result);
+                        *cell_result_type = result_type;
+                    }
+                    _ => {
+                        eprintln!("Cell ({row}, {col}) doesn't contain a formula.");
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Set the typed results of several worksheet formulas at once.
+    ///
+    /// This is a bulk version of [`Worksheet::set_formula_typed_result()`]
+    /// that sets the cached results of the formulas in a worksheet from a map
+    /// of cell positions to [`FormulaResult`] values, or values that convert
+    /// to them such as numbers, booleans and strings.
+    ///
+    /// # Parameters
+    ///
+    /// - `results`: A map of `(row, col)` cell positions to formula results.
+    ///
+    /// # Warnings
+    ///
+    /// You will get a warning if you try to set a formula result for a cell
+    /// that doesn't have a formula.
+    ///
+    pub fn set_formula_typed_results<T>(
+        &mut self,
+        results: &HashMap<(RowNum, ColNum), T>,
+    ) -> &mut Worksheet
+    where
+        T: Into<FormulaResult> + Clone,
+    {
+        for ((row, col), result) in results {
+            self.set_formula_typed_result(*row, *col, result.clone());
+        }
+
+        self
+    }
+
+    /// Write the default formula result for worksheet formulas.
+    ///
+    /// The `rust_xlsxwriter` library doesn’t calculate the result of a formula
+    /// written using [`Worksheet::write_formula_with_format()`] or
+    /// [`Worksheet::write_formula()`]. Instead it stores the value 0 as the
+    /// formula result. It then sets a global flag in the xlsx file to say that
+    /// all formulas and functions should be recalculated when the file is
+    /// opened.
+    ///
+    /// However, for `LibreOffice` the default formula result should be set to
+    /// the empty string literal `""`, via the `set_formula_result_default()`
+    /// method, to force calculation of the result.
+    ///
+    /// # Parameters
+    ///
+    /// - `result`: The default formula result to write to the cell.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates manually setting the default result
+    /// for all non-calculated formulas in a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_formula_result_default.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.set_formula_result_default("");
+    /// #
+    /// #     workbook.save("formulas.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_formula_result_default(&mut self, result: impl Into<String>) -> &mut Worksheet {
+        self.default_result = Box::from(result.into());
+        self
+    }
+
+    /// Set the worksheet formulas to be recalculated when the file is opened.
+    ///
+    /// The `rust_xlsxwriter` library doesn't calculate the result of formulas
+    /// and sets a workbook level flag to tell Excel to recalculate all formulas
+    /// when the file is opened. However, some applications only refresh
+    /// dependent values, such as the cached data in charts that refer to
+    /// formula cells, when a recalculation flag is set in the worksheet.
+    ///
+    /// The `set_full_calc_on_load()` method adds a worksheet level
+    /// `fullCalcOnLoad` flag to the file so that these applications also
+    /// recalculate the worksheet when it is opened.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a worksheet to be
+    /// recalculated when the file is opened.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_full_calc_on_load.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_formula(0, 0, "=SUM(1, 2, 3)")?;
+    ///
+    ///     worksheet.set_full_calc_on_load(true);
+    /// #
+    /// #     workbook.save("formulas.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_full_calc_on_load(&mut self, enable: bool) -> &mut Worksheet {
+        self.full_calc_on_load = enable;
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet overlay/formatting methods.
+    // -----------------------------------------------------------------------
+
+    /// Add formatting to a cell without overwriting the cell data.
+    ///
+    /// In Excel the data in a worksheet cell is comprised of a type, a value
+    /// and a format. When using `rust_xlsxwriter` the type is inferred and the
+    /// value and format are generally written at the same time using methods
+    /// like [`Worksheet::write_with_format()`]. However, if required you can
+    /// write the data separately and then add the format using methods like
+    /// `set_cell_format()`.
+    ///
+    /// Although this method requires an additional step it allows for use cases
+    /// where it is easier to write a large amount of data in one go and then
+    /// figure out where formatting should be applied. See also the
+    /// documentation section on [Worksheet Cell
+    /// formatting](../worksheet/index.html#cell-formatting).
+    ///
+    /// For use cases where the cell formatting changes based on cell values
+    /// Conditional Formatting may be a better option (see [Working with
+    /// Conditional Formats](../conditional_format/index.html)).
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the format of a worksheet
+    /// cell separately from writing the cell data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_cell_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some formats.
+    /// #     let red = Format::new().set_font_color("#FF0000");
+    /// #     let green = Format::new().set_font_color("#00FF00");
+    /// #
+    ///     // Write an array of data.
+    ///     let data = [
+    ///         [10, 11, 12, 13, 14],
+    ///         [20, 21, 22, 23, 24],
+    ///         [30, 31, 32, 33, 34],
+    ///     ];
+    ///     worksheet.write_row_matrix(1, 1, data)?;
+    ///
+    ///     // Add formatting to some of the cells.
+    ///     worksheet.set_cell_format(1, 1, &red)?;
     ///     worksheet.set_cell_format(2, 3, &green)?;
     ///     worksheet.set_cell_format(3, 5, &red)?;
     /// #
@@ -8703,8 +11172,9 @@ impl Worksheet {
     /// This can occasionally be useful for scenarios where it is easier to add
     /// data in bulk but then remove certain elements.
     ///
-    /// This method only clears data, it doesn't clear images or conditional
-    /// formatting, or other non-data elements.
+    /// This method only clears data, and any hyperlink written with the data,
+    /// it doesn't clear images or conditional formatting, or other non-data
+    /// elements.
     ///
     /// Note, this method doesn't return a [`Result`] or errors. Instructions to
     /// clear non-existent cells are simply ignored.
@@ -8772,33 +11242,39 @@ impl Worksheet {
         self
     }
 
-    /// Clear the formatting from a worksheet cell.
+    /// Clear the data and formatting from a range of worksheet cells.
     ///
-    /// This method can be used to clear the formatting previously added to a
-    /// worksheet cell using one of the worksheet `write_with_format()` methods.
+    /// This is a range version of [`Worksheet::clear_cell()`] that clears the
+    /// data and formatting from all of the cells in a range. It can be used to
+    /// blank out sections of a worksheet that is being reused as a template.
     ///
-    /// This can occasionally be useful for scenarios where it is easier to add
-    /// formatted data in bulk but then remove the formatting from certain
-    /// elements.
+    /// Any hyperlinks in the range are removed with the cell data. Any merged
+    /// ranges that are completely within the cleared range are also removed,
+    /// i.e., the cells are unmerged. Cells without data are ignored.
     ///
-    /// See also the [`Worksheet::set_cell_format()`] method for a similar
-    /// method to change the format of a cell.
+    /// # Parameters
     ///
-    /// Note, this method doesn't return a [`Result`] or errors. Instructions to
-    /// clear non-existent cells are simply ignored.
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// - [`XlsxError::ParameterError`] - The range partially overlaps a merged
+    ///   range. Merged ranges must be cleared in their entirety.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates clearing the formatting from some
-    /// previously written cells in a worksheet.
+    /// The following example demonstrates clearing a range of previously
+    /// written cell data and formatting from a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_clear_cell_format.rs
+    /// # // This code is available in examples/doc_worksheet_clear_range.rs
     /// #
     /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
     /// #
@@ -8824,9 +11300,8 @@ impl Worksheet {
     ///     worksheet.write_row_with_format(1, 0, data[1], &format)?;
     ///     worksheet.write_row_with_format(2, 0, data[2], &format)?;
     ///
-    ///     // Clear the format from the first and last cells in the data.
-    ///     worksheet.clear_cell_format(0, 0);
-    ///     worksheet.clear_cell_format(2, 4);
+    ///     // Clear the data in the middle of the range.
+    ///     worksheet.clear_range(1, 1, 2, 3)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -8834,28 +11309,371 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_clear_cell_format.png">
-    ///
-    pub fn clear_cell_format(&mut self, row: RowNum, col: ColNum) -> &mut Worksheet {
-        // If cell is outside the allowed range it doesn't need to be cleared.
-        if !self.check_dimensions_only(row, col) {
-            return self;
+    pub fn clear_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        self.clear_cell_format_internal(row, col);
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
 
-        self
-    }
+        let clear_range = CellRange::new(first_row, first_col, last_row, last_col);
+        let is_in_clear_range = |range: &CellRange| {
+            range.first_row >= first_row
+                && range.last_row <= last_row
+                && range.first_col >= first_col
+                && range.last_col <= last_col
+        };
 
-    // -----------------------------------------------------------------------
-    // Worksheet serde methods.
-    // -----------------------------------------------------------------------
+        // Check that the range doesn't partially overlap any merged range.
+        let mut has_merged_ranges = false;
+        for merged_range in &self.merged_ranges {
+            let is_inside = is_in_clear_range(merged_range);
+            let is_outside = merged_range.last_row < first_row
+                || merged_range.first_row > last_row
+                || merged_range.last_col < first_col
+                || merged_range.first_col > last_col;
 
-    /// Write a Serde serializable struct to a worksheet.
-    ///
+            if !is_inside && !is_outside {
+                return Err(XlsxError::ParameterError(format!(
+                    "Clear range {} partially overlaps merged range {}",
+                    clear_range.to_error_string(),
+                    merged_range.to_error_string()
+                )));
+            }
+
+            has_merged_ranges |= is_inside;
+        }
+
+        // Clear the cell data, and any hyperlinks or metadata attached to the
+        // cells, in the range.
+        let is_in_cols = |col: &ColNum| (first_col..=last_col).contains(col);
+        let mut cells: BTreeSet<(RowNum, ColNum)> = self
+            .data_table
+            .range(first_row..=last_row)
+            .flat_map(|(&row, columns)| {
+                columns
+                    .keys()
+                    .filter(|col| is_in_cols(col))
+                    .map(move |&col| (row, col))
+            })
+            .collect();
+
+        cells.extend(
+            self.hyperlinks
+                .keys()
+                .chain(self.value_metadata.keys())
+                .filter(|(row, col)| (first_row..=last_row).contains(row) && is_in_cols(col)),
+        );
+
+        for (row, col) in cells {
+            self.clear_cell_internal(row, col);
+        }
+
+        // Remove any merged ranges within the cleared range and re-index the
+        // remaining ranges.
+        if has_merged_ranges {
+            let merged_ranges = mem::take(&mut self.merged_ranges);
+            self.merged_cells.clear();
+
+            for merged_range in merged_ranges {
+                if is_in_clear_range(&merged_range) {
+                    continue;
+                }
+
+                let index = self.merged_ranges.len();
+                for row in merged_range.first_row..=merged_range.last_row {
+                    for col in merged_range.first_col..=merged_range.last_col {
+                        self.merged_cells.insert((row, col), index);
+                    }
+                }
+
+                self.merged_ranges.push(merged_range);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Copy a range of cells to another worksheet.
+    ///
+    /// Copy the cell data, formats and merged ranges from a range in the
+    /// current worksheet to a location in another worksheet. The other
+    /// worksheet can be in the same workbook, or in a different workbook,
+    /// which can be used to export a selected range as a new workbook. The
+    /// cell formats are added to the destination worksheet as required.
+    ///
+    /// Relative cell references in formulas are adjusted for the new location,
+    /// in the same way as Excel does when a cell is copied and pasted.
+    /// Absolute references like `$A$1` aren't changed. If a relative reference
+    /// would be moved outside the worksheet then the formula is replaced by its
+    /// cached result value, or by a blank cell if it doesn't have a cached
+    /// result.
+    ///
+    /// Only cell data is copied. Hyperlinks, notes, images and other objects
+    /// attached to the worksheet aren't copied.
+    ///
+    /// # Parameters
+    ///
+    /// - `destination`: The [`Worksheet`] to copy the cells to.
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `dest_row`: The first row of the destination range.
+    /// - `dest_col`: The first column of the destination range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits, in the source or the destination.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// - [`XlsxError::ParameterError`] - The range partially overlaps a merged
+    ///   range. Merged ranges must be copied in their entirety.
+    /// - [`XlsxError::MergeRangeOverlaps`] - A copied merged range overlaps a
+    ///   merged range in the destination worksheet.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates copying a range of cells from one
+    /// worksheet into a new workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_copy_range_to.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut worksheet = Worksheet::new();
+    ///     let bold = Format::new().set_bold();
+    ///
+    ///     // Add some data to the worksheet.
+    ///     worksheet.merge_range(0, 0, 0, 1, "Sales", &bold)?;
+    ///     worksheet.write_row(1, 0, ["North", "South"])?;
+    ///     worksheet.write_row(2, 0, [100, 200])?;
+    ///     worksheet.write_formula(3, 0, "=SUM(A3:B3)")?;
+    ///
+    ///     // Copy part of the data to a worksheet in a new workbook. The
+    ///     // formula is adjusted to "=SUM(B4:C4)".
+    ///     let mut summary = Worksheet::new();
+    ///     worksheet.copy_range_to(&mut summary, 0, 0, 3, 1, 1, 1)?;
+    ///
+    ///     let mut workbook = Workbook::new();
+    ///     workbook.push_worksheet(summary);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_range_to(
+        &self,
+        destination: &mut Worksheet,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        dest_row: RowNum,
+        dest_col: ColNum,
+    ) -> Result<(), XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if last_row >= ROW_MAX || last_col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Check that the destination range is in the allowed range.
+        let (Some(dest_last_row), Some(dest_last_col)) = (
+            dest_row.checked_add(last_row - first_row),
+            dest_col.checked_add(last_col - first_col),
+        ) else {
+            return Err(XlsxError::RowColumnLimitError);
+        };
+
+        if !destination.check_dimensions_only(dest_last_row, dest_last_col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Get the merged ranges within the copy range and check that the range
+        // doesn't partially overlap any merged range.
+        let copy_range = CellRange::new(first_row, first_col, last_row, last_col);
+        let mut merged_ranges = vec![];
+
+        for merged_range in &self.merged_ranges {
+            let is_inside = merged_range.first_row >= first_row
+                && merged_range.last_row <= last_row
+                && merged_range.first_col >= first_col
+                && merged_range.last_col <= last_col;
+            let is_outside = merged_range.last_row < first_row
+                || merged_range.first_row > last_row
+                || merged_range.last_col < first_col
+                || merged_range.first_col > last_col;
+
+            if !is_inside && !is_outside {
+                return Err(XlsxError::ParameterError(format!(
+                    "Copy range {} partially overlaps merged range {}",
+                    copy_range.to_error_string(),
+                    merged_range.to_error_string()
+                )));
+            }
+
+            if is_inside {
+                merged_ranges.push(CellRange::new(
+                    dest_row + (merged_range.first_row - first_row),
+                    dest_col + (merged_range.first_col - first_col),
+                    dest_row + (merged_range.last_row - first_row),
+                    dest_col + (merged_range.last_col - first_col),
+                ));
+            }
+        }
+
+        // Check that the copied merged ranges don't overlap any merged range
+        // in the destination worksheet.
+        for merged_range in &merged_ranges {
+            for row in merged_range.first_row..=merged_range.last_row {
+                for col in merged_range.first_col..=merged_range.last_col {
+                    if let Some(index) = destination.merged_cells.get(&(row, col)) {
+                        return Err(XlsxError::MergeRangeOverlaps(
+                            merged_range.to_error_string(),
+                            destination.merged_ranges[*index].to_error_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Copy the cell data, adjusting formulas for the new location.
+        let row_offset = i64::from(dest_row) - i64::from(first_row);
+        let col_offset = i64::from(dest_col) - i64::from(first_col);
+
+        for (row, columns) in self.data_table.range(first_row..=last_row) {
+            for (col, cell) in columns.range(first_col..=last_col) {
+                let row = dest_row + (row - first_row);
+                let col = dest_col + (col - first_col);
+                let cell = destination.copy_cell(cell, &self.xf_formats, row_offset, col_offset);
+
+                destination.check_dimensions(row, col);
+                destination.insert_cell(row, col, cell);
+            }
+        }
+
+        // Store the merged ranges.
+        for merged_range in merged_ranges {
+            let index = destination.merged_ranges.len();
+            for row in merged_range.first_row..=merged_range.last_row {
+                for col in merged_range.first_col..=merged_range.last_col {
+                    destination.merged_cells.insert((row, col), index);
+                }
+            }
+
+            destination.merged_ranges.push(merged_range);
+        }
+
+        Ok(())
+    }
+
+    /// Clear the formatting from a worksheet cell.
+    ///
+    /// This method can be used to clear the formatting previously added to a
+    /// worksheet cell using one of the worksheet `write_with_format()` methods.
+    ///
+    /// This can occasionally be useful for scenarios where it is easier to add
+    /// formatted data in bulk but then remove the formatting from certain
+    /// elements.
+    ///
+    /// See also the [`Worksheet::set_cell_format()`] method for a similar
+    /// method to change the format of a cell.
+    ///
+    /// Note, this method doesn't return a [`Result`] or errors. Instructions to
+    /// clear non-existent cells are simply ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates clearing the formatting from some
+    /// previously written cells in a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_clear_cell_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add a format.
+    /// #     let format = Format::new().set_font_color("#FF0000");
+    /// #
+    ///     // Some array data to write.
+    ///     let data = [
+    ///         [10, 11, 12, 13, 14],
+    ///         [20, 21, 22, 23, 24],
+    ///         [30, 31, 32, 33, 34],
+    ///     ];
+    ///
+    ///     // Write the array data as a series of rows.
+    ///     worksheet.write_row_with_format(0, 0, data[0], &format)?;
+    ///     worksheet.write_row_with_format(1, 0, data[1], &format)?;
+    ///     worksheet.write_row_with_format(2, 0, data[2], &format)?;
+    ///
+    ///     // Clear the format from the first and last cells in the data.
+    ///     worksheet.clear_cell_format(0, 0);
+    ///     worksheet.clear_cell_format(2, 4);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_clear_cell_format.png">
+    ///
+    pub fn clear_cell_format(&mut self, row: RowNum, col: ColNum) -> &mut Worksheet {
+        // If cell is outside the allowed range it doesn't need to be cleared.
+        if !self.check_dimensions_only(row, col) {
+            return self;
+        }
+
+        self.clear_cell_format_internal(row, col);
+
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet serde methods.
+    // -----------------------------------------------------------------------
+
+    /// Write a Serde serializable struct to a worksheet.
+    ///
     /// This method can be used to serialize [Serde](https://serde.rs) enabled
     /// data structures into cells in a worksheet.
     ///
@@ -8902,6 +11720,11 @@ impl Worksheet {
     /// Once the headers are set up an subsequent calls to `serialize()` will
     /// write the struct data in rows beneath the header.
     ///
+    /// Maps, such as a `BTreeMap` of scalar values, can also be serialized.
+    /// Each entry is written as a row with the key in the first column and the
+    /// value in the second column. See [Serializing
+    /// maps](crate::serializer#serializing-maps).
+    ///
     ///
     /// # Parameters
     ///
@@ -8974,18 +11797,428 @@ impl Worksheet {
     /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
-    ///
-    #[cfg(feature = "serde")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn serialize<T>(&mut self, data_structure: &T) -> Result<&mut Worksheet, XlsxError>
-    where
-        T: Serialize,
-    {
-        self.serialize_data_structure(data_structure)?;
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize.png">
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize<T>(&mut self, data_structure: &T) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: Serialize,
+    {
+        self.serialize_data_structure(data_structure)?;
+
+        Ok(self)
+    }
+
+    /// Serialize a map of keyed Serde derived structs to a worksheet.
+    ///
+    /// This method serializes `(key, struct)` pairs, such as the entries of a
+    /// `HashMap<u32, Record>`, to a worksheet. The struct fields are written
+    /// as per [`Worksheet::serialize()`] and the key is written to the key
+    /// column of the same row. The key column must be set up, along with the
+    /// other serialization headers, via
+    /// [`SerializeFieldOptions::set_key_field()`].
+    ///
+    /// The rows are written in the iteration order of the data. Since the
+    /// order of a `HashMap` isn't defined you may prefer to use a `BTreeMap`
+    /// or a sorted `Vec` of pairs.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: An iterator, or a reference to a map, of key and struct
+    ///   pairs that implement the [`serde::Serializer`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The serialized struct doesn't have a
+    ///   key field.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing a map of keyed structs
+    /// with the key written to the first column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_keyed.rs
+    /// #
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// # use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let item1 = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///     };
+    ///     let item2 = Produce {
+    ///         fruit: "Plum",
+    ///         cost: 0.15,
+    ///     };
+    ///     let item3 = Produce {
+    ///         fruit: "Pear",
+    ///         cost: 0.75,
+    ///     };
+    ///
+    ///     // Create a map of the data keyed by id.
+    ///     let items = BTreeMap::from([(101, item1), (102, item2), (103, item3)]);
+    ///
+    ///     // Add a key column with the header "Id".
+    ///     let key_field = CustomSerializeField::new("id").rename("Id");
+    ///     let header_options = SerializeFieldOptions::new().set_key_field(&key_field);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the keys and data.
+    ///     worksheet.serialize_keyed(&items)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_keyed<I, K, V>(&mut self, data: I) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Serialize,
+        V: Serialize,
+    {
+        let mut data = data.into_iter().peekable();
+        let Some((_, first_value)) = data.peek() else {
+            return Ok(self);
+        };
+
+        // Check that the target struct has a key field before writing any data.
+        // Maps don't have a type name so they use the current map area name.
+        let mut headers = SerializerHeader::new();
+        first_value.serialize(&mut headers)?;
+
+        let struct_name = if headers.struct_name == MAP_STRUCT_NAME {
+            self.serializer_state.map_struct_name.clone()
+        } else {
+            headers.struct_name
+        };
+
+        let Some(key_field) = self.serializer_state.key_field(&struct_name) else {
+            return Err(XlsxError::ParameterError(format!(
+                "No key field set for serialized struct '{struct_name}'"
+            )));
+        };
+
+        for (key, value) in data {
+            self.serialize_data_structure(&value)?;
+
+            // Write the key to the key column of the row that was just written.
+            self.serializer_state.current_field.clone_from(&key_field);
+            key.serialize(&mut *self)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write a Polars `DataFrame` to a worksheet.
+    ///
+    /// The `write_dataframe()` method writes the columns of a
+    /// [`polars::prelude::DataFrame`] to a worksheet, with a bold header row
+    /// of the column names followed by the column data. The data is written
+    /// directly from each column, one column at a time, without converting
+    /// the frame to rows.
+    ///
+    /// The column data types are mapped to Excel types as follows:
+    ///
+    /// - Integer and float columns are written as numbers.
+    /// - Boolean columns are written as booleans.
+    /// - String columns are written as strings.
+    /// - Date, Datetime and Time columns are written as Excel dates/times with
+    ///   the number formats `yyyy-mm-dd`, `yyyy-mm-dd hh:mm:ss` and
+    ///   `hh:mm:ss`, respectively. Any timezone is ignored.
+    /// - Other types are cast to strings.
+    ///
+    /// Null values are left as blank cells.
+    ///
+    /// This method provides a simple mapping for common data types. For more
+    /// control over the output, such as custom formats or writing the data as
+    /// a worksheet table, see the [`polars_excel_writer`] crate.
+    ///
+    /// [`polars_excel_writer`]: https://crates.io/crates/polars_excel_writer
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the header row.
+    /// - `col`: The zero indexed column number of the first column.
+    /// - `dataframe`: A Polars [`DataFrame`](polars::prelude::DataFrame)
+    ///   reference.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - The data would exceed Excel's
+    ///   worksheet limits. Nothing is written in this case.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::PolarsError`] - A column couldn't be cast to a supported
+    ///   type.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a Polars dataframe to a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_dataframe.rs
+    /// #
+    /// # use polars::prelude::*;
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let df = df!(
+    ///         "Region" => &["East", "West", "North"],
+    ///         "Sales" => &[Some(3000), None, Some(4000)],
+    ///         "Target" => &[true, false, true],
+    ///     )?;
+    ///
+    ///     worksheet.write_dataframe(0, 0, &df)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "polars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+    pub fn write_dataframe(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        dataframe: &DataFrame,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if dataframe.width() == 0 {
+            return Ok(self);
+        }
+
+        // Check that the data fits in the worksheet before writing anything.
+        let last_row = u64::from(row) + dataframe.height() as u64;
+        let last_col = u64::from(col) + dataframe.width() as u64 - 1;
+        if last_row >= u64::from(ROW_MAX) || last_col >= u64::from(COL_MAX) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        let header_format = Format::new().set_bold();
+        let date_format = Format::new().set_num_format("yyyy-mm-dd");
+        let datetime_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+        let time_format = Format::new().set_num_format("hh:mm:ss");
+
+        for (index, series) in dataframe.get_columns().iter().enumerate() {
+            let col = col + index as ColNum;
+            let row = row + 1;
+
+            self.write_string_with_format(row - 1, col, series.name(), &header_format)?;
+
+            match series.dtype() {
+                DataType::Boolean => {
+                    for (offset, value) in series.bool()?.into_iter().enumerate() {
+                        if let Some(value) = value {
+                            self.write_boolean(row + offset as RowNum, col, value)?;
+                        }
+                    }
+                }
+                DataType::Int8 => {
+                    let values = series.i8()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(f64::from)))?;
+                }
+                DataType::Int16 => {
+                    let values = series.i16()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(f64::from)))?;
+                }
+                DataType::Int32 => {
+                    let values = series.i32()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(f64::from)))?;
+                }
+                DataType::Int64 => {
+                    let values = series.i64()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(|v| v as f64)))?;
+                }
+                DataType::UInt8 => {
+                    let values = series.u8()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(f64::from)))?;
+                }
+                DataType::UInt16 => {
+                    let values = series.u16()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(f64::from)))?;
+                }
+                DataType::UInt32 => {
+                    let values = series.u32()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(f64::from)))?;
+                }
+                DataType::UInt64 => {
+                    let values = series.u64()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(|v| v as f64)))?;
+                }
+                DataType::Float32 => {
+                    let values = series.f32()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values.map(|v| v.map(f64::from)))?;
+                }
+                DataType::Float64 => {
+                    let values = series.f64()?.into_iter();
+                    self.write_dataframe_numbers(row, col, values)?;
+                }
+                DataType::Date => {
+                    // Convert days since the Unix epoch to an Excel serial date.
+                    let values = series.date()?.into_iter();
+                    let values = values.map(|v| v.map(|days| f64::from(days) + 25569.0));
+                    self.write_dataframe_datetimes(row, col, values, &date_format)?;
+                }
+                DataType::Datetime(time_unit, _) => {
+                    let units_per_day = match time_unit {
+                        TimeUnit::Nanoseconds => 86_400_000_000_000.0,
+                        TimeUnit::Microseconds => 86_400_000_000.0,
+                        TimeUnit::Milliseconds => 86_400_000.0,
+                    };
+
+                    // Convert the time units since the Unix epoch to an Excel
+                    // serial datetime.
+                    let values = series.datetime()?.into_iter();
+                    let values =
+                        values.map(|v| v.map(|units| units as f64 / units_per_day + 25569.0));
+                    self.write_dataframe_datetimes(row, col, values, &datetime_format)?;
+                }
+                DataType::Time => {
+                    // Convert nanoseconds since midnight to an Excel time.
+                    let values = series.time()?.into_iter();
+                    let values = values.map(|v| v.map(|ns| ns as f64 / 86_400_000_000_000.0));
+                    self.write_dataframe_datetimes(row, col, values, &time_format)?;
+                }
+                DataType::String => {
+                    self.write_dataframe_strings(row, col, series.str()?.into_iter())?;
+                }
+                _ => {
+                    let series = series.cast(&DataType::String)?;
+                    self.write_dataframe_strings(row, col, series.str()?.into_iter())?;
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Write a `serde_json::Value` to a worksheet.
+    ///
+    /// The `write_json()` method writes an arbitrary JSON value, such as the
+    /// result of parsing a JSON file with [`serde_json`], to a worksheet. It is
+    /// intended as a quick way to dump JSON data for inspection rather than
+    /// as a replacement for the [`Worksheet::serialize()`] methods, which
+    /// provide more control over the output.
+    ///
+    /// The value is laid out, starting at the `row` and `col` cell, using the
+    /// following strategy:
+    ///
+    /// - Scalar values are written to a single cell. Strings, numbers and
+    ///   booleans are written as the equivalent Excel types. Null values are
+    ///   left as empty cells.
+    /// - Objects are written as key/value pairs with one pair per row. The key
+    ///   is written in the first column and the value in the next column.
+    /// - Nested objects are flattened into dotted keys so that `{"a": {"b":
+    ///   1}}` is written as the key `a.b` with the value 1.
+    /// - Arrays where every element is an object are written as a table with
+    ///   a bold header row of the flattened keys, in the order they are first
+    ///   found, and one row per object.
+    /// - Other arrays are written with one element per row. Elements that are
+    ///   objects or arrays are laid out recursively using these rules.
+    /// - Within a table, array and empty object values are written as a
+    ///   compact JSON string.
+    ///
+    /// Note, `serde_json` stores object keys in alphabetical order unless
+    /// its `preserve_order` feature is enabled, in which case the keys are
+    /// written in the order of the original JSON. Numbers are written as
+    /// `f64` values so integers larger than 2<sup>53</sup> lose precision, as
+    /// they would in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `value`: A [`serde_json::Value`] reference.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing JSON data to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_json.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use serde_json::json;
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let data = json!({
+    ///         "name": "Quarterly sales",
+    ///         "meta": {"year": 2024, "final": true},
+    ///         "sales": [
+    ///             {"region": "East", "total": 3000},
+    ///             {"region": "West", "total": 8000},
+    ///         ],
+    ///     });
+    ///
+    ///     worksheet.write_json(0, 0, &data)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn write_json(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        value: &serde_json::Value,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let header_format = Format::new().set_bold();
+
+        self.write_json_value(row, col, value, &header_format)?;
 
         Ok(self)
     }
@@ -9260,10 +12493,7 @@ impl Worksheet {
         T: Serialize,
     {
         // Serialize the struct to determine the type name and the fields.
-        let mut headers = SerializerHeader {
-            struct_name: String::new(),
-            field_names: vec![],
-        };
+        let mut headers = SerializerHeader::new();
 
         data_structure.serialize(&mut headers)?;
 
@@ -9296,6 +12526,9 @@ impl Worksheet {
     /// requires the serializable type and not an actual instance. That method
     /// requires that your struct also derives "Deserialize".
     ///
+    /// See also [`Worksheet::serialize_headers_checked()`] which reports all of
+    /// the custom field names that don't match a field of the struct.
+    ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
@@ -9402,12 +12635,142 @@ impl Worksheet {
         T: Serialize,
     {
         // Serialize the struct to determine the type name and the fields.
-        let mut headers = SerializerHeader {
-            struct_name: String::new(),
-            field_names: vec![],
-        };
+        let mut headers = SerializerHeader::new();
+
+        data_structure.serialize(&mut headers)?;
+        self.store_serialization_headers_with_options(row, col, &headers, header_options)
+    }
 
+    /// Write the location and headers for data serialization, with additional
+    /// options, after checking the custom field names.
+    ///
+    /// The `serialize_headers_checked()` method is the same as
+    /// [`Worksheet::serialize_headers_with_options()`] except that it first
+    /// checks that the `field_name` of each of the [`CustomSerializeField`]
+    /// headers matches a field of the serialized struct. If any of them don't
+    /// match then an error is returned that lists all of the unknown field
+    /// names along with the actual field names of the struct. This makes it
+    /// easier to find misspelled or outdated field names in the custom
+    /// headers.
+    ///
+    /// The actual field names are the names that Serde serializes, so they
+    /// take into account any `#[serde(rename)]` attributes and, if they are
+    /// enabled, the "." separated paths of nested struct fields.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data_structure`: A reference to a struct that implements the
+    ///   [`serde::Serializer`] trait.
+    /// - `header_options`: A [`SerializeFieldOptions`] instance.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization or custom field names that don't match the fields of
+    ///   the struct.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates checking the custom header field
+    /// names of a Serde derived data structure to catch a misspelled field
+    /// name.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_headers_checked.rs
+    /// #
+    /// use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+    /// use serde::Serialize;
+    ///
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Create a serializable struct.
+    ///     #[derive(Serialize)]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create a data instance.
+    ///     let item = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///     };
+    ///
+    ///     // Set up custom headers with a misspelled field name.
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("fruit").rename("Fruit"),
+    ///         CustomSerializeField::new("cots").rename("Price"),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+    ///
+    ///     // Check the custom headers against the fields of the struct.
+    ///     let result = worksheet.serialize_headers_checked(0, 0, &item, &header_options);
+    ///
+    ///     // Print the error. This shows:
+    ///     //
+    ///     //   Serialization error: 'Custom field name 'cots' not found in
+    ///     //   struct 'Produce'. Valid field names are: 'fruit', 'cost''.
+    ///     //
+    ///     if let Err(error) = result {
+    ///         println!("{error}");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_headers_checked<T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data_structure: &T,
+        header_options: &SerializeFieldOptions,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: Serialize,
+    {
+        // Serialize the struct to determine the type name and the fields.
+        let mut headers = SerializerHeader::new();
         data_structure.serialize(&mut headers)?;
+
+        // Check the custom field names against the actual field names and
+        // the names of any nested struct groups.
+        let (field_names, group_names) = Self::serialization_field_names(&headers, header_options)?;
+        let field_names: Vec<String> = field_names.into_iter().chain(group_names).collect();
+
+        let unknown_names: Vec<String> = header_options
+            .custom_headers
+            .iter()
+            .filter(|custom_header| !field_names.contains(&custom_header.field_name))
+            .map(|custom_header| format!("'{}'", custom_header.field_name))
+            .collect();
+
+        if !unknown_names.is_empty() {
+            let field_names: Vec<String> = field_names
+                .iter()
+                .map(|field_name| format!("'{field_name}'"))
+                .collect();
+
+            return Err(XlsxError::SerdeError(format!(
+                "Custom field name {} not found in struct '{}'. Valid field names are: {}",
+                unknown_names.join(", "),
+                headers.struct_name,
+                field_names.join(", ")
+            )));
+        }
+
         self.store_serialization_headers_with_options(row, col, &headers, header_options)
     }
 
@@ -10093,13 +13456,282 @@ impl Worksheet {
     ///
     #[cfg(feature = "serde")]
     #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn get_serialize_column_dimensions(
-        &mut self,
-        struct_name: &str,
-        field_name: &str,
-    ) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
-        self.serializer_state
-            .get_column_dimensions(struct_name, field_name)
+    pub fn get_serialize_column_dimensions(
+        &mut self,
+        struct_name: &str,
+        field_name: &str,
+    ) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
+        self.serializer_state
+            .get_column_dimensions(struct_name, field_name)
+    }
+
+    /// Reset the serialization position of a struct to the start of the data.
+    ///
+    /// Each call to [`Worksheet::serialize()`] writes the struct data to the
+    /// next row below the previously serialized data. The `serialize_reset()`
+    /// method resets the write position of a serialized struct type back to
+    /// the first row below the headers, or the first column beside the headers
+    /// for a transposed layout, so that subsequent serializations overwrite
+    /// the existing data. This is useful for regenerating data in place
+    /// without setting up the headers again.
+    ///
+    /// Note, any previously serialized data that isn't overwritten isn't
+    /// cleared. Use [`Worksheet::clear_range()`] with the dimensions from
+    /// [`Worksheet::get_serialize_dimensions()`] if required.
+    ///
+    /// An unknown struct name is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `struct_name`: The name/type of the target struct as a string.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates resetting the serialization
+    /// position to overwrite previously serialized data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_reset.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Set up the start location and headers of the data to be serialized.
+    ///     worksheet.deserialize_headers::<Produce>(0, 0)?;
+    ///
+    ///     // Serialize some initial data.
+    ///     worksheet.serialize(&[
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cost: 1.05,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cost: 0.15,
+    ///         },
+    ///     ])?;
+    ///
+    ///     // Reset the serialization position and overwrite the data with new values.
+    ///     worksheet.serialize_reset("Produce");
+    ///
+    ///     worksheet.serialize(&[
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cost: 1.10,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cost: 0.20,
+    ///         },
+    ///     ])?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_reset(&mut self, struct_name: &str) -> &mut Worksheet {
+        self.serializer_state.reset_position(struct_name);
+        self
+    }
+
+    /// Get the number of struct instances serialized for a struct type.
+    ///
+    /// The `serialized_row_count()` method returns the number of rows of data,
+    /// not including the headers, that have been serialized for a struct type
+    /// via [`Worksheet::serialize()`]. This is useful when data is serialized
+    /// from an iterator, or in chunks, and you need to know where the data
+    /// ends, for example to write a totals row below it. For a transposed
+    /// layout the count is the number of columns of data.
+    ///
+    /// The count is reset by [`Worksheet::serialize_reset()`] and by setting
+    /// up the headers for the struct type again. It returns `None` if the
+    /// struct name is unknown.
+    ///
+    /// # Parameters
+    ///
+    /// - `struct_name`: The name/type of the target struct as a string.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting the number of serialized
+    /// rows in order to write a totals row below the data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialized_row_count.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Formula, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Set up the start location and headers of the data to be serialized.
+    ///     worksheet.deserialize_headers::<Produce>(0, 0)?;
+    ///
+    ///     // Serialize the data in chunks.
+    ///     for chunk in [["Peach", "Plum"], ["Pear", "Apple"]] {
+    ///         for fruit in chunk {
+    ///             worksheet.serialize(&Produce { fruit, cost: 1.25 })?;
+    ///         }
+    ///     }
+    ///
+    ///     // Get the number of serialized rows.
+    ///     let row_count = worksheet.serialized_row_count("Produce").unwrap_or_default();
+    ///
+    ///     // Write a totals row below the data, which starts after the header row.
+    ///     let bold = Format::new().set_bold();
+    ///     let total_row = row_count + 1;
+    ///
+    ///     worksheet.write_with_format(total_row, 0, "Total", &bold)?;
+    ///     worksheet.write_formula_with_format(
+    ///         total_row,
+    ///         1,
+    ///         Formula::new(format!("=SUM(B2:B{total_row})")),
+    ///         &bold,
+    ///     )?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialized_row_count(&self, struct_name: &str) -> Option<RowNum> {
+        self.serializer_state.get_row_count(struct_name)
+    }
+
+    /// Autofit the column widths of the serialized data, approximately.
+    ///
+    /// The `autofit_serialized_columns()` method sets the widths of the columns
+    /// that contain serialized headers and data based on the maximum width of
+    /// the data written to them. It is similar to [`Worksheet::autofit()`] and
+    /// uses the same width calculations, and limitations, but it only applies
+    /// to the serialized columns. The widths are tracked as the data is
+    /// serialized so it doesn't need to iterate over all of the cells in the
+    /// worksheet which can be expensive for larger worksheets.
+    ///
+    /// The widths are tracked for all of the data serialized to the worksheet,
+    /// for any struct type, so the method should be called after the data has
+    /// been serialized. Columns that have already been set to a width that is
+    /// greater than the calculated width aren't changed.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates autofitting the columns of
+    /// serialized data without autofitting the rest of the worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_autofit_serialized_columns.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Customer {
+    ///         name: &'static str,
+    ///         email: &'static str,
+    ///         city: &'static str,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let customers = [
+    ///         Customer {
+    ///             name: "Alice Anderson",
+    ///             email: "alice.anderson@example.com",
+    ///             city: "Amsterdam",
+    ///         },
+    ///         Customer {
+    ///             name: "Bob Brown",
+    ///             email: "bob@example.com",
+    ///             city: "Buenos Aires",
+    ///         },
+    ///     ];
+    ///
+    ///     // Set up the start location and headers of the data to be serialized.
+    ///     worksheet.deserialize_headers::<Customer>(0, 0)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&customers)?;
+    ///
+    ///     // Autofit the columns of the serialized data.
+    ///     worksheet.autofit_serialized_columns();
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn autofit_serialized_columns(&mut self) -> &mut Worksheet {
+        let max_widths = self.serializer_state.column_widths.clone();
+        self.store_autofit_widths(&max_widths);
+
+        self
+    }
+
+    // Get the field names of a serialized struct, including the "." separated
+    // paths of the fields of any nested structs if required, and the names of
+    // the nested struct groups.
+    #[cfg(feature = "serde")]
+    fn serialization_field_names(
+        headers: &SerializerHeader,
+        header_options: &SerializeFieldOptions,
+    ) -> Result<(Vec<String>, Vec<String>), XlsxError> {
+        let mut field_names = vec![];
+        let mut group_names = vec![];
+        if header_options.use_map_keys_as_headers && headers.struct_name == MAP_STRUCT_NAME {
+            field_names.clone_from(&headers.map_keys);
+        } else if header_options.use_nested_headers {
+            headers.nested_field_names("", 0, &mut field_names, &mut group_names)?;
+        } else {
+            field_names.clone_from(&headers.field_names);
+        }
+
+        Ok((field_names, group_names))
     }
 
     // Store serialization headers and options.
@@ -10111,8 +13743,22 @@ impl Worksheet {
         headers: &SerializerHeader,
         header_options: &SerializeFieldOptions,
     ) -> Result<&mut Worksheet, XlsxError> {
+        let (all_field_names, group_names) =
+            Self::serialization_field_names(headers, header_options)?;
+
+        // Check for duplicate field names, such as a field name that contains
+        // "." and matches the path of a nested field.
+        let mut field_names: HashSet<String> = HashSet::new();
+        for field_name in all_field_names.iter().chain(&group_names) {
+            if !field_names.insert(field_name.clone()) {
+                return Err(XlsxError::ParameterError(format!(
+                    "Duplicate field name '{field_name}' found for struct '{}'",
+                    headers.struct_name
+                )));
+            }
+        }
+
         // Check that any custom field names match the actual field names.
-        let field_names: HashSet<String> = HashSet::from_iter(headers.field_names.clone());
         for custom_header in &header_options.custom_headers {
             if !field_names.contains(&custom_header.field_name) {
                 return Err(XlsxError::ParameterError(format!(
@@ -10129,9 +13775,33 @@ impl Worksheet {
             custom_fields.insert(&custom_header.field_name, custom_header);
         }
 
-        // Clone the header options to modify it and store it internally.
+        // Clone the header options to modify it and store it internally. Maps
+        // don't have a type name so they can be given one by the user, and the
+        // map area becomes the target of the next serialized map.
         let mut header_options = header_options.clone();
-        header_options.struct_name = headers.struct_name.clone();
+        if headers.struct_name == MAP_STRUCT_NAME {
+            if header_options.struct_name.is_empty() {
+                header_options.struct_name = MAP_STRUCT_NAME.to_string();
+            }
+            self.serializer_state
+                .map_struct_name
+                .clone_from(&header_options.struct_name);
+        } else {
+            header_options.struct_name = headers.struct_name.clone();
+        }
+
+        // Separate the group labels of nested structs from the field headers.
+        header_options.group_headers = group_names
+            .iter()
+            .map(|group_name| match custom_fields.get(group_name) {
+                Some(custom_field) => (*custom_field).clone(),
+                None => CustomSerializeField::new(group_name),
+            })
+            .collect();
+
+        header_options
+            .custom_headers
+            .retain(|custom_header| !group_names.contains(&custom_header.field_name));
 
         // Create a "custom" header for default fields or replace them with user
         // specified custom fields. The "use_custom_headers_only" overrides the
@@ -10139,7 +13809,7 @@ impl Worksheet {
         if !header_options.use_custom_headers_only {
             let mut custom_headers: Vec<CustomSerializeField> = vec![];
 
-            for field_name in &headers.field_names {
+            for field_name in &all_field_names {
                 match custom_fields.get(field_name) {
                     Some(custom_field) => {
                         if !custom_field.skip {
@@ -10153,6 +13823,22 @@ impl Worksheet {
             header_options.custom_headers = custom_headers;
         }
 
+        // The default headers of nested fields and groups are the last part
+        // of the field path.
+        if header_options.use_nested_headers {
+            for custom_header in header_options
+                .custom_headers
+                .iter_mut()
+                .chain(&mut header_options.group_headers)
+            {
+                if custom_header.header_name == custom_header.field_name {
+                    if let Some((_, name)) = custom_header.field_name.rsplit_once('.') {
+                        custom_header.header_name = name.to_string();
+                    }
+                }
+            }
+        }
+
         self.store_custom_serialization_headers(row, col, &header_options)
     }
 
@@ -10172,6 +13858,12 @@ impl Worksheet {
             .map(CustomSerializeField::new)
             .collect();
 
+        // Maps use the default map name and become the target of the next
+        // serialized map.
+        if headers.struct_name == MAP_STRUCT_NAME {
+            self.serializer_state.map_struct_name = MAP_STRUCT_NAME.to_string();
+        }
+
         // Transfer the options to a default option struct.
         let header_options = SerializeFieldOptions {
             struct_name: headers.struct_name.clone(),
@@ -10212,60 +13904,151 @@ impl Worksheet {
             )));
         }
 
+        // Add the map key field, if any, as the first column.
+        let mut custom_headers = header_options.custom_headers.clone();
+        if let Some(key_field) = &header_options.key_field {
+            if custom_headers
+                .iter()
+                .any(|custom_header| custom_header.field_name == key_field.field_name)
+            {
+                return Err(XlsxError::ParameterError(format!(
+                    "Key field name '{}' is already a field of struct '{}'",
+                    key_field.field_name, header_options.struct_name
+                )));
+            }
+
+            custom_headers.insert(0, key_field.clone());
+        }
+
+        // Nested struct fields are written with multi-level headers which
+        // can't be used with a table.
+        let has_nested_headers = !header_options.group_headers.is_empty();
+        if has_nested_headers && header_options.table.is_some() {
+            return Err(XlsxError::ParameterError(format!(
+                "Tables aren't supported with nested headers for struct '{}'",
+                header_options.struct_name
+            )));
+        }
+
+        // The transposed layout has the headers in a column so it can't be
+        // used with multi-level headers or a table.
+        let transpose = header_options.transpose;
+        if transpose && (has_nested_headers || header_options.table.is_some()) {
+            return Err(XlsxError::ParameterError(format!(
+                "Tables and nested headers aren't supported with a transposed layout for struct '{}'",
+                header_options.struct_name
+            )));
+        }
+
         let mut fields = HashMap::new();
+        let mut header_fields = vec![];
         let min_row = row;
         let min_col = col;
         let mut max_row = row;
         let mut max_col = col;
 
+        let row_initial = row;
         let col_initial = col;
         let write_headers = header_options.has_headers;
 
-        let mut col_offset = 0;
-        for custom_header in &header_options.custom_headers {
+        // Table headers with an autofilter need an additional 16 pixels, when
+        // autofitted, for the dropdown arrow.
+        let autofilter_padding = match &header_options.table {
+            Some(table) if table.show_autofilter => 16,
+            _ => 0,
+        };
+
+        let mut offset = 0;
+        for custom_header in &custom_headers {
             if custom_header.skip {
                 continue;
             }
 
-            let col = col_initial + col_offset as u16;
+            // Fields are mapped to columns, or to rows in the transposed
+            // layout.
             let mut custom_header = custom_header.clone();
+            let (row, col) = if transpose {
+                max_row = row_initial + offset as RowNum;
+                (max_row, col_initial)
+            } else {
+                max_col = col_initial + offset as ColNum;
+                (row_initial, max_col)
+            };
+            custom_header.row = row;
             custom_header.col = col;
-            max_col = col;
-            col_offset += 1;
-
-            // Set the column width if specified by user.
-            if let Some(width) = custom_header.width {
-                self.set_column_width(col, width)?;
-            } else if let Some(pixel_width) = custom_header.pixel_width {
-                self.set_column_width_pixels(col, pixel_width)?;
+            offset += 1;
+
+            if !self.check_dimensions_only(row, col) {
+                return Err(XlsxError::RowColumnLimitError);
             }
 
-            // Set the column format if specified by user.
-            if let Some(format) = &custom_header.column_format {
-                self.set_column_format(col, format)?;
+            // Set the column width and format if specified by user. These
+            // don't apply to the rows of the transposed layout.
+            if !transpose {
+                if let Some(width) = custom_header.width {
+                    self.set_column_width(col, width)?;
+                } else if let Some(pixel_width) = custom_header.pixel_width {
+                    self.set_column_width_pixels(col, pixel_width)?;
+                }
+
+                if let Some(format) = &custom_header.column_format {
+                    self.set_column_format(col, format)?;
+                }
             }
 
             // Use the column specific header format or else the header row
             // format, and if neither of those have been specified then write
-            // without a format.
-            if write_headers {
+            // without a format. Nested headers are written below.
+            if write_headers && !has_nested_headers {
                 if let Some(format) = &custom_header.header_format {
-                    self.write_with_format(max_row, col, &custom_header.header_name, format)?;
+                    self.write_with_format(row, col, &custom_header.header_name, format)?;
                 } else if let Some(format) = &header_options.header_format {
-                    self.write_with_format(max_row, col, &custom_header.header_name, format)?;
+                    self.write_with_format(row, col, &custom_header.header_name, format)?;
                 } else {
-                    self.write(max_row, col, &custom_header.header_name)?;
+                    self.write(row, col, &custom_header.header_name)?;
                 };
+
+                self.store_serialized_width(row, col, autofilter_padding);
+            }
+
+            if has_nested_headers {
+                header_fields.push(custom_header.clone());
             }
 
             fields.insert(custom_header.field_name.clone(), custom_header);
         }
 
-        // Start the data serialization one row down if headers were written.
-        if write_headers {
+        // Start the data serialization below the headers, or to the right of
+        // them in the transposed layout, if written.
+        if write_headers && has_nested_headers {
+            max_row +=
+                self.write_nested_serialization_headers(row, &header_fields, header_options)?;
+        } else if write_headers && transpose {
+            max_col += 1;
+        } else if write_headers {
             max_row += 1;
         }
 
+        // Pre-format the cells below, or to the right of, the headers if
+        // specified by user.
+        for custom_header in fields.values() {
+            if let Some(format) = custom_header.value_format.as_ref() {
+                let range_len = custom_header.format_range_len;
+                if transpose {
+                    let last_col =
+                        max_col.saturating_add(range_len.min(u32::from(COL_MAX)) as ColNum);
+                    for col in max_col..last_col {
+                        self.write_blank(custom_header.row, col, format)?;
+                    }
+                } else {
+                    let last_row = max_row.saturating_add(range_len);
+                    for row in max_row..last_row {
+                        self.write_blank(row, custom_header.col, format)?;
+                    }
+                }
+            }
+        }
+
         // If a previous serialization was carried out with the same struct name
         // then write the previous table formatting.
         if let Some(header_config) = self
@@ -10300,39 +14083,474 @@ impl Worksheet {
                 max_row,
                 max_col,
                 table,
+                key_field: header_options
+                    .key_field
+                    .as_ref()
+                    .map(|key_field| key_field.field_name.clone()),
+                nested_groups: header_options
+                    .group_headers
+                    .iter()
+                    .map(|group_header| group_header.field_name.clone())
+                    .collect(),
+                transpose,
+                data_row: max_row,
+                data_col: max_col,
+                use_map_keys: header_options.use_map_keys_as_headers,
             },
         );
 
-        Ok(self)
+        Ok(self)
+    }
+
+    // Write the multi-level headers for serialized nested struct fields. The
+    // group labels of nested structs are merged across their field columns
+    // and the field headers are merged down to the last header row. Returns
+    // the number of header rows.
+    #[cfg(feature = "serde")]
+    fn write_nested_serialization_headers(
+        &mut self,
+        row: RowNum,
+        header_fields: &[CustomSerializeField],
+        header_options: &SerializeFieldOptions,
+    ) -> Result<RowNum, XlsxError> {
+        let group_headers = &header_options.group_headers;
+
+        // Get the nesting level of a field from the number of groups that it
+        // is part of.
+        let level = |field_name: &str| -> RowNum {
+            group_headers
+                .iter()
+                .filter(|group_header| {
+                    field_name
+                        .strip_prefix(&group_header.field_name)
+                        .is_some_and(|name| name.starts_with('.'))
+                })
+                .count() as RowNum
+        };
+
+        let last_row = header_fields
+            .iter()
+            .map(|header_field| row + level(&header_field.field_name))
+            .max()
+            .unwrap_or(row);
+
+        // Write the field headers, merged down to the last header row.
+        for header_field in header_fields {
+            let first_row = row + level(&header_field.field_name);
+
+            self.write_serialization_header(
+                first_row,
+                header_field.col,
+                last_row,
+                header_field.col,
+                header_field,
+                header_options,
+            )?;
+        }
+
+        // Write the group labels across each run of adjacent columns that
+        // contain the fields of the group.
+        for group_header in group_headers {
+            let group_row = row + level(&group_header.field_name);
+            let cols: Vec<ColNum> = header_fields
+                .iter()
+                .filter(|header_field| {
+                    header_field
+                        .field_name
+                        .strip_prefix(&group_header.field_name)
+                        .is_some_and(|name| name.starts_with('.'))
+                })
+                .map(|header_field| header_field.col)
+                .collect();
+
+            let mut start = 0;
+            for end in 1..=cols.len() {
+                if end == cols.len() || cols[end] != cols[end - 1] + 1 {
+                    self.write_serialization_header(
+                        group_row,
+                        cols[start],
+                        group_row,
+                        cols[end - 1],
+                        group_header,
+                        header_options,
+                    )?;
+                    start = end;
+                }
+            }
+        }
+
+        Ok(last_row - row + 1)
+    }
+
+    // Write a serialization header to a cell or merged range using the header
+    // specific format or else the header row format.
+    #[cfg(feature = "serde")]
+    fn write_serialization_header(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        custom_header: &CustomSerializeField,
+        header_options: &SerializeFieldOptions,
+    ) -> Result<(), XlsxError> {
+        let format = custom_header
+            .header_format
+            .as_ref()
+            .or(header_options.header_format.as_ref());
+        let name = &custom_header.header_name;
+
+        if first_row == last_row && first_col == last_col {
+            match format {
+                Some(format) => self.write_with_format(first_row, first_col, name, format)?,
+                None => self.write(first_row, first_col, name)?,
+            };
+        } else {
+            let format = format.cloned().unwrap_or_default();
+            self.merge_range(first_row, first_col, last_row, last_col, name, &format)?;
+        }
+
+        // Group labels merged across several columns don't add to the width
+        // of a single column.
+        if first_col == last_col {
+            self.store_serialized_width(first_row, first_col, 0);
+        }
+
+        Ok(())
+    }
+
+    // Write a column of optional numbers from a dataframe, starting at the
+    // first data row. Null values are skipped.
+    #[cfg(feature = "polars")]
+    fn write_dataframe_numbers(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        values: impl Iterator<Item = Option<f64>>,
+    ) -> Result<(), XlsxError> {
+        for (offset, value) in values.enumerate() {
+            if let Some(value) = value {
+                self.write_number(row + offset as RowNum, col, value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write a column of optional Excel serial dates/times from a dataframe.
+    #[cfg(feature = "polars")]
+    fn write_dataframe_datetimes(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        values: impl Iterator<Item = Option<f64>>,
+        format: &Format,
+    ) -> Result<(), XlsxError> {
+        for (offset, value) in values.enumerate() {
+            if let Some(value) = value {
+                self.store_datetime(row + offset as RowNum, col, value, Some(format))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write a column of optional strings from a dataframe.
+    #[cfg(feature = "polars")]
+    fn write_dataframe_strings<'a>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        values: impl Iterator<Item = Option<&'a str>>,
+    ) -> Result<(), XlsxError> {
+        for (offset, value) in values.enumerate() {
+            if let Some(value) = value {
+                self.write_string(row + offset as RowNum, col, value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Write a JSON value to the worksheet and return the number of rows used.
+    #[cfg(feature = "json")]
+    fn write_json_value(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        value: &serde_json::Value,
+        header_format: &Format,
+    ) -> Result<RowNum, XlsxError> {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut entries = vec![];
+                Self::flatten_json_object("", map, &mut entries);
+
+                // Write the key/value pairs with arrays laid out recursively
+                // to the right of the key.
+                let mut num_rows = 0;
+                for (key, value) in entries {
+                    self.write_string(row + num_rows, col, &key)?;
+
+                    let value_rows = match value {
+                        serde_json::Value::Array(_) => {
+                            self.write_json_value(row + num_rows, col + 1, value, header_format)?
+                        }
+                        serde_json::Value::Object(_) => 1,
+                        _ => {
+                            self.write_json_scalar(row + num_rows, col + 1, value)?;
+                            1
+                        }
+                    };
+
+                    num_rows += value_rows.max(1);
+                }
+
+                Ok(num_rows)
+            }
+            serde_json::Value::Array(array)
+                if !array.is_empty() && array.iter().all(serde_json::Value::is_object) =>
+            {
+                self.write_json_table(row, col, array, header_format)
+            }
+            serde_json::Value::Array(array) => {
+                let mut num_rows = 0;
+                for value in array {
+                    let value_rows =
+                        self.write_json_value(row + num_rows, col, value, header_format)?;
+                    num_rows += value_rows.max(1);
+                }
+
+                Ok(num_rows)
+            }
+            _ => {
+                self.write_json_scalar(row, col, value)?;
+                Ok(1)
+            }
+        }
+    }
+
+    // Write an array of JSON objects as a table with a header row of the
+    // flattened keys. Returns the number of rows used.
+    #[cfg(feature = "json")]
+    fn write_json_table(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        array: &[serde_json::Value],
+        header_format: &Format,
+    ) -> Result<RowNum, XlsxError> {
+        let mut headers: HashMap<String, ColNum> = HashMap::new();
+        let mut data_row = row;
+
+        for value in array {
+            let serde_json::Value::Object(map) = value else {
+                continue;
+            };
+
+            let mut entries = vec![];
+            Self::flatten_json_object("", map, &mut entries);
+            data_row += 1;
+
+            for (key, value) in entries {
+                // Add a new header column for the first instance of a key.
+                let header_col = match headers.get(&key) {
+                    Some(header_col) => *header_col,
+                    None => {
+                        let header_col = col + headers.len() as ColNum;
+                        self.write_string_with_format(row, header_col, &key, header_format)?;
+                        headers.insert(key, header_col);
+                        header_col
+                    }
+                };
+
+                self.write_json_scalar(data_row, header_col, value)?;
+            }
+        }
+
+        Ok(data_row - row + 1)
+    }
+
+    // Write a scalar JSON value to a cell. Arrays and objects are written as a
+    // compact JSON string and null values are ignored.
+    #[cfg(feature = "json")]
+    fn write_json_scalar(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        value: &serde_json::Value,
+    ) -> Result<(), XlsxError> {
+        match value {
+            serde_json::Value::Null => {}
+            serde_json::Value::Bool(boolean) => {
+                self.write_boolean(row, col, *boolean)?;
+            }
+            serde_json::Value::Number(number) => {
+                if let Some(number) = number.as_f64() {
+                    self.write_number(row, col, number)?;
+                }
+            }
+            serde_json::Value::String(string) => {
+                self.write_string(row, col, string)?;
+            }
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+                self.write_string(row, col, value.to_string())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Flatten a JSON object into (key, value) pairs, with the keys of nested
+    // objects joined to the parent key with a dot.
+    #[cfg(feature = "json")]
+    fn flatten_json_object<'a>(
+        prefix: &str,
+        map: &'a serde_json::Map<String, serde_json::Value>,
+        entries: &mut Vec<(String, &'a serde_json::Value)>,
+    ) {
+        for (key, value) in map {
+            let key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+
+            match value {
+                serde_json::Value::Object(child) if !child.is_empty() => {
+                    Self::flatten_json_object(&key, child, entries);
+                }
+                _ => entries.push((key, value)),
+            }
+        }
+    }
+
+    // Serialize the parent data structure to the worksheet.
+    #[cfg(feature = "serde")]
+    fn serialize_data_structure<T>(&mut self, data_structure: &T) -> Result<(), XlsxError>
+    where
+        T: Serialize,
+    {
+        // Clear any state left over from a failed serialization.
+        self.serializer_state.reset();
+
+        data_structure.serialize(self)?;
+        Ok(())
+    }
+
+    // Serialize individual data items to a worksheet cell.
+    #[cfg(feature = "serde")]
+    pub(crate) fn serialize_to_worksheet_cell(
+        &mut self,
+        data: impl IntoExcelData + Into<SerializedValue>,
+    ) -> Result<(), XlsxError> {
+        let result = self.serializer_state.current_state();
+
+        match result {
+            Ok(result) => {
+                let (row, col, value_format, value_transform) = result;
+                let format = value_format.as_ref().as_ref();
+
+                // Write dates and times with a date number format.
+                let result = if self.serializer_state.is_datetime {
+                    let value = match value_transform {
+                        Some(transform) => transform(data.into()),
+                        None => data.into(),
+                    };
+
+                    match value {
+                        SerializedValue::Number(number) => {
+                            let format = Self::serialized_datetime_format(format, number);
+                            self.store_datetime(row, col, number, Some(&format))
+                                .map(|_| ())
+                        }
+                        SerializedValue::String(string) => {
+                            self.write_serialized_value(row, col, string, format)
+                        }
+                        SerializedValue::Boolean(boolean) => {
+                            self.write_serialized_value(row, col, boolean, format)
+                        }
+                    }
+                } else {
+                    match value_transform {
+                        Some(transform) => match transform(data.into()) {
+                            SerializedValue::Number(number) => {
+                                self.write_serialized_value(row, col, number, format)
+                            }
+                            SerializedValue::String(string) => {
+                                self.write_serialized_value(row, col, string, format)
+                            }
+                            SerializedValue::Boolean(boolean) => {
+                                self.write_serialized_value(row, col, boolean, format)
+                            }
+                        },
+                        None => self.write_serialized_value(row, col, data, format),
+                    }
+                };
+
+                // Track the width of the data for autofitting the column.
+                if result.is_ok() {
+                    self.store_serialized_width(row, col, 0);
+                }
+
+                result
+            }
+            Err(()) => Ok(()),
+        }
+    }
+
+    // Get the format for a serialized date/time. If the value format doesn't
+    // have a number format then a default date, time or datetime format is
+    // added based on the serial value.
+    #[cfg(feature = "serde")]
+    fn serialized_datetime_format(format: Option<&Format>, number: f64) -> Format {
+        let format = format.cloned().unwrap_or_default();
+
+        if !format.num_format.is_empty() || format.num_format_index != 0 {
+            return format;
+        }
+
+        if number < 1.0 {
+            format.set_num_format("hh:mm:ss")
+        } else if number.fract() == 0.0 {
+            format.set_num_format("yyyy-mm-dd")
+        } else {
+            format.set_num_format("yyyy-mm-dd hh:mm:ss")
+        }
     }
 
-    // Serialize the parent data structure to the worksheet.
+    // Store the pixel width of a serialized cell, plus any additional padding,
+    // as the maximum width of the column if it is greater than the current
+    // maximum.
     #[cfg(feature = "serde")]
-    fn serialize_data_structure<T>(&mut self, data_structure: &T) -> Result<(), XlsxError>
-    where
-        T: Serialize,
-    {
-        data_structure.serialize(self)?;
-        Ok(())
+    fn store_serialized_width(&mut self, row: RowNum, col: ColNum, padding: u16) {
+        let pixel_width = match self
+            .data_table
+            .get(&row)
+            .and_then(|columns| columns.get(&col))
+        {
+            Some(cell) => Self::cell_pixel_width(cell),
+            None => return,
+        };
+
+        if pixel_width > 0 {
+            self.serializer_state
+                .update_column_width(col, pixel_width + padding);
+        }
     }
 
-    // Serialize individual data items to a worksheet cell.
+    // Write a serialized value with an optional value format.
     #[cfg(feature = "serde")]
-    pub(crate) fn serialize_to_worksheet_cell(
+    fn write_serialized_value(
         &mut self,
+        row: RowNum,
+        col: ColNum,
         data: impl IntoExcelData,
+        format: Option<&Format>,
     ) -> Result<(), XlsxError> {
-        let result = self.serializer_state.current_state();
-
-        match result {
-            Ok(result) => {
-                let (row, col, value_format) = result;
-                match &*value_format {
-                    Some(format) => self.write_with_format(row, col, data, format).map(|_| ()),
-                    None => self.write(row, col, data).map(|_| ()),
-                }
-            }
-            Err(()) => Ok(()),
+        match format {
+            Some(format) => self.write_with_format(row, col, data, format).map(|_| ()),
+            None => self.write(row, col, data).map(|_| ()),
         }
     }
 
@@ -10358,13 +14576,27 @@ impl Worksheet {
         let max_col = table_data.3;
         let min_row = table_data.0;
         let mut max_row = table_data.2;
-        let table = &table_data.4;
+        let mut table = table_data.4.clone();
 
         if table.show_total_row {
             max_row += 1;
         }
 
-        self.add_table(min_row, min_col, max_row, max_col, table)
+        // Use the serialized headers, including any custom renames, as the
+        // table column names so that they match the header cells.
+        if table.show_header_row {
+            let headers = self.default_table_headers(min_row, min_col, max_col, true);
+
+            table
+                .columns
+                .resize_with(headers.len(), crate::TableColumn::default);
+
+            for (column, header) in table.columns.iter_mut().zip(headers) {
+                column.name = header;
+            }
+        }
+
+        self.add_table(min_row, min_col, max_row, max_col, &table)
     }
 
     // -----------------------------------------------------------------------
@@ -10738,6 +14970,57 @@ impl Worksheet {
         self
     }
 
+    /// Set the position of the outline summary rows and columns.
+    ///
+    /// In Excel outline groups, the summary row is usually below the grouped
+    /// rows and the summary column is to the right of the grouped columns. The
+    /// position of the expand/collapse outline symbols follows the summary
+    /// position. This method can be used to place the summary rows above, and
+    /// the summary columns to the left of, the grouped data.
+    ///
+    /// This is equivalent to the "Summary rows below detail" and "Summary
+    /// columns to right of detail" options in the Excel "Data -> Outline ->
+    /// Settings" dialog.
+    ///
+    /// # Parameters
+    ///
+    /// - `symbols_below`: Put the summary rows and outline symbols below the
+    ///   detail rows. The Excel default is `true`.
+    /// - `symbols_right`: Put the summary columns and outline symbols to the
+    ///   right of the detail columns. The Excel default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the outline summary rows
+    /// above, and the summary columns to the left of, the detail data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_outline_settings.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.set_outline_settings(false, false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_outline_settings(
+        &mut self,
+        symbols_below: bool,
+        symbols_right: bool,
+    ) -> &mut Worksheet {
+        self.outline_symbols_below = symbols_below;
+        self.outline_symbols_right = symbols_right;
+        self
+    }
+
     /// Set the paper type/size when printing.
     ///
     /// This method is used to set the paper format for the printed output of a
@@ -11121,19 +15404,322 @@ impl Worksheet {
         self
     }
 
+    /// Set several worksheet view properties using a [`SheetView`] builder.
+    ///
+    /// The worksheet view properties such as right to left display, zoom,
+    /// gridlines and the selected cell are all stored in the `<sheetView>`
+    /// element of the worksheet. The `view()` method returns a [`SheetView`]
+    /// handle that allows them to be set together in a single chained call.
+    ///
+    /// The individual setter methods such as [`Worksheet::set_zoom()`] and
+    /// [`Worksheet::set_right_to_left()`] can still be used and have the same
+    /// effect.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting several worksheet view
+    /// properties using the view builder.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_view.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet
+    ///         .view()
+    ///         .right_to_left()
+    ///         .zoom(150)
+    ///         .hide_gridlines()
+    ///         .select(3, 2)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn view(&mut self) -> SheetView<'_> {
+        SheetView { worksheet: self }
+    }
+
+    /// Apply a preset group of worksheet view properties.
+    ///
+    /// The `apply_view_preset()` method sets several worksheet view properties
+    /// at once using a [`ViewPreset`] enum value. This can be used to switch a
+    /// worksheet between a view that is suited to entering data and a view that
+    /// is suited to presentations.
+    ///
+    /// The presets are a shortcut for calling the individual view setters,
+    /// [`Worksheet::set_screen_gridlines()`], [`Worksheet::set_zoom()`],
+    /// [`Worksheet::set_screen_headings()`] and
+    /// [`Worksheet::set_freeze_panes()`]. These methods can be called after
+    /// the preset to adjust the view properties. See [`ViewPreset`] for the
+    /// properties set by each preset.
+    ///
+    /// # Parameters
+    ///
+    /// - `preset`: A [`ViewPreset`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a presentation view for a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_apply_view_preset.rs
+    /// #
+    /// # use rust_xlsxwriter::{ViewPreset, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_row(0, 0, ["Region", "Sales"])?;
+    ///     worksheet.write_column(1, 0, ["North", "South", "East", "West"])?;
+    ///     worksheet.write_column(1, 1, [1200, 800, 1500, 950])?;
+    ///
+    ///     // Hide the gridlines and headers, zoom in and freeze the header row.
+    ///     worksheet.apply_view_preset(ViewPreset::Presentation);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn apply_view_preset(&mut self, preset: ViewPreset) -> &mut Worksheet {
+        match preset {
+            ViewPreset::DataEntry => {
+                self.set_screen_gridlines(true);
+                self.set_zoom(100);
+                self.set_screen_headings(true);
+                self.panes.freeze_cell = (0, 0);
+                self.panes.is_split = false;
+            }
+            ViewPreset::Presentation => {
+                self.set_screen_gridlines(false);
+                self.set_zoom(130);
+                self.set_screen_headings(false);
+                self.panes.freeze_cell = (1, 0);
+                self.panes.is_split = false;
+            }
+        }
+
+        self
+    }
+
+    /// Configure several worksheet properties at once.
+    ///
+    /// The `configure()` method sets the tab color, visibility, right to left
+    /// direction, zoom, screen gridlines and protection of a worksheet using
+    /// a [`WorksheetOptions`] struct. It is a shortcut for calling the
+    /// individual setters and is useful when creating a number of worksheets
+    /// with the same configuration.
+    ///
+    /// The options are validated before any of them are applied so the
+    /// worksheet is left unchanged if there is an error. Properties that
+    /// aren't set in the options aren't changed.
+    ///
+    /// # Parameters
+    ///
+    /// - `options`: A [`WorksheetOptions`] struct.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The tab color is invalid or the zoom
+    ///   is outside Excel's range of 10 <= zoom <= 400.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates configuring several worksheets with
+    /// the same options.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_configure.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Workbook, WorksheetOptions, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let options = WorksheetOptions::new()
+    ///         .set_tab_color(Color::Green)
+    ///         .set_zoom(150)
+    ///         .set_screen_gridlines(false)
+    ///         .set_protection_password("abc123");
+    ///
+    ///     for region in ["North", "South", "East", "West"] {
+    ///         let worksheet = workbook.add_worksheet();
+    ///         worksheet.set_name(region)?;
+    ///         worksheet.configure(&options)?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn configure(&mut self, options: &WorksheetOptions) -> Result<&mut Worksheet, XlsxError> {
+        // Validate the options before applying any of them.
+        if let Some(color) = options.tab_color {
+            if !color.is_valid() {
+                return Err(XlsxError::ParameterError(format!(
+                    "Invalid worksheet tab color: {color:?}."
+                )));
+            }
+        }
+
+        if let Some(zoom) = options.zoom {
+            if !(10..=400).contains(&zoom) {
+                return Err(XlsxError::ParameterError(format!(
+                    "Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400."
+                )));
+            }
+        }
+
+        if let Some(color) = options.tab_color {
+            self.set_tab_color(color);
+        }
+
+        if let Some(hidden) = options.hidden {
+            self.set_hidden(hidden);
+        }
+
+        if let Some(right_to_left) = options.right_to_left {
+            self.set_right_to_left(right_to_left);
+        }
+
+        if let Some(zoom) = options.zoom {
+            self.set_zoom(zoom);
+        }
+
+        if let Some(screen_gridlines) = options.screen_gridlines {
+            self.set_screen_gridlines(screen_gridlines);
+        }
+
+        if let Some(protection) = &options.protection {
+            self.protect_with_options(protection);
+        }
+
+        if let Some(password) = &options.password {
+            self.protect_with_password(password);
+        }
+
+        Ok(self)
+    }
+
     /// Freeze panes in a worksheet.
     ///
-    /// The `set_freeze_panes()` method can be used to divide a worksheet into
-    /// horizontal or vertical regions known as panes and to “freeze” these
-    /// panes so that the splitter bars are not visible.
+    /// The `set_freeze_panes()` method can be used to divide a worksheet into
+    /// horizontal or vertical regions known as panes and to “freeze” these
+    /// panes so that the splitter bars are not visible.
+    ///
+    /// As with Excel the split is to the top and left of the cell. So to freeze
+    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
+    /// Also, you can set one of the row and col parameters as 0 if you do not
+    /// want either the vertical or horizontal split. See the example below.
+    ///
+    /// In Excel it is also possible to set "split" panes without freezing them.
+    /// See [`Worksheet::set_split_panes()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the worksheet panes.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_freeze_panes.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let mut worksheet1 = Worksheet::new();
+    /// #     let mut worksheet2 = Worksheet::new();
+    /// #     let mut worksheet3 = Worksheet::new();
+    /// #
+    /// #     worksheet1.write_string(0, 0, "Scroll down")?;
+    /// #     worksheet2.write_string(0, 0, "Scroll across")?;
+    /// #     worksheet3.write_string(0, 0, "Scroll down or across")?;
+    /// #
+    ///     // Freeze the top row only.
+    ///     worksheet1.set_freeze_panes(1, 0)?;
+    ///
+    ///     // Freeze the leftmost column only.
+    ///     worksheet2.set_freeze_panes(0, 1)?;
+    ///
+    ///     // Freeze the top row and leftmost column.
+    ///     worksheet3.set_freeze_panes(1, 1)?;
+    /// #
+    /// #     workbook.push_worksheet(worksheet1);
+    /// #     workbook.push_worksheet(worksheet2);
+    /// #     workbook.push_worksheet(worksheet3);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes.png">
+    ///
+    pub fn set_freeze_panes(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        self.panes.freeze_cell = (row, col);
+        self.panes.is_split = false;
+        Ok(self)
+    }
+
+    /// Split panes in a worksheet.
+    ///
+    /// The `set_split_panes()` method can be used to divide a worksheet into
+    /// horizontal or vertical regions known as panes. It is similar to
+    /// [`Worksheet::set_freeze_panes()`] except that the panes aren't frozen
+    /// and the user can drag the splitter bars to resize them.
     ///
-    /// As with Excel the split is to the top and left of the cell. So to freeze
-    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
-    /// Also, you can set one of the row and col parameters as 0 if you do not
-    /// want either the vertical or horizontal split. See the example below.
+    /// As with freeze panes the split is to the top and left of the cell. So to
+    /// split the worksheet below the top row and to the right of the leftmost
+    /// column you would use `(1, 1)` (zero-indexed). You can set one of the row
+    /// and col parameters as 0 if you do not want either the vertical or
+    /// horizontal split.
     ///
-    /// In Excel it is also possible to set "split" panes without freezing them.
-    /// That feature isn't currently supported by `rust_xlsxwriter`.
+    /// Excel stores the split position in units of twips (1/20 of a point)
+    /// rather than as a cell position. This is calculated from the height of
+    /// the rows and the width of the columns above and to the left of the
+    /// split so any row heights or column widths should be set before the file
+    /// is saved.
+    ///
+    /// The top left visible cell of the scrolling pane can be set with
+    /// [`Worksheet::set_freeze_panes_top_cell()`].
     ///
     /// # Parameters
     ///
@@ -11147,36 +15733,26 @@ impl Worksheet {
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet panes.
+    /// The following example demonstrates setting split worksheet panes with
+    /// the bottom right pane pre-scrolled to cell `E21`.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_freeze_panes.rs
+    /// # // This code is available in examples/doc_worksheet_set_split_panes.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     let mut worksheet1 = Worksheet::new();
-    /// #     let mut worksheet2 = Worksheet::new();
-    /// #     let mut worksheet3 = Worksheet::new();
+    /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     worksheet1.write_string(0, 0, "Scroll down")?;
-    /// #     worksheet2.write_string(0, 0, "Scroll across")?;
-    /// #     worksheet3.write_string(0, 0, "Scroll down or across")?;
+    /// #     worksheet.write_string(0, 0, "Scroll down or across")?;
     /// #
-    ///     // Freeze the top row only.
-    ///     worksheet1.set_freeze_panes(1, 0)?;
-    ///
-    ///     // Freeze the leftmost column only.
-    ///     worksheet2.set_freeze_panes(0, 1)?;
+    ///     // Split the worksheet below the top row and right of the first column.
+    ///     worksheet.set_split_panes(1, 1)?;
     ///
-    ///     // Freeze the top row and leftmost column.
-    ///     worksheet3.set_freeze_panes(1, 1)?;
-    /// #
-    /// #     workbook.push_worksheet(worksheet1);
-    /// #     workbook.push_worksheet(worksheet2);
-    /// #     workbook.push_worksheet(worksheet3);
+    ///     // Pre-scroll the bottom right pane to cell E21.
+    ///     worksheet.set_freeze_panes_top_cell(20, 4)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -11184,11 +15760,7 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes.png">
-    ///
-    pub fn set_freeze_panes(
+    pub fn set_split_panes(
         &mut self,
         row: RowNum,
         col: ColNum,
@@ -11199,16 +15771,18 @@ impl Worksheet {
         }
 
         self.panes.freeze_cell = (row, col);
+        self.panes.is_split = true;
         Ok(self)
     }
 
     /// Set the top most cell in the scrolling area of a freeze pane.
     ///
     /// This method is used in conjunction with the
-    /// [`Worksheet::set_freeze_panes()`] method to set the top most visible
-    /// cell in the scrolling range. For example you may want to freeze the top
-    /// row but have the worksheet pre-scrolled so that cell `A20` is visible in
-    /// the scrolled area. See the example below.
+    /// [`Worksheet::set_freeze_panes()`] or [`Worksheet::set_split_panes()`]
+    /// methods to set the top most visible cell in the scrolling range. For
+    /// example you may want to freeze the top row but have the worksheet
+    /// pre-scrolled so that cell `A20` is visible in the scrolled area. See the
+    /// example below.
     ///
     /// # Parameters
     ///
@@ -12075,6 +16649,137 @@ impl Worksheet {
         self
     }
 
+    /// Turn on/off the row and column headers on the displayed worksheet.
+    ///
+    /// The `set_screen_headings()` method is used to show or hide the row
+    /// numbers and column letters shown at the left and top of the worksheet
+    /// in Excel. They are on by default. Hiding them can be useful for
+    /// worksheets that are used as dashboards or presentations.
+    ///
+    /// To turn on/off the printed row and column headers see the
+    /// [`Worksheet::set_print_headings()`] method.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_screen_headings(&mut self, enable: bool) -> &mut Worksheet {
+        self.screen_headings = enable;
+
+        self
+    }
+
+    /// Ignore an Excel error or warning in a worksheet cell.
+    ///
+    /// Excel flags cells that it thinks may contain errors, such as numbers
+    /// stored as text or formulas that differ from adjacent formulas, with a
+    /// small green triangle in the top left-hand corner of the cell. The
+    /// `ignore_error()` method can be used to turn off the warning for a cell,
+    /// like the "Ignore Error" option in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `error_type`: An [`IgnoreError`] enum value.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates ignoring Excel warnings in worksheet
+    /// cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_ignore_error.rs
+    /// #
+    /// # use rust_xlsxwriter::{IgnoreError, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some numbers as strings.
+    ///     worksheet.write_string(0, 0, "123")?;
+    ///     worksheet.write_string(1, 0, "456")?;
+    ///     worksheet.write_string(2, 0, "789")?;
+    ///
+    ///     // Turn off the "Number Stored as Text" warning in the first cell.
+    ///     worksheet.ignore_error(0, 0, IgnoreError::NumberStoredAsText)?;
+    ///
+    ///     // Turn off the warning in the remaining cells.
+    ///     worksheet.ignore_error_range(1, 0, 2, 0, IgnoreError::NumberStoredAsText)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn ignore_error(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        error_type: IgnoreError,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.ignore_error_range(row, col, row, col, error_type)
+    }
+
+    /// Ignore an Excel error or warning in a range of worksheet cells.
+    ///
+    /// This is a range version of [`Worksheet::ignore_error()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `error_type`: An [`IgnoreError`] enum value.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    pub fn ignore_error_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        error_type: IgnoreError,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+
+        // Ignore duplicate ranges, for example from rewriting a cell.
+        let ranges = self.ignored_errors.entry(error_type).or_default();
+        if !ranges.contains(&range) {
+            ranges.push(range);
+        }
+
+        Ok(self)
+    }
+
     /// Set the page setup option to turn on printed gridlines.
     ///
     /// The `set_print_gridlines()` method is use to turn on/off gridlines on
@@ -12140,6 +16845,61 @@ impl Worksheet {
         self
     }
 
+    /// Set the printer settings for a worksheet from an existing file.
+    ///
+    /// Excel stores printer specific configuration, such as the printer tray
+    /// or duplex options, in a binary `printerSettings.bin` file within the
+    /// xlsx container. The format of the file depends on the printer driver
+    /// and `rust_xlsxwriter` doesn't create it. However, the
+    /// `set_printer_settings()` method can be used to pass through the data
+    /// from an existing template file so that the printer configuration isn't
+    /// lost.
+    ///
+    /// The data can be extracted from the `xl/printerSettings/` directory of
+    /// an existing xlsx file, which is a zip file. The data isn't validated.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The binary printer settings data. An empty slice removes any
+    ///   previous printer settings.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding printer settings from a
+    /// template file to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_printer_settings.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Printer settings extracted from an existing xlsx file.
+    ///     let data = std::fs::read("examples/printerSettings1.bin")?;
+    ///
+    ///     worksheet.set_printer_settings(&data);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_printer_settings(&mut self, data: &[u8]) -> &mut Worksheet {
+        self.printer_settings = data.to_vec();
+
+        if !data.is_empty() {
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
     /// Set the page setup option to print the row and column headers on the
     /// printed page.
     ///
@@ -12513,62 +17273,7 @@ impl Worksheet {
             if let Some(columns) = self.data_table.get(&row_num) {
                 for col_num in self.dimensions.first_col..=self.dimensions.last_col {
                     if let Some(cell) = columns.get(&col_num) {
-                        let mut pixel_width = match cell {
-                            // For strings we do a calculation based on
-                            // character widths taken from Excel. For rich
-                            // strings we use the unformatted string. We also
-                            // split multi-line strings and handle each part
-                            // separately.
-                            CellType::String { string, .. }
-                            | CellType::RichString {
-                                raw_string: string, ..
-                            } => {
-                                let mut max = 0;
-                                for segment in string.lines() {
-                                    let length = utility::pixel_width(segment);
-                                    max = cmp::max(max, length);
-                                }
-                                max
-                            }
-
-                            // For numbers we use a workaround/optimization
-                            // since digits all have a pixel width of 7. This
-                            // gives a slightly greater width for the decimal
-                            // place and minus sign but only by a few pixels and
-                            // over-estimation is okay.
-                            CellType::Number { number, .. } => 7 * number.to_string().len() as u16,
-
-                            // For Boolean types we use the Excel standard
-                            // widths for TRUE and FALSE.
-                            CellType::Boolean { boolean, .. } => {
-                                if *boolean {
-                                    31
-                                } else {
-                                    36
-                                }
-                            }
-
-                            // For formulas we autofit the result of the formula
-                            // if it has a non-zero/default value.
-                            CellType::Formula { result, .. }
-                            | CellType::ArrayFormula { result, .. } => {
-                                if result.as_ref() == "0" || result.is_empty() {
-                                    0
-                                } else {
-                                    utility::pixel_width(result)
-                                }
-                            }
-
-                            // Datetimes are just numbers but they also have an
-                            // Excel format. It isn't feasible to parse the
-                            // number format to get the actual string width for
-                            // all format types so we use a width based on the
-                            // Excel's default format: mm/dd/yyyy.
-                            CellType::DateTime { .. } => 68,
-
-                            // Ignore the following types which don't add to the width.
-                            CellType::Blank { .. } | CellType::Error { .. } => 0,
-                        };
+                        let mut pixel_width = Self::cell_pixel_width(cell);
 
                         // If the cell is in an autofilter header we add an
                         // additional 16 pixels for the dropdown arrow.
@@ -12598,11 +17303,7 @@ impl Worksheet {
             }
         }
 
-        // Set the max character width for each column.
-        for (col, pixels) in &max_widths {
-            let width = Self::pixels_to_width(*pixels + 7);
-            self.store_column_width(*col, width, true);
-        }
+        self.store_autofit_widths(&max_widths);
 
         self
     }
@@ -12628,29 +17329,256 @@ impl Worksheet {
     /// - The name must start with a letter.
     /// - The name cannot be blank.
     ///
-    /// The name must be also be unique across the worksheets in the workbook.
+    /// The name must be also be unique, ignoring case, across the worksheets
+    /// and the workbook. This is checked when the workbook is saved, and
+    /// includes the default `SheetN` names given to worksheets in xlsm files
+    /// that don't have a user defined VBA name.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The vba name. It must follow the Excel rules, shown above.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::VbaNameError`] - The name doesn't meet one of Excel's
+    ///   criteria, shown above. This error is also returned by
+    ///   [`Workbook::save()`](crate::Workbook::save) if the name isn't
+    ///   unique.
+    ///
+    pub fn set_vba_name(&mut self, name: impl Into<String>) -> Result<&mut Worksheet, XlsxError> {
+        let name = name.into();
+        utility::validate_vba_name(&name)?;
+        self.vba_codename = Some(name);
+
+        Ok(self)
+    }
+
+    /// Set a fixed `sheetId` for the worksheet in the workbook file.
+    ///
+    /// By default the worksheets in a workbook are given sequential
+    /// `sheetId` values of 1, 2, 3, etc., in the order that they are added to
+    /// the workbook. This method can be used to set a fixed, user supplied
+    /// value instead. This is sometimes required for interoperability with
+    /// tools that diff, merge or patch the parts of an xlsx file and that
+    /// refer to worksheets by id.
+    ///
+    /// The relationship `r:id` of the worksheet isn't affected and is always
+    /// `rId1`, `rId2`, `rId3`, etc., based on the worksheet order.
+    ///
+    /// The id must be unique across the worksheets in the workbook. This is
+    /// checked when the workbook is saved.
+    ///
+    /// # Parameters
+    ///
+    /// - `sheet_id`: The worksheet id. It must be greater than 0.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The id is 0.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a fixed sheet id for a
+    /// worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_sheet_id.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet = workbook.add_worksheet().set_name("Data")?;
+    ///     worksheet.set_sheet_id(100)?;
+    ///
+    ///     let worksheet = workbook.add_worksheet().set_name("Summary")?;
+    ///     worksheet.set_sheet_id(200)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_sheet_id(&mut self, sheet_id: u32) -> Result<&mut Worksheet, XlsxError> {
+        if sheet_id == 0 {
+            return Err(XlsxError::ParameterError(
+                "Worksheet sheet id must be greater than 0.".to_string(),
+            ));
+        }
+
+        self.sheet_id = Some(sheet_id);
+
+        Ok(self)
+    }
+
+    /// Export the worksheet cell data to a CSV file or writer.
+    ///
+    /// The `to_csv()` method writes the data that has been written to the
+    /// worksheet, in row and column order, to a [`Write`] object such as a
+    /// [`File`](std::fs::File) or a `Vec<u8>` buffer. It is intended as a
+    /// simple debugging aid or as an export path for applications that can't
+    /// read xlsx files. It doesn't generate an xlsx file.
+    ///
+    /// The data is exported as follows:
+    ///
+    /// - The output starts at cell A1 and ends at the last row and column that
+    ///   contains data.
+    /// - Numbers and dates are written as their unformatted Excel values.
+    ///   Cell formats are ignored.
+    /// - Booleans are written as `TRUE` or `FALSE`.
+    /// - Strings are quoted if they contain a comma, a double quote or a
+    ///   newline. Rich strings are written as plain text.
+    /// - Formulas are written as their cached result, the value set via
+    ///   [`Formula::set_result()`](crate::Formula::set_result) or
+    ///   [`Worksheet::set_formula_result()`]. Formulas without a cached result
+    ///   are written as empty fields.
+    /// - Blank and error cells are written as empty fields.
+    ///
+    /// Each row is terminated with `\r\n` as per [RFC 4180].
+    ///
+    /// [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+    ///
+    /// # Parameters
+    ///
+    /// - `writer`: An object that implements the [`Write`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::IoError`] - A wrapper for various IO errors when writing
+    ///   the data.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates exporting worksheet data to a CSV
+    /// buffer.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_to_csv.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write some data to the worksheet.
+    ///     worksheet.write_row(0, 0, ["Name", "Price"])?;
+    ///     worksheet.write_row(1, 0, ["Apple, red", "1.5"])?;
+    ///     worksheet.write(2, 1, 2.25)?;
+    ///
+    ///     // Export the data as CSV.
+    ///     let mut buffer = vec![];
+    ///     worksheet.to_csv(&mut buffer)?;
+    ///
+    ///     assert_eq!(
+    ///         String::from_utf8(buffer).unwrap(),
+    ///         "Name,Price\r\n\"Apple, red\",1.5\r\n,2.25\r\n"
+    ///     );
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), XlsxError> {
+        self.write_delimited(writer, ',')
+    }
+
+    /// Export the worksheet cell data to a TSV file or writer.
+    ///
+    /// The `to_tsv()` method is the same as [`Worksheet::to_csv()`] except
+    /// that fields are separated by tab characters. Strings are quoted if they
+    /// contain a tab, a double quote or a newline.
     ///
     /// # Parameters
     ///
-    /// - `name`: The vba name. It must follow the Excel rules, shown above.
+    /// - `writer`: An object that implements the [`Write`] trait.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::VbaNameError`] - The name doesn't meet one of Excel's
-    ///   criteria, shown above.
+    /// - [`XlsxError::IoError`] - A wrapper for various IO errors when writing
+    ///   the data.
     ///
-    pub fn set_vba_name(&mut self, name: impl Into<String>) -> Result<&mut Worksheet, XlsxError> {
-        let name = name.into();
-        utility::validate_vba_name(&name)?;
-        self.vba_codename = Some(name);
-
-        Ok(self)
+    pub fn to_tsv<W: Write>(&self, writer: W) -> Result<(), XlsxError> {
+        self.write_delimited(writer, '\t')
     }
 
     // -----------------------------------------------------------------------
     // Crate level helper methods.
     // -----------------------------------------------------------------------
 
+    // Write the worksheet cell data as delimiter separated text for the CSV
+    // and TSV export methods.
+    fn write_delimited<W: Write>(&self, mut writer: W, delimiter: char) -> Result<(), XlsxError> {
+        let Some(last_row) = self.data_table.keys().next_back().copied() else {
+            return Ok(());
+        };
+
+        let last_col = self
+            .data_table
+            .values()
+            .filter_map(|columns| columns.keys().next_back().copied())
+            .max()
+            .unwrap_or(0);
+
+        let empty_row = BTreeMap::new();
+        for row_num in 0..=last_row {
+            let columns = self.data_table.get(&row_num).unwrap_or(&empty_row);
+            let mut fields = Vec::with_capacity(last_col as usize + 1);
+
+            for col_num in 0..=last_col {
+                let field = match columns.get(&col_num) {
+                    Some(
+                        CellType::String { string, .. }
+                        | CellType::RichString {
+                            raw_string: string, ..
+                        },
+                    ) => Self::quote_delimited_field(string, delimiter),
+                    Some(CellType::Number { number, .. } | CellType::DateTime { number, .. }) => {
+                        number.to_string()
+                    }
+                    Some(CellType::Boolean { boolean, .. }) => {
+                        if *boolean {
+                            "TRUE".to_string()
+                        } else {
+                            "FALSE".to_string()
+                        }
+                    }
+                    Some(
+                        CellType::Formula {
+                            result_type: FormulaResultType::Uncached,
+                            ..
+                        }
+                        | CellType::ArrayFormula {
+                            result_type: FormulaResultType::Uncached,
+                            ..
+                        },
+                    ) => String::new(),
+                    Some(
+                        CellType::Formula { result, .. } | CellType::ArrayFormula { result, .. },
+                    ) => Self::quote_delimited_field(result, delimiter),
+                    Some(CellType::Blank { .. } | CellType::Error { .. }) | None => String::new(),
+                };
+
+                fields.push(field);
+            }
+
+            write!(writer, "{}\r\n", fields.join(&delimiter.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    // Quote a CSV/TSV field if it contains the delimiter, a quote or a newline.
+    fn quote_delimited_field(field: &str, delimiter: char) -> String {
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
     // Get the minimum row number for the dimension check/set.
     fn get_min_row(&self) -> RowNum {
         if self.dimensions.first_row == ROW_MAX {
@@ -12669,6 +17597,50 @@ impl Worksheet {
         }
     }
 
+    // Extend the autofilter range to include any rows of data that were written
+    // directly below it, within the autofilter columns. This matches Excel
+    // which extends the filter when data is added to the end of the range.
+    // Blank cells and data outside the autofilter columns don't extend it. The
+    // range also stops at the first row of any table in the autofilter columns
+    // since Excel doesn't allow an autofilter to overlap a table.
+    pub(crate) fn extend_autofilter_range(&mut self) {
+        if !self.autofilter_defined_name.in_use {
+            return;
+        }
+
+        let first_row = self.autofilter_defined_name.first_row;
+        let first_col = self.autofilter_defined_name.first_col;
+        let last_col = self.autofilter_defined_name.last_col;
+        let mut last_row = self.autofilter_defined_name.last_row;
+
+        while let Some(columns) = self.data_table.get(&(last_row + 1)) {
+            let is_table_row = self.table_ranges.iter().any(|range| {
+                (range.first_row..=range.last_row).contains(&(last_row + 1))
+                    && range.first_col <= last_col
+                    && range.last_col >= first_col
+            });
+
+            if is_table_row {
+                break;
+            }
+
+            let has_data = columns
+                .range(first_col..=last_col)
+                .any(|(_, cell)| !matches!(cell, CellType::Blank { .. }));
+
+            if !has_data {
+                break;
+            }
+
+            last_row += 1;
+        }
+
+        if last_row != self.autofilter_defined_name.last_row {
+            self.autofilter_defined_name.last_row = last_row;
+            self.autofilter_area = utility::cell_range(first_row, first_col, last_row, last_col);
+        }
+    }
+
     // Hide any rows in the autofilter range that don't match the autofilter
     // conditions, like Excel does at runtime.
     pub(crate) fn hide_autofilter_rows(&mut self) {
@@ -12746,10 +17718,8 @@ impl Worksheet {
                             }
                         }
                     }
-                    CellType::Blank { .. } => {
-                        if filter_condition.should_match_blanks {
-                            return true;
-                        }
+                    CellType::Blank { .. } if filter_condition.should_match_blanks => {
+                        return true;
                     }
                     // We don't currently try to handle matching any other data types.
                     _ => {}
@@ -13023,6 +17993,101 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Validate the ranges of a merge before writing any data. The cells of the
+    // ranges are stored in a separate map so that we can check for overlaps
+    // within the ranges as well as with previous merged ranges.
+    fn validate_merge_ranges(
+        &mut self,
+        ranges: &[(RowNum, ColNum, RowNum, ColNum)],
+    ) -> Result<Vec<CellRange>, XlsxError> {
+        let mut cell_ranges: Vec<CellRange> = Vec::with_capacity(ranges.len());
+        let mut batch_cells: HashMap<(RowNum, ColNum), usize> = HashMap::new();
+
+        for &(first_row, first_col, last_row, last_col) in ranges {
+            // Check rows and cols are in the allowed range.
+            if !self.check_dimensions_only(first_row, first_col)
+                || !self.check_dimensions_only(last_row, last_col)
+            {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+
+            // Check order of first/last values.
+            if first_row > last_row || first_col > last_col {
+                return Err(XlsxError::RowColumnOrderError);
+            }
+
+            // Check that the range isn't a singe cell, which isn't allowed by
+            // Excel.
+            if first_row == last_row && first_col == last_col {
+                return Err(XlsxError::MergeRangeSingleCell);
+            }
+
+            // Create a cell range for storage and range testing.
+            let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
+
+            // Check if the merged range overlaps any previous merged range or
+            // another range in the batch. This is a major error in Excel.
+            let new_index = cell_ranges.len();
+            for row in first_row..=last_row {
+                for col in first_col..=last_col {
+                    let previous_cell_range = match self.merged_cells.get(&(row, col)) {
+                        Some(index) => self.merged_ranges.get(*index),
+                        None => batch_cells
+                            .insert((row, col), new_index)
+                            .and_then(|index| cell_ranges.get(index)),
+                    };
+
+                    if let Some(previous_cell_range) = previous_cell_range {
+                        return Err(XlsxError::MergeRangeOverlaps(
+                            cell_range.to_error_string(),
+                            previous_cell_range.to_error_string(),
+                        ));
+                    }
+                }
+            }
+
+            cell_ranges.push(cell_range);
+        }
+
+        Ok(cell_ranges)
+    }
+
+    // Pad out a merged range, apart from the first cell, with formatted blank
+    // cells and store the range.
+    fn store_merge_range(
+        &mut self,
+        cell_range: CellRange,
+        format: &Format,
+    ) -> Result<(), XlsxError> {
+        let first_row = cell_range.first_row;
+        let first_col = cell_range.first_col;
+        let last_row = cell_range.last_row;
+        let last_col = cell_range.last_col;
+
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                // Skip the first cell which is written by the caller.
+                if row == first_row && col == first_col {
+                    continue;
+                }
+                self.write_blank(row, col, format)?;
+            }
+        }
+
+        // Store the merge range. Note, the ranges are stored in a separate Vec
+        // to the cells to cut down on storage size.
+        let index = self.merged_ranges.len();
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                self.merged_cells.insert((row, col), index);
+            }
+        }
+
+        self.merged_ranges.push(cell_range);
+
+        Ok(())
+    }
+
     // Store a rich string cell in the worksheet data table structure.
     fn store_rich_string(
         &mut self,
@@ -13096,10 +18161,10 @@ impl Worksheet {
         };
 
         // Set the formula result to the default or user defined
-        let result = if formula.result.is_empty() {
-            self.default_result.clone()
+        let (result, result_type) = if formula.result.is_empty() {
+            (self.default_result.clone(), FormulaResultType::Uncached)
         } else {
-            formula.result.clone()
+            (formula.result.clone(), FormulaResultType::Inferred)
         };
 
         // Create the appropriate cell type to hold the data.
@@ -13107,6 +18172,7 @@ impl Worksheet {
             formula: Box::from(formula.formula_string),
             xf_index,
             result,
+            result_type,
         };
 
         self.insert_cell(row, col, cell);
@@ -13126,6 +18192,9 @@ impl Worksheet {
         format: Option<&Format>,
         is_dynamic: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
+        // Array formulas don't support value metadata.
+        self.check_value_metadata_range(first_row, first_col, last_row, last_col)?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions(first_row, first_col)
             || !self.check_dimensions(last_row, last_col)
@@ -13158,10 +18227,10 @@ impl Worksheet {
         }
 
         // Set the formula result to the default or user defined
-        let result = if formula.result.is_empty() {
-            self.default_result.clone()
+        let (result, result_type) = if formula.result.is_empty() {
+            (self.default_result.clone(), FormulaResultType::Uncached)
         } else {
-            formula.result.clone()
+            (formula.result.clone(), FormulaResultType::Inferred)
         };
 
         // Create the appropriate cell type to hold the data.
@@ -13169,6 +18238,7 @@ impl Worksheet {
             formula: Box::from(formula.formula_string),
             xf_index,
             result,
+            result_type,
             is_dynamic,
             range: range.into_boxed_str(),
         };
@@ -13197,6 +18267,9 @@ impl Worksheet {
         col: ColNum,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        // Blank cells don't support value metadata.
+        self.check_value_metadata_range(row, col, row, col)?;
+
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -13277,6 +18350,9 @@ impl Worksheet {
         image: &Image,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        // Embedded images use their own value metadata.
+        self.check_value_metadata_range(row, col, row, col)?;
+
         // Check row and columns are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -13334,9 +18410,14 @@ impl Worksheet {
 
     // A rich string is handled in Excel like any other shared string except
     // that it has inline font markup within the string. To generate the
-    // required font xml we use an instance of the Style struct.
+    // required font xml we use an instance of the Style struct. Segments
+    // without a format are written without font markup so that they use the
+    // font of the cell format.
     #[allow(clippy::similar_names)]
-    fn get_rich_string(segments: &[(&Format, &str)]) -> Result<(String, String), XlsxError> {
+    fn get_rich_string<'a, F>(segments: &[(F, &str)]) -> Result<(String, String), XlsxError>
+    where
+        F: Into<Option<&'a Format>> + Copy,
+    {
         // Check that there is at least one segment tuple.
         if segments.is_empty() {
             let error = "Rich string must contain at least 1 (&Format, &str) tuple.";
@@ -13349,6 +18430,7 @@ impl Worksheet {
         let mut styler = Styles::new(
             &xf_formats,
             &dxf_formats,
+            &[],
             0,
             0,
             0,
@@ -13378,15 +18460,18 @@ impl Worksheet {
             };
 
             // First segment doesn't require a font run for the default format.
-            if format.is_default() && first_segment {
-                styler.writer.xml_start_tag_only("r");
-                styler.writer.xml_data_element("t", string, &attributes);
-                styler.writer.xml_end_tag("r");
-            } else {
-                styler.writer.xml_start_tag_only("r");
-                styler.write_font(&format.font, false);
-                styler.writer.xml_data_element("t", string, &attributes);
-                styler.writer.xml_end_tag("r");
+            match (*format).into() {
+                Some(format) if !(format.is_default() && first_segment) => {
+                    styler.writer.xml_start_tag_only("r");
+                    styler.write_font(&format.font, false);
+                    styler.writer.xml_data_element("t", string, &attributes);
+                    styler.writer.xml_end_tag("r");
+                }
+                _ => {
+                    styler.writer.xml_start_tag_only("r");
+                    styler.writer.xml_data_element("t", string, &attributes);
+                    styler.writer.xml_end_tag("r");
+                }
             }
             first_segment = false;
         }
@@ -13394,8 +18479,43 @@ impl Worksheet {
         Ok((styler.writer.read_to_string(), raw_string))
     }
 
+    // Check that a range of cells that don't support value metadata doesn't
+    // contain the cell being written by write_with_metadata().
+    fn check_value_metadata_range(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<(), XlsxError> {
+        if let Some((row, col, _)) = self.value_metadata_pending {
+            if (first_row..=last_row).contains(&row) && (first_col..=last_col).contains(&col) {
+                return Err(XlsxError::ParameterError(format!(
+                    "Value metadata isn't supported for blank, array formula or \
+                     embedded image cell {}.",
+                    utility::row_col_to_cell(row, col)
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     // Insert a cell value into the worksheet data table structure.
     fn insert_cell(&mut self, row: RowNum, col: ColNum, cell: CellType) {
+        // Remove any value metadata from a previous value, or link the value
+        // to the metadata from write_with_metadata().
+        match self.value_metadata_pending {
+            Some((pending_row, pending_col, index)) if pending_row == row && pending_col == col => {
+                self.value_metadata.insert((row, col), index);
+            }
+            _ => {
+                if !self.value_metadata.is_empty() {
+                    self.value_metadata.remove(&(row, col));
+                }
+            }
+        }
+
         match self.data_table.entry(row) {
             Entry::Occupied(mut entry) => {
                 // The row already exists. Insert/replace column value.
@@ -13411,6 +18531,156 @@ impl Worksheet {
         }
     }
 
+    // Create a copy of a cell from another worksheet for use in the current
+    // worksheet. The cell format is mapped to the local format index and
+    // formula references are shifted by the row and column offsets. Formulas
+    // that can't be shifted are replaced by their cached result.
+    fn copy_cell(
+        &mut self,
+        cell: &CellType,
+        xf_formats: &[Format],
+        row_offset: i64,
+        col_offset: i64,
+    ) -> CellType {
+        let mut copy_xf_index = |xf_index: &u32| match xf_formats.get(*xf_index as usize) {
+            Some(format) => self.format_xf_index(format),
+            None => 0,
+        };
+
+        match cell {
+            CellType::ArrayFormula {
+                formula,
+                xf_index,
+                result,
+                result_type,
+                is_dynamic,
+                range,
+            } => {
+                let xf_index = copy_xf_index(xf_index);
+                let formula = Formula::shift_references(formula, row_offset, col_offset);
+                let range = Formula::shift_references(range, row_offset, col_offset);
+
+                match (formula, range) {
+                    (Some(formula), Some(range)) => {
+                        self.has_dynamic_arrays |= *is_dynamic;
+
+                        CellType::ArrayFormula {
+                            formula: formula.into_boxed_str(),
+                            xf_index,
+                            result: result.clone(),
+                            result_type: *result_type,
+                            is_dynamic: *is_dynamic,
+                            range: range.into_boxed_str(),
+                        }
+                    }
+                    _ => self.formula_result_cell(result, *result_type, xf_index),
+                }
+            }
+            CellType::Formula {
+                formula,
+                xf_index,
+                result,
+                result_type,
+            } => {
+                let xf_index = copy_xf_index(xf_index);
+
+                match Formula::shift_references(formula, row_offset, col_offset) {
+                    Some(formula) => CellType::Formula {
+                        formula: formula.into_boxed_str(),
+                        xf_index,
+                        result: result.clone(),
+                        result_type: *result_type,
+                    },
+                    None => self.formula_result_cell(result, *result_type, xf_index),
+                }
+            }
+            CellType::Blank { xf_index } => CellType::Blank {
+                xf_index: copy_xf_index(xf_index),
+            },
+            CellType::Boolean { boolean, xf_index } => CellType::Boolean {
+                boolean: *boolean,
+                xf_index: copy_xf_index(xf_index),
+            },
+            CellType::Error { xf_index, value } => CellType::Error {
+                xf_index: copy_xf_index(xf_index),
+                value: *value,
+            },
+            CellType::Number { number, xf_index } => CellType::Number {
+                number: *number,
+                xf_index: copy_xf_index(xf_index),
+            },
+            CellType::DateTime { number, xf_index } => CellType::DateTime {
+                number: *number,
+                xf_index: copy_xf_index(xf_index),
+            },
+            CellType::String {
+                string, xf_index, ..
+            } => {
+                let xf_index = copy_xf_index(xf_index);
+                self.uses_string_table = true;
+
+                CellType::String {
+                    string: string.clone(),
+                    xf_index,
+                    string_id: 0,
+                }
+            }
+            CellType::RichString {
+                string,
+                xf_index,
+                raw_string,
+                ..
+            } => {
+                let xf_index = copy_xf_index(xf_index);
+                self.uses_string_table = true;
+
+                CellType::RichString {
+                    string: string.clone(),
+                    xf_index,
+                    raw_string: raw_string.clone(),
+                    string_id: 0,
+                }
+            }
+        }
+    }
+
+    // Convert a cached formula result into a value cell of the stored result
+    // type. Formulas without a cached result are converted to blank cells and
+    // error results are kept as a formula of the error value.
+    fn formula_result_cell(
+        &mut self,
+        result: &str,
+        result_type: FormulaResultType,
+        xf_index: u32,
+    ) -> CellType {
+        match (result_type, result.parse::<f64>()) {
+            (FormulaResultType::Uncached, _) => CellType::Blank { xf_index },
+            (FormulaResultType::Boolean, _) => CellType::Boolean {
+                boolean: result == "TRUE",
+                xf_index,
+            },
+            (FormulaResultType::Error, _) => CellType::Formula {
+                formula: Box::from(result),
+                xf_index,
+                result: Box::from(result),
+                result_type,
+            },
+            (FormulaResultType::Number | FormulaResultType::Inferred, Ok(number)) => {
+                CellType::Number { number, xf_index }
+            }
+            _ if result.is_empty() => CellType::Blank { xf_index },
+            _ => {
+                self.uses_string_table = true;
+
+                CellType::String {
+                    string: Arc::from(result),
+                    xf_index,
+                    string_id: 0,
+                }
+            }
+        }
+    }
+
     // Insert a cell format value into the worksheet data table structure. This
     // function creates a new blank cell if no other cell value exists.
     fn insert_cell_format(&mut self, row: RowNum, col: ColNum, format_id: u32) {
@@ -13489,6 +18759,12 @@ impl Worksheet {
     // Clear the data and formatting from a worksheet cell. Ignores non-existing
     // cells.
     fn clear_cell_internal(&mut self, row: RowNum, col: ColNum) {
+        self.hyperlinks.remove(&(row, col));
+
+        if !self.value_metadata.is_empty() {
+            self.value_metadata.remove(&(row, col));
+        }
+
         let Some(columns) = self.data_table.get_mut(&row) else {
             return;
         };
@@ -13736,22 +19012,24 @@ impl Worksheet {
                 // Note, autofit() will only update a user defined value if is
                 // greater than it. All other conditions are simple updates.
                 if autofit && !col_options.autofit {
-                    if width > col_options.width {
-                        col_options.width = width;
+                    if width > col_options.width.unwrap_or(DEFAULT_COL_WIDTH) {
+                        col_options.width = Some(width);
                         col_options.autofit = true;
                     }
                 } else {
-                    col_options.width = width;
+                    col_options.width = Some(width);
                     col_options.autofit = autofit;
                 }
             }
             None => {
                 // Create a new column metadata object.
                 let col_options = ColOptions {
-                    width,
+                    width: Some(width),
                     xf_index: 0,
                     hidden: false,
                     autofit,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_cols.insert(col, col_options);
             }
@@ -13860,46 +19138,166 @@ impl Worksheet {
         self.global_xf_indices = workbook_xf_indices.to_vec();
     }
 
-    // Set the mapping between the local format indices and the global/workbook
-    // indices for cell formats.
-    pub(crate) fn set_global_dxf_indices(&mut self, workbook_dxf_indices: &[u32]) {
-        self.global_dxf_indices = workbook_dxf_indices.to_vec();
+    // Remove any value metadata for cells that no longer have a value, so that
+    // stale entries don't create an unused metadata file.
+    pub(crate) fn remove_stale_value_metadata(&mut self) {
+        if self.value_metadata.is_empty() {
+            return;
+        }
+
+        let data_table = &self.data_table;
+        self.value_metadata.retain(|(row, col), _| {
+            data_table
+                .get(row)
+                .is_some_and(|columns| columns.contains_key(col))
+        });
+    }
+
+    // Set the mapping between the local format indices and the global/workbook
+    // indices for cell formats.
+    pub(crate) fn set_global_dxf_indices(&mut self, workbook_dxf_indices: &[u32]) {
+        self.global_dxf_indices = workbook_dxf_indices.to_vec();
+    }
+
+    // Translate the cell xf_index into a global/workbook format index. We also
+    // need to make sure that an unformatted cell (xf_index == 0) takes the row
+    // format (if it exists) or, failing that, the column format (if that
+    // exists).
+    fn get_cell_xf_index(
+        &mut self,
+        xf_index: u32,
+        row_options: Option<&RowOptions>,
+        col_num: ColNum,
+    ) -> u32 {
+        // The local cell format index.
+        let mut xf_index = xf_index;
+
+        // If it is zero the cell is unformatted and we check for a row format.
+        if xf_index == 0 {
+            if let Some(row_options) = row_options {
+                xf_index = row_options.xf_index;
+            }
+        }
+
+        // If it is still zero the row was unformatted so we check for a column
+        // format.
+        if xf_index == 0 {
+            if let Some(col_options) = self.changed_cols.get(&col_num) {
+                xf_index = col_options.xf_index;
+            }
+        }
+
+        // Finally convert the local format index into a global/workbook index.
+        if xf_index != 0 {
+            xf_index = self.global_xf_indices[xf_index as usize];
+        }
+
+        xf_index
+    }
+
+    // Translate localized function names in the worksheet formulas to US
+    // English. Formulas that contain a dynamic function after translation are
+    // converted to single cell dynamic array formulas, as they would be when
+    // written directly with the English function names.
+    pub(crate) fn localize_formulas(&mut self, locale: FormulaLocale) {
+        if locale == FormulaLocale::English {
+            return;
+        }
+
+        for (row, columns) in &mut self.data_table {
+            for (col, cell) in columns.iter_mut() {
+                match cell {
+                    CellType::Formula {
+                        formula,
+                        xf_index,
+                        result,
+                        result_type,
+                    } => {
+                        let Some((localized, is_dynamic)) =
+                            Formula::localize_formula(formula, locale)
+                        else {
+                            continue;
+                        };
+
+                        if is_dynamic {
+                            *cell = CellType::ArrayFormula {
+                                formula: Box::from(localized),
+                                xf_index: *xf_index,
+                                result: mem::take(result),
+                                result_type: *result_type,
+                                is_dynamic: true,
+                                range: utility::cell_range(*row, *col, *row, *col).into_boxed_str(),
+                            };
+                            self.has_dynamic_arrays = true;
+                        } else {
+                            *formula = Box::from(localized);
+                        }
+                    }
+                    CellType::ArrayFormula {
+                        formula,
+                        is_dynamic,
+                        ..
+                    } => {
+                        let Some((localized, has_dynamic_function)) =
+                            Formula::localize_formula(formula, locale)
+                        else {
+                            continue;
+                        };
+
+                        *formula = Box::from(localized);
+
+                        if has_dynamic_function {
+                            *is_dynamic = true;
+                            self.has_dynamic_arrays = true;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Insert an image scaled to fit the area of a range of cells. The range is
+    // stored with the image so that the scale can be recalculated when the
+    // file is saved, in case the column widths or row heights have changed
+    // since the image was inserted.
+    fn insert_image_fit_to_size(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        image: &Image,
+        keep_aspect_ratio: bool,
+    ) {
+        let mut image = image.clone();
+        image.fit_to_range = Some((last_row, last_col, keep_aspect_ratio));
+
+        let image = self.scale_image_to_range(first_row, first_col, image);
+        self.images.insert((first_row, first_col), image);
     }
 
-    // Translate the cell xf_index into a global/workbook format index. We also
-    // need to make sure that an unformatted cell (xf_index == 0) takes the row
-    // format (if it exists) or, failing that, the column format (if that
-    // exists).
-    fn get_cell_xf_index(
+    // Scale an image to the current size, in pixels, of the range of cells
+    // that it is fitted to, if any.
+    fn scale_image_to_range(
         &mut self,
-        xf_index: u32,
-        row_options: Option<&RowOptions>,
-        col_num: ColNum,
-    ) -> u32 {
-        // The local cell format index.
-        let mut xf_index = xf_index;
-
-        // If it is zero the cell is unformatted and we check for a row format.
-        if xf_index == 0 {
-            if let Some(row_options) = row_options {
-                xf_index = row_options.xf_index;
-            }
-        }
+        first_row: RowNum,
+        first_col: ColNum,
+        image: Image,
+    ) -> Image {
+        let Some((last_row, last_col, keep_aspect_ratio)) = image.fit_to_range else {
+            return image;
+        };
 
-        // If it is still zero the row was unformatted so we check for a column
-        // format.
-        if xf_index == 0 {
-            if let Some(col_options) = self.changed_cols.get(&col_num) {
-                xf_index = col_options.xf_index;
-            }
-        }
+        let width = (first_col..=last_col)
+            .map(|col| self.column_pixel_width(col, image.object_movement))
+            .sum::<u32>();
 
-        // Finally convert the local format index into a global/workbook index.
-        if xf_index != 0 {
-            xf_index = self.global_xf_indices[xf_index as usize];
-        }
+        let height = (first_row..=last_row)
+            .map(|row| self.row_pixel_height(row, image.object_movement))
+            .sum::<u32>();
 
-        xf_index
+        image.set_scale_to_size(width, height, keep_aspect_ratio)
     }
 
     // Convert the image dimensions into drawing dimensions and add them to the
@@ -13912,6 +19310,22 @@ impl Worksheet {
     ) {
         let mut rel_ids: HashMap<String, u32> = HashMap::new();
 
+        // Rescale any images that are fitted to cells in case the column
+        // widths or row heights were changed after they were inserted.
+        let fitted_cells: Vec<(RowNum, ColNum)> = self
+            .images
+            .iter()
+            .filter(|(_, image)| image.fit_to_range.is_some())
+            .map(|(cell, _)| *cell)
+            .collect();
+
+        for cell in fitted_cells {
+            if let Some(image) = self.images.remove(&cell) {
+                let image = self.scale_image_to_range(cell.0, cell.1, image);
+                self.images.insert(cell, image);
+            }
+        }
+
         for (cell, image) in &self.images.clone() {
             let row = cell.0;
             let col = cell.1;
@@ -14003,16 +19417,16 @@ impl Worksheet {
     pub(crate) fn prepare_header_footer_images(
         &mut self,
         image_ids: &mut HashMap<String, u32>,
-        base_image_id: u32,
+        image_id: &mut u32,
     ) {
         let mut rel_ids: HashMap<String, u32> = HashMap::new();
         for image in self.header_footer_images.clone().into_iter().flatten() {
             let image_id = match image_ids.get(&image.hash) {
                 Some(image_id) => *image_id,
                 None => {
-                    let image_id = 1 + base_image_id + image_ids.len() as u32;
-                    image_ids.insert(image.hash.clone(), image_id);
-                    image_id
+                    *image_id += 1;
+                    image_ids.insert(image.hash.clone(), *image_id);
+                    *image_id
                 }
             };
 
@@ -14093,11 +19507,34 @@ impl Worksheet {
         // Convert the Note objects to VmlInfo objects, along with their dimensions.
         for (cell_row, columns) in &self.notes.clone() {
             for (cell_col, note) in columns {
+                let mut note = note.clone();
+
+                // If the note is attached to a merged range then, like Excel,
+                // position it relative to the right side of the merged area
+                // instead of the right side of the parent cell.
+                if let Some(index) = self.merged_cells.get(&(*cell_row, *cell_col)) {
+                    note.cell_col = self.merged_ranges[*index].last_col;
+                }
+
+                // Set the note dimensions to fit the text if required.
+                if note.autosize.unwrap_or(self.autosize_all_notes) {
+                    let author = if note.has_author_prefix {
+                        self.note_authors
+                            .iter()
+                            .find(|(_, id)| **id == note.author_id)
+                            .map(|(author, _)| author.as_str())
+                    } else {
+                        None
+                    };
+
+                    note.set_autosize_dimensions(author);
+                }
+
                 let note_row = note.row();
                 let note_col = note.col();
 
                 let mut vml_info = note.vml_info();
-                vml_info.drawing_info = self.position_object_pixels(note_row, note_col, note);
+                vml_info.drawing_info = self.position_object_pixels(note_row, note_col, &note);
                 vml_info.row = *cell_row;
                 vml_info.col = *cell_col;
 
@@ -14152,6 +19589,28 @@ impl Worksheet {
             .push(("comments".to_string(), comment_name, String::new()));
     }
 
+    // Store the printerSettingsN.bin file linkage to the worksheets rels file.
+    pub(crate) fn add_printer_settings_rel_link(&mut self, printer_settings_id: u32) {
+        let printer_settings_name =
+            format!("../printerSettings/printerSettings{printer_settings_id}.bin");
+        self.printer_settings_relationships.push((
+            "printerSettings".to_string(),
+            printer_settings_name,
+            String::new(),
+        ));
+    }
+
+    // Store the threadedCommentN.xml file linkage to the worksheets rels file.
+    pub(crate) fn add_threaded_comment_rel_link(&mut self, threaded_comment_id: u32) {
+        let threaded_comment_name =
+            format!("../threadedComments/threadedComment{threaded_comment_id}.xml");
+        self.threaded_comment_relationships.push((
+            "threadedComment".to_string(),
+            threaded_comment_name,
+            String::new(),
+        ));
+    }
+
     // Convert the chart dimensions into drawing dimensions and add them to the
     // Drawing object. Also set the rel linkages between the files.
     pub(crate) fn prepare_worksheet_charts(&mut self, mut chart_id: u32, drawing_id: u32) -> u32 {
@@ -14190,11 +19649,41 @@ impl Worksheet {
                 drawing_name,
                 String::new(),
             ));
+
+            self.has_drawing_object_linkage = true;
         }
 
         chart_id
     }
 
+    // Convert the text box dimensions into drawing dimensions and add them to
+    // the Drawing object. Text boxes don't require any drawing rel links.
+    pub(crate) fn prepare_worksheet_textboxes(&mut self, drawing_id: u32) {
+        for (cell, textbox) in &self.textboxes.clone() {
+            let row = cell.0;
+            let col = cell.1;
+
+            // Convert the text box dimensions to drawing dimensions and store
+            // the drawing object.
+            let mut drawing_info = self.position_object_emus(row, col, textbox);
+            drawing_info.textbox = Some(textbox.clone());
+            self.drawing.drawings.push(drawing_info);
+        }
+
+        // Store the linkage to the worksheets rels file, if it hasn't already
+        // been set by the image or chart preparation functions.
+        if !self.has_drawing_object_linkage {
+            let drawing_name = format!("../drawings/drawing{drawing_id}.xml");
+            self.drawing_object_relationships.push((
+                "drawing".to_string(),
+                drawing_name,
+                String::new(),
+            ));
+
+            self.has_drawing_object_linkage = true;
+        }
+    }
+
     // Set a unique table id for each table and also set the rel linkages
     // between the worksheet and table xml files.
     pub(crate) fn prepare_worksheet_tables(&mut self, mut table_id: u32) -> u32 {
@@ -14211,6 +19700,13 @@ impl Worksheet {
                 }
             }
 
+            // Change any custom style format local index from local to global.
+            if let Some(custom_style) = table.custom_style.as_mut() {
+                for (_, format) in custom_style.element_formats_mut() {
+                    format.dxf_index = self.global_dxf_indices[format.dxf_index as usize];
+                }
+            }
+
             self.table_relationships.push((
                 "table".to_string(),
                 format!("../tables/table{table_id}.xml"),
@@ -14398,6 +19894,7 @@ impl Worksheet {
             drawing_type: object.drawing_type(),
             rel_id: 0,
             url: None,
+            textbox: None,
         }
     }
 
@@ -14409,7 +19906,8 @@ impl Worksheet {
 
         match self.changed_cols.get(&col) {
             Some(col_options) => {
-                let pixel_width = col_options.width;
+                // Columns that haven't been sized use the worksheet default width.
+                let pixel_width = col_options.width.unwrap_or(self.user_default_col_width);
                 let hidden = col_options.hidden;
 
                 if hidden && position != ObjectMovement::MoveAndSizeWithCellsAfter {
@@ -14423,7 +19921,13 @@ impl Worksheet {
                 }
             }
             // If the width hasn't been set we use the default value.
-            None => 64,
+            None => {
+                if self.user_default_col_width == DEFAULT_COL_WIDTH {
+                    DEFAULT_COL_WIDTH_PIXELS
+                } else {
+                    (self.user_default_col_width * max_digit_width).round() as u32 + padding as u32
+                }
+            }
         }
     }
 
@@ -14460,6 +19964,7 @@ impl Worksheet {
         }
 
         self.rel_count = 0;
+        self.has_drawing_object_linkage = false;
         self.drawing.drawings.clear();
         self.table_relationships.clear();
         self.hyperlink_relationships.clear();
@@ -14467,12 +19972,15 @@ impl Worksheet {
         self.drawing_relationships.clear();
         self.vml_drawing_relationships.clear();
         self.comment_relationships.clear();
+        self.threaded_comment_relationships.clear();
+        self.printer_settings_relationships.clear();
         self.header_footer_vml_info.clear();
     }
 
     // Check if any external relationships are required.
     pub(crate) fn has_relationships(&self) -> bool {
         !self.hyperlink_relationships.is_empty()
+            || !self.printer_settings_relationships.is_empty()
             || !self.drawing_object_relationships.is_empty()
             || !self.table_relationships.is_empty()
     }
@@ -14539,7 +20047,119 @@ impl Worksheet {
         }
     }
 
-    // Convert column pixel width to character width.
+    // Set the thick top or bottom border flags for a row.
+    fn store_row_thick_border(
+        &mut self,
+        row: RowNum,
+        thick_top: bool,
+        thick_bottom: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Set a suitable column range for the row dimension check/set.
+        let min_col = self.get_min_col();
+
+        // Check row is in the allowed range.
+        if !self.check_dimensions(row, min_col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Update an existing row metadata object or create a new one.
+        let row_options = self.changed_rows.entry(row).or_insert(RowOptions {
+            height: self.user_default_row_height,
+            xf_index: 0,
+            hidden: false,
+            thick_top: false,
+            thick_bottom: false,
+        });
+
+        row_options.thick_top |= thick_top;
+        row_options.thick_bottom |= thick_bottom;
+
+        Ok(self)
+    }
+
+    // Parse a number stored as a string, ignoring leading and trailing
+    // whitespace. Excel doesn't support NaN or infinite values.
+    fn parse_number(number: &str) -> Result<f64, XlsxError> {
+        match number.trim().parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(value),
+            _ => Err(XlsxError::ParameterError(format!(
+                "String '{number}' cannot be converted to a number."
+            ))),
+        }
+    }
+
+    // Get the approximate pixel width of the data in a cell, for autofit.
+    fn cell_pixel_width(cell: &CellType) -> u16 {
+        match cell {
+            // For strings we do a calculation based on character widths taken
+            // from Excel. For rich strings we use the unformatted string. We
+            // also split multi-line strings and handle each part separately.
+            CellType::String { string, .. }
+            | CellType::RichString {
+                raw_string: string, ..
+            } => {
+                let mut max = 0;
+                for segment in string.lines() {
+                    let length = utility::pixel_width(segment);
+                    max = cmp::max(max, length);
+                }
+                max
+            }
+
+            // For numbers we use a workaround/optimization since digits all
+            // have a pixel width of 7. This gives a slightly greater width for
+            // the decimal place and minus sign but only by a few pixels and
+            // over-estimation is okay.
+            CellType::Number { number, .. } => 7 * number.to_string().len() as u16,
+
+            // For Boolean types we use the Excel standard widths for TRUE and
+            // FALSE.
+            CellType::Boolean { boolean, .. } => {
+                if *boolean {
+                    31
+                } else {
+                    36
+                }
+            }
+
+            // For formulas we autofit the result of the formula if it has a
+            // non-zero/default value.
+            CellType::Formula { result, .. } | CellType::ArrayFormula { result, .. } => {
+                if result.as_ref() == "0" || result.is_empty() {
+                    0
+                } else {
+                    utility::pixel_width(result)
+                }
+            }
+
+            // Datetimes are just numbers but they also have an Excel format. It
+            // isn't feasible to parse the number format to get the actual
+            // string width for all format types so we use a width based on the
+            // Excel's default format: mm/dd/yyyy.
+            CellType::DateTime { .. } => 68,
+
+            // Ignore the following types which don't add to the width.
+            CellType::Blank { .. } | CellType::Error { .. } => 0,
+        }
+    }
+
+    // Set the autofit column widths from the maximum pixel widths of the data
+    // in each column.
+    fn store_autofit_widths(&mut self, max_widths: &HashMap<ColNum, u16>) {
+        // Scale the widths if the workbook has a default font size other than
+        // 11, since the character widths are based on Calibri 11.
+        let font_scale = self.default_font_size / 11.0;
+
+        // Set the max character width for each column.
+        for (col, pixels) in max_widths {
+            let pixels = (f64::from(*pixels + 7) * font_scale).round() as u16;
+            let width = Self::pixels_to_width(pixels);
+            self.store_column_width(*col, width, true);
+        }
+    }
+
+    // Convert column pixel width to character width. Widths less than one
+    // character, i.e., less than 12 pixels, don't include the padding.
     pub(crate) fn pixels_to_width(pixels: u16) -> f64 {
         // Properties for Calibri 11.
         let max_digit_width = 7.0_f64;
@@ -14590,6 +20210,13 @@ impl Worksheet {
                                     }
                                     data.push(number.to_string());
                                 }
+                                CellType::Boolean { boolean, .. } => {
+                                    // Excel treats booleans as 1/0 in charts.
+                                    if cache.cache_type != ChartRangeCacheDataType::String {
+                                        cache.cache_type = ChartRangeCacheDataType::Number;
+                                    }
+                                    data.push(u8::from(*boolean).to_string());
+                                }
 
                                 _ => data.push(String::new()),
                             },
@@ -14597,7 +20224,11 @@ impl Worksheet {
                         }
                     }
                 }
-                None => data.push(String::new()),
+                None => {
+                    for _ in first_col..=last_col {
+                        data.push(String::new());
+                    }
+                }
             }
         }
 
@@ -14666,6 +20297,11 @@ impl Worksheet {
         // Write the sheetData element.
         self.write_sheet_data();
 
+        // Write the sheetCalcPr element.
+        if self.full_calc_on_load {
+            self.write_sheet_calc_pr();
+        }
+
         // Write the sheetProtection element.
         if self.protection_on {
             self.write_sheet_protection();
@@ -14729,6 +20365,11 @@ impl Worksheet {
             self.write_col_breaks();
         }
 
+        // Write the ignoredErrors element.
+        if !self.ignored_errors.is_empty() {
+            self.write_ignored_errors();
+        }
+
         // Write the drawing element.
         if !self.drawing.drawings.is_empty() {
             self.write_drawing();
@@ -14788,10 +20429,13 @@ impl Worksheet {
 
     // Write the <sheetPr> element.
     fn write_sheet_pr(&mut self) {
+        let has_outline_pr = !self.outline_symbols_below || !self.outline_symbols_right;
+
         if self.filter_conditions.is_empty()
             && !self.fit_to_page
             && (self.tab_color == Color::Default || self.tab_color == Color::Automatic)
             && self.vba_codename.is_none()
+            && !has_outline_pr
         {
             return;
         }
@@ -14805,22 +20449,45 @@ impl Worksheet {
         }
 
         if self.fit_to_page
+            || has_outline_pr
             || (self.tab_color != Color::Default && self.tab_color != Color::Automatic)
         {
             self.writer.xml_start_tag("sheetPr", &attributes);
 
-            // Write the pageSetUpPr element.
-            self.write_page_set_up_pr();
-
             // Write the tabColor element.
             self.write_tab_color();
 
+            // Write the outlinePr element.
+            self.write_outline_pr();
+
+            // Write the pageSetUpPr element.
+            self.write_page_set_up_pr();
+
             self.writer.xml_end_tag("sheetPr");
         } else {
             self.writer.xml_empty_tag("sheetPr", &attributes);
         }
     }
 
+    // Write the <outlinePr> element.
+    fn write_outline_pr(&mut self) {
+        if self.outline_symbols_below && self.outline_symbols_right {
+            return;
+        }
+
+        let mut attributes = vec![];
+
+        if !self.outline_symbols_below {
+            attributes.push(("summaryBelow", "0"));
+        }
+
+        if !self.outline_symbols_right {
+            attributes.push(("summaryRight", "0"));
+        }
+
+        self.writer.xml_empty_tag("outlinePr", &attributes);
+    }
+
     // Write the <pageSetUpPr> element.
     fn write_page_set_up_pr(&mut self) {
         if !self.fit_to_page {
@@ -14893,6 +20560,10 @@ impl Worksheet {
             attributes.push(("showGridLines", "0".to_string()));
         }
 
+        if !self.screen_headings {
+            attributes.push(("showRowColHeaders", "0".to_string()));
+        }
+
         if self.right_to_left {
             attributes.push(("rightToLeft", "1".to_string()));
         }
@@ -14961,6 +20632,11 @@ impl Worksheet {
             return;
         }
 
+        if self.panes.is_split {
+            self.write_split_panes();
+            return;
+        }
+
         let row = self.panes.freeze_cell.0;
         let col = self.panes.freeze_cell.1;
 
@@ -14987,6 +20663,75 @@ impl Worksheet {
         }
     }
 
+    // Write the elements associated with split, rather than frozen, panes.
+    // Unlike frozen panes the selections in each pane are anchored to the top
+    // left cell of the scrolling area.
+    fn write_split_panes(&mut self) {
+        let row = self.panes.freeze_cell.0;
+        let col = self.panes.freeze_cell.1;
+        let top_left = self.panes.top_left();
+        let (top_row, left_col) = if self.panes.top_cell == (0, 0) {
+            self.panes.freeze_cell
+        } else {
+            self.panes.top_cell
+        };
+
+        // Convert the split position from pixels to twips (1/20 point) where
+        // there are 15 twips per pixel. The offsets are for the row and column
+        // header bars.
+        let mut x_split = 0;
+        let mut y_split = 0;
+
+        if col > 0 {
+            let pixels: u32 = (0..col)
+                .map(|col| self.column_pixel_width(col, ObjectMovement::MoveAndSizeWithCells))
+                .sum();
+            x_split = pixels * 15 + 390;
+        }
+
+        if row > 0 {
+            let pixels: u32 = (0..row)
+                .map(|row| self.row_pixel_height(row, ObjectMovement::MoveAndSizeWithCells))
+                .sum();
+            y_split = pixels * 15 + 300;
+        }
+
+        let active_pane = if row > 0 && col > 0 {
+            "bottomRight"
+        } else if col > 0 {
+            "topRight"
+        } else {
+            "bottomLeft"
+        };
+
+        // Write the pane element.
+        let mut attributes = vec![];
+
+        if x_split > 0 {
+            attributes.push(("xSplit", x_split.to_string()));
+        }
+
+        if y_split > 0 {
+            attributes.push(("ySplit", y_split.to_string()));
+        }
+
+        attributes.push(("topLeftCell", top_left.clone()));
+        attributes.push(("activePane", active_pane.to_string()));
+
+        self.writer.xml_empty_tag("pane", &attributes);
+
+        // Write the selection elements.
+        if row > 0 && col > 0 {
+            let col_cell = utility::row_col_to_cell(0, left_col);
+            let row_cell = utility::row_col_to_cell(top_row, 0);
+
+            self.write_selection("topRight", &col_cell, &col_cell);
+            self.write_selection("bottomLeft", &row_cell, &row_cell);
+        }
+
+        self.write_selection(active_pane, &top_left, &top_left);
+    }
+
     // Write the <pane> element.
     fn write_pane(&mut self, active_pane: &str) {
         let row = self.panes.freeze_cell.0;
@@ -15029,7 +20774,18 @@ impl Worksheet {
 
     // Write the <sheetFormatPr> element.
     fn write_sheet_format_pr(&mut self) {
-        let mut attributes = vec![("defaultRowHeight", self.user_default_row_height.to_string())];
+        let mut attributes = vec![];
+
+        if self.user_default_col_width != DEFAULT_COL_WIDTH {
+            let width = self.user_default_col_width;
+            attributes.push(("baseColWidth", (width.floor() as u16).to_string()));
+            attributes.push((
+                "defaultColWidth",
+                Self::column_character_width(width).to_string(),
+            ));
+        }
+
+        attributes.push(("defaultRowHeight", self.user_default_row_height.to_string()));
 
         if self.user_default_row_height != DEFAULT_ROW_HEIGHT {
             attributes.push(("customHeight", "1".to_string()));
@@ -15039,6 +20795,10 @@ impl Worksheet {
             attributes.push(("zeroHeight", "1".to_string()));
         }
 
+        if self.outline_col_level > 0 {
+            attributes.push(("outlineLevelCol", self.outline_col_level.to_string()));
+        }
+
         if self.use_x14_extensions {
             attributes.push(("x14ac:dyDescent", "0.25".to_string()));
         }
@@ -15082,21 +20842,83 @@ impl Worksheet {
     fn write_hyperlinks(&mut self) {
         self.writer.xml_start_tag_only("hyperlinks");
 
-        let mut rel_id = 1u32;
+        // Excel stores a relationship for each external url, even if they are
+        // the same. Optionally, identical urls can share a relationship id.
+        let mut rel_id = 0;
+        let mut rel_ids: HashMap<String, u32> = HashMap::new();
+
         for (cell, hyperlink) in &mut self.hyperlinks.clone() {
-            rel_id = hyperlink.increment_rel_id(rel_id);
+            if matches!(
+                hyperlink.link_type,
+                HyperlinkType::Url | HyperlinkType::File
+            ) {
+                let shared_rel_id = if self.share_hyperlink_relationships {
+                    rel_ids.get(&hyperlink.url_link).copied()
+                } else {
+                    None
+                };
+
+                hyperlink.rel_id = match shared_rel_id {
+                    Some(shared_rel_id) => shared_rel_id,
+                    None => {
+                        rel_id += 1;
+
+                        if self.share_hyperlink_relationships {
+                            rel_ids.insert(hyperlink.url_link.clone(), rel_id);
+                        }
+
+                        // Store the linkage to the worksheets rels file.
+                        self.hyperlink_relationships.push((
+                            "hyperlink".to_string(),
+                            hyperlink.url_link.to_string(),
+                            "External".to_string(),
+                        ));
+
+                        rel_id
+                    }
+                };
+            }
+
             self.write_hyperlink(cell.0, cell.1, hyperlink);
         }
 
-        self.rel_count = rel_id - 1;
+        self.rel_count = rel_id;
 
         self.writer.xml_end_tag("hyperlinks");
     }
 
+    // Get the priority of each conditional format rule, keyed by the range and
+    // the index of the rule in the range. The default priority is the order
+    // that the rules were added to the worksheet. Rules with a user defined
+    // priority are sorted by it and take precedence over an equal default
+    // priority. The priorities are then renumbered from 1.
+    fn conditional_format_priorities(&self) -> HashMap<(String, usize), u32> {
+        let mut rules: Vec<(u32, bool, usize, &(String, usize))> = self
+            .conditional_format_order
+            .iter()
+            .enumerate()
+            .map(|(order, key)| {
+                let rule = &self.conditional_formats[&key.0][key.1];
+                match rule.priority() {
+                    Some(priority) => (priority, false, order, key),
+                    None => (order as u32 + 1, true, order, key),
+                }
+            })
+            .collect();
+
+        rules.sort_unstable();
+
+        rules
+            .into_iter()
+            .enumerate()
+            .map(|(index, (_, _, _, key))| (key.clone(), index as u32 + 1))
+            .collect()
+    }
+
     // Write the <conditionalFormatting> element.
     fn write_conditional_formats(&mut self) {
         let mut guid_index = 1;
-        let mut priority = 1;
+        let priorities = self.conditional_format_priorities();
 
         for (cell_range, conditionals_for_range) in &self.conditional_formats {
             let has_x14_only = conditionals_for_range
@@ -15110,7 +20932,9 @@ impl Worksheet {
                     .xml_start_tag("conditionalFormatting", &attributes);
             }
 
-            for conditional_format in conditionals_for_range {
+            for (index, conditional_format) in conditionals_for_range.iter().enumerate() {
+                let priority = priorities[&(cell_range.clone(), index)];
+
                 // Create a pseudo GUID for each unique Excel 2010 data bar.
                 let mut guid = String::new();
                 if conditional_format.has_x14_extensions() {
@@ -15132,8 +20956,6 @@ impl Worksheet {
                     let rule = conditional_format.rule(dxf_index, priority, cell_range, &guid);
                     self.writer.xml_raw_string(&rule);
                 }
-
-                priority += 1;
             }
 
             if !has_x14_only {
@@ -15148,7 +20970,7 @@ impl Worksheet {
         self.writer.xml_start_tag_only("x14:conditionalFormattings");
 
         let mut guid_index = 1;
-        let mut priority = 1;
+        let priorities = self.conditional_format_priorities();
 
         for (cell_range, conditionals_for_range) in &self.conditional_formats {
             // Only create an entry if range contains a x14 style conditional.
@@ -15167,8 +20989,10 @@ impl Worksheet {
             self.writer
                 .xml_start_tag("x14:conditionalFormatting", &attributes);
 
-            for conditional_format in conditionals_for_range {
+            for (index, conditional_format) in conditionals_for_range.iter().enumerate() {
                 if conditional_format.has_x14_extensions() {
+                    let priority = priorities[&(cell_range.clone(), index)];
+
                     // Create a pseudo GUID for each unique Excel 2010 data bar.
                     let guid = format!(
                         "{{DA7ABA51-AAAA-BBBB-{:04X}-{:012X}}}",
@@ -15180,7 +21004,6 @@ impl Worksheet {
                     let rule = conditional_format.x14_rule(priority, &guid);
                     self.writer.xml_raw_string(&rule);
                 }
-                priority += 1;
             }
 
             self.writer.xml_data_element_only("xm:sqref", cell_range);
@@ -15192,16 +21015,48 @@ impl Worksheet {
 
     // Write the <dataValidations> element.
     fn write_data_validations(&mut self) {
-        let attributes = [("count", self.data_validations.len().to_string())];
+        let data_validations = self.coalesce_data_validations();
+        let attributes = [("count", data_validations.len().to_string())];
+
+        self.writer.xml_start_tag("dataValidations", &attributes);
+
+        for (range, data_validation) in &data_validations {
+            // Write the dataValidation element.
+            self.write_data_validation(range, data_validation);
+        }
+
+        self.writer.xml_end_tag("dataValidations");
+    }
+
+    // Combine data validations with identical rules and properties into a
+    // single validation with a space separated range, like Excel. This
+    // significantly reduces the file size for worksheets where a large number
+    // of cells are validated individually. Validations with relative cell
+    // references aren't combined since they depend on the position of the
+    // range.
+    fn coalesce_data_validations(&self) -> Vec<(String, DataValidation)> {
+        let mut data_validations: Vec<(String, DataValidation)> = vec![];
+        let mut indices: HashMap<DataValidation, usize> = HashMap::new();
+
+        for (range, data_validation) in &self.data_validations {
+            let mut data_validation = data_validation.clone();
+            data_validation.multi_range.clear();
+
+            if !data_validation.has_relative_references() {
+                if let Some(index) = indices.get(&data_validation) {
+                    let sqref = &mut data_validations[*index].0;
+                    sqref.push(' ');
+                    sqref.push_str(range);
+                    continue;
+                }
 
-        self.writer.xml_start_tag("dataValidations", &attributes);
+                indices.insert(data_validation.clone(), data_validations.len());
+            }
 
-        for (range, data_validation) in &self.data_validations.clone() {
-            // Write the dataValidation element.
-            self.write_data_validation(range, data_validation);
+            data_validations.push((range.clone(), data_validation));
         }
 
-        self.writer.xml_end_tag("dataValidations");
+        data_validations
     }
 
     // Write the <dataValidation> element.
@@ -15351,13 +21206,6 @@ impl Worksheet {
                 if !hyperlink.tool_tip.is_empty() {
                     attributes.push(("tooltip", hyperlink.tool_tip.to_string()));
                 }
-
-                // Store the linkage to the worksheets rels file.
-                self.hyperlink_relationships.push((
-                    "hyperlink".to_string(),
-                    hyperlink.url_link.to_string(),
-                    "External".to_string(),
-                ));
             }
             HyperlinkType::Internal => {
                 // Internal links don't use the rel file reference id.
@@ -15458,6 +21306,11 @@ impl Worksheet {
         attributes.push(("horizontalDpi", "200".to_string()));
         attributes.push(("verticalDpi", "200".to_string()));
 
+        if !self.printer_settings.is_empty() {
+            self.rel_count += 1;
+            attributes.push(("r:id", format!("rId{}", self.rel_count)));
+        }
+
         self.writer.xml_empty_tag("pageSetup", &attributes);
     }
 
@@ -15560,9 +21413,12 @@ impl Worksheet {
 
     // Store unique strings in the SST table and convert them to a string id
     // which is used when writing out the string cells.
-    pub(crate) fn update_string_table_ids(&mut self, string_table: &mut SharedStringsTable) {
+    pub(crate) fn update_string_table_ids(
+        &mut self,
+        string_table: &mut SharedStringsTable,
+    ) -> Result<(), XlsxError> {
         if !self.uses_string_table {
-            return;
+            return Ok(());
         }
 
         for columns in self.data_table.values_mut() {
@@ -15574,13 +21430,15 @@ impl Worksheet {
                     | CellType::RichString {
                         string, string_id, ..
                     } => {
-                        let string_index = string_table.shared_string_index(Arc::clone(string));
+                        let string_index = string_table.shared_string_index(Arc::clone(string))?;
                         *string_id = string_index;
                     }
                     _ => {}
                 }
             }
         }
+
+        Ok(())
     }
 
     // Write out all the row and cell data in the worksheet data table.
@@ -15612,11 +21470,13 @@ impl Worksheet {
             // The row has data. Write it out cell by cell.
             self.write_table_row(row_num, span, row_options, true);
             for (&col_num, cell) in columns {
+                let vm = self.cell_value_metadata(row_num, col_num);
+
                 match cell {
                     CellType::Number { number, xf_index }
                     | CellType::DateTime { number, xf_index } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                        self.write_number_cell(row_num, col_num, *number, xf_index);
+                        self.write_number_cell(row_num, col_num, *number, xf_index, vm);
                     }
                     CellType::String {
                         string_id,
@@ -15629,20 +21489,30 @@ impl Worksheet {
                         ..
                     } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                        self.write_string_cell(row_num, col_num, *string_id, xf_index);
+                        self.write_string_cell(row_num, col_num, *string_id, xf_index, vm);
                     }
                     CellType::Formula {
                         formula,
                         xf_index,
                         result,
+                        result_type,
                     } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                        self.write_formula_cell(row_num, col_num, formula, xf_index, result);
+                        self.write_formula_cell(
+                            row_num,
+                            col_num,
+                            formula,
+                            xf_index,
+                            result,
+                            *result_type,
+                            vm,
+                        );
                     }
                     CellType::ArrayFormula {
                         formula,
                         xf_index,
                         result,
+                        result_type,
                         is_dynamic,
                         range,
                     } => {
@@ -15653,6 +21523,7 @@ impl Worksheet {
                             formula,
                             xf_index,
                             result,
+                            *result_type,
                             *is_dynamic,
                             range,
                         );
@@ -15663,7 +21534,7 @@ impl Worksheet {
                     }
                     CellType::Boolean { boolean, xf_index } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                        self.write_boolean_cell(row_num, col_num, *boolean, xf_index);
+                        self.write_boolean_cell(row_num, col_num, *boolean, xf_index, vm);
                     }
                     CellType::Error { value, xf_index } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
@@ -15682,8 +21553,10 @@ impl Worksheet {
 
     // Calculate the "spans" attribute of the <row> tag. This is an xlsx
     // optimization and isn't strictly required. However, it makes comparing
-    // files easier. The span is the same for each block of 16 rows.
-    fn calculate_spans(&mut self) -> HashMap<u32, String> {
+    // files easier and helps Excel allocate memory when loading large files.
+    // The span is the min:max range of the used columns in each block of 16
+    // rows, which matches the value written by Excel, rather than per row.
+    fn calculate_spans(&self) -> HashMap<u32, String> {
         let mut spans: HashMap<RowNum, String> = HashMap::new();
         let mut span_min = COL_MAX;
         let mut span_max = 0;
@@ -15768,6 +21641,14 @@ impl Worksheet {
             if row_options.height != DEFAULT_ROW_HEIGHT {
                 attributes.push(("customHeight", "1".to_string()));
             }
+
+            if row_options.thick_top {
+                attributes.push(("thickTop", "1".to_string()));
+            }
+
+            if row_options.thick_bottom {
+                attributes.push(("thickBot", "1".to_string()));
+            }
         } else if self.user_default_row_height != DEFAULT_ROW_HEIGHT {
             attributes.push(("ht", self.user_default_row_height.to_string()));
             attributes.push(("customHeight", "1".to_string()));
@@ -15781,8 +21662,9 @@ impl Worksheet {
     }
 
     // Write the <c> element for a number.
-    fn write_number_cell(&mut self, row: RowNum, col: ColNum, number: f64, xf_index: u32) {
+    fn write_number_cell(&mut self, row: RowNum, col: ColNum, number: f64, xf_index: u32, vm: u32) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
+        let vm = Self::vm_attribute(vm);
 
         // Use the optional ryu crate to format f64 cell number data as a
         // string. Note, the the slightly faster `format_finite()` buffer
@@ -15796,19 +21678,21 @@ impl Worksheet {
         if xf_index > 0 {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" s="{}"><v>{}</v></c>"#,
+                r#"<c r="{}{}" s="{}"{}><v>{}</v></c>"#,
                 col_name,
                 row + 1,
                 xf_index,
+                vm,
                 number
             )
             .expect(XML_WRITE_ERROR);
         } else {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}"><v>{}</v></c>"#,
+                r#"<c r="{}{}"{}><v>{}</v></c>"#,
                 col_name,
                 row + 1,
+                vm,
                 number
             )
             .expect(XML_WRITE_ERROR);
@@ -15816,25 +21700,35 @@ impl Worksheet {
     }
 
     // Write the <c> element for a string.
-    fn write_string_cell(&mut self, row: RowNum, col: ColNum, string_index: u32, xf_index: u32) {
+    fn write_string_cell(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        string_index: u32,
+        xf_index: u32,
+        vm: u32,
+    ) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
+        let vm = Self::vm_attribute(vm);
 
         if xf_index > 0 {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" s="{}" t="s"><v>{}</v></c>"#,
+                r#"<c r="{}{}" s="{}" t="s"{}><v>{}</v></c>"#,
                 col_name,
                 row + 1,
                 xf_index,
+                vm,
                 string_index
             )
             .expect(XML_WRITE_ERROR);
         } else {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" t="s"><v>{}</v></c>"#,
+                r#"<c r="{}{}" t="s"{}><v>{}</v></c>"#,
                 col_name,
                 row + 1,
+                vm,
                 string_index
             )
             .expect(XML_WRITE_ERROR);
@@ -15842,6 +21736,7 @@ impl Worksheet {
     }
 
     // Write the <c> element for a formula.
+    #[allow(clippy::too_many_arguments)]
     fn write_formula_cell(
         &mut self,
         row: RowNum,
@@ -15849,6 +21744,8 @@ impl Worksheet {
         formula: &str,
         xf_index: u32,
         result: &str,
+        result_type: FormulaResultType,
+        vm: u32,
     ) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
 
@@ -15858,19 +21755,17 @@ impl Worksheet {
             String::new()
         };
 
-        let result_type = if result.parse::<f64>().is_err() {
-            r#" t="str""#
-        } else {
-            ""
-        };
+        let (result_type, result) = Self::formula_result_type(result, result_type);
+        let vm = Self::vm_attribute(vm);
 
         write!(
             &mut self.writer.xmlfile,
-            r#"<c r="{}{}"{}{}><f>{}</f><v>{}</v></c>"#,
+            r#"<c r="{}{}"{}{}{}><f>{}</f><v>{}</v></c>"#,
             col_name,
             row + 1,
             style,
             result_type,
+            vm,
             crate::xmlwriter::escape_xml_data(formula),
             crate::xmlwriter::escape_xml_data(result),
         )
@@ -15886,6 +21781,7 @@ impl Worksheet {
         formula: &str,
         xf_index: u32,
         result: &str,
+        result_type: FormulaResultType,
         is_dynamic: bool,
         range: &str,
     ) {
@@ -15899,11 +21795,7 @@ impl Worksheet {
 
         let cm = if is_dynamic { r#" cm="1""# } else { "" };
 
-        let result_type = if result.parse::<f64>().is_err() {
-            r#" t="str""#
-        } else {
-            ""
-        };
+        let (result_type, result) = Self::formula_result_type(result, result_type);
 
         write!(
             &mut self.writer.xmlfile,
@@ -15920,6 +21812,53 @@ impl Worksheet {
         .expect(XML_WRITE_ERROR);
     }
 
+    // Get the cell type attribute and value for a formula result. Results set
+    // from a string are stored as numbers if they are numeric, otherwise as
+    // strings. Typed boolean results are stored as 1/0.
+    fn formula_result_type(result: &str, result_type: FormulaResultType) -> (&'static str, &str) {
+        match result_type {
+            FormulaResultType::Inferred | FormulaResultType::Uncached => {
+                if result.parse::<f64>().is_err() {
+                    (r#" t="str""#, result)
+                } else {
+                    ("", result)
+                }
+            }
+            FormulaResultType::Number => ("", result),
+            FormulaResultType::String => (r#" t="str""#, result),
+            FormulaResultType::Boolean => {
+                if result == "TRUE" {
+                    (r#" t="b""#, "1")
+                } else {
+                    (r#" t="b""#, "0")
+                }
+            }
+            FormulaResultType::Error => (r#" t="e""#, result),
+        }
+    }
+
+    // Get the 1-based "vm" index of a cell with user value metadata, or 0 if
+    // there isn't any. The user metadata follows the embedded image metadata.
+    fn cell_value_metadata(&self, row: RowNum, col: ColNum) -> u32 {
+        if self.value_metadata.is_empty() {
+            return 0;
+        }
+
+        match self.value_metadata.get(&(row, col)) {
+            Some(index) => self.value_metadata_offset + index + 1,
+            None => 0,
+        }
+    }
+
+    // Get the "vm" cell attribute for a value metadata index, if any.
+    fn vm_attribute(vm: u32) -> String {
+        if vm > 0 {
+            format!(r#" vm="{vm}""#)
+        } else {
+            String::new()
+        }
+    }
+
     // Write the <c> element for a blank cell.
     fn write_blank_cell(&mut self, row: RowNum, col: ColNum, xf_index: u32) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
@@ -15939,26 +21878,36 @@ impl Worksheet {
     }
 
     // Write the <c> element for a boolean cell.
-    fn write_boolean_cell(&mut self, row: RowNum, col: ColNum, boolean: bool, xf_index: u32) {
+    fn write_boolean_cell(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        boolean: bool,
+        xf_index: u32,
+        vm: u32,
+    ) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
         let boolean = i32::from(boolean);
+        let vm = Self::vm_attribute(vm);
 
         if xf_index > 0 {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" s="{}" t="b"><v>{}</v></c>"#,
+                r#"<c r="{}{}" s="{}" t="b"{}><v>{}</v></c>"#,
                 col_name,
                 row + 1,
                 xf_index,
+                vm,
                 boolean
             )
             .expect(XML_WRITE_ERROR);
         } else {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" t="b"><v>{}</v></c>"#,
+                r#"<c r="{}{}" t="b"{}><v>{}</v></c>"#,
                 col_name,
                 row + 1,
+                vm,
                 boolean
             )
             .expect(XML_WRITE_ERROR);
@@ -16000,14 +21949,38 @@ impl Worksheet {
 
         self.writer.xml_start_tag_only("cols");
 
+        // Mark the summary columns of collapsed outline groups. Excel stores
+        // the collapsed state on the column just outside the group, to the
+        // right or left depending on the outline settings.
+        let mut changed_cols = self.changed_cols.clone();
+        for (first_col, last_col, level) in &self.collapsed_column_groups {
+            let summary_col = if self.outline_symbols_right {
+                if *last_col + 1 >= COL_MAX {
+                    continue;
+                }
+                *last_col + 1
+            } else {
+                if *first_col == 0 {
+                    continue;
+                }
+                *first_col - 1
+            };
+
+            let col_options = changed_cols.entry(summary_col).or_insert(ColOptions {
+                width: None,
+                xf_index: 0,
+                hidden: false,
+                autofit: false,
+                level: level - 1,
+                collapsed: false,
+            });
+            col_options.collapsed = true;
+        }
+
         // We need to write contiguous equivalent columns as a range with first
         // and last columns, so we convert the HashMap to a sorted vector and
         // iterate over that.
-        let mut col_options: Vec<_> = self
-            .changed_cols
-            .iter()
-            .map(|(k, v)| (*k, v.clone()))
-            .collect();
+        let mut col_options: Vec<_> = changed_cols.into_iter().collect();
         col_options.sort_unstable_by_key(|x| x.0);
         let mut col_options = col_options.into_iter();
 
@@ -16042,31 +22015,22 @@ impl Worksheet {
     fn write_col_element(&mut self, first_col: ColNum, last_col: ColNum, col_options: &ColOptions) {
         let first_col = first_col + 1;
         let last_col = last_col + 1;
-        let mut width = col_options.width;
         let xf_index = col_options.xf_index;
-        let has_custom_width = width != DEFAULT_COL_WIDTH;
         let hidden = col_options.hidden;
 
+        // Columns that haven't been sized use the worksheet default width.
+        let mut width = col_options.width.unwrap_or(self.user_default_col_width);
+
+        let has_custom_width = width != self.user_default_col_width;
+
         // The default col width changes to 0 for hidden columns.
-        if width == DEFAULT_COL_WIDTH && hidden {
+        if width == self.user_default_col_width && hidden {
             width = 0.0;
         }
 
         // Convert column width from user units to character width.
         if width > 0.0 {
-            // Properties for Calibri 11.
-            let max_digit_width = 7.0_f64;
-            let padding = 5.0_f64;
-
-            if width < 1.0 {
-                width = ((width * (max_digit_width + padding)).round() / max_digit_width * 256.0)
-                    .floor()
-                    / 256.0;
-            } else {
-                width = (((width * max_digit_width).round() + padding) / max_digit_width * 256.0)
-                    .floor()
-                    / 256.0;
-            }
+            width = Self::column_character_width(width);
         }
 
         let mut attributes = vec![
@@ -16092,9 +22056,33 @@ impl Worksheet {
             attributes.push(("customWidth", "1".to_string()));
         }
 
+        if col_options.level > 0 {
+            attributes.push(("outlineLevel", col_options.level.to_string()));
+        }
+
+        if col_options.collapsed {
+            attributes.push(("collapsed", "1".to_string()));
+        }
+
         self.writer.xml_empty_tag("col", &attributes);
     }
 
+    // Convert a column width from user units to the character width, including
+    // padding, that is stored in the file.
+    fn column_character_width(width: f64) -> f64 {
+        // Properties for Calibri 11.
+        let max_digit_width = 7.0_f64;
+        let padding = 5.0_f64;
+
+        if width < 1.0 {
+            ((width * (max_digit_width + padding)).round() / max_digit_width * 256.0).floor()
+                / 256.0
+        } else {
+            (((width * max_digit_width).round() + padding) / max_digit_width * 256.0).floor()
+                / 256.0
+        }
+    }
+
     // Write the <headerFooter> element.
     fn write_header_footer(&mut self) {
         let mut attributes = vec![];
@@ -16207,6 +22195,13 @@ impl Worksheet {
         self.writer.xml_empty_tag("tablePart", &attributes);
     }
 
+    // Write the <sheetCalcPr> element.
+    fn write_sheet_calc_pr(&mut self) {
+        let attributes = [("fullCalcOnLoad", "1")];
+
+        self.writer.xml_empty_tag("sheetCalcPr", &attributes);
+    }
+
     // Write the <sheetProtection> element.
     fn write_sheet_protection(&mut self) {
         let mut attributes = vec![];
@@ -16361,6 +22356,23 @@ impl Worksheet {
         self.writer.xml_empty_tag("brk", &attributes);
     }
 
+    // Write the <ignoredErrors> element.
+    fn write_ignored_errors(&mut self) {
+        self.writer.xml_start_tag_only("ignoredErrors");
+
+        for (error_type, ranges) in &self.ignored_errors {
+            // Write the ignoredError element.
+            let attributes = [
+                ("sqref", ranges.join(" ")),
+                (error_type.attribute(), "1".to_string()),
+            ];
+
+            self.writer.xml_empty_tag("ignoredError", &attributes);
+        }
+
+        self.writer.xml_end_tag("ignoredErrors");
+    }
+
     // Write the <extLst> element.
     fn write_extensions(&mut self) {
         self.writer.xml_start_tag_only("extLst");
@@ -17050,6 +23062,326 @@ impl<T: IntoExcelData, E: IntoExcelData> IntoExcelData for Result<T, E> {
     }
 }
 
+// -----------------------------------------------------------------------
+// SheetView
+// -----------------------------------------------------------------------
+
+/// The `SheetView` struct is a builder for the worksheet view properties.
+///
+/// A `SheetView` is created via the [`Worksheet::view()`] method. It borrows
+/// the worksheet and sets the properties that are written to the worksheet
+/// `<sheetView>` element. Each method consumes and returns the `SheetView` so
+/// that the properties can be chained together.
+///
+/// See [`Worksheet::view()`] for an example.
+///
+pub struct SheetView<'a> {
+    worksheet: &'a mut Worksheet,
+}
+
+impl SheetView<'_> {
+    /// Display the worksheet cells from right to left.
+    ///
+    /// See [`Worksheet::set_right_to_left()`] for more details.
+    ///
+    pub fn right_to_left(self) -> Self {
+        self.worksheet.set_right_to_left(true);
+        self
+    }
+
+    /// Set the worksheet zoom factor.
+    ///
+    /// See [`Worksheet::set_zoom()`] for more details.
+    ///
+    /// # Parameters
+    ///
+    /// - `zoom`: The worksheet zoom level in the range 10 <= zoom <= 400.
+    ///
+    pub fn zoom(self, zoom: u16) -> Self {
+        self.worksheet.set_zoom(zoom);
+        self
+    }
+
+    /// Hide the worksheet screen gridlines.
+    ///
+    /// See [`Worksheet::set_screen_gridlines()`] for more details.
+    ///
+    pub fn hide_gridlines(self) -> Self {
+        self.worksheet.set_screen_gridlines(false);
+        self
+    }
+
+    /// Hide the worksheet screen row and column headers.
+    ///
+    /// See [`Worksheet::set_screen_headings()`] for more details.
+    ///
+    pub fn hide_headings(self) -> Self {
+        self.worksheet.set_screen_headings(false);
+        self
+    }
+
+    /// Set the selected cell in the worksheet.
+    ///
+    /// See [`Worksheet::set_selection()`] for more details and for selecting a
+    /// range of cells.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    pub fn select(self, row: RowNum, col: ColNum) -> Result<Self, XlsxError> {
+        self.worksheet.set_selection(row, col, row, col)?;
+        Ok(self)
+    }
+}
+
+// -----------------------------------------------------------------------
+// ViewPreset
+// -----------------------------------------------------------------------
+
+/// The `ViewPreset` enum defines groups of worksheet view properties.
+///
+/// The presets are used with [`Worksheet::apply_view_preset()`] to set several
+/// worksheet view properties at once.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ViewPreset {
+    /// A view for entering data. The screen gridlines and row/column headers
+    /// are shown, the zoom is 100% and any frozen panes are removed. This is
+    /// the same as the default Excel view.
+    DataEntry,
+
+    /// A view for presentations. The screen gridlines and row/column headers
+    /// are hidden, the zoom is 130% and the top row is frozen so that it
+    /// remains visible as a header.
+    Presentation,
+}
+
+// -----------------------------------------------------------------------
+// WorksheetOptions
+// -----------------------------------------------------------------------
+
+/// The `WorksheetOptions` struct is used to configure several worksheet
+/// properties at once.
+///
+/// `WorksheetOptions` is used with [`Worksheet::configure()`] to set the tab
+/// color, visibility, right to left direction, zoom, screen gridlines and
+/// protection of a worksheet in a single call. Properties that aren't set in
+/// the options are left unchanged in the worksheet. This is useful when
+/// creating several worksheets with the same configuration.
+///
+/// See the [`Worksheet::configure()`] method for an example.
+///
+#[derive(Clone, Default)]
+pub struct WorksheetOptions {
+    tab_color: Option<Color>,
+    hidden: Option<bool>,
+    right_to_left: Option<bool>,
+    zoom: Option<u16>,
+    screen_gridlines: Option<bool>,
+    protection: Option<ProtectionOptions>,
+    password: Option<String>,
+}
+
+impl WorksheetOptions {
+    /// Create a new `WorksheetOptions` object to use with the
+    /// [`Worksheet::configure()`] method.
+    ///
+    pub fn new() -> WorksheetOptions {
+        WorksheetOptions::default()
+    }
+
+    /// Set the worksheet tab color. See [`Worksheet::set_tab_color()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The tab color property defined by a [`Color`] enum value.
+    ///
+    pub fn set_tab_color(mut self, color: impl Into<Color>) -> WorksheetOptions {
+        self.tab_color = Some(color.into());
+        self
+    }
+
+    /// Hide the worksheet. See [`Worksheet::set_hidden()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_hidden(mut self, enable: bool) -> WorksheetOptions {
+        self.hidden = Some(enable);
+        self
+    }
+
+    /// Display the worksheet cells from right to left. See
+    /// [`Worksheet::set_right_to_left()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_right_to_left(mut self, enable: bool) -> WorksheetOptions {
+        self.right_to_left = Some(enable);
+        self
+    }
+
+    /// Set the worksheet zoom factor. See [`Worksheet::set_zoom()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `zoom`: The worksheet zoom level. The range is 10 <= zoom <= 400.
+    ///
+    pub fn set_zoom(mut self, zoom: u16) -> WorksheetOptions {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    /// Turn on/off the screen gridlines. See
+    /// [`Worksheet::set_screen_gridlines()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_screen_gridlines(mut self, enable: bool) -> WorksheetOptions {
+        self.screen_gridlines = Some(enable);
+        self
+    }
+
+    /// Protect the worksheet with the given protection options. See
+    /// [`Worksheet::protect_with_options()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `options`: Worksheet protection options as defined by a
+    ///   [`ProtectionOptions`] struct.
+    ///
+    pub fn set_protection(mut self, options: &ProtectionOptions) -> WorksheetOptions {
+        self.protection = Some(options.clone());
+        self
+    }
+
+    /// Protect the worksheet with a password. See
+    /// [`Worksheet::protect_with_password()`].
+    ///
+    /// This can be combined with [`WorksheetOptions::set_protection()`] to
+    /// set both the protection options and the password.
+    ///
+    /// # Parameters
+    ///
+    /// - `password`: The password string. Note, only ascii text passwords are
+    ///   supported.
+    ///
+    pub fn set_protection_password(mut self, password: &str) -> WorksheetOptions {
+        self.password = Some(password.to_string());
+        self
+    }
+}
+
+// -----------------------------------------------------------------------
+// IgnoreError
+// -----------------------------------------------------------------------
+
+/// The `IgnoreError` enum defines the Excel cell errors and warnings that can
+/// be ignored.
+///
+/// The errors are ignored with [`Worksheet::ignore_error()`] or
+/// [`Worksheet::ignore_error_range()`]. The names are the same as the options
+/// shown in the Excel "Error Checking" dialog.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IgnoreError {
+    /// Ignore errors/warnings for formulas that evaluate to an error, such as
+    /// `#DIV/0!`.
+    EvalError,
+
+    /// Ignore errors/warnings for dates stored as text with a two digit year.
+    TwoDigitTextYear,
+
+    /// Ignore errors/warnings for numbers stored as text.
+    NumberStoredAsText,
+
+    /// Ignore errors/warnings for formulas that differ from adjacent formulas.
+    FormulaDiffers,
+
+    /// Ignore errors/warnings for formulas that omit adjacent cells in a range.
+    FormulaRange,
+
+    /// Ignore errors/warnings for unlocked cells that contain formulas.
+    FormulaUnlocked,
+
+    /// Ignore errors/warnings for formulas that refer to empty cells.
+    EmptyCellReference,
+
+    /// Ignore errors/warnings for cells in a table that don't match the data
+    /// validation for the table column.
+    ListDataValidation,
+
+    /// Ignore errors/warnings for table cells that differ from the table
+    /// column formula.
+    CalculatedColumn,
+}
+
+impl IgnoreError {
+    // Get the <ignoredError> attribute name for the error type.
+    fn attribute(self) -> &'static str {
+        match self {
+            IgnoreError::EvalError => "evalError",
+            IgnoreError::TwoDigitTextYear => "twoDigitTextYear",
+            IgnoreError::NumberStoredAsText => "numberStoredAsText",
+            IgnoreError::FormulaDiffers => "formula",
+            IgnoreError::FormulaRange => "formulaRange",
+            IgnoreError::FormulaUnlocked => "unlockedFormula",
+            IgnoreError::EmptyCellReference => "emptyCellReference",
+            IgnoreError::ListDataValidation => "listDataValidation",
+            IgnoreError::CalculatedColumn => "calculatedColumn",
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// MatrixFormats
+// -----------------------------------------------------------------------
+
+/// The `MatrixFormats` enum defines how formats are applied to a matrix of
+/// data.
+///
+/// It is used with [`Worksheet::write_row_matrix_with_formats()`] to specify
+/// either one format per column, which is applied to every row of the matrix,
+/// or one format per cell.
+///
+/// Cells that don't have a corresponding format, for example if there are
+/// fewer formats than columns, are written without a format.
+///
+#[derive(Clone, Copy)]
+pub enum MatrixFormats<'a> {
+    /// One format per column of the matrix. The formats are applied to each
+    /// row of data.
+    PerColumn(&'a [Format]),
+
+    /// One format per cell of the matrix, in the same row by column layout as
+    /// the data.
+    PerCell(&'a [Vec<Format>]),
+}
+
+impl MatrixFormats<'_> {
+    // Get the format, if any, for a cell relative to the start of the matrix.
+    pub(crate) fn get(&self, row_offset: usize, col_offset: usize) -> Option<&Format> {
+        match self {
+            MatrixFormats::PerColumn(formats) => formats.get(col_offset),
+            MatrixFormats::PerCell(formats) => formats
+                .get(row_offset)
+                .and_then(|formats| formats.get(col_offset)),
+        }
+    }
+}
+
 // -----------------------------------------------------------------------
 // Helper enums/structs/functions.
 // -----------------------------------------------------------------------
@@ -17115,14 +23447,18 @@ struct RowOptions {
     height: f64,
     xf_index: u32,
     hidden: bool,
+    thick_top: bool,
+    thick_bottom: bool,
 }
 
 #[derive(Clone, PartialEq)]
 struct ColOptions {
-    width: f64,
+    width: Option<f64>,
     xf_index: u32,
     hidden: bool,
     autofit: bool,
+    level: u8,
+    collapsed: bool,
 }
 
 #[derive(Clone)]
@@ -17131,6 +23467,7 @@ enum CellType {
         formula: Box<str>,
         xf_index: u32,
         result: Box<str>,
+        result_type: FormulaResultType,
         is_dynamic: bool,
         range: Box<str>,
     },
@@ -17149,6 +23486,7 @@ enum CellType {
         formula: Box<str>,
         xf_index: u32,
         result: Box<str>,
+        result_type: FormulaResultType,
     },
     Number {
         number: f64,
@@ -17182,6 +23520,7 @@ enum PageView {
 struct Panes {
     freeze_cell: (RowNum, ColNum),
     top_cell: (RowNum, ColNum),
+    is_split: bool,
 }
 
 impl Panes {
@@ -43,6 +43,254 @@ mod worksheet_tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn test_assemble_split_panes() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_split_panes(1, 1).unwrap();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0">
+                  <pane xSplit="1350" ySplit="600" topLeftCell="B2" activePane="bottomRight"/>
+                  <selection pane="topRight" activeCell="B1" sqref="B1"/>
+                  <selection pane="bottomLeft" activeCell="A2" sqref="A2"/>
+                  <selection pane="bottomRight" activeCell="B2" sqref="B2"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_split_panes_top_cell() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_row_height(0, 30).unwrap();
+        worksheet.set_split_panes(2, 0).unwrap();
+        worksheet.set_freeze_panes_top_cell(20, 0).unwrap();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0">
+                  <pane ySplit="1200" topLeftCell="A21" activePane="bottomLeft"/>
+                  <selection pane="bottomLeft" activeCell="A21" sqref="A21"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" ht="30" customHeight="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_row_thick_borders() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_row_thick_top(0).unwrap();
+        worksheet.set_row_thick_bottom(2).unwrap();
+        worksheet.set_row_thick_top(2).unwrap();
+        worksheet.set_row_height(3, 30).unwrap();
+        worksheet.set_row_thick_bottom(3).unwrap();
+
+        let result = worksheet.set_row_thick_top(ROW_MAX);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A4"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" thickTop="1"/>
+                <row r="3" thickTop="1" thickBot="1"/>
+                <row r="4" ht="30" customHeight="1" thickBot="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_default_column_width() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        let format = Format::new().set_bold();
+
+        worksheet.set_default_column_width(15);
+        worksheet.set_column_width(1, 30).unwrap();
+        worksheet.set_column_width(2, 8.43).unwrap();
+        worksheet.set_column_format(3, &format).unwrap();
+        worksheet.set_global_xf_indices(&[0, 1]);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="D1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr baseColWidth="15" defaultColWidth="15.7109375" defaultRowHeight="15"/>
+              <cols>
+                <col min="2" max="2" width="30.7109375" customWidth="1"/>
+                <col min="3" max="3" width="9.140625" customWidth="1"/>
+                <col min="4" max="4" width="15.7109375" style="1"/>
+              </cols>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // An explicit 8.43 width isn't replaced by the worksheet default.
+        let movement = ObjectMovement::MoveAndSizeWithCells;
+        assert_eq!(64, worksheet.column_pixel_width(2, movement));
+        assert_eq!(110, worksheet.column_pixel_width(3, movement));
+    }
+
+    #[test]
+    fn test_assemble_column_range_format() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        let currency_format = Format::new().set_num_format("$0.00");
+        let bold_format = Format::new().set_bold();
+
+        worksheet
+            .set_column_range_format(1, 3, &currency_format)
+            .unwrap();
+
+        // Unformatted cells take the column format, formatted cells override it.
+        worksheet.write_number(0, 1, 1.5).unwrap();
+        worksheet
+            .write_number_with_format(0, 2, 2.5, &bold_format)
+            .unwrap();
+        worksheet.set_global_xf_indices(&[0, 1, 2]);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="B1:D1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <cols>
+                <col min="2" max="4" width="9.140625" style="1"/>
+              </cols>
+              <sheetData>
+                <row r="1" spans="2:3">
+                  <c r="B1" s="1">
+                    <v>1.5</v>
+                  </c>
+                  <c r="C1" s="2">
+                    <v>2.5</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn row_spans() {
+        let mut worksheet = Worksheet::new();
+
+        // Sparse cells in the first block of 16 rows.
+        worksheet.write_number(0, 2, 1).unwrap();
+        worksheet.write_number(0, 10, 1).unwrap();
+        worksheet.write_number(5, 4, 1).unwrap();
+
+        // A single cell in the second block.
+        worksheet.write_number(20, 7, 1).unwrap();
+
+        // A note and blank cell in the fourth block. The third block is empty.
+        worksheet.insert_note(50, 1, &Note::new("Note")).unwrap();
+        worksheet.write_blank(60, 30, &Format::new()).unwrap();
+
+        let spans = worksheet.calculate_spans();
+
+        assert_eq!(3, spans.len());
+        assert_eq!(Some("3:11"), spans.get(&0).map(AsRef::as_ref));
+        assert_eq!(Some("8:8"), spans.get(&1).map(AsRef::as_ref));
+        assert_eq!(None, spans.get(&2));
+        assert_eq!(Some("2:31"), spans.get(&3).map(AsRef::as_ref));
+    }
+
     #[test]
     fn verify_header_footer_images() {
         let strings = [
@@ -83,6 +331,208 @@ mod worksheet_tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn write_ndarray() {
+        use ndarray::array;
+
+        let data = array![[1.0, 2.0, 3.0], [4.0, f64::NAN, 6.0]];
+
+        // The output should match the equivalent row matrix.
+        let mut worksheet1 = Worksheet::new();
+        worksheet1.write_ndarray(1, 1, &data).unwrap();
+        worksheet1.write_ndarray(4, 1, &data.t()).unwrap();
+
+        let mut worksheet2 = Worksheet::new();
+        worksheet2
+            .write_row_matrix(1, 1, [[1.0, 2.0, 3.0], [4.0, f64::NAN, 6.0]])
+            .unwrap();
+        worksheet2
+            .write_row_matrix(4, 1, [[1.0, 4.0], [2.0, f64::NAN], [3.0, 6.0]])
+            .unwrap();
+
+        worksheet1.assemble_xml_file();
+        worksheet2.assemble_xml_file();
+
+        let got = worksheet1.writer.read_to_str();
+        let expected = worksheet2.writer.read_to_str();
+        assert!(got.contains(r#"<dimension ref="B2:D7"/>"#));
+        assert_eq!(expected, got);
+
+        // Test the headers.
+        let mut worksheet = Worksheet::new();
+        let data = array![[1_u8, 2], [3, 4]];
+
+        let result = worksheet.write_ndarray_with_headers(0, 0, &["A"], &data);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.write_ndarray_with_headers(ROW_MAX - 2, 0, &["A", "B"], &data);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_ndarray(0, COL_MAX - 1, &data);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(worksheet.data_table.is_empty());
+
+        worksheet
+            .write_ndarray_with_headers(ROW_MAX - 3, COL_MAX - 2, &["A", "B"], &data)
+            .unwrap();
+        assert_eq!(3, worksheet.data_table.len());
+
+        // Overwritten cells shouldn't keep any previous value metadata.
+        let mut worksheet = Worksheet::new();
+        worksheet.write_with_metadata(0, 1, 1, 0).unwrap();
+        worksheet.write_ndarray(0, 0, &data).unwrap();
+        assert!(worksheet.value_metadata.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "polars")]
+    fn write_dataframe() {
+        use polars::prelude::*;
+
+        let mut worksheet = Worksheet::new();
+
+        let dates = Series::new("Date", &[Some(19_723), None])
+            .cast(&DataType::Date)
+            .unwrap();
+        let times = Series::new("Time", &[43_200_000_000_000_i64, 0])
+            .cast(&DataType::Time)
+            .unwrap();
+        let datetimes = Series::new("Datetime", &[1_704_110_400_000_i64, 0])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+
+        let mut df = df!(
+            "String" => &[Some("Foo"), None],
+            "Int" => &[Some(1_i64), None],
+            "Float" => &[1.5_f32, 2.5],
+            "Bool" => &[true, false],
+        )
+        .unwrap();
+        df.with_column(dates).unwrap();
+        df.with_column(times).unwrap();
+        df.with_column(datetimes).unwrap();
+
+        worksheet.write_dataframe(1, 1, &df).unwrap();
+
+        // Get a simple string representation of the worksheet cells.
+        let cell = |worksheet: &Worksheet, row: RowNum, col: ColNum| match worksheet
+            .data_table
+            .get(&row)
+            .and_then(|row| row.get(&col))
+        {
+            Some(CellType::String { string, .. }) => string.to_string(),
+            Some(CellType::Number { number, .. }) => number.to_string(),
+            Some(CellType::DateTime { number, .. }) => format!("date:{number}"),
+            Some(CellType::Boolean { boolean, .. }) => boolean.to_string(),
+            Some(_) => "other".to_string(),
+            None => String::new(),
+        };
+
+        let expected = [
+            ["String", "Int", "Float", "Bool", "Date", "Time", "Datetime"],
+            [
+                "Foo",
+                "1",
+                "1.5",
+                "true",
+                "date:45292",
+                "date:0.5",
+                "date:45292.5",
+            ],
+            ["", "", "2.5", "false", "", "date:0", "date:25569"],
+        ];
+        for (i, values) in expected.iter().enumerate() {
+            for (j, value) in values.iter().enumerate() {
+                assert_eq!(*value, cell(&worksheet, 1 + i as u32, 1 + j as u16));
+            }
+        }
+
+        // Test that nothing is written if the data exceeds the worksheet.
+        let mut worksheet = Worksheet::new();
+        let result = worksheet.write_dataframe(ROW_MAX - 2, 0, &df);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(worksheet.data_table.is_empty());
+
+        let result = worksheet.write_dataframe(ROW_MAX - 3, COL_MAX - 7, &df);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn write_json() {
+        let mut worksheet = Worksheet::new();
+
+        let data = serde_json::json!({
+            "name": "Sales",
+            "meta": {"year": 2024, "final": true, "notes": null},
+            "tags": ["a", "b"],
+            "sales": [
+                {"region": "East", "total": 3000},
+                {"region": "West", "total": 8000, "extra": {"id": 1, "list": [1, 2]}},
+            ],
+        });
+
+        worksheet.write_json(1, 1, &data).unwrap();
+
+        // Get a simple string representation of the worksheet cells.
+        let cell = |worksheet: &Worksheet, row: RowNum, col: ColNum| match worksheet
+            .data_table
+            .get(&row)
+            .and_then(|row| row.get(&col))
+        {
+            Some(CellType::String { string, .. }) => string.to_string(),
+            Some(CellType::Number { number, .. }) => number.to_string(),
+            Some(CellType::Boolean { boolean, .. }) => boolean.to_string(),
+            Some(_) => "other".to_string(),
+            None => String::new(),
+        };
+
+        // The object keys are sorted and nested objects use dotted keys.
+        let expected = [
+            ("meta.final", "true"),
+            ("meta.notes", ""),
+            ("meta.year", "2024"),
+            ("name", "Sales"),
+        ];
+        for (i, (key, value)) in expected.iter().enumerate() {
+            assert_eq!(*key, cell(&worksheet, 1 + i as u32, 1));
+            assert_eq!(*value, cell(&worksheet, 1 + i as u32, 2));
+        }
+
+        // Arrays of objects are written as tables with flattened headers.
+        assert_eq!("sales", cell(&worksheet, 5, 1));
+        let expected = [
+            ["region", "total", "extra.id", "extra.list"],
+            ["East", "3000", "", ""],
+            ["West", "8000", "1", "[1,2]"],
+        ];
+        for (i, values) in expected.iter().enumerate() {
+            for (j, value) in values.iter().enumerate() {
+                assert_eq!(*value, cell(&worksheet, 5 + i as u32, 2 + j as u16));
+            }
+        }
+
+        // Other arrays are written with one element per row.
+        assert_eq!("tags", cell(&worksheet, 8, 1));
+        assert_eq!("a", cell(&worksheet, 8, 2));
+        assert_eq!("b", cell(&worksheet, 9, 2));
+        assert_eq!(
+            (1, 9),
+            (
+                worksheet.dimensions.first_row,
+                worksheet.dimensions.last_row
+            )
+        );
+
+        // Test a scalar value and the row limit.
+        worksheet.write_json(0, 0, &serde_json::json!(1.5)).unwrap();
+        assert_eq!("1.5", cell(&worksheet, 0, 0));
+
+        let result = worksheet.write_json(ROW_MAX - 1, 0, &serde_json::json!([1, 2]));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn get_serialize_dimensions() {
@@ -133,572 +583,3082 @@ mod worksheet_tests {
     }
 
     #[test]
-    fn row_matches_list_filter_blanks() {
+    #[cfg(feature = "serde")]
+    fn serialize_reset() {
         let mut worksheet = Worksheet::new();
-        let bold = Format::new().set_bold();
 
-        worksheet.write_string(0, 0, "Header").unwrap();
-        worksheet.write_string(1, 0, "").unwrap();
-        worksheet.write_string(2, 0, " ").unwrap();
-        worksheet.write_string(3, 0, "  ").unwrap();
-        worksheet.write_string_with_format(4, 0, "", &bold).unwrap();
+        #[derive(Serialize)]
+        struct MyStruct {
+            column1: u8,
+        }
 
-        let filter_condition = FilterCondition::new().add_list_blanks_filter();
+        worksheet
+            .serialize_headers(2, 2, &MyStruct { column1: 0 })
+            .unwrap();
 
-        assert!(!worksheet.row_matches_list_filter(0, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(1, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(2, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(3, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(4, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(5, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(7, 7, &filter_condition));
+        assert_eq!(Some(0), worksheet.serialized_row_count("MyStruct"));
+
+        for column1 in 1..=3 {
+            worksheet.serialize(&MyStruct { column1 }).unwrap();
+        }
+
+        assert_eq!(Some(3), worksheet.serialized_row_count("MyStruct"));
+
+        // Test that the data is overwritten after a reset.
+        worksheet.serialize_reset("MyStruct");
+        worksheet.serialize(&MyStruct { column1: 9 }).unwrap();
+
+        assert_eq!(Some(1), worksheet.serialized_row_count("MyStruct"));
+        assert_eq!(None, worksheet.serialized_row_count("Doesn't exist"));
+
+        let result = worksheet.get_serialize_dimensions("MyStruct").unwrap();
+        assert_eq!((2, 2, 3, 2), result);
+
+        for (row, value) in [(3, 9.0), (4, 2.0), (5, 3.0)] {
+            assert!(matches!(
+                worksheet.data_table[&row].get(&2),
+                Some(CellType::Number { number, .. }) if *number == value
+            ));
+        }
+
+        // Test that an unknown struct name is ignored.
+        worksheet.serialize_reset("Doesn't exist");
     }
 
     #[test]
-    fn row_matches_list_filter_strings() {
+    #[cfg(feature = "serde")]
+    fn serialize_cell_format_range_len() {
         let mut worksheet = Worksheet::new();
-        worksheet.write_string(0, 0, "Header").unwrap();
-        worksheet.write_string(1, 0, "South").unwrap();
-        worksheet.write_string(2, 0, "south").unwrap();
-        worksheet.write_string(3, 0, "SOUTH").unwrap();
-        worksheet.write_string(4, 0, "South ").unwrap();
-        worksheet.write_string(5, 0, " South").unwrap();
-        worksheet.write_string(6, 0, " South ").unwrap();
-        worksheet.write_string(7, 0, "Mouth").unwrap();
+        let format = Format::new().set_num_format("$0.00");
 
-        let filter_condition = FilterCondition::new().add_list_filter("South");
+        #[derive(Serialize)]
+        struct MyStruct {
+            column1: u8,
+            column2: f64,
+        }
 
-        assert!(worksheet.row_matches_list_filter(1, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(2, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(3, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(4, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(5, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(6, 0, &filter_condition));
-        assert!(!worksheet.row_matches_list_filter(7, 0, &filter_condition));
-    }
+        let data = MyStruct {
+            column1: 1,
+            column2: 2.5,
+        };
 
-    #[test]
-    fn row_matches_list_filter_numbers() {
-        let mut worksheet = Worksheet::new();
+        let custom_headers = [
+            CustomSerializeField::new("column1").set_cell_format_range_len(3),
+            CustomSerializeField::new("column2")
+                .set_value_format(&format)
+                .set_cell_format_range_len(3),
+        ];
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
 
-        worksheet.write_string(0, 0, "Header").unwrap();
-        worksheet.write_number(1, 0, 1000).unwrap();
-        worksheet.write_number(2, 0, 1000.0).unwrap();
-        worksheet.write_string(3, 0, "1000").unwrap();
-        worksheet.write_string(4, 0, " 1000 ").unwrap();
-        worksheet.write_number(5, 0, 2000).unwrap();
+        worksheet
+            .serialize_headers_with_options(1, 1, &data, &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        let filter_condition = FilterCondition::new().add_list_filter(1000);
+        // The serialized data is the header plus one row but the formatted
+        // cells extend three rows below the header.
+        let result = worksheet.get_serialize_dimensions("MyStruct").unwrap();
+        assert_eq!((1, 1, 2, 2), result);
+        assert_eq!(4, worksheet.dimensions.last_row);
 
-        assert!(worksheet.row_matches_list_filter(1, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(2, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(3, 0, &filter_condition));
-        assert!(worksheet.row_matches_list_filter(4, 0, &filter_condition));
-        assert!(!worksheet.row_matches_list_filter(5, 0, &filter_condition));
+        // The column without a value format isn't pre-formatted.
+        assert!(!worksheet.data_table[&4].contains_key(&1));
+        assert!(matches!(
+            worksheet.data_table[&4].get(&2),
+            Some(CellType::Blank { xf_index: 1 })
+        ));
+        assert!(matches!(
+            worksheet.data_table[&2].get(&2),
+            Some(CellType::Number { xf_index: 1, .. })
+        ));
     }
 
     #[test]
-    fn process_pagebreaks() {
+    #[cfg(feature = "serde")]
+    fn serialize_value_transform() {
         let mut worksheet = Worksheet::new();
+        let format = Format::new().set_num_format("$0.00");
 
-        // Test removing duplicates.
-        let got = Worksheet::process_pagebreaks(&[1, 1, 1, 1]).unwrap();
-        assert_eq!(vec![1], got);
+        #[derive(Serialize)]
+        struct MyStruct {
+            name: &'static str,
+            value: i32,
+            flag: bool,
+            other: Option<u8>,
+        }
 
-        // Test removing 0.
-        let got = Worksheet::process_pagebreaks(&[0, 1, 2, 3, 4]).unwrap();
-        assert_eq!(vec![1, 2, 3, 4], got);
+        let data = MyStruct {
+            name: "Secret",
+            value: -5,
+            flag: true,
+            other: None,
+        };
 
-        // Test sort order.
-        let got = Worksheet::process_pagebreaks(&[1, 12, 2, 13, 3, 4]).unwrap();
-        assert_eq!(vec![1, 2, 3, 4, 12, 13], got);
+        fn redact(value: SerializedValue) -> SerializedValue {
+            match value {
+                SerializedValue::String(_) => SerializedValue::String("***".to_string()),
+                _ => value,
+            }
+        }
 
-        // Exceed the number of allow breaks.
-        let breaks = (1u32..=1024).collect::<Vec<u32>>();
-        let result = Worksheet::process_pagebreaks(&breaks);
-        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        fn clamp(value: SerializedValue) -> SerializedValue {
+            match value {
+                SerializedValue::Number(number) => SerializedValue::Number(number.max(0.0)),
+                _ => value,
+            }
+        }
 
-        // Test row and column limits.
-        let result = worksheet.set_page_breaks(&[ROW_MAX]);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        fn invert(value: SerializedValue) -> SerializedValue {
+            match value {
+                SerializedValue::Boolean(boolean) => SerializedValue::Boolean(!boolean),
+                _ => value,
+            }
+        }
 
-        let result = worksheet.set_vertical_page_breaks(&[COL_MAX as u32]);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
-    }
+        fn default(value: SerializedValue) -> SerializedValue {
+            match value {
+                SerializedValue::String(string) if string.is_empty() => {
+                    SerializedValue::Number(1.0)
+                }
+                _ => value,
+            }
+        }
 
-    #[test]
-    fn set_header_image() {
-        let mut worksheet = Worksheet::new();
+        let custom_headers = [
+            CustomSerializeField::new("name").set_value_transform(redact),
+            CustomSerializeField::new("value")
+                .set_value_format(&format)
+                .set_value_transform(clamp),
+            CustomSerializeField::new("flag").set_value_transform(invert),
+            CustomSerializeField::new("other").set_value_transform(default),
+        ];
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
 
-        let image = Image::new("tests/input/images/red.jpg").unwrap();
-        worksheet.set_header("&R&G");
+        worksheet
+            .serialize_headers_with_options(0, 0, &data, &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        // Test inserting an image without a matching header position.
-        let result = worksheet.set_header_image(&image, HeaderImagePosition::Left);
-        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
-    }
+        assert!(matches!(
+            worksheet.data_table[&1].get(&0),
+            Some(CellType::String { string, .. }) if string.as_ref() == "***"
+        ));
 
-    #[test]
-    fn rich_string() {
-        let mut worksheet = Worksheet::new();
+        // The transformed number keeps its value format.
+        assert!(matches!(
+            worksheet.data_table[&1].get(&1),
+            Some(CellType::Number { number, xf_index: 1 }) if *number == 0.0
+        ));
 
-        // Test an empty array.
-        let segments = [];
-        let result = worksheet.write_rich_string(0, 0, &segments);
-        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        assert!(matches!(
+            worksheet.data_table[&1].get(&2),
+            Some(CellType::Boolean { boolean: false, .. })
+        ));
 
-        // Test an empty string.
-        let default = Format::default();
-        let segments = [(&default, "")];
-        let result = worksheet.write_rich_string(0, 0, &segments);
-        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        assert!(matches!(
+            worksheet.data_table[&1].get(&3),
+            Some(CellType::Number { number, .. }) if *number == 1.0
+        ));
     }
 
     #[test]
-    fn test_calculate_spans_1() {
+    #[cfg(feature = "serde")]
+    fn serialize_nested_headers() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (0..17).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct Quarter {
+            revenue: f64,
+            cost: f64,
         }
 
-        let expected = HashMap::from([(0, "1:16".to_string()), (1, "17:17".to_string())]);
-        let got = worksheet.calculate_spans();
-
-        assert_eq!(expected, got);
-    }
+        #[derive(Serialize)]
+        struct Sale {
+            region: &'static str,
+            q1: Quarter,
+            q2: Option<Quarter>,
+            notes: &'static str,
+        }
 
-    #[test]
-    fn test_calculate_spans_2() {
-        let mut worksheet = Worksheet::new();
+        let data = [
+            Sale {
+                region: "North",
+                q1: Quarter {
+                    revenue: 1.0,
+                    cost: 2.0,
+                },
+                q2: Some(Quarter {
+                    revenue: 3.0,
+                    cost: 4.0,
+                }),
+                notes: "A",
+            },
+            Sale {
+                region: "South",
+                q1: Quarter {
+                    revenue: 5.0,
+                    cost: 6.0,
+                },
+                q2: None,
+                notes: "B",
+            },
+        ];
 
-        for (col_num, row_num) in (1..18).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
-        }
+        // Sub-fields with the same name in different groups are distinct.
+        let custom_headers = [
+            CustomSerializeField::new("q2").rename("Q2"),
+            CustomSerializeField::new("q1.cost").rename("Q1 Cost"),
+            CustomSerializeField::new("q2.cost").skip(true),
+        ];
+        let header_options = SerializeFieldOptions::new()
+            .set_custom_headers(&custom_headers)
+            .use_nested_headers(true);
 
-        let expected = HashMap::from([(0, "1:15".to_string()), (1, "16:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        worksheet
+            .serialize_headers_with_options(1, 1, &data[0], &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        assert_eq!(expected, got);
-    }
+        let cell_string = |row: RowNum, col: ColNum| match worksheet.data_table[&row].get(&col) {
+            Some(CellType::String { string, .. }) => string.to_string(),
+            _ => String::new(),
+        };
+        let cell_number = |row: RowNum, col: ColNum| match worksheet.data_table[&row].get(&col) {
+            Some(CellType::Number { number, .. }) => *number,
+            _ => -1.0,
+        };
 
-    #[test]
-    fn test_calculate_spans_3() {
-        let mut worksheet = Worksheet::new();
+        // Check the two level headers.
+        let headers: Vec<String> = (1..=5).map(|col| cell_string(1, col)).collect();
+        assert_eq!(vec!["region", "q1", "", "Q2", "notes"], headers);
+
+        let headers: Vec<String> = (2..=4).map(|col| cell_string(2, col)).collect();
+        assert_eq!(vec!["revenue", "Q1 Cost", "revenue"], headers);
+
+        let merged_ranges: Vec<(RowNum, ColNum, RowNum, ColNum)> = worksheet
+            .merged_ranges
+            .iter()
+            .map(|range| {
+                (
+                    range.first_row,
+                    range.first_col,
+                    range.last_row,
+                    range.last_col,
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![(1, 1, 2, 1), (1, 5, 2, 5), (1, 2, 1, 3)],
+            merged_ranges
+        );
 
-        for (col_num, row_num) in (2..19).enumerate() {
+        // Check the data.
+        assert_eq!("North", cell_string(3, 1));
+        assert_eq!(1.0, cell_number(3, 2));
+        assert_eq!(2.0, cell_number(3, 3));
+        assert_eq!(3.0, cell_number(3, 4));
+        assert_eq!("A", cell_string(3, 5));
+
+        assert_eq!("South", cell_string(4, 1));
+        assert_eq!(5.0, cell_number(4, 2));
+        assert_eq!(6.0, cell_number(4, 3));
+        assert!(!worksheet.data_table[&4].contains_key(&4));
+        assert_eq!("B", cell_string(4, 5));
+
+        assert_eq!(
+            (1, 3, 4, 3),
             worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
-        }
-
-        let expected = HashMap::from([(0, "1:14".to_string()), (1, "15:17".to_string())]);
-        let got = worksheet.calculate_spans();
+                .get_serialize_column_dimensions("Sale", "q1.cost")
+                .unwrap()
+        );
 
-        assert_eq!(expected, got);
+        // Tables aren't supported with nested headers.
+        let header_options = header_options.set_table_default();
+        let result = worksheet.serialize_headers_with_options(10, 0, &data[0], &header_options);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
     }
 
     #[test]
-    fn test_calculate_spans_4() {
+    #[cfg(feature = "serde")]
+    fn serialize_nested_headers_depth() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (3..20).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct Level4 {
+            value: u8,
         }
 
-        let expected = HashMap::from([(0, "1:13".to_string()), (1, "14:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        #[derive(Serialize)]
+        struct Level3 {
+            level4: Level4,
+        }
 
-        assert_eq!(expected, got);
-    }
+        #[derive(Serialize)]
+        struct Level2 {
+            level3: Level3,
+        }
 
-    #[test]
-    fn test_calculate_spans_5() {
-        let mut worksheet = Worksheet::new();
+        #[derive(Serialize)]
+        struct Level1 {
+            level2: Level2,
+        }
 
-        for (col_num, row_num) in (4..21).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct Top {
+            level1: Level1,
         }
 
-        let expected = HashMap::from([(0, "1:12".to_string()), (1, "13:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let data = Top {
+            level1: Level1 {
+                level2: Level2 {
+                    level3: Level3 {
+                        level4: Level4 { value: 1 },
+                    },
+                },
+            },
+        };
 
-        assert_eq!(expected, got);
+        let header_options = SerializeFieldOptions::new().use_nested_headers(true);
+
+        // The nested structs are too deep.
+        let result = worksheet.serialize_headers_with_options(0, 0, &data, &header_options);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // The maximum depth is allowed.
+        worksheet
+            .serialize_headers_with_options(0, 0, &data.level1, &header_options)
+            .unwrap();
+        worksheet.serialize(&data.level1).unwrap();
+
+        assert!(matches!(
+            worksheet.data_table[&3].get(&0),
+            Some(CellType::String { string, .. }) if string.as_ref() == "value"
+        ));
+        assert!(matches!(
+            worksheet.data_table[&4].get(&0),
+            Some(CellType::Number { number, .. }) if *number == 1.0
+        ));
     }
 
     #[test]
-    fn test_calculate_spans_6() {
+    #[cfg(feature = "serde")]
+    fn serialize_table_column_names() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (5..22).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct MyStruct {
+            column1: u8,
+            column2: u8,
         }
 
-        let expected = HashMap::from([(0, "1:11".to_string()), (1, "12:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let data = MyStruct {
+            column1: 1,
+            column2: 2,
+        };
 
-        assert_eq!(expected, got);
-    }
+        // A user defined column name should be replaced by the serialized,
+        // and renamed, header.
+        let columns = [
+            crate::TableColumn::new().set_header("Other"),
+            crate::TableColumn::new().set_total_function(TableFunction::Sum),
+        ];
+        let table = Table::new().set_total_row(true).set_columns(&columns);
 
-    #[test]
-    fn test_calculate_spans_7() {
-        let mut worksheet = Worksheet::new();
+        let custom_headers = [CustomSerializeField::new("column2").rename("Total")];
+        let header_options = SerializeFieldOptions::new()
+            .set_custom_headers(&custom_headers)
+            .set_table(table);
 
-        for (col_num, row_num) in (6..23).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
-        }
+        worksheet
+            .serialize_headers_with_options(0, 0, &data, &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
+        worksheet.store_serialized_tables().unwrap();
 
-        let expected = HashMap::from([(0, "1:10".to_string()), (1, "11:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let table = &worksheet.tables[0];
+        assert_eq!("column1", table.columns[0].name);
+        assert_eq!("Total", table.columns[1].name);
+        assert!(table.columns[1].total_function == TableFunction::Sum);
 
-        assert_eq!(expected, got);
+        assert!(matches!(
+            worksheet.data_table[&0].get(&0),
+            Some(CellType::String { string, .. }) if string.as_ref() == "column1"
+        ));
     }
 
     #[test]
-    fn test_calculate_spans_8() {
+    #[cfg(feature = "serde")]
+    fn serialize_keyed() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (7..24).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct MyStruct {
+            column1: u8,
+            column2: u8,
         }
 
-        let expected = HashMap::from([(0, "1:9".to_string()), (1, "10:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let data = BTreeMap::from([
+            (
+                10,
+                MyStruct {
+                    column1: 1,
+                    column2: 2,
+                },
+            ),
+            (
+                20,
+                MyStruct {
+                    column1: 3,
+                    column2: 4,
+                },
+            ),
+        ]);
 
-        assert_eq!(expected, got);
-    }
+        // Test serializing without a key field.
+        worksheet.serialize_headers(0, 0, &data[&10]).unwrap();
+        let result = worksheet.serialize_keyed(&data);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        assert!(!worksheet.data_table.contains_key(&1));
 
-    #[test]
-    fn test_calculate_spans_9() {
-        let mut worksheet = Worksheet::new();
+        // Test a key field that clashes with a struct field.
+        let key_field = CustomSerializeField::new("column1");
+        let header_options = SerializeFieldOptions::new().set_key_field(&key_field);
+        let result = worksheet.serialize_headers_with_options(0, 0, &data[&10], &header_options);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
 
-        for (col_num, row_num) in (8..25).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
-        }
+        // Test that the key is written to the first column.
+        let key_field = CustomSerializeField::new("id").rename("Id");
+        let header_options = SerializeFieldOptions::new().set_key_field(&key_field);
+        worksheet
+            .serialize_headers_with_options(0, 0, &data[&10], &header_options)
+            .unwrap();
+        worksheet.serialize_keyed(&data).unwrap();
 
-        let expected = HashMap::from([(0, "1:8".to_string()), (1, "9:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let result = worksheet.get_serialize_dimensions("MyStruct").unwrap();
+        assert_eq!((0, 0, 2, 2), result);
 
-        assert_eq!(expected, got);
+        assert!(matches!(
+            worksheet.data_table[&0].get(&0),
+            Some(CellType::String { string, .. }) if string.as_ref() == "Id"
+        ));
+
+        for (row, key, value) in [(1, 10.0, 1.0), (2, 20.0, 3.0)] {
+            assert!(matches!(
+                worksheet.data_table[&row].get(&0),
+                Some(CellType::Number { number, .. }) if *number == key
+            ));
+            assert!(matches!(
+                worksheet.data_table[&row].get(&1),
+                Some(CellType::Number { number, .. }) if *number == value
+            ));
+        }
     }
 
     #[test]
-    fn test_calculate_spans_10() {
+    #[cfg(feature = "serde")]
+    fn serialize_map() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (9..26).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
-        }
+        let data = BTreeMap::from([("alpha", 1.5), ("beta", 2.5), ("gamma", 3.5)]);
 
-        let expected = HashMap::from([(0, "1:7".to_string()), (1, "8:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        // Test the map headers with a renamed field.
+        let header_options = SerializeFieldOptions::new()
+            .set_custom_headers(&[CustomSerializeField::new("value").rename("Total")]);
+        worksheet
+            .serialize_headers_with_options(1, 2, &data, &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        assert_eq!(expected, got);
-    }
+        let result = worksheet.get_serialize_dimensions("map").unwrap();
+        assert_eq!((1, 2, 4, 3), result);
 
-    #[test]
-    fn test_calculate_spans_11() {
-        let mut worksheet = Worksheet::new();
+        for (col, header) in [(2, "key"), (3, "Total")] {
+            assert!(matches!(
+                worksheet.data_table[&1].get(&col),
+                Some(CellType::String { string, .. }) if string.as_ref() == header
+            ));
+        }
 
-        for (col_num, row_num) in (10..27).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        for (row, key, value) in [(2, "alpha", 1.5), (3, "beta", 2.5), (4, "gamma", 3.5)] {
+            assert!(matches!(
+                worksheet.data_table[&row].get(&2),
+                Some(CellType::String { string, .. }) if string.as_ref() == key
+            ));
+            assert!(matches!(
+                worksheet.data_table[&row].get(&3),
+                Some(CellType::Number { number, .. }) if *number == value
+            ));
         }
 
-        let expected = HashMap::from([(0, "1:6".to_string()), (1, "7:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        // Test that the map headers can also be deserialized.
+        worksheet
+            .deserialize_headers::<BTreeMap<String, f64>>(6, 0)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        assert_eq!(expected, got);
+        let result = worksheet.get_serialize_dimensions("map").unwrap();
+        assert_eq!((6, 0, 9, 1), result);
+
+        // Test a map with non-scalar values.
+        let data = BTreeMap::from([("alpha", vec![1, 2])]);
+        let result = worksheet.serialize(&data);
+        assert!(matches!(result, Err(XlsxError::SerdeError(_))));
+
+        // Test that a failed map serialization doesn't affect the next one.
+        let data = BTreeMap::from([("delta", 4.5)]);
+        worksheet.serialize(&data).unwrap();
+
+        let result = worksheet.get_serialize_dimensions("map").unwrap();
+        assert_eq!((6, 0, 11, 1), result);
     }
 
     #[test]
-    fn test_calculate_spans_12() {
+    #[cfg(feature = "serde")]
+    fn serialize_transpose() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (11..28).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct MyStruct {
+            column1: u8,
+            column2: u8,
+            column3: u8,
         }
 
-        let expected = HashMap::from([(0, "1:5".to_string()), (1, "6:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let data = [
+            MyStruct {
+                column1: 1,
+                column2: 2,
+                column3: 3,
+            },
+            MyStruct {
+                column1: 4,
+                column2: 5,
+                column3: 6,
+            },
+        ];
 
-        assert_eq!(expected, got);
-    }
+        // Test that tables aren't supported with the transposed layout.
+        let header_options = SerializeFieldOptions::new()
+            .set_transpose(true)
+            .set_table_default();
+        let result = worksheet.serialize_headers_with_options(1, 1, &data[0], &header_options);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
 
-    #[test]
-    fn test_calculate_spans_13() {
-        let mut worksheet = Worksheet::new();
+        // Test the headers in a column and the instances in the next columns.
+        let header_options = SerializeFieldOptions::new()
+            .set_transpose(true)
+            .set_custom_headers(&[CustomSerializeField::new("column2").skip(true)]);
+        worksheet
+            .serialize_headers_with_options(1, 1, &data[0], &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        for (col_num, row_num) in (12..29).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        let result = worksheet.get_serialize_dimensions("MyStruct").unwrap();
+        assert_eq!((1, 1, 2, 3), result);
+
+        assert_eq!(Some(2), worksheet.serialized_row_count("MyStruct"));
+
+        let result = worksheet
+            .get_serialize_column_dimensions("MyStruct", "column3")
+            .unwrap();
+        assert_eq!((2, 1, 2, 3), result);
+
+        for (row, header) in [(1, "column1"), (2, "column3")] {
+            assert!(matches!(
+                worksheet.data_table[&row].get(&1),
+                Some(CellType::String { string, .. }) if string.as_ref() == header
+            ));
         }
 
-        let expected = HashMap::from([(0, "1:4".to_string()), (1, "5:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        for (row, col, value) in [(1, 2, 1.0), (2, 2, 3.0), (1, 3, 4.0), (2, 3, 6.0)] {
+            assert!(matches!(
+                worksheet.data_table[&row].get(&col),
+                Some(CellType::Number { number, .. }) if *number == value
+            ));
+        }
 
-        assert_eq!(expected, got);
+        assert!(!worksheet.data_table.contains_key(&3));
     }
 
     #[test]
-    fn test_calculate_spans_14() {
+    #[cfg(feature = "serde")]
+    fn serialize_datetime_format() {
         let mut worksheet = Worksheet::new();
+        let custom_format = Format::new().set_num_format_index(14);
 
-        for (col_num, row_num) in (13..30).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct MyStruct {
+            date: ExcelDateTime,
+            time: ExcelDateTime,
+            datetime: ExcelDateTime,
+            custom: ExcelDateTime,
+            missing: Option<ExcelDateTime>,
         }
 
-        let expected = HashMap::from([(0, "1:3".to_string()), (1, "4:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let data = MyStruct {
+            date: ExcelDateTime::from_ymd(2024, 1, 1).unwrap(),
+            time: ExcelDateTime::from_hms(12, 0, 0).unwrap(),
+            datetime: ExcelDateTime::parse_from_str("2024-01-01 12:00:00").unwrap(),
+            custom: ExcelDateTime::from_ymd(2024, 1, 1).unwrap(),
+            missing: None,
+        };
 
-        assert_eq!(expected, got);
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&[
+            CustomSerializeField::new("custom").set_value_format(&custom_format),
+        ]);
+        worksheet
+            .serialize_headers_with_options(0, 0, &data, &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        // Check the default, and custom, number formats of the dates.
+        let date_format = Format::new().set_num_format("yyyy-mm-dd");
+        let time_format = Format::new().set_num_format("hh:mm:ss");
+        let datetime_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+
+        for (col, format, value) in [
+            (0, date_format, 45292.0),
+            (1, time_format, 0.5),
+            (2, datetime_format, 45292.5),
+            (3, custom_format, 45292.0),
+        ] {
+            let expected_xf_index = worksheet.format_xf_index(&format);
+            assert!(matches!(
+                worksheet.data_table[&1].get(&col),
+                Some(CellType::DateTime { number, xf_index })
+                    if *number == value && *xf_index == expected_xf_index
+            ));
+        }
+
+        assert!(worksheet.data_table[&1].get(&4).is_none());
     }
 
     #[test]
-    fn test_calculate_spans_15() {
+    #[cfg(feature = "serde")]
+    fn serialize_skip_none() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (14..31).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct MyStruct {
+            column1: Option<u8>,
+            column2: Option<u8>,
         }
 
-        let expected = HashMap::from([(0, "1:2".to_string()), (1, "3:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        let data = [
+            MyStruct {
+                column1: None,
+                column2: None,
+            },
+            MyStruct {
+                column1: Some(1),
+                column2: Some(2),
+            },
+        ];
 
-        assert_eq!(expected, got);
-    }
+        let format = Format::new().set_bold();
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&[
+            CustomSerializeField::new("column1").set_value_format(&format),
+            CustomSerializeField::new("column2")
+                .set_value_format(&format)
+                .skip_none(true),
+        ]);
+        worksheet
+            .serialize_headers_with_options(0, 0, &data[0], &header_options)
+            .unwrap();
 
-    #[test]
-    fn test_calculate_spans_16() {
-        let mut worksheet = Worksheet::new();
+        // Write some existing data to overlay.
+        worksheet.write(1, 0, "existing").unwrap();
+        worksheet.write(1, 1, "existing").unwrap();
 
-        for (col_num, row_num) in (15..32).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
-        }
+        worksheet.serialize(&data).unwrap();
 
-        let expected = HashMap::from([(0, "1:1".to_string()), (1, "2:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        // The None value without skip_none() overwrites the existing data.
+        assert!(matches!(
+            worksheet.data_table[&1].get(&0),
+            Some(CellType::Blank { .. })
+        ));
 
-        assert_eq!(expected, got);
+        // The None value with skip_none() keeps the existing data.
+        assert!(matches!(
+            worksheet.data_table[&1].get(&1),
+            Some(CellType::String { string, .. }) if string.as_ref() == "existing"
+        ));
+
+        for (col, value) in [(0, 1.0), (1, 2.0)] {
+            assert!(matches!(
+                worksheet.data_table[&2].get(&col),
+                Some(CellType::Number { number, .. }) if *number == value
+            ));
+        }
     }
 
     #[test]
-    fn test_calculate_spans_17() {
+    #[cfg(feature = "serde")]
+    fn serialize_flatten() {
         let mut worksheet = Worksheet::new();
 
-        for (col_num, row_num) in (16..33).enumerate() {
-            worksheet
-                .write_number(row_num, col_num as u16, 1.0)
-                .unwrap();
+        #[derive(Serialize)]
+        struct Address {
+            city: &'static str,
+            zip: u32,
         }
 
-        let expected = HashMap::from([(1, "1:16".to_string()), (2, "17:17".to_string())]);
-        let got = worksheet.calculate_spans();
+        #[derive(Serialize)]
+        struct Customer {
+            name: &'static str,
+            #[serde(flatten)]
+            address: Address,
+        }
 
-        assert_eq!(expected, got);
-    }
+        let data = [
+            Customer {
+                name: "Aoife",
+                address: Address {
+                    city: "Dublin",
+                    zip: 1,
+                },
+            },
+            Customer {
+                name: "Caoimhe",
+                address: Address {
+                    city: "Galway",
+                    zip: 2,
+                },
+            },
+        ];
 
-    #[test]
-    fn test_calculate_spans_18() {
-        let mut worksheet = Worksheet::new();
+        let header_options = SerializeFieldOptions::new()
+            .use_map_keys_as_headers(true)
+            .set_custom_headers(&[CustomSerializeField::new("city").rename("City")]);
+        worksheet
+            .serialize_headers_with_options(0, 0, &data[0], &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        for (col_num, row_num) in (16..33).enumerate() {
-            worksheet
-                .write_number(row_num, (col_num + 1) as u16, 1.0)
-                .unwrap();
+        let result = worksheet.get_serialize_dimensions("map").unwrap();
+        assert_eq!((0, 0, 2, 2), result);
+
+        for (col, header) in [(0, "name"), (1, "City"), (2, "zip")] {
+            assert!(matches!(
+                worksheet.data_table[&0].get(&col),
+                Some(CellType::String { string, .. }) if string.as_ref() == header
+            ));
         }
 
-        let expected = HashMap::from([(1, "2:17".to_string()), (2, "18:18".to_string())]);
-        let got = worksheet.calculate_spans();
+        for (row, name, city, zip) in [(1, "Aoife", "Dublin", 1.0), (2, "Caoimhe", "Galway", 2.0)] {
+            assert!(matches!(
+                worksheet.data_table[&row].get(&0),
+                Some(CellType::String { string, .. }) if string.as_ref() == name
+            ));
+            assert!(matches!(
+                worksheet.data_table[&row].get(&1),
+                Some(CellType::String { string, .. }) if string.as_ref() == city
+            ));
+            assert!(matches!(
+                worksheet.data_table[&row].get(&2),
+                Some(CellType::Number { number, .. }) if *number == zip
+            ));
+        }
 
-        assert_eq!(expected, got);
+        // Maps can be serialized to separately named areas of a worksheet.
+        let mut worksheet = Worksheet::new();
+        let prices = BTreeMap::from([("apple", 1.5), ("pear", 2.0)]);
+        let stock = BTreeMap::from([("apple", 10), ("pear", 20), ("plum", 30)]);
+
+        let header_options = SerializeFieldOptions::new().set_struct_name("prices");
+        worksheet
+            .serialize_headers_with_options(0, 0, &prices, &header_options)
+            .unwrap();
+        worksheet.serialize(&prices).unwrap();
+
+        let header_options = SerializeFieldOptions::new()
+            .use_map_keys_as_headers(true)
+            .set_struct_name("stock");
+        worksheet
+            .serialize_headers_with_options(0, 3, &stock, &header_options)
+            .unwrap();
+        worksheet.serialize(&stock).unwrap();
+
+        let result = worksheet.get_serialize_dimensions("prices").unwrap();
+        assert_eq!((0, 0, 2, 1), result);
+
+        let result = worksheet.get_serialize_dimensions("stock").unwrap();
+        assert_eq!((0, 3, 1, 5), result);
+
+        assert!(worksheet.get_serialize_dimensions("map").is_err());
     }
 
     #[test]
-    fn check_invalid_worksheet_names() {
+    #[cfg(feature = "serde")]
+    fn serialize_value_transform_closure() {
         let mut worksheet = Worksheet::new();
 
-        let result = worksheet.set_name("");
-        assert!(matches!(result, Err(XlsxError::SheetnameCannotBeBlank(_))));
+        #[derive(Serialize)]
+        struct MyStruct {
+            code: u8,
+            price: f64,
+        }
 
-        let name = "name_that_is_longer_than_thirty_one_characters".to_string();
-        let result = worksheet.set_name(&name);
-        assert!(matches!(result, Err(XlsxError::SheetnameLengthExceeded(_))));
+        let data = [
+            MyStruct {
+                code: 1,
+                price: 10.0,
+            },
+            MyStruct {
+                code: 7,
+                price: 2.5,
+            },
+        ];
 
-        let name = "name_with_special_character_[".to_string();
-        let result = worksheet.set_name(&name);
-        assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
-        ));
+        let labels = BTreeMap::from([(1, "One"), (2, "Two")]);
+        let scale = 4.0;
+
+        let custom_headers = [
+            CustomSerializeField::new("code").set_value_transform(move |value| match value {
+                SerializedValue::Number(number) => match labels.get(&(number as u8)) {
+                    Some(label) => SerializedValue::String(label.to_string()),
+                    None => value,
+                },
+                _ => value,
+            }),
+            CustomSerializeField::new("price").set_value_transform(move |value| match value {
+                SerializedValue::Number(number) => SerializedValue::Number(number * scale),
+                _ => value,
+            }),
+        ];
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
 
-        let name = "name_with_special_character_]".to_string();
-        let result = worksheet.set_name(&name);
-        assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
-        ));
+        worksheet
+            .serialize_headers_with_options(0, 0, &data[0], &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
 
-        let name = "name_with_special_character_:".to_string();
-        let result = worksheet.set_name(&name);
         assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+            worksheet.data_table[&1].get(&0),
+            Some(CellType::String { string, .. }) if string.as_ref() == "One"
         ));
 
-        let name = "name_with_special_character_*".to_string();
-        let result = worksheet.set_name(&name);
+        // Values without a label are written unchanged.
         assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+            worksheet.data_table[&2].get(&0),
+            Some(CellType::Number { number, .. }) if *number == 7.0
         ));
 
-        let name = "name_with_special_character_?".to_string();
-        let result = worksheet.set_name(&name);
         assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+            worksheet.data_table[&1].get(&1),
+            Some(CellType::Number { number, .. }) if *number == 40.0
         ));
 
-        let name = "name_with_special_character_/".to_string();
-        let result = worksheet.set_name(&name);
         assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+            worksheet.data_table[&2].get(&1),
+            Some(CellType::Number { number, .. }) if *number == 10.0
         ));
+    }
 
-        let name = "name_with_special_character_\\".to_string();
-        let result = worksheet.set_name(&name);
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_headers_checked() {
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            name: &'static str,
+            #[serde(rename = "Value")]
+            value: u8,
+        }
+
+        let data = MyStruct {
+            name: "Alice",
+            value: 1,
+        };
+
+        // All of the unknown field names are reported.
+        let custom_headers = [
+            CustomSerializeField::new("nmae"),
+            CustomSerializeField::new("Value"),
+            CustomSerializeField::new("value"),
+        ];
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+        let result = worksheet.serialize_headers_checked(0, 0, &data, &header_options);
         assert!(matches!(
             result,
-            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+            Err(XlsxError::SerdeError(message)) if message ==
+                "Custom field name 'nmae', 'value' not found in struct 'MyStruct'. \
+                 Valid field names are: 'name', 'Value'"
         ));
 
-        let name = "'start with apostrophe".to_string();
-        let result = worksheet.set_name(&name);
+        // Valid field names are written as headers.
+        let custom_headers = [CustomSerializeField::new("Value").rename("Amount")];
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+        worksheet
+            .serialize_headers_checked(0, 0, &data, &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
+
         assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameStartsOrEndsWithApostrophe(_))
+            worksheet.data_table[&0].get(&1),
+            Some(CellType::String { string, .. }) if string.as_ref() == "Amount"
         ));
-
-        let name = "end with apostrophe'".to_string();
-        let result = worksheet.set_name(&name);
         assert!(matches!(
-            result,
-            Err(XlsxError::SheetnameStartsOrEndsWithApostrophe(_))
+            worksheet.data_table[&1].get(&1),
+            Some(CellType::Number { number, .. }) if *number == 1.0
         ));
     }
 
     #[test]
-    fn get_name() {
-        let mut worksheet = Worksheet::new();
+    #[cfg(feature = "serde")]
+    fn autofit_serialized_columns() {
+        #[derive(Serialize)]
+        struct MyStruct {
+            name: &'static str,
+            value: f64,
+        }
 
-        let got = worksheet.name();
-        assert_eq!("", got);
+        let data = [
+            MyStruct {
+                name: "Short",
+                value: 1.0,
+            },
+            MyStruct {
+                name: "A much longer name",
+                value: 123_456.5,
+            },
+        ];
 
-        let exp = "Sheet1";
-        worksheet.set_name(exp).unwrap();
-        let got = worksheet.name();
-        assert_eq!(exp, got);
-    }
+        // The widths match a full autofit of the serialized data.
+        let mut expected = Worksheet::new();
+        expected.serialize_headers(1, 1, &data[0]).unwrap();
+        expected.serialize(&data).unwrap();
+        expected.autofit();
 
-    #[test]
-    fn merge_range() {
         let mut worksheet = Worksheet::new();
-        let format = Format::default();
-
-        // Test single merge cell.
-        let result = worksheet.merge_range(1, 1, 1, 1, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::MergeRangeSingleCell)));
-
-        // Test for overlap.
-        let _worksheet = worksheet.merge_range(1, 1, 20, 20, "Foo", &format);
-        let result = worksheet.merge_range(2, 2, 3, 3, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+        worksheet.serialize_headers(1, 1, &data[0]).unwrap();
+        worksheet.serialize(&data).unwrap();
+        worksheet.write(0, 4, "Data that isn't serialized").unwrap();
+        worksheet.autofit_serialized_columns();
 
-        // Test out of range value.
-        let result = worksheet.merge_range(ROW_MAX, 1, 1, 1, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        for col in [1, 2] {
+            assert_eq!(
+                expected.changed_cols[&col].width,
+                worksheet.changed_cols[&col].width
+            );
+        }
 
-        // Test out reversed values
-        let result = worksheet.merge_range(5, 1, 1, 1, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+        // Columns that weren't serialized aren't changed.
+        assert!(!worksheet.changed_cols.contains_key(&4));
     }
 
     #[test]
-    fn check_dimensions() {
+    fn row_matches_list_filter_blanks() {
         let mut worksheet = Worksheet::new();
-        let format = Format::default();
-
-        assert!(!worksheet.check_dimensions(ROW_MAX, 0));
-        assert!(!worksheet.check_dimensions(0, COL_MAX));
+        let bold = Format::new().set_bold();
 
-        let result = worksheet.write_string_with_format(ROW_MAX, 0, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        worksheet.write_string(0, 0, "Header").unwrap();
+        worksheet.write_string(1, 0, "").unwrap();
+        worksheet.write_string(2, 0, " ").unwrap();
+        worksheet.write_string(3, 0, "  ").unwrap();
+        worksheet.write_string_with_format(4, 0, "", &bold).unwrap();
 
-        let result = worksheet.write_string(ROW_MAX, 0, "Foo");
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        let filter_condition = FilterCondition::new().add_list_blanks_filter();
 
-        let result = worksheet.write_number_with_format(ROW_MAX, 0, 0, &format);
+        assert!(!worksheet.row_matches_list_filter(0, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(1, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(2, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(3, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(4, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(5, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(7, 7, &filter_condition));
+    }
+
+    #[test]
+    fn row_matches_list_filter_strings() {
+        let mut worksheet = Worksheet::new();
+        worksheet.write_string(0, 0, "Header").unwrap();
+        worksheet.write_string(1, 0, "South").unwrap();
+        worksheet.write_string(2, 0, "south").unwrap();
+        worksheet.write_string(3, 0, "SOUTH").unwrap();
+        worksheet.write_string(4, 0, "South ").unwrap();
+        worksheet.write_string(5, 0, " South").unwrap();
+        worksheet.write_string(6, 0, " South ").unwrap();
+        worksheet.write_string(7, 0, "Mouth").unwrap();
+
+        let filter_condition = FilterCondition::new().add_list_filter("South");
+
+        assert!(worksheet.row_matches_list_filter(1, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(2, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(3, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(4, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(5, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(6, 0, &filter_condition));
+        assert!(!worksheet.row_matches_list_filter(7, 0, &filter_condition));
+    }
+
+    #[test]
+    fn row_matches_list_filter_numbers() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_string(0, 0, "Header").unwrap();
+        worksheet.write_number(1, 0, 1000).unwrap();
+        worksheet.write_number(2, 0, 1000.0).unwrap();
+        worksheet.write_string(3, 0, "1000").unwrap();
+        worksheet.write_string(4, 0, " 1000 ").unwrap();
+        worksheet.write_number(5, 0, 2000).unwrap();
+
+        let filter_condition = FilterCondition::new().add_list_filter(1000);
+
+        assert!(worksheet.row_matches_list_filter(1, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(2, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(3, 0, &filter_condition));
+        assert!(worksheet.row_matches_list_filter(4, 0, &filter_condition));
+        assert!(!worksheet.row_matches_list_filter(5, 0, &filter_condition));
+    }
+
+    #[test]
+    fn process_pagebreaks() {
+        let mut worksheet = Worksheet::new();
+
+        // Test removing duplicates.
+        let got = Worksheet::process_pagebreaks(&[1, 1, 1, 1]).unwrap();
+        assert_eq!(vec![1], got);
+
+        // Test removing 0.
+        let got = Worksheet::process_pagebreaks(&[0, 1, 2, 3, 4]).unwrap();
+        assert_eq!(vec![1, 2, 3, 4], got);
+
+        // Test sort order.
+        let got = Worksheet::process_pagebreaks(&[1, 12, 2, 13, 3, 4]).unwrap();
+        assert_eq!(vec![1, 2, 3, 4, 12, 13], got);
+
+        // Exceed the number of allow breaks.
+        let breaks = (1u32..=1024).collect::<Vec<u32>>();
+        let result = Worksheet::process_pagebreaks(&breaks);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Test row and column limits.
+        let result = worksheet.set_page_breaks(&[ROW_MAX]);
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
 
-        let result = worksheet.write_number(ROW_MAX, 0, 0);
+        let result = worksheet.set_vertical_page_breaks(&[COL_MAX as u32]);
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
 
-        let result = worksheet.set_row_height(ROW_MAX, 20);
+    #[test]
+    fn set_header_image() {
+        let mut worksheet = Worksheet::new();
+
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+        worksheet.set_header("&R&G");
+
+        // Test inserting an image without a matching header position.
+        let result = worksheet.set_header_image(&image, HeaderImagePosition::Left);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn write_array() {
+        let mut worksheet = Worksheet::new();
+
+        // Arrays that would extend past the worksheet limits aren't partially
+        // written.
+        let result = worksheet.write_array_row(0, COL_MAX - 2, [1, 2, 3]);
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(worksheet.data_table.is_empty());
 
-        let result = worksheet.set_row_height_pixels(ROW_MAX, 20);
+        let result = worksheet.write_array_column(ROW_MAX - 2, 0, [1, 2, 3]);
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(worksheet.data_table.is_empty());
 
-        let result = worksheet.set_row_format(ROW_MAX, &format);
+        // Arrays that end at the worksheet limits are allowed.
+        worksheet
+            .write_array_row(0, COL_MAX - 3, [1, 2, 3])
+            .unwrap();
+        worksheet
+            .write_array_column(ROW_MAX - 3, 0, [1, 2, 3])
+            .unwrap();
+        assert!(worksheet.data_table[&0].contains_key(&(COL_MAX - 1)));
+        assert!(worksheet.data_table[&(ROW_MAX - 1)].contains_key(&0));
+
+        // Empty arrays are ignored.
+        let empty: [f64; 0] = [];
+        worksheet.write_array_row(ROW_MAX, 0, empty).unwrap();
+    }
+
+    #[test]
+    fn write_duration() {
+        let mut worksheet = Worksheet::new();
+
+        let result = worksheet.write_duration(0, 0, -1);
+        assert!(matches!(result, Err(XlsxError::DateTimeRangeError(_))));
+
+        let result = worksheet.write_duration(0, 0, f64::NAN);
+        assert!(matches!(result, Err(XlsxError::DateTimeRangeError(_))));
+
+        let result = worksheet.write_duration(ROW_MAX, 0, 60);
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
 
-        let result = worksheet.set_column_width(COL_MAX, 20);
+        // Durations greater than 24 hours aren't wrapped.
+        worksheet.write_duration(0, 0, 135_000).unwrap();
+        assert!(matches!(
+            worksheet.data_table[&0].get(&0),
+            Some(CellType::DateTime { number, xf_index: 1 }) if *number == 1.5625
+        ));
+
+        // The default elapsed time format is reused.
+        worksheet.write_duration(1, 0, 60).unwrap();
+        assert!(matches!(
+            worksheet.data_table[&1].get(&0),
+            Some(CellType::DateTime { xf_index: 1, .. })
+        ));
+        assert_eq!("[h]:mm", worksheet.xf_formats[1].num_format);
+    }
+
+    #[test]
+    fn insert_image_fit_to_range() {
+        let mut worksheet = Worksheet::new();
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+
+        // Test range out of bounds.
+        let result = worksheet.insert_image_fit_to_range(0, 0, ROW_MAX, 2, &image, false);
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
 
-        let result = worksheet.set_column_width_pixels(COL_MAX, 20);
+        // Test reversed range.
+        let result = worksheet.insert_image_fit_to_range(5, 0, 1, 2, &image, false);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+        assert!(worksheet.images.is_empty());
+
+        // Test that the image is inserted at the top left cell of the range.
+        worksheet
+            .insert_image_fit_to_range(1, 1, 3, 2, &image, false)
+            .unwrap();
+        assert!(worksheet.images.contains_key(&(1, 1)));
+        assert!(worksheet.images[&(1, 1)].is_scaled());
+    }
+
+    #[test]
+    fn insert_image_with_url() {
+        let mut worksheet = Worksheet::new();
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+
+        let result = worksheet.insert_image_with_url(ROW_MAX, 0, &image, "https://example.com");
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
 
-        let result = worksheet.set_column_format(COL_MAX, &format);
+        let result = worksheet.insert_image_with_url(0, 0, &image, "foo://example.com");
+        assert!(matches!(result, Err(XlsxError::UnknownUrlType(_))));
+        assert!(worksheet.images.is_empty());
+
+        // Images with the same url share a hyperlink relationship.
+        worksheet
+            .insert_image_with_url(0, 0, &image, "https://example.com")
+            .unwrap();
+        worksheet
+            .insert_image_with_url(4, 0, &image, "https://example.com")
+            .unwrap();
+
+        let mut image_ids = HashMap::new();
+        let mut image_id = 0;
+        worksheet.prepare_worksheet_images(&mut image_ids, &mut image_id, 1);
+
+        let hyperlinks: Vec<_> = worksheet
+            .drawing_relationships
+            .iter()
+            .filter(|rel| rel.0 == "hyperlink")
+            .collect();
+        assert_eq!(1, hyperlinks.len());
+        assert_eq!("https://example.com", hyperlinks[0].1);
+        assert_eq!(2, worksheet.drawing.drawings.len());
+    }
+
+    #[test]
+    fn write_row_matrix_with_formats() {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+        let italic = Format::new().set_italic();
+
+        let xf_index = |worksheet: &Worksheet, row: RowNum, col: ColNum| match worksheet
+            .data_table
+            .get(&row)
+            .and_then(|columns| columns.get(&col))
+        {
+            Some(CellType::Number { xf_index, .. }) => *xf_index,
+            _ => panic!("expected a number cell"),
+        };
+
+        // Formats per column, with fewer formats than columns.
+        let data = [[1, 2, 3], [4, 5, 6]];
+        let formats = [bold.clone(), italic.clone()];
+        worksheet
+            .write_row_matrix_with_formats(0, 0, data, MatrixFormats::PerColumn(&formats))
+            .unwrap();
+
+        for row in 0..2 {
+            assert_eq!(
+                bold,
+                worksheet.xf_formats[xf_index(&worksheet, row, 0) as usize]
+            );
+            assert_eq!(
+                italic,
+                worksheet.xf_formats[xf_index(&worksheet, row, 1) as usize]
+            );
+            assert_eq!(0, xf_index(&worksheet, row, 2));
+        }
+
+        // Formats per cell, with a ragged format matrix.
+        let formats = vec![vec![italic.clone()], vec![Format::new(), bold.clone()]];
+        worksheet
+            .write_row_matrix_with_formats(4, 1, data, MatrixFormats::PerCell(&formats))
+            .unwrap();
+
+        assert_eq!(
+            italic,
+            worksheet.xf_formats[xf_index(&worksheet, 4, 1) as usize]
+        );
+        assert_eq!(0, xf_index(&worksheet, 4, 2));
+        assert_eq!(0, xf_index(&worksheet, 5, 1));
+        assert_eq!(
+            bold,
+            worksheet.xf_formats[xf_index(&worksheet, 5, 2) as usize]
+        );
+        assert_eq!(0, xf_index(&worksheet, 5, 3));
+
+        let result =
+            worksheet.write_row_matrix_with_formats(ROW_MAX, 0, data, MatrixFormats::PerCell(&[]));
         assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
     }
 
     #[test]
-    fn long_string() {
+    fn spill_ref() {
         let mut worksheet = Worksheet::new();
-        let chars: [u8; 32_768] = [64; 32_768];
-        let long_string = std::str::from_utf8(&chars);
 
-        let result = worksheet.write_string(0, 0, long_string.unwrap());
+        worksheet.set_name("Data").unwrap();
+        assert_eq!("Data!$A$1#", worksheet.spill_ref(0, 0));
+
+        worksheet.set_name("My Data").unwrap();
+        assert_eq!("'My Data'!$C$5#", worksheet.spill_ref(4, 2));
+
+        // Write a dynamic formula and a dependent formula over its spill range.
+        worksheet
+            .write_dynamic_formula(0, 0, "=SEQUENCE(10)")
+            .unwrap();
+
+        let formula = format!("=SUM({})", worksheet.spill_ref(0, 0));
+        worksheet.write_formula(0, 2, formula.as_str()).unwrap();
+
+        match worksheet.data_table.get(&0).and_then(|row| row.get(&2)) {
+            Some(CellType::ArrayFormula {
+                formula,
+                is_dynamic,
+                range,
+                ..
+            }) => {
+                assert_eq!("SUM(_xlfn.ANCHORARRAY('My Data'!$A$1))", formula.as_ref());
+                assert_eq!("C1", range.as_ref());
+                assert!(*is_dynamic);
+            }
+            _ => panic!("expected a dynamic formula cell"),
+        }
+        assert!(worksheet.has_dynamic_arrays);
+    }
+
+    #[test]
+    fn write_cell() {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+
+        // Errors shouldn't leave any data in the worksheet.
+        let cell = CellBuilder::new(1).set_note(&Note::new("Note"));
+        let result = worksheet.write_cell(ROW_MAX, 0, &cell);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let long_note = Note::new("x".repeat(MAX_STRING_LEN));
+        let cell = CellBuilder::new(1).set_note(&long_note);
+        let result = worksheet.write_cell(0, 0, &cell);
         assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
+        assert!(worksheet.data_table.is_empty());
+        assert!(worksheet.notes.is_empty());
+
+        let result = CellBuilder::new("Link").set_url("foo://example.com");
+        assert!(matches!(result, Err(XlsxError::UnknownUrlType(_))));
+
+        // Write a cell with all of the properties.
+        let cell = CellBuilder::new(1.5)
+            .set_format(&bold)
+            .set_note(&Note::new("Note"))
+            .set_url("https://example.com")
+            .unwrap();
+        worksheet.write_cell(1, 2, &cell).unwrap();
+
+        match worksheet.data_table.get(&1).and_then(|row| row.get(&2)) {
+            Some(CellType::Number { number, xf_index }) => {
+                assert_eq!(1.5, *number);
+                assert_eq!(bold, worksheet.xf_formats[*xf_index as usize]);
+            }
+            _ => panic!("expected a number cell"),
+        }
+
+        assert_eq!(
+            "https://example.com",
+            worksheet.hyperlinks.get(&(1, 2)).unwrap().url_link
+        );
+        assert!(worksheet.notes.get(&1).unwrap().contains_key(&2));
+
+        // A hyperlink without a format uses the default hyperlink format.
+        let cell = CellBuilder::new("Link")
+            .set_url("https://example.com")
+            .unwrap();
+        worksheet.write_cell(2, 2, &cell).unwrap();
+
+        match worksheet.data_table.get(&2).and_then(|row| row.get(&2)) {
+            Some(CellType::String { xf_index, .. }) => {
+                assert_eq!(
+                    Format::new().set_hyperlink(),
+                    worksheet.xf_formats[*xf_index as usize]
+                );
+            }
+            _ => panic!("expected a string cell"),
+        }
+    }
+
+    #[test]
+    fn copy_range_to() {
+        let mut worksheet = Worksheet::new();
+        let mut destination = Worksheet::new();
+        let bold = Format::new().set_bold();
+        let italic = Format::new().set_italic();
+
+        worksheet.merge_range(0, 0, 0, 1, "Title", &bold).unwrap();
+        worksheet.write_with_format(1, 0, 1, &italic).unwrap();
+        worksheet.write_formula(1, 1, "=A2*$A$2").unwrap();
+        worksheet.write_formula(2, 1, "=A1").unwrap();
+
+        // Test range errors.
+        let result = worksheet.copy_range_to(&mut destination, 0, 0, ROW_MAX, 1, 0, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.copy_range_to(&mut destination, 2, 0, 1, 1, 0, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result = worksheet.copy_range_to(&mut destination, 0, 0, 2, 1, ROW_MAX - 2, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        // Test partially overlapping a merged range.
+        let result = worksheet.copy_range_to(&mut destination, 0, 1, 1, 1, 0, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        assert!(destination.data_table.is_empty());
+
+        // Copy the range. The first formula is shifted and the second one would
+        // move off the worksheet so it is replaced by its result.
+        worksheet
+            .copy_range_to(&mut destination, 0, 0, 2, 1, 3, 2)
+            .unwrap();
+
+        assert_eq!(1, destination.merged_ranges.len());
+        assert_eq!("C4:D4", destination.merged_ranges[0].to_range_string());
+        assert!(matches!(
+            destination.data_table[&3].get(&2),
+            Some(CellType::String { string, xf_index: 1, .. }) if &**string == "Title"
+        ));
+        assert!(matches!(
+            destination.data_table[&4].get(&2),
+            Some(CellType::Number { number, xf_index: 2 }) if *number == 1.0
+        ));
+        assert!(matches!(
+            destination.data_table[&4].get(&3),
+            Some(CellType::Formula { formula, .. }) if &**formula == "C5*$A$2"
+        ));
+        assert!(matches!(
+            destination.data_table[&5].get(&3),
+            Some(CellType::Formula { formula, .. }) if &**formula == "C4"
+        ));
+        assert!(destination.xf_formats[1] == bold);
+        assert!(destination.xf_formats[2] == italic);
+
+        // Test overlapping a merged range in the destination.
+        let result = worksheet.copy_range_to(&mut destination, 0, 0, 0, 1, 3, 3);
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+
+        // Test a formula that can't be shifted.
+        worksheet
+            .copy_range_to(&mut destination, 2, 1, 2, 1, 0, 0)
+            .unwrap();
+        assert!(matches!(
+            destination.data_table[&0].get(&0),
+            Some(CellType::Blank { xf_index: 0 })
+        ));
+
+        // Formulas that can't be shifted keep the type of their cached result.
+        let mut worksheet = Worksheet::new();
+        let mut destination = Worksheet::new();
+        for col in 0..5 {
+            worksheet.write_formula(1, col, "=A1").unwrap();
+        }
+        worksheet.set_formula_typed_result(1, 0, FormulaResult::String("123".to_string()));
+        worksheet.set_formula_typed_result(1, 1, 1.5);
+        worksheet.set_formula_typed_result(1, 2, true);
+        worksheet.set_formula_typed_result(1, 3, FormulaResult::Error("#N/A".to_string()));
+        worksheet.set_formula_result(1, 4, "abc");
+
+        worksheet
+            .copy_range_to(&mut destination, 1, 0, 1, 4, 0, 0)
+            .unwrap();
+
+        let cells = &destination.data_table[&0];
+        assert!(matches!(
+            cells.get(&0),
+            Some(CellType::String { string, .. }) if &**string == "123"
+        ));
+        assert!(matches!(
+            cells.get(&1),
+            Some(CellType::Number { number, .. }) if *number == 1.5
+        ));
+        assert!(matches!(
+            cells.get(&2),
+            Some(CellType::Boolean { boolean: true, .. })
+        ));
+        assert!(matches!(
+            cells.get(&3),
+            Some(CellType::Formula { formula, result_type: FormulaResultType::Error, .. })
+                if &**formula == "#N/A"
+        ));
+        assert!(matches!(
+            cells.get(&4),
+            Some(CellType::String { string, .. }) if &**string == "abc"
+        ));
+    }
+
+    #[test]
+    fn rich_string() {
+        let mut worksheet = Worksheet::new();
+
+        // Test an empty array.
+        let segments = [];
+        let result = worksheet.write_rich_string(0, 0, &segments);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Test an empty string.
+        let default = Format::default();
+        let segments = [(&default, "")];
+        let result = worksheet.write_rich_string(0, 0, &segments);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn rich_string_without_formats() {
+        let bold = Format::new().set_bold();
+
+        // Segments without a format don't have font properties.
+        let segments = [(None, "This is "), (Some(&bold), "bold"), (None, " text")];
+        let (string, raw_string) = Worksheet::get_rich_string(&segments).unwrap();
+
+        assert_eq!(raw_string, "This is bold text");
+        assert_eq!(
+            string,
+            concat!(
+                r#"<r><t xml:space="preserve">This is </t></r>"#,
+                r#"<r><rPr><b/><sz val="11"/><color theme="1"/><rFont val="Calibri"/><family val="2"/><scheme val="minor"/></rPr><t>bold</t></r>"#,
+                r#"<r><t xml:space="preserve"> text</t></r>"#,
+            )
+        );
+
+        // Test the public methods.
+        let mut worksheet = Worksheet::new();
+        worksheet
+            .write_rich_string_segments(0, 0, &segments)
+            .unwrap();
+        worksheet
+            .write_rich_string_segments_with_format(1, 0, &segments, &bold)
+            .unwrap();
+        assert!(matches!(
+            worksheet.data_table[&1].get(&0),
+            Some(CellType::RichString { raw_string, xf_index: 1, .. })
+                if &**raw_string == "This is bold text"
+        ));
+
+        let result = worksheet.write_rich_string_segments(2, 0, &[]);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_calculate_spans_1() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (0..17).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:16".to_string()), (1, "17:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_2() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (1..18).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:15".to_string()), (1, "16:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_3() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (2..19).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:14".to_string()), (1, "15:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_4() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (3..20).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:13".to_string()), (1, "14:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_5() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (4..21).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:12".to_string()), (1, "13:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_6() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (5..22).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:11".to_string()), (1, "12:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_7() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (6..23).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:10".to_string()), (1, "11:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_8() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (7..24).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:9".to_string()), (1, "10:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_9() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (8..25).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:8".to_string()), (1, "9:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_10() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (9..26).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:7".to_string()), (1, "8:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_11() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (10..27).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:6".to_string()), (1, "7:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_12() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (11..28).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:5".to_string()), (1, "6:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_13() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (12..29).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:4".to_string()), (1, "5:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_14() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (13..30).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:3".to_string()), (1, "4:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_15() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (14..31).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:2".to_string()), (1, "3:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_16() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (15..32).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(0, "1:1".to_string()), (1, "2:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_17() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (16..33).enumerate() {
+            worksheet
+                .write_number(row_num, col_num as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(1, "1:16".to_string()), (2, "17:17".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_calculate_spans_18() {
+        let mut worksheet = Worksheet::new();
+
+        for (col_num, row_num) in (16..33).enumerate() {
+            worksheet
+                .write_number(row_num, (col_num + 1) as u16, 1.0)
+                .unwrap();
+        }
+
+        let expected = HashMap::from([(1, "2:17".to_string()), (2, "18:18".to_string())]);
+        let got = worksheet.calculate_spans();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn check_invalid_worksheet_names() {
+        let mut worksheet = Worksheet::new();
+
+        let result = worksheet.set_name("");
+        assert!(matches!(result, Err(XlsxError::SheetnameCannotBeBlank(_))));
+
+        let name = "name_that_is_longer_than_thirty_one_characters".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(result, Err(XlsxError::SheetnameLengthExceeded(_))));
+
+        let name = "name_with_special_character_[".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+
+        let name = "name_with_special_character_]".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+
+        let name = "name_with_special_character_:".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+
+        let name = "name_with_special_character_*".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+
+        let name = "name_with_special_character_?".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+
+        let name = "name_with_special_character_/".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+
+        let name = "name_with_special_character_\\".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+
+        let name = "'start with apostrophe".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameStartsOrEndsWithApostrophe(_))
+        ));
+
+        let name = "end with apostrophe'".to_string();
+        let result = worksheet.set_name(&name);
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameStartsOrEndsWithApostrophe(_))
+        ));
+    }
+
+    #[test]
+    fn get_name() {
+        let mut worksheet = Worksheet::new();
+
+        let got = worksheet.name();
+        assert_eq!("", got);
+
+        let exp = "Sheet1";
+        worksheet.set_name(exp).unwrap();
+        let got = worksheet.name();
+        assert_eq!(exp, got);
+    }
+
+    #[test]
+    fn merge_ranges() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::default();
+
+        // Test for overlap within the batch. Nothing should be written.
+        let result =
+            worksheet.merge_ranges(&[(1, 1, 2, 2), (5, 5, 6, 6), (2, 2, 3, 3)], "", &format);
+        match result {
+            Err(XlsxError::MergeRangeOverlaps(new, previous)) => {
+                assert_eq!("(2, 2, 3, 3) / C3:D4", new);
+                assert_eq!("(1, 1, 2, 2) / B2:C3", previous);
+            }
+            _ => panic!("Expected MergeRangeOverlaps error"),
+        }
+        assert!(worksheet.merged_ranges.is_empty());
+        assert!(worksheet.merged_cells.is_empty());
+        assert!(worksheet.data_table.is_empty());
+
+        // Test a valid batch.
+        worksheet
+            .merge_ranges(&[(1, 1, 2, 2), (1, 4, 2, 5)], "Foo", &format)
+            .unwrap();
+        assert_eq!(2, worksheet.merged_ranges.len());
+        assert_eq!(8, worksheet.merged_cells.len());
+
+        // Test for overlap with a previous range.
+        let result = worksheet.merge_ranges(&[(8, 8, 9, 9), (2, 5, 3, 6)], "", &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+        assert_eq!(2, worksheet.merged_ranges.len());
+
+        // Test single merge cell.
+        let result = worksheet.merge_ranges(&[(10, 1, 11, 1), (12, 1, 12, 1)], "", &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeSingleCell)));
+    }
+
+    #[test]
+    fn merge_range() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::default();
+
+        // Test single merge cell.
+        let result = worksheet.merge_range(1, 1, 1, 1, "Foo", &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeSingleCell)));
+
+        // Test for overlap.
+        let _worksheet = worksheet.merge_range(1, 1, 20, 20, "Foo", &format);
+        let result = worksheet.merge_range(2, 2, 3, 3, "Foo", &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+
+        // Test out of range value.
+        let result = worksheet.merge_range(ROW_MAX, 1, 1, 1, "Foo", &format);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        // Test out reversed values
+        let result = worksheet.merge_range(5, 1, 1, 1, "Foo", &format);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+    }
+
+    #[test]
+    fn merge_range_rich() {
+        let mut worksheet1 = Worksheet::new();
+        let mut worksheet2 = Worksheet::new();
+        let default = Format::default();
+        let bold = Format::new().set_bold();
+        let format = Format::new().set_bold().set_italic();
+        let segments = [(&bold, "Title"), (&default, " subtitle")];
+
+        // Test the equivalence with overwriting the first cell of a merge.
+        worksheet1
+            .merge_range_rich(1, 1, 2, 3, &segments, &format)
+            .unwrap();
+
+        worksheet2.merge_range(1, 1, 2, 3, "", &format).unwrap();
+        worksheet2
+            .write_rich_string_with_format(1, 1, &segments, &format)
+            .unwrap();
+
+        worksheet1.set_global_xf_indices(&[0, 1]);
+        worksheet2.set_global_xf_indices(&[0, 1]);
+        worksheet1.assemble_xml_file();
+        worksheet2.assemble_xml_file();
+
+        let got = worksheet1.writer.read_to_str();
+        let expected = worksheet2.writer.read_to_str();
+        assert!(got.contains(r#"<mergeCell ref="B2:D3"/>"#));
+        assert_eq!(expected, got);
+
+        // Test that nothing is written for an invalid rich string or range.
+        let mut worksheet = Worksheet::new();
+
+        let result = worksheet.merge_range_rich(1, 1, 2, 2, &[(&bold, "")], &format);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.merge_range_rich(1, 1, 1, 1, &segments, &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeSingleCell)));
+
+        assert!(worksheet.merged_ranges.is_empty());
+        assert!(worksheet.data_table.is_empty());
+    }
+
+    #[test]
+    fn extend_autofilter_range() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new().set_bold();
+
+        worksheet.autofilter(0, 1, 2, 2).unwrap();
+        for row in 0..=5 {
+            worksheet.write_number(row, 2, row).unwrap();
+        }
+
+        // Rows outside the autofilter columns and blank cells don't extend
+        // the range.
+        worksheet.write_number(6, 3, 6).unwrap();
+        worksheet.write_blank(6, 1, &format).unwrap();
+        worksheet.write_number(7, 1, 7).unwrap();
+
+        worksheet.extend_autofilter_range();
+
+        assert_eq!(5, worksheet.autofilter_defined_name.last_row);
+        assert_eq!("B1:C6", worksheet.autofilter_area);
+
+        // The range isn't reduced if there is less data.
+        let mut worksheet = Worksheet::new();
+        worksheet.autofilter(0, 0, 10, 0).unwrap();
+        worksheet.write_number(1, 0, 1).unwrap();
+
+        worksheet.extend_autofilter_range();
+
+        assert_eq!("A1:A11", worksheet.autofilter_area);
+
+        // The range doesn't extend into a table below it.
+        let mut worksheet = Worksheet::new();
+        worksheet.autofilter(0, 0, 1, 1).unwrap();
+        worksheet.write_number(1, 0, 1).unwrap();
+        worksheet.add_table(2, 0, 4, 1, &Table::new()).unwrap();
+        worksheet.write_number(3, 0, 1).unwrap();
+
+        worksheet.extend_autofilter_range();
+
+        assert_eq!("A1:B2", worksheet.autofilter_area);
+    }
+
+    #[test]
+    fn check_dimensions() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::default();
+
+        assert!(!worksheet.check_dimensions(ROW_MAX, 0));
+        assert!(!worksheet.check_dimensions(0, COL_MAX));
+
+        let result = worksheet.write_string_with_format(ROW_MAX, 0, "Foo", &format);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_string(ROW_MAX, 0, "Foo");
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_number_with_format(ROW_MAX, 0, 0, &format);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_number(ROW_MAX, 0, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.set_row_height(ROW_MAX, 20);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.set_row_height_pixels(ROW_MAX, 20);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.set_row_format(ROW_MAX, &format);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.set_column_width(COL_MAX, 20);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.set_column_width_pixels(COL_MAX, 20);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.set_column_format(COL_MAX, &format);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn set_column_width_pixels() {
+        let mut worksheet = Worksheet::new();
+
+        // Test the boundary between the widths with and without padding.
+        let widths = [
+            (1, 1.0 / 12.0, 0.140625),
+            (6, 0.5, 0.85546875),
+            (11, 11.0 / 12.0, 1.5703125),
+            (12, 1.0, 1.7109375),
+            (13, 8.0 / 7.0, 1.85546875),
+            (64, 59.0 / 7.0, 9.140625),
+            (117, 16.0, 16.7109375),
+        ];
+
+        for (col, &(pixels, width, stored_width)) in widths.iter().enumerate() {
+            let col = col as ColNum;
+            worksheet.set_column_width_pixels(col, pixels).unwrap();
+
+            let col_width = worksheet.changed_cols.get(&col).unwrap().width.unwrap();
+            assert_eq!(width, col_width, "pixels: {pixels}");
+            assert_eq!(
+                stored_width,
+                Worksheet::column_character_width(col_width),
+                "pixels: {pixels}"
+            );
+
+            // The width should convert back to the same number of pixels.
+            let pixel_width =
+                worksheet.column_pixel_width(col, ObjectMovement::MoveAndSizeWithCells);
+            assert_eq!(u32::from(pixels), pixel_width);
+        }
+
+        // A zero width hides the column.
+        worksheet.set_column_width_pixels(10, 0).unwrap();
+        assert!(worksheet.changed_cols.get(&10).unwrap().hidden);
+    }
+
+    #[test]
+    fn write_number_from_str() {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+
+        let numbers = [
+            ("1234", 1234.0),
+            ("-12.5", -12.5),
+            ("1.5e3", 1500.0),
+            (" 42\t", 42.0),
+        ];
+
+        for (row, &(string, expected)) in numbers.iter().enumerate() {
+            worksheet
+                .write_number_from_str(row as RowNum, 0, string)
+                .unwrap();
+
+            match worksheet
+                .data_table
+                .get(&(row as RowNum))
+                .and_then(|row| row.get(&0))
+            {
+                Some(CellType::Number { number, xf_index }) => {
+                    assert_eq!(expected, *number);
+                    assert_eq!(0, *xf_index);
+                }
+                _ => panic!("expected a number cell for '{string}'"),
+            }
+        }
+
+        worksheet
+            .write_number_from_str_with_format(0, 1, "0.5", &bold)
+            .unwrap();
+
+        match worksheet.data_table.get(&0).and_then(|row| row.get(&1)) {
+            Some(CellType::Number { number, xf_index }) => {
+                assert_eq!(0.5, *number);
+                assert_eq!(bold, worksheet.xf_formats[*xf_index as usize]);
+            }
+            _ => panic!("expected a number cell"),
+        }
+
+        // Strings that aren't finite numbers are errors.
+        for string in ["", "N/A", "1,234", "$10", "NaN", "inf"] {
+            let result = worksheet.write_number_from_str(10, 0, string);
+            assert!(
+                matches!(result, Err(XlsxError::ParameterError(_))),
+                "string: '{string}'"
+            );
+        }
+        assert!(!worksheet.data_table.contains_key(&10));
+
+        let result = worksheet.write_number_from_str(ROW_MAX, 0, "1");
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn write_row_iter() {
+        let mut worksheet = Worksheet::new();
+
+        let last_col = worksheet.write_row_iter(0, 2, (1..=3).map(|n| n * 10));
+        assert_eq!(Some(4), last_col.unwrap());
+
+        let last_col = worksheet.write_row_iter(1, 2, std::iter::empty::<f64>());
+        assert_eq!(None, last_col.unwrap());
+
+        let last_col = worksheet.write_row_iter(2, COL_MAX - 2, 1..=3);
+        assert!(matches!(last_col, Err(XlsxError::RowColumnLimitError)));
+
+        assert_eq!(3, worksheet.data_table[&0].len());
+        assert!(worksheet.data_table[&0].contains_key(&4));
+    }
+
+    #[test]
+    fn long_string() {
+        let mut worksheet = Worksheet::new();
+        let chars: [u8; 32_768] = [64; 32_768];
+        let long_string = std::str::from_utf8(&chars);
+
+        let result = worksheet.write_string(0, 0, long_string.unwrap());
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
+    }
+
+    #[test]
+    fn export_to_csv_and_tsv() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write(0, 1, "Name").unwrap();
+        worksheet.write(0, 2, "Value").unwrap();
+        worksheet.write(1, 1, "Say \"hi\", please").unwrap();
+        worksheet.write(1, 2, 1.5).unwrap();
+        worksheet.write(2, 1, true).unwrap();
+        worksheet
+            .write(2, 2, Formula::new("=1+1").set_result("2"))
+            .unwrap();
+        worksheet
+            .write(3, 1, Formula::new("=A2").set_result("0"))
+            .unwrap();
+        worksheet.write(3, 2, Formula::new("=A1")).unwrap();
+        worksheet.write(4, 1, "a\tb").unwrap();
+
+        let mut buffer = vec![];
+        worksheet.to_csv(&mut buffer).unwrap();
+        let got = String::from_utf8(buffer).unwrap();
+
+        let expected = concat!(
+            ",Name,Value\r\n",
+            ",\"Say \"\"hi\"\", please\",1.5\r\n",
+            ",TRUE,2\r\n",
+            ",0,\r\n",
+            ",a\tb,\r\n",
+        );
+        assert_eq!(expected, got);
+
+        let mut buffer = vec![];
+        worksheet.to_tsv(&mut buffer).unwrap();
+        let got = String::from_utf8(buffer).unwrap();
+
+        let expected = concat!(
+            "\tName\tValue\r\n",
+            "\t\"Say \"\"hi\"\", please\"\t1.5\r\n",
+            "\tTRUE\t2\r\n",
+            "\t0\t\r\n",
+            "\t\"a\tb\"\t\r\n",
+        );
+        assert_eq!(expected, got);
+
+        // An empty worksheet has no output.
+        let mut buffer = vec![];
+        Worksheet::new().to_csv(&mut buffer).unwrap();
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn write_url_with_text_and_format() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new().set_bold();
+
+        worksheet
+            .write_url_with_text_and_format(0, 0, "https://crates.io", "Crates", &format)
+            .unwrap();
+        worksheet
+            .write_url_with_text_and_format(1, 0, "https://docs.rs", "", &format)
+            .unwrap();
+
+        let text = |row| match &worksheet.data_table[&row][&0] {
+            CellType::String {
+                string, xf_index, ..
+            } => (string.to_string(), *xf_index),
+            _ => unreachable!(),
+        };
+
+        // The text and format are applied and empty text falls back to the url.
+        assert_eq!(("Crates".to_string(), 1), text(0));
+        assert_eq!(("https://docs.rs".to_string(), 1), text(1));
+        assert_eq!("https://crates.io", worksheet.hyperlinks[&(0, 0)].url_link);
+    }
+
+    #[test]
+    fn unprotected_range_names() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet
+            .unprotect_range_with_options(0, 0, 0, 0, "Range2", "")
+            .unwrap();
+        worksheet.unprotect_range(1, 0, 1, 0).unwrap();
+        worksheet.unprotect_range(2, 0, 2, 0).unwrap();
+
+        // Default names skip any user defined names.
+        let names: Vec<&str> = worksheet
+            .unprotected_ranges
+            .iter()
+            .map(|(_, name, _)| name.as_str())
+            .collect();
+        assert_eq!(vec!["Range2", "Range3", "Range4"], names);
+
+        // User defined names must be unique, ignoring case.
+        let result = worksheet.unprotect_range_with_options(3, 0, 3, 0, "range3", "");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn shared_hyperlink_relationships() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet
+            .write(0, 0, Url::new("https://www.rust-lang.org"))
+            .unwrap();
+        worksheet
+            .write(1, 0, Url::new("https://crates.io"))
+            .unwrap();
+        worksheet
+            .write(2, 0, Url::new("https://www.rust-lang.org"))
+            .unwrap();
+        worksheet
+            .write(3, 0, Url::new("internal:Sheet1!A1"))
+            .unwrap();
+
+        // The default is one relationship per external link, like Excel.
+        worksheet.write_hyperlinks();
+        assert_eq!(3, worksheet.hyperlink_relationships.len());
+        assert_eq!(3, worksheet.rel_count);
+
+        worksheet.reset();
+        worksheet.set_shared_hyperlink_relationships(true);
+        worksheet.write_hyperlinks();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <hyperlinks>
+              <hyperlink ref="A1" r:id="rId1"/>
+              <hyperlink ref="A2" r:id="rId2"/>
+              <hyperlink ref="A3" r:id="rId1"/>
+              <hyperlink ref="A4" location="Sheet1!A1" display="Sheet1!A1"/>
+            </hyperlinks>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+        assert_eq!(2, worksheet.hyperlink_relationships.len());
+        assert_eq!(2, worksheet.rel_count);
+    }
+
+    #[test]
+    fn test_assemble_sheet_view_builder() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet
+            .view()
+            .right_to_left()
+            .zoom(150)
+            .hide_gridlines()
+            .select(3, 2)
+            .unwrap();
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView showGridLines="0" rightToLeft="1" zoomScale="150" zoomScaleNormal="150" workbookViewId="0">
+                  <selection activeCell="C4" sqref="C4"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_outline_settings() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_outline_settings(false, true);
+        worksheet.set_tab_color("#FF0000");
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <sheetPr>
+                <tabColor rgb="FFFF0000"/>
+                <outlinePr summaryBelow="0"/>
+              </sheetPr>
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn dimension_ref() {
+        // An empty worksheet has a default dimension of A1.
+        let mut worksheet = Worksheet::new();
+        worksheet.write_dimension();
+
+        let got = worksheet.writer.read_to_str();
+        assert_eq!(r#"<dimension ref="A1"/>"#, got);
+
+        // The dimension covers the written cells and any merged ranges.
+        let mut worksheet = Worksheet::new();
+        let format = Format::new();
+
+        worksheet.write_string(2, 1, "Top left").unwrap();
+        worksheet.write_number(5, 3, 123).unwrap();
+        worksheet
+            .merge_range(8, 3, 9, 4, "Merged", &format)
+            .unwrap();
+        worksheet.write_dimension();
+
+        let got = worksheet.writer.read_to_str();
+        assert_eq!(r#"<dimension ref="B3:E10"/>"#, got);
+    }
+
+    #[test]
+    fn note_anchor_on_merged_range() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new();
+        let note = Note::new("Some text");
+
+        // A note on an unmerged cell is anchored one column to the right.
+        worksheet.insert_note(1, 1, &note).unwrap();
+        worksheet.prepare_vml_objects(1, 1024);
+
+        let from = &worksheet.comments_vml_info[0].drawing_info.from;
+        assert_eq!((0, 2), (from.row, from.col));
+
+        // A note on a merged range is anchored to the right of the range.
+        let mut worksheet = Worksheet::new();
+        worksheet
+            .merge_range(1, 1, 2, 3, "Merged", &format)
+            .unwrap();
+        worksheet.insert_note(1, 1, &note).unwrap();
+        worksheet.prepare_vml_objects(1, 1024);
+
+        let vml_info = &worksheet.comments_vml_info[0];
+        let from = &vml_info.drawing_info.from;
+        assert_eq!((0, 4), (from.row, from.col));
+        assert_eq!((1, 1), (vml_info.row, vml_info.col));
+    }
+
+    #[test]
+    fn note_autosize() {
+        let mut worksheet = Worksheet::new();
+
+        // Short text with the default author prefix.
+        let note = Note::new("Short").set_autosize(true);
+        worksheet.insert_note(0, 0, &note).unwrap();
+
+        // A long line that is wrapped at the maximum width.
+        let note = Note::new("x".repeat(100))
+            .add_author_prefix(false)
+            .set_autosize(true);
+        worksheet.insert_note(1, 0, &note).unwrap();
+
+        // Multiple lines are sized by the widest line and the line count.
+        let note = Note::new("a\nbbbb\nc")
+            .add_author_prefix(false)
+            .set_autosize(true);
+        worksheet.insert_note(2, 0, &note).unwrap();
+
+        // Notes without autosize keep the default dimensions.
+        let note = Note::new("x".repeat(100));
+        worksheet.insert_note(3, 0, &note).unwrap();
+
+        worksheet.prepare_vml_objects(1, 1024);
+
+        let dimensions: Vec<(f64, f64)> = worksheet
+            .comments_vml_info
+            .iter()
+            .map(|vml_info| (vml_info.width, vml_info.height))
+            .collect();
+
+        assert_eq!(
+            vec![(52.0, 36.0), (320.0, 36.0), (34.0, 49.0), (128.0, 74.0)],
+            dimensions
+        );
+
+        // Test the worksheet default and a note that overrides it.
+        let mut worksheet = Worksheet::new();
+        worksheet.autosize_all_notes(true);
+
+        let note = Note::new("Short");
+        worksheet.insert_note(0, 0, &note).unwrap();
+
+        let note = Note::new("Short").set_autosize(false);
+        worksheet.insert_note(1, 0, &note).unwrap();
+
+        worksheet.prepare_vml_objects(1, 1024);
+
+        assert_eq!(52.0, worksheet.comments_vml_info[0].width);
+        assert_eq!(128.0, worksheet.comments_vml_info[1].width);
+    }
+
+    #[test]
+    fn ignore_errors() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet
+            .ignore_error(0, 0, IgnoreError::NumberStoredAsText)
+            .unwrap();
+        worksheet
+            .ignore_error_range(2, 1, 4, 2, IgnoreError::NumberStoredAsText)
+            .unwrap();
+        worksheet
+            .ignore_error(5, 0, IgnoreError::EvalError)
+            .unwrap();
+
+        worksheet.write_ignored_errors();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <ignoredErrors>
+              <ignoredError sqref="A6" evalError="1"/>
+              <ignoredError sqref="A1 B3:C5" numberStoredAsText="1"/>
+            </ignoredErrors>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        let result = worksheet.ignore_error(ROW_MAX, 0, IgnoreError::EvalError);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.ignore_error_range(1, 0, 0, 0, IgnoreError::EvalError);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+    }
+
+    #[test]
+    fn write_number_as_text() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_number_as_text(0, 0, "00123").unwrap();
+        worksheet.write_number_as_text(0, 0, "00123").unwrap();
+
+        match worksheet.data_table.get(&0).unwrap().get(&0) {
+            Some(CellType::String {
+                string, xf_index, ..
+            }) => {
+                assert_eq!("00123", string.as_ref());
+
+                let format = &worksheet.xf_formats[*xf_index as usize];
+                assert_eq!("@", format.num_format);
+                assert!(format.quote_prefix);
+            }
+            _ => unreachable!(),
+        }
+
+        assert_eq!(
+            Some(&vec!["A1".to_string()]),
+            worksheet
+                .ignored_errors
+                .get(&IgnoreError::NumberStoredAsText)
+        );
+
+        let result = worksheet.write_number_as_text(1, 0, "ABC123");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn apply_view_preset() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.apply_view_preset(ViewPreset::Presentation);
+        worksheet.write_sheet_view();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <sheetView showGridLines="0" showRowColHeaders="0" zoomScale="130" zoomScaleNormal="130" workbookViewId="0">
+              <pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>
+              <selection pane="bottomLeft"/>
+            </sheetView>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Switch back to the default view.
+        let mut worksheet = Worksheet::new();
+
+        worksheet.apply_view_preset(ViewPreset::Presentation);
+        worksheet.apply_view_preset(ViewPreset::DataEntry);
+        worksheet.write_sheet_view();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <sheetView workbookViewId="0"/>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn print_fit_to_pages_width_only() {
+        let mut worksheet = Worksheet::new();
+
+        // Fit to 1 page wide and as many pages tall as necessary.
+        worksheet.set_print_fit_to_pages(1, 0);
+        worksheet.write_sheet_pr();
+        worksheet.write_page_setup();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <sheetPr>
+              <pageSetUpPr fitToPage="1"/>
+            </sheetPr>
+            <pageSetup fitToHeight="0" orientation="portrait" horizontalDpi="200" verticalDpi="200"/>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn printer_settings() {
+        let mut worksheet = Worksheet::new();
+
+        // The relationship id follows any hyperlink relationships.
+        worksheet.set_printer_settings(&[1, 2, 3]);
+        worksheet.rel_count = 1;
+        worksheet.write_page_setup();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <pageSetup orientation="portrait" horizontalDpi="200" verticalDpi="200" r:id="rId2"/>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn autofit_default_font_size() {
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "Hello world").unwrap();
+        worksheet.autofit();
+        let default_width = worksheet.changed_cols[&0].width.unwrap();
+
+        // A larger default font gives a proportionally wider column.
+        let mut worksheet = Worksheet::new();
+        worksheet.default_font_size = 22.0;
+        worksheet.write(0, 0, "Hello world").unwrap();
+        worksheet.autofit();
+        let scaled_width = worksheet.changed_cols[&0].width.unwrap();
+
+        assert!(scaled_width > default_width * 1.9);
+    }
+
+    #[test]
+    fn set_column_group() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.set_column_group(1, 3, 1, false).unwrap();
+        worksheet.set_column_group(5, 6, 1, true).unwrap();
+        worksheet.set_column_group(2, 2, 2, false).unwrap();
+
+        worksheet.write_sheet_format_pr();
+        worksheet.write_cols();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <sheetFormatPr defaultRowHeight="15" outlineLevelCol="2"/>
+            <cols>
+              <col min="2" max="2" width="9.140625" outlineLevel="1"/>
+              <col min="3" max="3" width="9.140625" outlineLevel="2"/>
+              <col min="4" max="4" width="9.140625" outlineLevel="1"/>
+              <col min="6" max="7" width="0" hidden="1" customWidth="1" outlineLevel="1"/>
+              <col min="8" max="8" width="9.140625" collapsed="1"/>
+            </cols>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // The summary column is to the left of the group with the alternative
+        // outline settings.
+        let mut worksheet = Worksheet::new();
+
+        worksheet.set_outline_settings(true, false);
+        worksheet.set_column_group(5, 6, 1, true).unwrap();
+        worksheet.write_cols();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <cols>
+              <col min="5" max="5" width="9.140625" collapsed="1"/>
+              <col min="6" max="7" width="0" hidden="1" customWidth="1" outlineLevel="1"/>
+            </cols>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Check the parameter validation.
+        let result = worksheet.set_column_group(3, 2, 1, false);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result = worksheet.set_column_group(0, 2, 8, false);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn configure() {
+        let mut worksheet = Worksheet::new();
+
+        let options = WorksheetOptions::new()
+            .set_tab_color(Color::Red)
+            .set_right_to_left(true)
+            .set_zoom(150)
+            .set_screen_gridlines(false)
+            .set_protection_password("password");
+
+        worksheet.configure(&options).unwrap();
+        worksheet.write_sheet_view();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <sheetView showGridLines="0" rightToLeft="1" zoomScale="150" zoomScaleNormal="150" workbookViewId="0"/>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+        assert_eq!(Color::Red, worksheet.tab_color);
+        assert!(worksheet.protection_on);
+        assert_eq!(0x83AF, worksheet.protection_hash);
+
+        // Invalid options shouldn't change the worksheet.
+        let mut worksheet = Worksheet::new();
+
+        let options = WorksheetOptions::new()
+            .set_tab_color(Color::Red)
+            .set_hidden(true)
+            .set_zoom(500);
+
+        let result = worksheet.configure(&options);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        assert_eq!(Color::Default, worksheet.tab_color);
+        assert!(worksheet.visible == Visible::Default);
+        assert_eq!(100, worksheet.zoom);
+    }
+
+    #[test]
+    fn clear_range() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new().set_bold();
+
+        for row in 0..5 {
+            worksheet.write_row(row, 0, [1, 2, 3, 4]).unwrap();
+        }
+        worksheet
+            .merge_range(6, 0, 6, 1, "Merge1", &format)
+            .unwrap();
+        worksheet
+            .merge_range(6, 2, 7, 3, "Merge2", &format)
+            .unwrap();
+        worksheet
+            .merge_range(9, 0, 9, 3, "Merge3", &format)
+            .unwrap();
+
+        // Clear part of the data and a merged range.
+        worksheet.clear_range(1, 0, 6, 1).unwrap();
+
+        let got: Vec<(RowNum, Vec<ColNum>)> = worksheet
+            .data_table
+            .iter()
+            .map(|(&row, columns)| (row, columns.keys().copied().collect()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (0, vec![0, 1, 2, 3]),
+                (1, vec![2, 3]),
+                (2, vec![2, 3]),
+                (3, vec![2, 3]),
+                (4, vec![2, 3]),
+                (6, vec![2, 3]),
+                (7, vec![2, 3]),
+                (9, vec![0, 1, 2, 3]),
+            ],
+            got
+        );
+
+        // The remaining merged ranges are re-indexed.
+        let ranges: Vec<String> = worksheet
+            .merged_ranges
+            .iter()
+            .map(|range| {
+                utility::cell_range(
+                    range.first_row,
+                    range.first_col,
+                    range.last_row,
+                    range.last_col,
+                )
+            })
+            .collect();
+
+        assert_eq!(vec!["C7:D8", "A10:D10"], ranges);
+        assert_eq!(Some(&0), worksheet.merged_cells.get(&(7, 3)));
+        assert_eq!(Some(&1), worksheet.merged_cells.get(&(9, 0)));
+        assert_eq!(None, worksheet.merged_cells.get(&(6, 0)));
+
+        // Partial overlap of a merged range.
+        let result = worksheet.clear_range(7, 3, 9, 3);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Invalid ranges.
+        let result = worksheet.clear_range(2, 0, 1, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result = worksheet.clear_range(0, 0, ROW_MAX, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        // Hyperlinks and value metadata are cleared with the cell data.
+        let mut worksheet = Worksheet::new();
+        worksheet
+            .write_url(0, 0, "https://www.rust-lang.org")
+            .unwrap();
+        worksheet
+            .write_url(0, 2, "https://www.rust-lang.org")
+            .unwrap();
+        worksheet.write_with_metadata(1, 0, 1, 0).unwrap();
+
+        worksheet.clear_range(0, 0, 1, 1).unwrap();
+
+        assert_eq!(
+            vec![&(0, 2)],
+            worksheet.hyperlinks.keys().collect::<Vec<_>>()
+        );
+        assert!(worksheet.value_metadata.is_empty());
+    }
+
+    #[test]
+    fn insert_notes() {
+        let mut worksheet = Worksheet::new();
+        worksheet.write_number(0, 0, 42).unwrap();
+
+        let notes = HashMap::from([((0, 0), "Note 1"), ((0, 2), "Note 2"), ((3, 1), "Note 3")]);
+        worksheet.insert_notes(&notes).unwrap();
+
+        // The cell value and the note coexist.
+        assert!(matches!(
+            worksheet.data_table.get(&0).unwrap().get(&0),
+            Some(CellType::Number { .. })
+        ));
+
+        let got: Vec<(RowNum, ColNum, &str)> = worksheet
+            .notes
+            .iter()
+            .flat_map(|(&row, columns)| {
+                columns
+                    .iter()
+                    .map(move |(&col, note)| (row, col, note.text.as_str()))
+            })
+            .collect();
+
+        assert_eq!(
+            vec![(0, 0, "Note 1"), (0, 2, "Note 2"), (3, 1, "Note 3")],
+            got
+        );
+
+        // Nothing is added if any of the cells are out of range.
+        let mut worksheet = Worksheet::new();
+        let notes = HashMap::from([((0, 0), "Note 1"), ((ROW_MAX, 0), "Note 2")]);
+        let result = worksheet.insert_notes(&notes);
+
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(worksheet.notes.is_empty());
+    }
+
+    #[test]
+    fn localize_formulas() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write_formula(0, 0, "=SUMME(A2:A3)").unwrap();
+        worksheet.write_formula(1, 0, "=SUM(A2:A3)").unwrap();
+        worksheet.write_formula(2, 0, "=SEQUENZ(3)").unwrap();
+        worksheet
+            .write_array_formula(3, 0, 4, 0, "=RUNDEN(A2:A3,0)")
+            .unwrap();
+
+        worksheet.localize_formulas(FormulaLocale::German);
+
+        let formulas: Vec<(&str, bool)> = (0..=3)
+            .map(
+                |row| match worksheet.data_table.get(&row).unwrap().get(&0) {
+                    Some(CellType::Formula { formula, .. }) => (formula.as_ref(), false),
+                    Some(CellType::ArrayFormula {
+                        formula,
+                        is_dynamic,
+                        ..
+                    }) => (formula.as_ref(), *is_dynamic),
+                    _ => unreachable!(),
+                },
+            )
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("SUM(A2:A3)", false),
+                ("SUM(A2:A3)", false),
+                ("_xlfn.SEQUENCE(3)", true),
+                ("ROUND(A2:A3,0)", false),
+            ],
+            formulas
+        );
+        assert!(worksheet.has_dynamic_arrays);
+    }
+
+    #[test]
+    fn test_assemble_formula_results() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        for row in 0..5 {
+            worksheet.write_formula(row, 0, "=A10").unwrap();
+        }
+
+        let results = HashMap::from([
+            ((0, 0), "42"),
+            ((1, 0), "TRUE"),
+            ((2, 0), "false"),
+            ((3, 0), "#DIV/0!"),
+            ((4, 0), "Text"),
+        ]);
+        worksheet.set_formula_results(&results);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A5"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1">
+                    <f>A10</f>
+                    <v>42</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:1">
+                  <c r="A2" t="str">
+                    <f>A10</f>
+                    <v>TRUE</v>
+                  </c>
+                </row>
+                <row r="3" spans="1:1">
+                  <c r="A3" t="str">
+                    <f>A10</f>
+                    <v>false</v>
+                  </c>
+                </row>
+                <row r="4" spans="1:1">
+                  <c r="A4" t="str">
+                    <f>A10</f>
+                    <v>#DIV/0!</v>
+                  </c>
+                </row>
+                <row r="5" spans="1:1">
+                  <c r="A5" t="str">
+                    <f>A10</f>
+                    <v>Text</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_formula_typed_results() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        for row in 0..5 {
+            worksheet.write_formula(row, 0, "=A10").unwrap();
+        }
+
+        let results = HashMap::from([
+            ((0, 0), FormulaResult::Number(42.0)),
+            ((1, 0), FormulaResult::Boolean(true)),
+            ((2, 0), FormulaResult::Boolean(false)),
+            ((3, 0), FormulaResult::Error("#DIV/0!".to_string())),
+            ((4, 0), FormulaResult::String("42".to_string())),
+        ]);
+        worksheet.set_formula_typed_results(&results);
+
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A5"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1">
+                    <f>A10</f>
+                    <v>42</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:1">
+                  <c r="A2" t="b">
+                    <f>A10</f>
+                    <v>1</v>
+                  </c>
+                </row>
+                <row r="3" spans="1:1">
+                  <c r="A3" t="b">
+                    <f>A10</f>
+                    <v>0</v>
+                  </c>
+                </row>
+                <row r="4" spans="1:1">
+                  <c r="A4" t="e">
+                    <f>A10</f>
+                    <v>#DIV/0!</v>
+                  </c>
+                </row>
+                <row r="5" spans="1:1">
+                  <c r="A5" t="str">
+                    <f>A10</f>
+                    <v>42</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
     }
 }
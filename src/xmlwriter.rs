@@ -164,17 +164,17 @@ impl XMLWriter {
 }
 
 // Escape XML characters in attributes.
-pub(crate) fn escape_attributes(attribute: &str) -> Cow<str> {
+pub(crate) fn escape_attributes(attribute: &str) -> Cow<'_, str> {
     escape_string(attribute, match_attribute_html_char)
 }
 
 // Escape XML characters in data sections of tags.
-pub(crate) fn escape_xml_data(data: &str) -> Cow<str> {
+pub(crate) fn escape_xml_data(data: &str) -> Cow<'_, str> {
     escape_string(data, match_xml_char)
 }
 
 // Escape non-url characters in a hyperlink/url.
-pub(crate) fn escape_url(data: &str) -> Cow<str> {
+pub(crate) fn escape_url(data: &str) -> Cow<'_, str> {
     escape_string(data, match_url_char)
 }
 
@@ -190,7 +190,7 @@ fn match_attribute_html_char(ch: char) -> Option<&'static str> {
         '<' => Some("&lt;"),
         '>' => Some("&gt;"),
         '\n' => Some("&#xA;"),
-        _ => None,
+        _ => match_control_char(ch),
     }
 }
 
@@ -198,18 +198,25 @@ fn match_attribute_html_char(ch: char) -> Option<&'static str> {
 //
 // Note, this is different from match_attribute_html_char() because double
 // quotes and newline are not escaped by Excel.
-//
-// We need to mimic Excel by escaping control and non-printing characters in the
-// range '\x00' - '\x1F'.
 fn match_xml_char(ch: char) -> Option<&'static str> {
     match ch {
         // Standard XML escapes.
         '&' => Some("&amp;"),
         '<' => Some("&lt;"),
         '>' => Some("&gt;"),
+        _ => match_control_char(ch),
+    }
+}
 
-        // Excel escapes control characters and other non-printing characters in
-        // the range '\x00' - '\x1F' with _xHHHH_.
+// Match characters that aren't allowed in XML 1.0 documents.
+//
+// We need to mimic Excel by escaping control and non-printing characters in the
+// range '\x00' - '\x1F', and the non-characters U+FFFE and U+FFFF, with
+// _xHHHH_. Without this the characters would be written raw and Excel would
+// reject the file as invalid XML. Note, unpaired UTF-16 surrogates can't occur
+// since a Rust `str` is always valid UTF-8.
+fn match_control_char(ch: char) -> Option<&'static str> {
+    match ch {
         '\x00' => Some("_x0000_"),
         '\x01' => Some("_x0001_"),
         '\x02' => Some("_x0002_"),
@@ -242,6 +249,8 @@ fn match_xml_char(ch: char) -> Option<&'static str> {
         '\x1D' => Some("_x001D_"),
         '\x1E' => Some("_x001E_"),
         '\x1F' => Some("_x001F_"),
+        '\u{FFFE}' => Some("_xFFFE_"),
+        '\u{FFFF}' => Some("_xFFFF_"),
 
         _ => None,
     }
@@ -266,7 +275,7 @@ fn match_url_char(ch: char) -> Option<&'static str> {
 }
 
 // Generic escape function with function pointer for the required handler.
-fn escape_string<F>(original: &str, char_handler: F) -> Cow<str>
+fn escape_string<F>(original: &str, char_handler: F) -> Cow<'_, str>
 where
     F: FnOnce(char) -> Option<&'static str> + Copy,
 {
@@ -290,30 +299,34 @@ where
     Cow::Borrowed(original)
 }
 
-// Excel escapes control characters with _xHHHH_, see match_xml_char() above. As
-// a result it also escapes any literal strings of that type by encoding the
-// leading underscore. So  "_x0000_" -> _x005F_x0000_.
-fn escape_xml_escapes(original: &str) -> Cow<str> {
-    if !original.contains("_x00") {
+// Excel escapes control characters with _xHHHH_, see match_control_char()
+// above. As a result it also escapes any literal strings of that type by
+// encoding the leading underscore. So  "_x0000_" -> _x005F_x0000_. This also
+// applies to literal escapes outside the control range, such as "_xD800_",
+// which Excel would otherwise decode to an invalid unpaired surrogate.
+fn escape_xml_escapes(original: &str) -> Cow<'_, str> {
+    if !original.contains("_x") {
         return Cow::Borrowed(original);
     }
 
-    let string_end = original.len();
+    let bytes = original.as_bytes();
     let escape_length = "_x0000_".len();
     let mut escaped_string = original.to_string();
 
     // Match from right so we can escape target string at the same indices.
-    let matches: Vec<_> = original.rmatch_indices("_x00").collect();
+    let matches: Vec<_> = original.rmatch_indices("_x").collect();
 
     for (index, _) in matches {
-        if index + escape_length > string_end {
+        if index + escape_length > bytes.len() {
             continue;
         }
 
-        // Check that the digits in _xABCD_ are a valid hex code.
-        if original[index + 2..index + 6]
-            .chars()
-            .all(|c| c.is_ascii_hexdigit())
+        // Check that the digits in _xABCD_ are a valid hex code. Bytes are used
+        // to avoid slicing through any multi-byte characters.
+        if bytes[index + 2..index + 6]
+            .iter()
+            .all(u8::is_ascii_hexdigit)
+            && bytes[index + 6] == b'_'
         {
             escaped_string.replace_range(index..index, "_x005F");
         }
@@ -160,4 +160,39 @@ mod xmlwriter_tests {
         let got = writer.read_to_str();
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_xml_data_element_with_control_chars() {
+        let expected = r#"<foo text="a_x0001_b&#xA;c_x001F_">a_x0001_b
+c_x001F_	_xFFFE_</foo>"#;
+        let attributes = vec![("text", "a\x01b\nc\x1F")];
+
+        let mut writer = XMLWriter::new();
+        writer.xml_data_element("foo", "a\x01b\nc\x1F\t\u{FFFE}", &attributes);
+
+        let got = writer.read_to_str();
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_xml_si_element_with_tricky_strings() {
+        let strings = [
+            ("\x00", "<si><t>_x0000_</t></si>"),
+            ("&<>\"", "<si><t>&amp;&lt;&gt;\"</t></si>"),
+            ("_x0041_", "<si><t>_x005F_x0041_</t></si>"),
+            ("_xD800_", "<si><t>_x005F_xD800_</t></si>"),
+            ("_xD800", "<si><t>_xD800</t></si>"),
+            ("_x000é", "<si><t>_x000é</t></si>"),
+            ("_x0é", "<si><t>_x0é</t></si>"),
+            ("😀\x07", "<si><t>😀_x0007_</t></si>"),
+        ];
+
+        for (string, expected) in strings {
+            let mut writer = XMLWriter::new();
+            writer.xml_si_element(string, false);
+
+            let got = writer.read_to_str();
+            assert_eq!(expected, got);
+        }
+    }
 }
@@ -37,6 +37,35 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test that the autofilter range is extended to the data written below it.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.autofilter(0, 0, 10, 3)?;
+
+    // Write the headers.
+    worksheet.write_string(0, 0, "Region")?;
+    worksheet.write_string(0, 1, "Item")?;
+    worksheet.write_string(0, 2, "Volume")?;
+    worksheet.write_string(0, 3, "Month")?;
+
+    // Write the data used in the autofilter.
+    let data = common::get_autofilter_data();
+    for (row, data) in data.iter().enumerate() {
+        let row = 1 + row as u32;
+        worksheet.write_string(row, 0, data.0)?;
+        worksheet.write_string(row, 1, data.1)?;
+        worksheet.write_number(row, 2, data.2)?;
+        worksheet.write_string(row, 3, data.3)?;
+    }
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_autofilter01() {
     let test_runner = common::TestRunner::new()
@@ -47,3 +76,15 @@ fn test_autofilter01() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_autofilter01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("autofilter01")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
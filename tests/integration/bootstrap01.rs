@@ -109,6 +109,47 @@ fn create_new_xlsx_file_8(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case to demonstrate creating a basic file via a temporary file.
+fn create_new_xlsx_file_9(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let _worksheet = workbook.add_worksheet();
+
+    workbook.save_atomic(filename)?;
+
+    // The temporary file should have been renamed.
+    let temp_filename = format!("{filename}.tmp");
+    assert!(!Path::new(&temp_filename).exists());
+
+    Ok(())
+}
+
+// Test case to demonstrate creating a basic file with a progress callback.
+fn create_new_xlsx_file_10(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let _worksheet = workbook.add_worksheet();
+
+    let mut events = vec![];
+    workbook.save_with_progress(filename, |progress| events.push(progress))?;
+
+    // Each part should be reported once, in order.
+    assert_eq!("[Content_Types].xml", events[0].part_name);
+    for (index, progress) in events.iter().enumerate() {
+        assert_eq!(index + 1, progress.parts_written);
+        assert_eq!(1, progress.worksheet_count);
+    }
+
+    let worksheet = events
+        .iter()
+        .find(|progress| progress.part_name == "xl/worksheets/sheet1.xml")
+        .unwrap();
+    assert_eq!(1, worksheet.worksheets_written);
+
+    let last = events.last().unwrap();
+    assert!(last.bytes_written > worksheet.bytes_written);
+
+    Ok(())
+}
+
 #[test]
 fn bootstrap01_single_worksheet() {
     let test_runner = common::TestRunner::new()
@@ -204,3 +245,27 @@ fn bootstrap01_save_to_writer_buffer() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn bootstrap01_save_atomic() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap01")
+        .set_function(create_new_xlsx_file_9)
+        .unique("9")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn bootstrap01_save_with_progress() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap01")
+        .set_function(create_new_xlsx_file_10)
+        .unique("10")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
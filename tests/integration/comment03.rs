@@ -6,10 +6,10 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Note, Workbook, XlsxError};
+use rust_xlsxwriter::{CellBuilder, Note, Workbook, XlsxError};
 
 // Create rust_xlsxwriter file to compare against Excel file.
-fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
 
     let worksheet = workbook.add_worksheet();
@@ -26,11 +26,42 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test with a CellBuilder.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_default_note_author("John");
+
+    let note = Note::new("Some text").add_author_prefix(false);
+    let cell = CellBuilder::new("Foo").set_note(&note);
+
+    worksheet.write_cell(0, 0, &cell)?;
+    worksheet.insert_note(1048575, 16383, &note)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_comment03_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("comment03")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
 #[test]
-fn test_comment03() {
+fn test_comment03_2() {
     let test_runner = common::TestRunner::new()
         .set_name("comment03")
-        .set_function(create_new_xlsx_file)
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
         .initialize();
 
     test_runner.assert_eq();
@@ -18,6 +18,7 @@ macro_rules! assert_result {
 macro_rules! static_regex {
     ($re:literal) => {{
         static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+        #[allow(clippy::regex_creation_in_loops)]
         RE.get_or_init(|| regex::Regex::new($re).unwrap())
     }};
 }
@@ -70,7 +71,7 @@ where
     }
 
     // Set the testcase name.
-    pub fn set_name(mut self, testcase: &'a str) -> TestRunner<F> {
+    pub fn set_name(mut self, testcase: &'a str) -> TestRunner<'a, F> {
         self.test_name = testcase;
         self
     }
@@ -84,7 +85,7 @@ where
     // Set string to add to the default output filename to make it unique so
     // that the multiple tests can be run in parallel.
     #[allow(dead_code)]
-    pub fn unique(mut self, unique_string: &'a str) -> TestRunner<F> {
+    pub fn unique(mut self, unique_string: &'a str) -> TestRunner<'a, F> {
         self.unique = unique_string;
         self
     }
@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use rust_xlsxwriter::{Format, FormatAlign, Workbook, XlsxError};
 
 // Test case to test simple formatting.
 fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
@@ -25,6 +25,32 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test that resetting an explicit alignment reverts to the default number
+// alignment.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    let format1 = Format::new()
+        .set_bold()
+        .set_align(FormatAlign::Center)
+        .set_align(FormatAlign::VerticalCenter)
+        .reset_alignment();
+    let format2 = Format::new()
+        .set_bold()
+        .set_num_format_index(1)
+        .set_indent(1)
+        .reset_alignment();
+
+    worksheet.write_number_with_format(0, 0, 1, &format1)?;
+    worksheet.write_number_with_format(1, 0, 2, &format2)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_format15() {
     let test_runner = common::TestRunner::new()
@@ -35,3 +61,15 @@ fn test_format15() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_format15_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("format15")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
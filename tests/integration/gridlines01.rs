@@ -9,7 +9,7 @@ use crate::common;
 use rust_xlsxwriter::{Workbook, XlsxError};
 
 // Create rust_xlsxwriter file to compare against Excel file.
-fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
 
     let worksheet = workbook.add_worksheet();
@@ -24,11 +24,41 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test hiding the gridlines via the workbook default.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_default_hide_gridlines(true);
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_portrait();
+
+    worksheet.write(0, 0, "Foo")?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_gridlines01_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("gridlines01")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
 #[test]
-fn test_gridlines01() {
+fn test_gridlines01_2() {
     let test_runner = common::TestRunner::new()
         .set_name("gridlines01")
-        .set_function(create_new_xlsx_file)
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
         .initialize();
 
     test_runner.assert_eq();
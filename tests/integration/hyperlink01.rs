@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Format, Url, Workbook, XlsxError};
+use rust_xlsxwriter::{CellBuilder, Format, Url, Workbook, XlsxError};
 
 // Test to demonstrate simple hyperlinks.
 fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
@@ -36,6 +36,24 @@ fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test with a CellBuilder.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    let format = Format::default();
+
+    let cell = CellBuilder::new("http://www.perl.org/")
+        .set_format(&format)
+        .set_url("http://www.perl.org/")?;
+
+    worksheet.write_cell(0, 0, &cell)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_hyperlink01_1() {
     let test_runner = common::TestRunner::new()
@@ -59,3 +77,15 @@ fn test_hyperlink01_2() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_hyperlink01_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("hyperlink01")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
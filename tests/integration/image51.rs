@@ -31,6 +31,23 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test with the Worksheet::insert_image_with_url() shortcut.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    let image = Image::new("tests/input/images/red.png")?.set_alt_text("red.png");
+    worksheet.insert_image_with_url(8, 4, &image, "https://duckduckgo.com/?q=1")?;
+
+    let image = Image::new("tests/input/images/red2.png")?.set_alt_text("red2.png");
+    worksheet.insert_image_with_url(12, 4, &image, "https://duckduckgo.com/?q=2")?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_image51() {
     let test_runner = common::TestRunner::new()
@@ -41,3 +58,15 @@ fn test_image51() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_image51_with_url() {
+    let test_runner = common::TestRunner::new()
+        .set_name("image51")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -81,6 +81,30 @@ fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test to demonstrate adding image scaling. The cell sizes are set after the
+// images are fitted to the cells.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    let image = Image::new("tests/input/images/red.png")?.set_alt_text("red.png");
+
+    worksheet.insert_image(0, 0, &image)?;
+
+    worksheet.insert_image_fit_to_cell(2, 0, &image, false)?;
+
+    worksheet.insert_image_fit_to_cell(4, 0, &image, true)?;
+
+    worksheet.set_column_width_pixels(0, 192)?;
+    worksheet.set_row_height_pixels(2, 64)?;
+    worksheet.set_row_height_pixels(4, 64)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_image_scale01_1() {
     let test_runner = common::TestRunner::new()
@@ -116,3 +140,15 @@ fn test_image_scale01_3() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_image_scale01_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("image_scale01")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
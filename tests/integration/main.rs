@@ -708,6 +708,7 @@ mod object_position17;
 mod object_position18;
 mod object_position19;
 mod object_position20;
+mod outline01;
 mod overlay01;
 mod overlay02;
 mod overlay03;
@@ -897,6 +898,7 @@ mod top_left_cell01;
 mod top_left_cell02;
 mod top_left_cell03;
 mod utf8_06;
+mod value_metadata01;
 mod vml01;
 mod vml02;
 mod vml03;
@@ -0,0 +1,38 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+// Test to demonstrate column outline groups.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // A collapsed group with the summary column to the right.
+    worksheet.set_column_group(1, 6, 1, true)?;
+
+    // A nested group.
+    worksheet.set_column_group(9, 10, 1, false)?;
+    worksheet.set_column_group(10, 10, 2, false)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_outline01() {
+    let test_runner = common::TestRunner::new()
+        .set_name("outline01")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
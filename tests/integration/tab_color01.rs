@@ -9,7 +9,7 @@ use crate::common;
 use rust_xlsxwriter::{Color, Workbook, XlsxError};
 
 // Test to demonstrate setting the worksheet tab color.
-fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
 
     let worksheet = workbook.add_worksheet();
@@ -22,11 +22,39 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test setting the tab color via the workbook default.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_default_tab_color(Color::Red);
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(0, 0, "Foo")?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_tab_color01_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("tab_color01")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
 #[test]
-fn test_tab_color01() {
+fn test_tab_color01_2() {
     let test_runner = common::TestRunner::new()
         .set_name("tab_color01")
-        .set_function(create_new_xlsx_file)
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
         .initialize();
 
     test_runner.assert_eq();
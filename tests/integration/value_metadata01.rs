@@ -0,0 +1,35 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+// Test to demonstrate cells with value metadata.
+fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let stock = workbook.add_value_metadata("XLSTOCK");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_with_metadata(0, 0, "MSFT", stock)?;
+    worksheet.write_with_metadata(1, 0, 42, stock)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_value_metadata01() {
+    let test_runner = common::TestRunner::new()
+        .set_name("value_metadata01")
+        .set_function(create_new_xlsx_file)
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}